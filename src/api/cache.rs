@@ -1,4 +1,5 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 #[derive(Debug, Serialize)]
 pub struct PurgeAll {
@@ -7,7 +8,55 @@ pub struct PurgeAll {
 
 #[derive(Debug, Serialize)]
 pub struct PurgeFiles {
-    pub files: Vec<String>,
+    pub files: Vec<PurgeFile>,
+}
+
+/// A URL to purge, optionally narrowed to the cached variant selected by
+/// request headers (cache keys that vary on `Origin`, `CF-Device-Type`, ...)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(untagged)]
+pub enum PurgeFile {
+    Url(String),
+    WithHeaders {
+        url: String,
+        #[serde(default)]
+        headers: BTreeMap<String, String>,
+    },
+}
+
+impl PurgeFile {
+    pub fn url(&self) -> &str {
+        match self {
+            Self::Url(url) | Self::WithHeaders { url, .. } => url,
+        }
+    }
+
+    pub fn headers(&self) -> Option<&BTreeMap<String, String>> {
+        match self {
+            Self::Url(_) => None,
+            Self::WithHeaders { headers, .. } => Some(headers),
+        }
+    }
+}
+
+impl From<String> for PurgeFile {
+    fn from(url: String) -> Self {
+        Self::Url(url)
+    }
+}
+
+impl std::fmt::Display for PurgeFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.url())?;
+        if let Some(headers) = self.headers().filter(|h| !h.is_empty()) {
+            let headers: Vec<String> = headers
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, value))
+                .collect();
+            write!(f, " [{}]", headers.join(", "))?;
+        }
+        Ok(())
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -24,3 +73,57 @@ pub struct PurgeHosts {
 pub struct PurgePrefixes {
     pub prefixes: Vec<String>,
 }
+
+/// Body of a PATCH to a zone-level on/off setting
+#[derive(Debug, Serialize)]
+pub struct SetCacheSetting {
+    pub value: String,
+}
+
+/// Cache Reserve clear operation (one per zone at a time)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CacheReserveClear {
+    #[serde(default)]
+    pub id: Option<String>,
+    /// "In-progress" or "Completed"
+    pub state: String,
+    #[serde(default)]
+    pub start_ts: Option<String>,
+    #[serde(default)]
+    pub end_ts: Option<String>,
+}
+
+impl CacheReserveClear {
+    pub fn is_completed(&self) -> bool {
+        self.state.eq_ignore_ascii_case("completed")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_purge_file_serializes_plain_and_with_headers() {
+        let files: Vec<PurgeFile> = serde_json::from_value(serde_json::json!([
+            "https://example.com/a",
+            {"url": "https://example.com/b", "headers": {"CF-Device-Type": "mobile"}}
+        ]))
+        .unwrap();
+        assert_eq!(
+            files[0],
+            PurgeFile::Url("https://example.com/a".to_string())
+        );
+        assert_eq!(
+            files[1].to_string(),
+            "https://example.com/b [CF-Device-Type: mobile]"
+        );
+        assert_eq!(
+            serde_json::to_value(PurgeFiles { files }).unwrap(),
+            serde_json::json!({"files": [
+                "https://example.com/a",
+                {"url": "https://example.com/b", "headers": {"CF-Device-Type": "mobile"}}
+            ]})
+        );
+    }
+}
@@ -0,0 +1,202 @@
+use serde::{Deserialize, Serialize};
+
+/// Validity periods (days) accepted by the Origin CA
+pub const ORIGIN_CERT_VALIDITY_DAYS: &[u32] = &[7, 30, 90, 365, 730, 1095, 5475];
+
+/// Origin CA certificate representation from Cloudflare API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OriginCertificate {
+    /// Unique identifier for the certificate
+    pub id: String,
+    /// Hostnames (SANs) covered by the certificate
+    #[serde(default)]
+    pub hostnames: Vec<String>,
+    /// Signature type (origin-rsa, origin-ecc)
+    #[serde(default)]
+    pub request_type: Option<String>,
+    /// Validity period in days
+    #[serde(default)]
+    pub requested_validity: Option<u32>,
+    /// When the certificate expires
+    #[serde(default)]
+    pub expires_on: Option<String>,
+    /// PEM-encoded certificate (only returned on create and get)
+    #[serde(default)]
+    pub certificate: Option<String>,
+    /// PEM-encoded CSR the certificate was issued for
+    #[serde(default)]
+    pub csr: Option<String>,
+    /// When the certificate was revoked
+    #[serde(default)]
+    pub revoked_at: Option<String>,
+}
+
+/// Request payload for issuing an Origin CA certificate
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateOriginCertificate {
+    pub csr: String,
+    pub hostnames: Vec<String>,
+    /// origin-rsa or origin-ecc
+    pub request_type: String,
+    pub requested_validity: u32,
+}
+
+/// Key algorithm for a locally generated Origin CA key
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OriginKeyType {
+    Rsa,
+    Ecdsa,
+}
+
+impl OriginKeyType {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "rsa" => Some(Self::Rsa),
+            "ecdsa" => Some(Self::Ecdsa),
+            _ => None,
+        }
+    }
+
+    /// `request_type` value for this key type
+    pub fn request_type(&self) -> &'static str {
+        match self {
+            Self::Rsa => "origin-rsa",
+            Self::Ecdsa => "origin-ecc",
+        }
+    }
+}
+
+/// Certificate authorities accepted when ordering an advanced certificate pack
+pub const CERTIFICATE_AUTHORITIES: &[&str] = &["google", "lets_encrypt", "ssl_com"];
+/// Validity periods (days) accepted for advanced certificate packs
+pub const CERTIFICATE_PACK_VALIDITY_DAYS: &[u32] = &[14, 30, 90, 365];
+/// Domain control validation methods for advanced certificate packs
+pub const CERTIFICATE_PACK_VALIDATION_METHODS: &[&str] = &["txt", "http", "email"];
+
+/// Edge certificate pack (universal, advanced or custom) for a zone
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CertificatePack {
+    pub id: String,
+    /// Pack type (universal, advanced, ...)
+    #[serde(default)]
+    pub r#type: Option<String>,
+    /// Hostnames covered by the pack
+    #[serde(default)]
+    pub hosts: Vec<String>,
+    /// Pack status (active, pending_validation, expired, ...)
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub certificate_authority: Option<String>,
+    #[serde(default)]
+    pub validation_method: Option<String>,
+    #[serde(default)]
+    pub validity_days: Option<u32>,
+    /// Certificates in the pack (one per signature algorithm)
+    #[serde(default)]
+    pub certificates: Vec<PackCertificate>,
+}
+
+impl CertificatePack {
+    /// Earliest expiry across the pack's certificates
+    pub fn expires_on(&self) -> Option<&str> {
+        self.certificates
+            .iter()
+            .filter_map(|cert| cert.expires_on.as_deref())
+            .min()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PackCertificate {
+    pub id: String,
+    #[serde(default)]
+    pub hosts: Vec<String>,
+    #[serde(default)]
+    pub issuer: Option<String>,
+    #[serde(default)]
+    pub signature: Option<String>,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub expires_on: Option<String>,
+}
+
+/// Request payload for ordering an advanced certificate pack
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderCertificatePack {
+    /// Always "advanced"
+    pub r#type: String,
+    pub hosts: Vec<String>,
+    pub certificate_authority: String,
+    pub validation_method: String,
+    pub validity_days: u32,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub cloudflare_branding: bool,
+}
+
+/// Universal SSL settings for a zone
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UniversalSslSettings {
+    pub enabled: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_type_request_type() {
+        assert_eq!(OriginKeyType::parse("rsa"), Some(OriginKeyType::Rsa));
+        assert_eq!(OriginKeyType::Rsa.request_type(), "origin-rsa");
+        assert_eq!(OriginKeyType::Ecdsa.request_type(), "origin-ecc");
+        assert_eq!(OriginKeyType::parse("dsa"), None);
+    }
+
+    #[test]
+    fn test_origin_certificate_deserialize() {
+        let cert: OriginCertificate = serde_json::from_value(serde_json::json!({
+            "id": "328578533902268680212849205732770752308931942346",
+            "hostnames": ["example.com", "*.example.com"],
+            "request_type": "origin-rsa",
+            "requested_validity": 5475,
+            "expires_on": "2039-01-01 00:00:00 +0000 UTC",
+            "certificate": "-----BEGIN CERTIFICATE-----\n...\n-----END CERTIFICATE-----\n"
+        }))
+        .unwrap();
+        assert_eq!(cert.hostnames.len(), 2);
+        assert_eq!(cert.requested_validity, Some(5475));
+        assert!(cert.revoked_at.is_none());
+    }
+
+    #[test]
+    fn test_certificate_pack_earliest_expiry() {
+        let pack: CertificatePack = serde_json::from_value(serde_json::json!({
+            "id": "pack1",
+            "type": "universal",
+            "hosts": ["example.com", "*.example.com"],
+            "status": "active",
+            "certificates": [
+                {"id": "c1", "signature": "ECDSAWithSHA256", "expires_on": "2025-06-01T00:00:00Z"},
+                {"id": "c2", "signature": "SHA256WithRSA", "expires_on": "2025-05-01T00:00:00Z"}
+            ]
+        }))
+        .unwrap();
+        assert_eq!(pack.expires_on(), Some("2025-05-01T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_order_pack_omits_default_branding() {
+        let order = OrderCertificatePack {
+            r#type: "advanced".to_string(),
+            hosts: vec!["example.com".to_string()],
+            certificate_authority: "lets_encrypt".to_string(),
+            validation_method: "txt".to_string(),
+            validity_days: 90,
+            cloudflare_branding: false,
+        };
+        let json = serde_json::to_value(&order).unwrap();
+        assert!(json.get("cloudflare_branding").is_none());
+        assert_eq!(json["type"], "advanced");
+    }
+}
@@ -0,0 +1,196 @@
+use serde::{Deserialize, Serialize};
+
+/// Accepted `--ssl-method` values
+pub const SSL_METHODS: &[&str] = &["http", "txt", "email"];
+/// Accepted `--ssl-type` values
+pub const SSL_TYPES: &[&str] = &["dv"];
+
+/// Custom hostname (Cloudflare for SaaS) representation from Cloudflare API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CustomHostname {
+    /// Unique identifier for the custom hostname
+    pub id: String,
+    /// Customer hostname (e.g. "app.customer.com")
+    pub hostname: String,
+    /// Hostname status (pending, active, moved, deleted, ...)
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Certificate settings and validation state
+    #[serde(default)]
+    pub ssl: Option<CustomHostnameSsl>,
+    /// Origin used instead of the zone's fallback origin
+    #[serde(default)]
+    pub custom_origin_server: Option<String>,
+    /// SNI sent to the custom origin server
+    #[serde(default)]
+    pub custom_origin_sni: Option<String>,
+    /// Free-form metadata available to Workers and rules
+    #[serde(default)]
+    pub custom_metadata: Option<serde_json::Map<String, serde_json::Value>>,
+    /// TXT record proving ownership of the hostname
+    #[serde(default)]
+    pub ownership_verification: Option<OwnershipVerification>,
+    /// HTTP file proving ownership of the hostname
+    #[serde(default)]
+    pub ownership_verification_http: Option<OwnershipVerificationHttp>,
+    /// Reasons the hostname is not active yet
+    #[serde(default)]
+    pub verification_errors: Vec<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+impl CustomHostname {
+    /// SSL status, or "-" when no certificate has been requested
+    pub fn ssl_status(&self) -> &str {
+        self.ssl
+            .as_ref()
+            .and_then(|ssl| ssl.status.as_deref())
+            .unwrap_or("-")
+    }
+
+    pub fn is_ssl_active(&self) -> bool {
+        self.ssl_status() == "active"
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct CustomHostnameSsl {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Certificate status (initializing, pending_validation, active, ...)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Domain control validation method (http, txt, email)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    /// Certificate type (dv)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    /// Records the customer must publish for certificate validation
+    #[serde(default, skip_serializing)]
+    pub validation_records: Vec<ValidationRecord>,
+    #[serde(default, skip_serializing)]
+    pub validation_errors: Vec<ValidationError>,
+}
+
+/// One DCV record: a TXT record, an HTTP file or approver emails
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ValidationRecord {
+    #[serde(default)]
+    pub txt_name: Option<String>,
+    #[serde(default)]
+    pub txt_value: Option<String>,
+    #[serde(default)]
+    pub http_url: Option<String>,
+    #[serde(default)]
+    pub http_body: Option<String>,
+    #[serde(default)]
+    pub emails: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ValidationError {
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OwnershipVerification {
+    #[serde(default)]
+    pub r#type: Option<String>,
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OwnershipVerificationHttp {
+    pub http_url: String,
+    pub http_body: String,
+}
+
+/// Request payload for creating a custom hostname
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateCustomHostname {
+    pub hostname: String,
+    pub ssl: CustomHostnameSsl,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_origin_server: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_metadata: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+/// Request payload for updating a custom hostname (only set fields change)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UpdateCustomHostname {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ssl: Option<CustomHostnameSsl>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_origin_server: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_metadata: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl UpdateCustomHostname {
+    pub fn is_empty(&self) -> bool {
+        self.ssl.is_none() && self.custom_origin_server.is_none() && self.custom_metadata.is_none()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_hostname_deserialize() {
+        let hostname: CustomHostname = serde_json::from_value(serde_json::json!({
+            "id": "ch1",
+            "hostname": "app.customer.com",
+            "status": "pending",
+            "ssl": {
+                "id": "ssl1",
+                "status": "pending_validation",
+                "method": "txt",
+                "type": "dv",
+                "validation_records": [{
+                    "txt_name": "_acme-challenge.app.customer.com",
+                    "txt_value": "abc123"
+                }]
+            },
+            "ownership_verification": {
+                "type": "txt",
+                "name": "_cf-custom-hostname.app.customer.com",
+                "value": "uuid"
+            }
+        }))
+        .unwrap();
+        assert_eq!(hostname.ssl_status(), "pending_validation");
+        assert!(!hostname.is_ssl_active());
+        let ssl = hostname.ssl.unwrap();
+        assert_eq!(
+            ssl.validation_records[0].txt_value.as_deref(),
+            Some("abc123")
+        );
+    }
+
+    #[test]
+    fn test_create_payload_skips_read_only_fields() {
+        let create = CreateCustomHostname {
+            hostname: "app.customer.com".to_string(),
+            ssl: CustomHostnameSsl {
+                method: Some("http".to_string()),
+                r#type: Some("dv".to_string()),
+                ..Default::default()
+            },
+            custom_origin_server: None,
+            custom_metadata: None,
+        };
+        let json = serde_json::to_value(&create).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "hostname": "app.customer.com",
+                "ssl": {"method": "http", "type": "dv"}
+            })
+        );
+    }
+}
@@ -120,15 +120,35 @@ pub struct D1RawQueryResult {
 pub struct D1ExportRequest {
     /// Output format (currently only "polling" is supported)
     pub output_format: String,
+    /// Bookmark returned by the previous poll of a running export
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_bookmark: Option<String>,
+    /// What to include in the dump
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dump_options: Option<D1DumpOptions>,
 }
 
-/// Response from initiating a D1 export
+/// Contents of a D1 SQL dump
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct D1DumpOptions {
+    /// Export only the schema
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub no_data: bool,
+    /// Export only the data (INSERT statements)
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub no_schema: bool,
+    /// Export only these tables
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tables: Vec<String>,
+}
+
+/// Response from initiating or polling a D1 export
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct D1ExportResponse {
     /// Task ID for polling export status
     #[serde(default)]
     pub task_id: String,
-    /// Status of the export task
+    /// Status of the export task ("active", "complete" or "error")
     #[serde(default)]
     pub status: String,
     /// Signed URL to download the export (when complete)
@@ -137,6 +157,38 @@ pub struct D1ExportResponse {
     /// Error message if export failed
     #[serde(default)]
     pub error: Option<String>,
+    /// Bookmark the export is taken at; sent back when polling
+    #[serde(default)]
+    pub at_bookmark: Option<String>,
+    /// Progress messages
+    #[serde(default)]
+    pub messages: Vec<String>,
+    /// Dump location once the export is complete
+    #[serde(default)]
+    pub result: Option<D1ExportResult>,
+}
+
+impl D1ExportResponse {
+    pub fn is_complete(&self) -> bool {
+        self.status == "complete"
+    }
+
+    /// Where to download the finished dump
+    pub fn download_url(&self) -> Option<&str> {
+        self.result
+            .as_ref()
+            .and_then(|r| r.signed_url.as_deref())
+            .or(self.signed_url.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct D1ExportResult {
+    #[serde(default)]
+    pub filename: Option<String>,
+    /// Short-lived URL of the SQL dump
+    #[serde(default)]
+    pub signed_url: Option<String>,
 }
 
 /// Request to import SQL into a D1 database
@@ -150,6 +202,21 @@ pub struct D1ImportRequest {
     pub sql: Option<String>,
 }
 
+/// Step of a multi-step import: "init", "ingest" or "poll"
+#[derive(Debug, Clone, Serialize)]
+pub struct D1ImportAction {
+    pub action: String,
+    /// MD5 of the SQL file (init and ingest)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    /// Name of the uploaded file returned by init (ingest)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// Bookmark of the previous response (poll)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current_bookmark: Option<String>,
+}
+
 /// Response from a D1 import operation
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct D1ImportResponse {
@@ -162,6 +229,35 @@ pub struct D1ImportResponse {
     /// Error message if import failed
     #[serde(default)]
     pub error: Option<String>,
+    /// Pre-signed URL to PUT the SQL file to (init; absent when a file with
+    /// the same etag was already uploaded)
+    #[serde(default)]
+    pub upload_url: Option<String>,
+    /// Name of the uploaded file (init)
+    #[serde(default)]
+    pub filename: Option<String>,
+    /// Status of an ingest: "active", "complete" or "error"
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Bookmark to poll the ingest with
+    #[serde(default)]
+    pub at_bookmark: Option<String>,
+    /// Progress messages
+    #[serde(default)]
+    pub messages: Vec<String>,
+    /// Totals once the ingest is complete
+    #[serde(default)]
+    pub result: Option<D1ImportResult>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct D1ImportResult {
+    #[serde(default)]
+    pub num_queries: u64,
+    #[serde(default)]
+    pub final_bookmark: Option<String>,
+    #[serde(default)]
+    pub meta: D1QueryMeta,
 }
 
 /// Time travel bookmark for point-in-time recovery
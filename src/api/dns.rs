@@ -77,6 +77,21 @@ pub struct UpdateDnsRecord {
     pub priority: Option<u16>,
 }
 
+/// DNSSEC state for a zone
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Dnssec {
+    /// active, pending, disabled, pending-disabled or error
+    pub status: String,
+    #[serde(default)]
+    pub ds: Option<String>,
+}
+
+impl Dnssec {
+    pub fn is_active(&self) -> bool {
+        self.status == "active"
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
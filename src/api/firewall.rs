@@ -1 +1,254 @@
-// Placeholder module
+use serde::{Deserialize, Serialize};
+
+/// Actions an IP Access Rule can apply
+pub const ACCESS_RULE_MODES: &[&str] = &[
+    "block",
+    "challenge",
+    "js_challenge",
+    "managed_challenge",
+    "whitelist",
+];
+
+/// What an IP Access Rule matches on
+pub const ACCESS_RULE_TARGETS: &[&str] = &["ip", "ip6", "ip_range", "asn", "country"];
+
+/// Kinds of account-level custom lists
+pub const LIST_KINDS: &[&str] = &["ip", "asn", "hostname", "redirect"];
+
+// ============================================================================
+// IP Access Rules
+// ============================================================================
+
+/// IP Access Rule representation from Cloudflare API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccessRule {
+    pub id: String,
+    /// Action applied to matching requests (block, challenge, whitelist, ...)
+    pub mode: String,
+    pub configuration: AccessRuleConfiguration,
+    #[serde(default)]
+    pub notes: String,
+    /// Where the rule was defined (zone or account)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub scope: Option<AccessRuleScope>,
+    #[serde(default)]
+    pub created_on: Option<String>,
+    #[serde(default)]
+    pub modified_on: Option<String>,
+}
+
+/// Target and value an IP Access Rule matches
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccessRuleConfiguration {
+    /// ip, ip6, ip_range, asn or country
+    pub target: String,
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccessRuleScope {
+    #[serde(default)]
+    pub id: Option<String>,
+    /// "zone", "account" or "user"
+    #[serde(rename = "type", default)]
+    pub scope_type: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateAccessRule {
+    pub mode: String,
+    pub configuration: AccessRuleConfiguration,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+/// Filters for listing IP Access Rules
+#[derive(Debug, Clone, Default)]
+pub struct AccessRuleFilter {
+    pub mode: Option<String>,
+    pub target: Option<String>,
+    pub value: Option<String>,
+    pub notes: Option<String>,
+}
+
+// ============================================================================
+// Custom Lists
+// ============================================================================
+
+/// Account-level custom list (used from rule expressions as `$name`)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CustomList {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    /// ip, asn, hostname or redirect
+    pub kind: String,
+    #[serde(default)]
+    pub num_items: u64,
+    #[serde(default)]
+    pub num_referencing_filters: u64,
+    #[serde(default)]
+    pub created_on: Option<String>,
+    #[serde(default)]
+    pub modified_on: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateCustomList {
+    pub name: String,
+    pub kind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Item stored in a custom list
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ListItem {
+    #[serde(default)]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub asn: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redirect: Option<serde_json::Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created_on: Option<String>,
+}
+
+impl ListItem {
+    /// Human-readable value of the item regardless of list kind
+    pub fn value(&self) -> String {
+        if let Some(ip) = &self.ip {
+            return ip.clone();
+        }
+        if let Some(asn) = self.asn {
+            return format!("AS{}", asn);
+        }
+        if let Some(hostname) = &self.hostname {
+            return hostname
+                .get("url_hostname")
+                .and_then(|h| h.as_str())
+                .map(String::from)
+                .unwrap_or_else(|| hostname.to_string());
+        }
+        if let Some(redirect) = &self.redirect {
+            return redirect
+                .get("source_url")
+                .and_then(|s| s.as_str())
+                .map(String::from)
+                .unwrap_or_else(|| redirect.to_string());
+        }
+        String::new()
+    }
+}
+
+/// Item to add to a custom list
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct CreateListItem {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub asn: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DeleteListItems {
+    pub items: Vec<ListItemId>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ListItemId {
+    pub id: String,
+}
+
+/// Handle returned by asynchronous list item operations
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListOperationRef {
+    pub operation_id: String,
+}
+
+/// Status of an asynchronous list item operation
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ListOperation {
+    pub id: String,
+    /// pending, running, completed or failed
+    pub status: String,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub completed: Option<String>,
+}
+
+impl ListOperation {
+    pub fn is_finished(&self) -> bool {
+        matches!(self.status.as_str(), "completed" | "failed")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_access_rule_deserialize() {
+        let rule: AccessRule = serde_json::from_str(
+            r#"{
+                "id": "r1",
+                "mode": "block",
+                "configuration": {"target": "asn", "value": "AS12345"},
+                "notes": "abuse",
+                "scope": {"id": "acc1", "type": "account"}
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(rule.configuration.target, "asn");
+        assert_eq!(rule.scope.unwrap().scope_type, "account");
+    }
+
+    #[test]
+    fn test_list_item_value() {
+        let ip: ListItem = serde_json::from_str(r#"{"id": "i1", "ip": "192.0.2.0/24"}"#).unwrap();
+        assert_eq!(ip.value(), "192.0.2.0/24");
+
+        let asn: ListItem = serde_json::from_str(r#"{"id": "i2", "asn": 13335}"#).unwrap();
+        assert_eq!(asn.value(), "AS13335");
+
+        let host: ListItem =
+            serde_json::from_str(r#"{"id": "i3", "hostname": {"url_hostname": "example.com"}}"#)
+                .unwrap();
+        assert_eq!(host.value(), "example.com");
+    }
+
+    #[test]
+    fn test_create_list_item_skips_none() {
+        let item = CreateListItem {
+            ip: Some("192.0.2.1".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_string(&item).unwrap(),
+            r#"{"ip":"192.0.2.1"}"#
+        );
+    }
+
+    #[test]
+    fn test_list_operation_finished() {
+        let op: ListOperation =
+            serde_json::from_str(r#"{"id": "op1", "status": "running"}"#).unwrap();
+        assert!(!op.is_finished());
+        let op: ListOperation =
+            serde_json::from_str(r#"{"id": "op1", "status": "failed", "error": "bad ip"}"#)
+                .unwrap();
+        assert!(op.is_finished());
+    }
+}
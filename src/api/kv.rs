@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+
+/// Maximum number of keys per bulk write or bulk delete request
+pub const KV_BULK_LIMIT: usize = 10_000;
+
+/// Workers KV namespace from Cloudflare API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KvNamespace {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub supports_url_encoding: Option<bool>,
+}
+
+/// Request payload for creating or renaming a namespace
+#[derive(Debug, Clone, Serialize)]
+pub struct KvNamespaceTitle {
+    pub title: String,
+}
+
+/// Key returned by a key listing
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KvKey {
+    pub name: String,
+    /// Absolute expiration as seconds since the UNIX epoch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+}
+
+/// One entry of a bulk write, in the same shape wrangler reads from
+/// `kv bulk put` files
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct KvBulkEntry {
+    pub key: String,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration_ttl: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+    /// `value` is base64-encoded binary data
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub base64: bool,
+}
+
+/// Result of a bulk write or bulk delete request
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct KvBulkResult {
+    #[serde(default)]
+    pub successful_key_count: usize,
+    #[serde(default)]
+    pub unsuccessful_keys: Vec<String>,
+}
+
+/// Key listed in the `manifest.json` written by `kv export`
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct KvExportEntry {
+    pub key: String,
+    /// File holding the value, relative to the export directory
+    pub file: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expiration: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bulk_entry_wrangler_format() {
+        let entry: KvBulkEntry = serde_json::from_value(serde_json::json!({
+            "key": "greeting",
+            "value": "aGVsbG8=",
+            "expiration_ttl": 3600,
+            "base64": true
+        }))
+        .unwrap();
+        assert!(entry.base64);
+        assert_eq!(entry.expiration_ttl, Some(3600));
+
+        let plain = KvBulkEntry {
+            key: "k".to_string(),
+            value: "v".to_string(),
+            expiration: None,
+            expiration_ttl: None,
+            metadata: None,
+            base64: false,
+        };
+        assert_eq!(
+            serde_json::to_value(&plain).unwrap(),
+            serde_json::json!({"key": "k", "value": "v"})
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/// Accepted `--type` values for monitors
+pub const MONITOR_TYPES: &[&str] = &["http", "https", "tcp", "udp_icmp", "icmp_ping", "smtp"];
+/// Accepted `--steering` values for load balancers
+pub const STEERING_POLICIES: &[&str] = &[
+    "off",
+    "geo",
+    "random",
+    "dynamic_latency",
+    "proximity",
+    "least_outstanding_requests",
+    "least_connections",
+];
+
+/// Health monitor (account-level) from Cloudflare API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Monitor {
+    pub id: String,
+    /// Protocol used for health checks
+    #[serde(default)]
+    pub r#type: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub method: Option<String>,
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Expected HTTP status codes (e.g. "2xx", "200")
+    #[serde(default)]
+    pub expected_codes: Option<String>,
+    #[serde(default)]
+    pub expected_body: Option<String>,
+    /// Seconds between checks
+    #[serde(default)]
+    pub interval: Option<u32>,
+    #[serde(default)]
+    pub timeout: Option<u32>,
+    #[serde(default)]
+    pub retries: Option<u32>,
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub header: Option<BTreeMap<String, Vec<String>>>,
+    #[serde(default)]
+    pub follow_redirects: Option<bool>,
+    #[serde(default)]
+    pub allow_insecure: Option<bool>,
+    #[serde(default)]
+    pub modified_on: Option<String>,
+}
+
+/// Create/update payload for a monitor (only set fields are sent)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MonitorPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_codes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expected_body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub interval: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<BTreeMap<String, Vec<String>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follow_redirects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_insecure: Option<bool>,
+}
+
+impl MonitorPayload {
+    pub fn is_empty(&self) -> bool {
+        serde_json::to_value(self).map_or(true, |v| v.as_object().is_some_and(|o| o.is_empty()))
+    }
+}
+
+/// Origin pool (account-level) from Cloudflare API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Pool {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Healthy origins needed for the pool to be healthy
+    #[serde(default)]
+    pub minimum_origins: Option<u32>,
+    /// Monitor ID
+    #[serde(default)]
+    pub monitor: Option<String>,
+    #[serde(default)]
+    pub origins: Vec<Origin>,
+    #[serde(default)]
+    pub notification_email: Option<String>,
+    /// Regions health checks run from
+    #[serde(default)]
+    pub check_regions: Option<Vec<String>>,
+    /// Overall health, when the pool has a monitor
+    #[serde(default)]
+    pub healthy: Option<bool>,
+    #[serde(default)]
+    pub modified_on: Option<String>,
+}
+
+impl Pool {
+    /// Find an origin by name or address
+    pub fn origin_mut(&mut self, origin: &str) -> Option<&mut Origin> {
+        self.origins
+            .iter_mut()
+            .find(|o| o.name == origin || o.address == origin)
+    }
+}
+
+/// One origin server within a pool
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct Origin {
+    pub name: String,
+    /// IP address or hostname
+    pub address: String,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Share of traffic relative to other origins (0.0-1.0)
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<BTreeMap<String, Vec<String>>>,
+    /// Fields not modelled above (e.g. `port`, `virtual_network_id`), kept so
+    /// saving a pool's origin list does not erase them
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
+
+/// Create/update payload for a pool (only set fields are sent)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct PoolPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum_origins: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monitor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub origins: Option<Vec<Origin>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notification_email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_regions: Option<Vec<String>>,
+}
+
+impl PoolPayload {
+    pub fn is_empty(&self) -> bool {
+        serde_json::to_value(self).map_or(true, |v| v.as_object().is_some_and(|o| o.is_empty()))
+    }
+}
+
+/// Health of a pool as seen from each check region
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PoolHealth {
+    #[serde(default)]
+    pub pool_id: Option<String>,
+    /// Keyed by region code (e.g. "WNAM", "EEU")
+    #[serde(default)]
+    pub pop_health: BTreeMap<String, RegionHealth>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RegionHealth {
+    #[serde(default)]
+    pub healthy: bool,
+    /// One single-entry map per origin, keyed by origin address
+    #[serde(default)]
+    pub origins: Vec<BTreeMap<String, OriginHealth>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct OriginHealth {
+    #[serde(default)]
+    pub healthy: bool,
+    /// Round-trip time (e.g. "66ms")
+    #[serde(default)]
+    pub rtt: Option<String>,
+    #[serde(default)]
+    pub failure_reason: Option<String>,
+    #[serde(default)]
+    pub response_code: Option<u16>,
+}
+
+/// Zone load balancer from Cloudflare API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LoadBalancer {
+    pub id: String,
+    /// Hostname the load balancer answers for
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    #[serde(default)]
+    pub proxied: bool,
+    #[serde(default)]
+    pub ttl: Option<u32>,
+    /// Pool IDs in failover order
+    #[serde(default)]
+    pub default_pools: Vec<String>,
+    #[serde(default)]
+    pub fallback_pool: Option<String>,
+    #[serde(default)]
+    pub steering_policy: Option<String>,
+    #[serde(default)]
+    pub session_affinity: Option<String>,
+    #[serde(default)]
+    pub modified_on: Option<String>,
+}
+
+/// Create/update payload for a load balancer (only set fields are sent)
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LoadBalancerPayload {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub proxied: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_pools: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback_pool: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub steering_policy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub session_affinity: Option<String>,
+}
+
+impl LoadBalancerPayload {
+    pub fn is_empty(&self) -> bool {
+        serde_json::to_value(self).map_or(true, |v| v.as_object().is_some_and(|o| o.is_empty()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_deserialize_defaults() {
+        let pool: Pool = serde_json::from_value(serde_json::json!({
+            "id": "17b5962d775c646f3f9725cbc7a53df4",
+            "name": "primary-dc",
+            "origins": [
+                {"name": "web1", "address": "192.0.2.1"},
+                {"name": "web2", "address": "192.0.2.2", "enabled": false, "weight": 0.5}
+            ]
+        }))
+        .unwrap();
+        assert!(pool.enabled);
+        assert!(pool.origins[0].enabled);
+        assert_eq!(pool.origins[0].weight, 1.0);
+        assert!(!pool.origins[1].enabled);
+    }
+
+    #[test]
+    fn test_pool_health_deserialize() {
+        let health: PoolHealth = serde_json::from_value(serde_json::json!({
+            "pool_id": "pool1",
+            "pop_health": {
+                "WNAM": {
+                    "healthy": true,
+                    "origins": [{"192.0.2.1": {"healthy": true, "rtt": "66ms", "response_code": 200}}]
+                }
+            }
+        }))
+        .unwrap();
+        let region = &health.pop_health["WNAM"];
+        assert!(region.healthy);
+        assert_eq!(region.origins[0]["192.0.2.1"].rtt.as_deref(), Some("66ms"));
+    }
+
+    #[test]
+    fn test_payloads_skip_unset_fields() {
+        assert!(PoolPayload::default().is_empty());
+        assert!(MonitorPayload::default().is_empty());
+        let payload = LoadBalancerPayload {
+            enabled: Some(false),
+            ..Default::default()
+        };
+        assert!(!payload.is_empty());
+        assert_eq!(
+            serde_json::to_value(&payload).unwrap(),
+            serde_json::json!({"enabled": false})
+        );
+    }
+}
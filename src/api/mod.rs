@@ -1,11 +1,20 @@
 pub mod analytics;
 pub mod cache;
+pub mod certificates;
+pub mod custom_hostnames;
 pub mod d1;
 pub mod dns;
 pub mod firewall;
+pub mod kv;
+pub mod load_balancing;
+pub mod page_rules;
 pub mod pages;
 pub mod pagination;
+pub mod queues;
 pub mod r2;
 pub mod response;
+pub mod rulesets;
 pub mod token;
+pub mod tunnel;
+pub mod workers;
 pub mod zone;
@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+/// Page Rule representation from Cloudflare API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PageRule {
+    /// Unique identifier for the page rule
+    pub id: String,
+    /// URL patterns the rule matches (always a single "url" target in practice)
+    #[serde(default)]
+    pub targets: Vec<PageRuleTarget>,
+    /// Settings applied when the rule matches
+    #[serde(default)]
+    pub actions: Vec<PageRuleAction>,
+    /// Higher values are evaluated first; only the first matching rule applies
+    #[serde(default)]
+    pub priority: i64,
+    /// Status of the rule (active, disabled)
+    #[serde(default)]
+    pub status: String,
+    /// When the rule was created
+    #[serde(default)]
+    pub created_on: Option<String>,
+    /// When the rule was last modified
+    #[serde(default)]
+    pub modified_on: Option<String>,
+}
+
+impl PageRule {
+    /// URL pattern of the rule (e.g. "*example.com/images/*")
+    pub fn url_pattern(&self) -> Option<&str> {
+        self.targets
+            .iter()
+            .find(|t| t.target == "url")
+            .map(|t| t.constraint.value.as_str())
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == "active"
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PageRuleTarget {
+    /// Target type (currently always "url")
+    pub target: String,
+    pub constraint: PageRuleConstraint,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PageRuleConstraint {
+    /// Match operator (currently always "matches")
+    pub operator: String,
+    /// URL pattern, with `*` wildcards
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PageRuleAction {
+    /// Setting name (forwarding_url, cache_level, ssl, ...)
+    pub id: String,
+    /// Setting value; shape depends on the action
+    #[serde(default)]
+    pub value: serde_json::Value,
+}
+
+impl PageRuleAction {
+    /// Short "id=value" form used in tables and migration plans
+    pub fn summary(&self) -> String {
+        match &self.value {
+            serde_json::Value::Null => self.id.clone(),
+            serde_json::Value::String(s) => format!("{}={}", self.id, s),
+            serde_json::Value::Object(map) if self.id == "forwarding_url" => format!(
+                "{}={} {}",
+                self.id,
+                map.get("status_code")
+                    .map(|v| v.to_string())
+                    .unwrap_or_default(),
+                map.get("url").and_then(|v| v.as_str()).unwrap_or_default()
+            ),
+            other => format!("{}={}", self.id, other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_rule_deserialize() {
+        let rule: PageRule = serde_json::from_value(serde_json::json!({
+            "id": "pr1",
+            "targets": [{
+                "target": "url",
+                "constraint": {"operator": "matches", "value": "*example.com/old/*"}
+            }],
+            "actions": [{
+                "id": "forwarding_url",
+                "value": {"url": "https://example.com/new/$2", "status_code": 301}
+            }, {
+                "id": "always_use_https"
+            }],
+            "priority": 2,
+            "status": "active"
+        }))
+        .unwrap();
+        assert_eq!(rule.url_pattern(), Some("*example.com/old/*"));
+        assert!(rule.is_active());
+        assert_eq!(
+            rule.actions[0].summary(),
+            "forwarding_url=301 https://example.com/new/$2"
+        );
+        assert_eq!(rule.actions[1].summary(), "always_use_https");
+    }
+}
@@ -0,0 +1,197 @@
+use serde::{Deserialize, Serialize};
+
+/// Queue from Cloudflare API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Queue {
+    pub queue_id: String,
+    pub queue_name: String,
+    #[serde(default)]
+    pub created_on: Option<String>,
+    #[serde(default)]
+    pub modified_on: Option<String>,
+    #[serde(default)]
+    pub producers_total_count: Option<u32>,
+    #[serde(default)]
+    pub consumers_total_count: Option<u32>,
+    /// Workers (and R2 buckets) that send messages to the queue
+    #[serde(default)]
+    pub producers: Vec<QueueProducer>,
+    #[serde(default)]
+    pub consumers: Vec<QueueConsumer>,
+    #[serde(default)]
+    pub settings: Option<QueueSettings>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QueueProducer {
+    /// "worker" or "r2_bucket"
+    #[serde(default)]
+    pub r#type: Option<String>,
+    #[serde(default)]
+    pub script: Option<String>,
+    #[serde(default)]
+    pub bucket_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct QueueSettings {
+    /// Delay before a message becomes visible to consumers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub delivery_delay: Option<u32>,
+    /// How long messages are kept, in seconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message_retention_period: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateQueue {
+    pub queue_name: String,
+}
+
+/// Consumer of a queue: a Worker, or an HTTP pull endpoint
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QueueConsumer {
+    #[serde(default)]
+    pub consumer_id: Option<String>,
+    /// "worker" or "http_pull"
+    pub r#type: String,
+    /// Worker script (worker consumers only)
+    #[serde(default, alias = "script", alias = "service")]
+    pub script_name: Option<String>,
+    #[serde(default)]
+    pub dead_letter_queue: Option<String>,
+    #[serde(default)]
+    pub settings: ConsumerSettings,
+    #[serde(default)]
+    pub created_on: Option<String>,
+}
+
+impl QueueConsumer {
+    /// Script name for worker consumers, "HTTP pull" otherwise
+    pub fn target(&self) -> String {
+        match &self.script_name {
+            Some(script) => script.clone(),
+            None if self.r#type == "http_pull" => "HTTP pull".to_string(),
+            None => "-".to_string(),
+        }
+    }
+}
+
+/// Request payload for creating or updating a consumer
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateQueueConsumer {
+    pub r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub script_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dead_letter_queue: Option<String>,
+    pub settings: ConsumerSettings,
+}
+
+/// Batching and retry settings of a consumer
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct ConsumerSettings {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub batch_size: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_retries: Option<u32>,
+    /// Longest wait for a full batch (worker consumers)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_wait_time_ms: Option<u32>,
+    /// Upper bound of concurrent consumer invocations (worker consumers)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrency: Option<u32>,
+    /// Seconds before a retried message is redelivered
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_delay: Option<u32>,
+    /// How long pulled messages stay leased (HTTP pull consumers)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub visibility_timeout_ms: Option<u32>,
+}
+
+/// Message body sent with `queues send`
+#[derive(Debug, Clone, Serialize)]
+pub struct SendMessage {
+    pub body: serde_json::Value,
+    /// "json" or "text"
+    pub content_type: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PullMessages {
+    pub batch_size: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility_timeout_ms: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct PulledMessages {
+    #[serde(default)]
+    pub messages: Vec<QueueMessage>,
+    #[serde(default)]
+    pub message_backlog_count: Option<u64>,
+}
+
+/// Message leased by an HTTP pull consumer
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QueueMessage {
+    pub id: String,
+    /// Handle used to acknowledge or retry the message
+    pub lease_id: String,
+    #[serde(default)]
+    pub body: serde_json::Value,
+    #[serde(default)]
+    pub attempts: u32,
+    #[serde(default)]
+    pub timestamp_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AckMessages {
+    pub acks: Vec<LeaseRef>,
+    pub retries: Vec<RetryLease>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaseRef {
+    pub lease_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RetryLease {
+    pub lease_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay_seconds: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct AckResult {
+    #[serde(default, rename = "ackCount")]
+    pub ack_count: u32,
+    #[serde(default, rename = "retryCount")]
+    pub retry_count: u32,
+    #[serde(default)]
+    pub warnings: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consumer_target() {
+        let worker: QueueConsumer = serde_json::from_value(serde_json::json!({
+            "consumer_id": "c1",
+            "type": "worker",
+            "service": "processor",
+            "settings": {"batch_size": 50}
+        }))
+        .unwrap();
+        assert_eq!(worker.target(), "processor");
+        assert_eq!(worker.settings.batch_size, Some(50));
+
+        let pull: QueueConsumer =
+            serde_json::from_value(serde_json::json!({"type": "http_pull"})).unwrap();
+        assert_eq!(pull.target(), "HTTP pull");
+    }
+}
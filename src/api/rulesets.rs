@@ -0,0 +1,287 @@
+use serde::{Deserialize, Serialize};
+
+/// WAF custom rules phase
+pub const PHASE_FIREWALL_CUSTOM: &str = "http_request_firewall_custom";
+/// Single (dynamic) redirect rules phase
+pub const PHASE_DYNAMIC_REDIRECT: &str = "http_request_dynamic_redirect";
+/// Request/response header modification phase
+pub const PHASE_LATE_TRANSFORM: &str = "http_request_late_transform";
+/// Cache rules phase
+pub const PHASE_CACHE_SETTINGS: &str = "http_request_cache_settings";
+/// Origin rules phase
+pub const PHASE_ORIGIN: &str = "http_request_origin";
+/// Rate limiting rules phase
+pub const PHASE_RATELIMIT: &str = "http_ratelimit";
+/// Configuration rules phase (per-request zone setting overrides)
+pub const PHASE_CONFIG_SETTINGS: &str = "http_config_settings";
+
+/// Friendly aliases accepted wherever a phase name is expected
+const PHASE_ALIASES: &[(&str, &str)] = &[
+    ("waf", PHASE_FIREWALL_CUSTOM),
+    ("redirect", PHASE_DYNAMIC_REDIRECT),
+    ("transform", PHASE_LATE_TRANSFORM),
+    ("cache", PHASE_CACHE_SETTINGS),
+    ("origin", PHASE_ORIGIN),
+    ("ratelimit", PHASE_RATELIMIT),
+    ("config", PHASE_CONFIG_SETTINGS),
+];
+
+/// Resolve a friendly alias (e.g. "waf") to its phase name.
+/// Unknown names are passed through so any phase can be targeted.
+pub fn resolve_phase(name: &str) -> &str {
+    PHASE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == name)
+        .map(|(_, phase)| *phase)
+        .unwrap_or(name)
+}
+
+/// Friendly alias for a phase name, or the phase itself when it has none
+pub fn phase_alias(phase: &str) -> &str {
+    PHASE_ALIASES
+        .iter()
+        .find(|(_, name)| *name == phase)
+        .map(|(alias, _)| *alias)
+        .unwrap_or(phase)
+}
+
+/// Where a ruleset lives: a single zone or the whole account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RulesetScope {
+    Zone(String),
+    Account(String),
+}
+
+impl RulesetScope {
+    /// Endpoint prefix for this scope (e.g. "/zones/{id}")
+    pub fn path(&self) -> String {
+        match self {
+            Self::Zone(id) => format!("/zones/{}", id),
+            Self::Account(id) => format!("/accounts/{}", id),
+        }
+    }
+}
+
+/// Ruleset representation from Cloudflare API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Ruleset {
+    /// Unique identifier for the ruleset
+    pub id: String,
+    /// Human-readable name
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Informative description
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Kind of ruleset (root, zone, managed, custom)
+    #[serde(default)]
+    pub kind: String,
+    /// Phase the ruleset runs in
+    #[serde(default)]
+    pub phase: String,
+    /// Version of the ruleset
+    #[serde(default)]
+    pub version: Option<String>,
+    /// When the ruleset was last updated
+    #[serde(default)]
+    pub last_updated: Option<String>,
+    /// Rules in evaluation order (omitted by list endpoints)
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+/// A single rule inside a ruleset
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Rule {
+    /// Unique identifier for the rule
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Version of the rule
+    #[serde(default)]
+    pub version: Option<String>,
+    /// Action to perform when the expression matches
+    pub action: String,
+    /// Rules language expression
+    pub expression: String,
+    /// Informative description
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Whether the rule is active
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+    /// Parameters for the action (redirect target, cache settings, etc.)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action_parameters: Option<serde_json::Value>,
+    /// Rate limiting characteristics (http_ratelimit phase only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ratelimit: Option<serde_json::Value>,
+    /// Stable reference for the rule
+    #[serde(default, rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub rule_ref: Option<String>,
+    /// When the rule was last updated
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<String>,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+/// Rule definition sent when creating or updating rules
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct RuleInput {
+    /// Action to perform when the expression matches
+    pub action: String,
+    /// Rules language expression
+    pub expression: String,
+    /// Informative description
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Whether the rule is active (defaults to true)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Parameters for the action
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action_parameters: Option<serde_json::Value>,
+    /// Rate limiting characteristics (http_ratelimit phase only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ratelimit: Option<serde_json::Value>,
+    /// Stable reference for the rule
+    #[serde(default, rename = "ref", skip_serializing_if = "Option::is_none")]
+    pub rule_ref: Option<String>,
+    /// Where to place the rule within the ruleset
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<RulePosition>,
+}
+
+impl From<Rule> for RuleInput {
+    fn from(rule: Rule) -> Self {
+        Self {
+            action: rule.action,
+            expression: rule.expression,
+            description: rule.description,
+            enabled: Some(rule.enabled),
+            action_parameters: rule.action_parameters,
+            ratelimit: rule.ratelimit,
+            rule_ref: rule.rule_ref,
+            position: None,
+        }
+    }
+}
+
+/// Position of a rule within a ruleset (exactly one field should be set)
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct RulePosition {
+    /// Place the rule before the rule with this ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    /// Place the rule after the rule with this ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    /// Place the rule at this 1-based index
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub index: Option<u32>,
+}
+
+/// Request payload replacing all rules of a phase entrypoint ruleset
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateEntrypoint {
+    /// Informative description
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Complete list of rules, in evaluation order
+    pub rules: Vec<RuleInput>,
+}
+
+/// Rules for one phase as written in a `rules apply` file
+#[derive(Debug, Clone, Deserialize)]
+pub struct PhaseRules {
+    /// Phase name or friendly alias (waf, redirect, transform, cache, origin, ratelimit)
+    pub phase: String,
+    /// Informative description for the entrypoint ruleset
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Complete list of rules, in evaluation order
+    #[serde(default)]
+    pub rules: Vec<RuleInput>,
+}
+
+/// Contents of a `rules apply` file: one phase or a list of phases
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum RulesDocument {
+    Many { rulesets: Vec<PhaseRules> },
+    One(PhaseRules),
+}
+
+impl RulesDocument {
+    pub fn into_phases(self) -> Vec<PhaseRules> {
+        match self {
+            Self::Many { rulesets } => rulesets,
+            Self::One(phase) => vec![phase],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_phase_aliases() {
+        assert_eq!(resolve_phase("waf"), PHASE_FIREWALL_CUSTOM);
+        assert_eq!(resolve_phase("ratelimit"), PHASE_RATELIMIT);
+        assert_eq!(resolve_phase("config"), PHASE_CONFIG_SETTINGS);
+        assert_eq!(phase_alias(PHASE_CACHE_SETTINGS), "cache");
+        assert_eq!(
+            resolve_phase("http_request_transform"),
+            "http_request_transform"
+        );
+    }
+
+    #[test]
+    fn test_scope_path() {
+        assert_eq!(RulesetScope::Zone("z1".into()).path(), "/zones/z1");
+        assert_eq!(RulesetScope::Account("a1".into()).path(), "/accounts/a1");
+    }
+
+    #[test]
+    fn test_rule_defaults_enabled() {
+        let rule: Rule =
+            serde_json::from_str(r#"{"action": "block", "expression": "ip.src eq 1.1.1.1"}"#)
+                .unwrap();
+        assert!(rule.enabled);
+        assert!(rule.id.is_none());
+    }
+
+    #[test]
+    fn test_rule_input_serialize_skips_none() {
+        let rule = RuleInput {
+            action: "block".to_string(),
+            expression: "true".to_string(),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&rule).unwrap();
+        assert_eq!(json, r#"{"action":"block","expression":"true"}"#);
+    }
+
+    #[test]
+    fn test_rules_document_single_phase() {
+        let doc: RulesDocument = serde_json::from_str(
+            r#"{"phase": "waf", "rules": [{"action": "block", "expression": "true"}]}"#,
+        )
+        .unwrap();
+        let phases = doc.into_phases();
+        assert_eq!(phases.len(), 1);
+        assert_eq!(phases[0].phase, "waf");
+    }
+
+    #[test]
+    fn test_rules_document_many_phases() {
+        let doc: RulesDocument = serde_json::from_str(
+            r#"{"rulesets": [{"phase": "waf", "rules": []}, {"phase": "cache"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(doc.into_phases().len(), 2);
+    }
+}
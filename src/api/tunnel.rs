@@ -0,0 +1,210 @@
+use serde::{Deserialize, Serialize};
+
+/// Suffix of the CNAME target that routes a hostname to a tunnel
+pub const TUNNEL_CNAME_SUFFIX: &str = "cfargotunnel.com";
+
+/// Cloudflare Tunnel (cloudflared) from Cloudflare API
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Tunnel {
+    pub id: String,
+    pub name: String,
+    /// inactive, degraded, healthy or down
+    #[serde(default)]
+    pub status: Option<String>,
+    /// "cloudflare" when the configuration is managed remotely, "local" otherwise
+    #[serde(default)]
+    pub config_src: Option<String>,
+    #[serde(default)]
+    pub remote_config: Option<bool>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+    #[serde(default)]
+    pub deleted_at: Option<String>,
+    #[serde(default)]
+    pub conns_active_at: Option<String>,
+    #[serde(default)]
+    pub connections: Vec<TunnelConnection>,
+}
+
+impl Tunnel {
+    /// CNAME target for hostnames routed to this tunnel
+    pub fn cname_target(&self) -> String {
+        format!("{}.{}", self.id, TUNNEL_CNAME_SUFFIX)
+    }
+
+    pub fn is_remotely_managed(&self) -> bool {
+        self.remote_config.unwrap_or(false) || self.config_src.as_deref() == Some("cloudflare")
+    }
+}
+
+/// One connection between a cloudflared connector and a Cloudflare data center
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TunnelConnection {
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Data center the connection terminates at (e.g. "DFW")
+    #[serde(default)]
+    pub colo_name: Option<String>,
+    #[serde(default)]
+    pub origin_ip: Option<String>,
+    #[serde(default)]
+    pub opened_at: Option<String>,
+    #[serde(default)]
+    pub is_pending_reconnect: bool,
+    #[serde(default)]
+    pub client_id: Option<String>,
+    #[serde(default)]
+    pub client_version: Option<String>,
+}
+
+/// A running cloudflared instance and its connections
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TunnelConnector {
+    pub id: String,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub arch: Option<String>,
+    #[serde(default)]
+    pub run_at: Option<String>,
+    #[serde(default)]
+    pub conns: Vec<TunnelConnection>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateTunnel {
+    pub name: String,
+    pub config_src: String,
+}
+
+/// Remotely-managed tunnel configuration, in cloudflared's config.yml layout.
+///
+/// Keys a local config.yml carries that don't apply remotely (`tunnel`,
+/// `credentials-file`) are ignored when reading one.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct TunnelConfig {
+    #[serde(default)]
+    pub ingress: Vec<IngressRule>,
+    /// Defaults applied to every ingress rule
+    #[serde(
+        rename = "originRequest",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub origin_request: Option<serde_json::Value>,
+    #[serde(
+        rename = "warp-routing",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub warp_routing: Option<serde_json::Value>,
+}
+
+/// Maps requests for a hostname (and optional path regex) to a local service
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq)]
+pub struct IngressRule {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    /// e.g. http://localhost:8080, ssh://localhost:22 or http_status:404
+    pub service: String,
+    #[serde(
+        rename = "originRequest",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub origin_request: Option<serde_json::Value>,
+}
+
+impl IngressRule {
+    /// A rule without hostname or path matches every request
+    pub fn is_catch_all(&self) -> bool {
+        self.hostname.is_none() && self.path.is_none()
+    }
+}
+
+/// Configuration as returned by GET .../configurations
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TunnelConfigurationResponse {
+    #[serde(default)]
+    pub tunnel_id: Option<String>,
+    #[serde(default)]
+    pub version: Option<u64>,
+    #[serde(default)]
+    pub config: Option<TunnelConfig>,
+    #[serde(default)]
+    pub source: Option<String>,
+    #[serde(default)]
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PutTunnelConfiguration {
+    pub config: TunnelConfig,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tunnel_config_from_cloudflared_yaml() {
+        let yaml = r#"
+tunnel: 6ff42ae2-765d-4adf-8112-31c55c1551ef
+credentials-file: /root/.cloudflared/6ff42ae2.json
+originRequest:
+  connectTimeout: 30s
+ingress:
+  - hostname: app.example.com
+    service: http://localhost:8080
+  - hostname: app.example.com
+    path: ^/api/
+    service: http://localhost:9000
+    originRequest:
+      noTLSVerify: true
+  - service: http_status:404
+"#;
+        let config: TunnelConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.ingress.len(), 3);
+        assert_eq!(config.ingress[1].path.as_deref(), Some("^/api/"));
+        assert!(config.ingress[2].is_catch_all());
+        assert_eq!(
+            config.origin_request.unwrap()["connectTimeout"],
+            serde_json::json!("30s")
+        );
+    }
+
+    #[test]
+    fn test_tunnel_config_serializes_camel_case_keys() {
+        let config = TunnelConfig {
+            ingress: vec![IngressRule {
+                service: "http_status:404".to_string(),
+                origin_request: Some(serde_json::json!({"noTLSVerify": true})),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&config).unwrap(),
+            serde_json::json!({
+                "ingress": [{"service": "http_status:404", "originRequest": {"noTLSVerify": true}}]
+            })
+        );
+    }
+
+    #[test]
+    fn test_cname_target() {
+        let tunnel: Tunnel = serde_json::from_value(serde_json::json!({
+            "id": "6ff42ae2-765d-4adf-8112-31c55c1551ef",
+            "name": "prod",
+            "config_src": "cloudflare"
+        }))
+        .unwrap();
+        assert!(tunnel.is_remotely_managed());
+        assert_eq!(
+            tunnel.cname_target(),
+            "6ff42ae2-765d-4adf-8112-31c55c1551ef.cfargotunnel.com"
+        );
+    }
+}
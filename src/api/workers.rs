@@ -0,0 +1,186 @@
+use serde::{Deserialize, Serialize};
+
+/// Binding types that survive a deploy without being re-sent (secrets are
+/// write-only, so a deploy would otherwise drop them)
+pub const KEEP_BINDINGS: &[&str] = &["secret_text", "secret_key"];
+
+/// Worker script from Cloudflare API (`id` is the script name)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorkerScript {
+    pub id: String,
+    #[serde(default)]
+    pub etag: Option<String>,
+    /// Event handlers the script exports (fetch, scheduled, queue, ...)
+    #[serde(default)]
+    pub handlers: Vec<String>,
+    #[serde(default)]
+    pub compatibility_date: Option<String>,
+    #[serde(default)]
+    pub usage_model: Option<String>,
+    #[serde(default)]
+    pub has_modules: Option<bool>,
+    #[serde(default)]
+    pub created_on: Option<String>,
+    #[serde(default)]
+    pub modified_on: Option<String>,
+}
+
+/// Script settings (bindings and runtime options)
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct WorkerSettings {
+    #[serde(default)]
+    pub bindings: Vec<WorkerBinding>,
+    #[serde(default)]
+    pub compatibility_date: Option<String>,
+    #[serde(default)]
+    pub compatibility_flags: Vec<String>,
+    #[serde(default)]
+    pub usage_model: Option<String>,
+    #[serde(default)]
+    pub logpush: Option<bool>,
+}
+
+/// A binding as sent in upload metadata and returned by settings.
+///
+/// The fields besides `type` and `name` depend on the type
+/// (e.g. `namespace_id` for kv_namespace, `bucket_name` for r2_bucket).
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
+pub struct WorkerBinding {
+    pub r#type: String,
+    pub name: String,
+    #[serde(flatten)]
+    pub fields: serde_json::Map<String, serde_json::Value>,
+}
+
+impl WorkerBinding {
+    /// The binding's target (namespace ID, bucket, text, ...) for display
+    pub fn target(&self) -> String {
+        self.fields
+            .values()
+            .map(|v| match v {
+                serde_json::Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+/// `metadata` part of a script upload
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct WorkerMetadata {
+    /// Module that exports the handlers
+    pub main_module: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub compatibility_date: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub compatibility_flags: Vec<String>,
+    pub bindings: Vec<WorkerBinding>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub keep_bindings: Vec<String>,
+}
+
+/// Route mapping a zone URL pattern to a script
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorkerRoute {
+    pub id: String,
+    pub pattern: String,
+    /// None when the route disables Workers for matching URLs
+    #[serde(default)]
+    pub script: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateWorkerRoute {
+    pub pattern: String,
+    pub script: String,
+}
+
+/// Custom domain serving a Worker directly
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorkerDomain {
+    pub id: String,
+    pub hostname: String,
+    /// Script name
+    pub service: String,
+    #[serde(default)]
+    pub environment: Option<String>,
+    #[serde(default)]
+    pub zone_id: Option<String>,
+    #[serde(default)]
+    pub zone_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AttachWorkerDomain {
+    pub hostname: String,
+    pub service: String,
+    pub environment: String,
+    pub zone_id: String,
+}
+
+/// Cron trigger of a script
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CronTrigger {
+    pub cron: String,
+    #[serde(default)]
+    pub created_on: Option<String>,
+    #[serde(default)]
+    pub modified_on: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorkerSchedules {
+    #[serde(default)]
+    pub schedules: Vec<CronTrigger>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CronSchedule {
+    pub cron: String,
+}
+
+/// Secret bound to a script (values are write-only and never returned)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WorkerSecret {
+    pub name: String,
+    #[serde(default)]
+    pub r#type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PutWorkerSecret {
+    pub name: String,
+    pub text: String,
+    pub r#type: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binding_round_trip() {
+        let json = serde_json::json!({
+            "type": "kv_namespace",
+            "name": "CACHE",
+            "namespace_id": "0f2ac74b498b48028cb68387c421e279"
+        });
+        let binding: WorkerBinding = serde_json::from_value(json.clone()).unwrap();
+        assert_eq!(binding.r#type, "kv_namespace");
+        assert_eq!(binding.target(), "0f2ac74b498b48028cb68387c421e279");
+        assert_eq!(serde_json::to_value(&binding).unwrap(), json);
+    }
+
+    #[test]
+    fn test_metadata_skips_empty_fields() {
+        let metadata = WorkerMetadata {
+            main_module: "index.js".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_json::to_value(&metadata).unwrap(),
+            serde_json::json!({"main_module": "index.js", "bindings": []})
+        );
+    }
+}
@@ -1,11 +1,41 @@
 use clap::Subcommand;
 
+use super::zone::ZoneSelector;
+
 #[derive(Subcommand)]
+#[command(after_long_help = r#"EXAMPLES:
+  cfad cache purge example.com --all
+  cfad cache purge example.com --files https://example.com/a.css,https://example.com/b.js
+  cfad cache purge example.com --from-file changed-urls.txt
+  cfad cache purge --files https://www.example.com/a.css,https://cdn.example.net/b.js
+  cfad cache purge example.com --files https://example.com/ \
+    --header 'CF-Device-Type: mobile' --header 'CF-Device-Type: tablet'
+  cfad cache purge example.com --from-file variants.json
+  git diff --name-only | sed 's|^|https://example.com/|' | cfad cache purge example.com --from-file -
+  cfad cache purge example.com --sitemap https://example.com/sitemap.xml
+  git diff --name-only HEAD~1 -- public | sed 's|^public/||' > changed.txt
+  cfad cache purge example.com --changed-files changed.txt --base-url https://example.com/
+
+Without a zone, URL purges are routed to the zone owning each URL's host
+(longest matching zone name). URLs outside the account's zones are reported and
+make the command fail after the others are purged.
+
+Large purges are split into requests of 30 items and paced under the API rate limit.
+"#)]
+#[allow(clippy::large_enum_variant)]
 pub enum CacheCommand {
     /// Purge cache
     Purge {
-        /// Zone name or ID
-        zone: String,
+        /// Zone name or ID. URL purges without a zone are routed to the zone
+        /// owning each URL's host.
+        #[arg(required_unless_present_any = [
+            "zones", "zones_file", "all_zones", "zone_status",
+            "files", "from_file", "sitemap", "changed_files",
+        ])]
+        zone: Option<String>,
+
+        #[command(flatten)]
+        selector: ZoneSelector,
 
         /// Purge everything
         #[arg(long, group = "purge_type")]
@@ -15,6 +45,29 @@ pub enum CacheCommand {
         #[arg(long, group = "purge_type", value_delimiter = ',')]
         files: Option<Vec<String>>,
 
+        /// Purge URLs listed in a file, one per line, or given as a JSON array of
+        /// URLs and {"url", "headers"} objects ('-' reads stdin)
+        #[arg(long, value_name = "PATH", group = "purge_type")]
+        from_file: Option<String>,
+
+        /// Purge the pages listed in a sitemap (file or URL; sitemap indexes are followed)
+        #[arg(long, value_name = "FILE|URL", group = "purge_type")]
+        sitemap: Option<String>,
+
+        /// Purge the URLs of changed files listed one per line, relative to the
+        /// site root ('-' reads stdin); index.html also purges its directory URL
+        #[arg(long, value_name = "PATH", group = "purge_type", requires = "base_url")]
+        changed_files: Option<String>,
+
+        /// Site URL that --changed-files paths are relative to
+        #[arg(long, value_name = "URL", requires = "changed_files")]
+        base_url: Option<String>,
+
+        /// Purge only the cached variant selected by this request header
+        /// (repeatable; several values of one header purge each variant)
+        #[arg(long = "header", value_name = "NAME: VALUE")]
+        headers: Vec<String>,
+
         /// Purge by cache tags
         #[arg(long, group = "purge_type", value_delimiter = ',')]
         tags: Option<Vec<String>>,
@@ -27,4 +80,73 @@ pub enum CacheCommand {
         #[arg(long, group = "purge_type", value_delimiter = ',')]
         prefixes: Option<Vec<String>>,
     },
+
+    /// Tiered Cache, Smart Tiered Cache topology, Cache Reserve and Argo Smart Routing
+    #[command(subcommand)]
+    Settings(CacheSettingsCommand),
+}
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"SETTINGS:
+  tiered-caching   Tiered Cache (argo/tiered_caching)
+  smart-topology   Smart Tiered Cache topology (cache/tiered_cache_smart_topology_enable)
+  cache-reserve    Cache Reserve (cache/cache_reserve)
+  argo             Argo Smart Routing (argo/smart_routing)
+
+EXAMPLES:
+  cfad cache settings show example.com
+  cfad cache settings set example.com --cache-reserve off --argo off
+  cfad cache settings clear-reserve example.com --confirm
+  cfad cache settings clear-reserve example.com --status
+"#)]
+pub enum CacheSettingsCommand {
+    /// Show the caching and routing settings of a zone
+    Show {
+        /// Zone name or ID
+        zone: String,
+    },
+
+    /// Turn caching and routing settings on or off
+    Set {
+        /// Zone name or ID
+        zone: String,
+
+        /// Tiered Cache
+        #[arg(long, value_parser = ["on", "off"])]
+        tiered_caching: Option<String>,
+
+        /// Smart Tiered Cache topology
+        #[arg(long, value_parser = ["on", "off"])]
+        smart_topology: Option<String>,
+
+        /// Cache Reserve
+        #[arg(long, value_parser = ["on", "off"])]
+        cache_reserve: Option<String>,
+
+        /// Argo Smart Routing
+        #[arg(long, value_parser = ["on", "off"])]
+        argo: Option<String>,
+    },
+
+    /// Delete everything stored in Cache Reserve (Cache Reserve must be off)
+    ClearReserve {
+        /// Zone name or ID
+        zone: String,
+
+        /// Show the current clear operation instead of starting one
+        #[arg(long, conflicts_with_all = ["confirm", "no_wait"])]
+        status: bool,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+
+        /// Return after starting instead of waiting for the clear to finish
+        #[arg(long)]
+        no_wait: bool,
+
+        /// Maximum time to wait in seconds
+        #[arg(long, default_value = "3600")]
+        timeout: u64,
+    },
 }
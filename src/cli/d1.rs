@@ -1,4 +1,4 @@
-use clap::Subcommand;
+use clap::{Args, Subcommand};
 
 #[derive(Subcommand)]
 #[command(after_long_help = r#"CREATING INDEXES:
@@ -19,6 +19,12 @@ use clap::Subcommand;
   Drop an index:
     cfad d1 query <database> "DROP INDEX idx_name"
 
+PARAMETERS:
+  Bind values to ? placeholders instead of splicing them into SQL:
+    cfad d1 query <database> "SELECT * FROM users WHERE id = ? AND email = ?" \
+      --param int:42 --param ada@example.com
+    cfad d1 query <database> "INSERT INTO t (a, b) VALUES (?, ?)" --params-json '[1, null]'
+
 NOTE: D1 is based on SQLite. Stored procedures are not supported, but triggers are:
     cfad d1 query <database> "CREATE TRIGGER ... AFTER INSERT ON table ..."
 "#)]
@@ -94,6 +100,9 @@ pub enum D1Command {
         /// SQL query to execute
         sql: String,
 
+        #[command(flatten)]
+        params: D1QueryParamsArgs,
+
         /// Use raw output format (arrays instead of objects)
         #[arg(long)]
         raw: bool,
@@ -116,6 +125,33 @@ pub enum D1Command {
         table: Option<String>,
     },
 
+    /// Open an interactive SQL shell
+    #[command(
+        after_long_help = r#"Statements may span several lines and run once terminated by ';'.
+History is kept in d1_history next to the config file.
+
+META-COMMANDS:
+  .tables              List tables
+  .schema [TABLE]      Show CREATE statements
+  .indexes             List indexes
+  .mode table|json|csv Set the output format
+  .timer on|off        Show duration and rows read/written after each statement
+  .read FILE           Execute SQL from a file
+  .quit                Exit (or Ctrl-D)
+
+EXAMPLES:
+  cfad d1 shell my-db
+"#
+    )]
+    Shell {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Database name or ID
+        database_id: String,
+    },
+
     /// Execute SQL from a file
     #[command(name = "query-file")]
     QueryFile {
@@ -129,6 +165,9 @@ pub enum D1Command {
         /// Path to SQL file
         file: String,
 
+        #[command(flatten)]
+        params: D1QueryParamsArgs,
+
         /// Use raw output format (arrays instead of objects)
         #[arg(long)]
         raw: bool,
@@ -138,7 +177,12 @@ pub enum D1Command {
         format: String,
     },
 
-    /// Export a D1 database to SQL
+    /// Export a D1 database to a SQL file
+    #[command(after_long_help = r#"EXAMPLES:
+  cfad d1 export my-db --output backup.sql
+  cfad d1 export my-db --output schema.sql --no-data
+  cfad d1 export my-db --output users.sql --table users --table sessions
+"#)]
     Export {
         /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
         #[arg(long)]
@@ -146,6 +190,26 @@ pub enum D1Command {
 
         /// Database ID
         database_id: String,
+
+        /// File to write the SQL dump to (replaced only once the download completes)
+        #[arg(long, short)]
+        output: String,
+
+        /// Export only the schema
+        #[arg(long, conflicts_with = "no_schema")]
+        no_data: bool,
+
+        /// Export only the data
+        #[arg(long)]
+        no_schema: bool,
+
+        /// Export only this table (repeatable)
+        #[arg(long = "table", value_name = "TABLE")]
+        tables: Vec<String>,
+
+        /// Maximum time to wait for the export in seconds
+        #[arg(long, default_value = "1800")]
+        timeout: u64,
     },
 
     /// Import SQL into a D1 database
@@ -159,6 +223,10 @@ pub enum D1Command {
 
         /// Path to SQL file
         file: String,
+
+        /// Maximum time to wait for a large import in seconds
+        #[arg(long, default_value = "1800")]
+        timeout: u64,
     },
 
     /// Get time travel bookmark
@@ -196,4 +264,79 @@ pub enum D1Command {
         #[arg(long)]
         confirm: bool,
     },
+
+    /// Numbered SQL migrations (wrangler-compatible)
+    #[command(subcommand)]
+    Migrations(D1MigrationsCommand),
+}
+
+/// Values bound to `?` placeholders of a query
+#[derive(Args, Debug, Clone, Default)]
+pub struct D1QueryParamsArgs {
+    /// Parameter for the next placeholder (repeatable): a plain string, null,
+    /// int:5, float:1.5, bool:true, json:<value> or str:<string>
+    #[arg(long = "param", value_name = "VALUE", conflicts_with = "params_json")]
+    pub params: Vec<String>,
+
+    /// All parameters as a JSON array, e.g. '[5, "ada", null]'
+    #[arg(long, value_name = "JSON")]
+    pub params_json: Option<String>,
+}
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"MIGRATIONS:
+  Migrations are files named NNNN_name.sql in the migrations directory and
+  are applied in order. Applied migrations are recorded in the d1_migrations
+  table, the same table wrangler uses, so both tools can be mixed.
+
+EXAMPLES:
+  cfad d1 migrations create my-db add_users
+  cfad d1 migrations list my-db
+  cfad d1 migrations apply my-db --bookmark
+"#)]
+pub enum D1MigrationsCommand {
+    /// Create the next numbered migration file
+    Create {
+        /// Database name or ID
+        database_id: String,
+
+        /// Migration name, e.g. add_users
+        name: String,
+
+        /// Migrations directory
+        #[arg(long, default_value = "migrations")]
+        dir: String,
+    },
+
+    /// Show applied and pending migrations
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Database name or ID
+        database_id: String,
+
+        /// Migrations directory
+        #[arg(long, default_value = "migrations")]
+        dir: String,
+    },
+
+    /// Apply pending migrations in order, stopping at the first failure
+    Apply {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Database name or ID
+        database_id: String,
+
+        /// Migrations directory
+        #[arg(long, default_value = "migrations")]
+        dir: String,
+
+        /// Take a time travel bookmark first to roll back a failed migration
+        #[arg(long)]
+        bookmark: bool,
+    },
 }
@@ -1,11 +1,17 @@
 use clap::Subcommand;
 
+use super::zone::ZoneSelector;
+
 #[derive(Subcommand)]
 pub enum DnsCommand {
     /// List DNS records
     List {
         /// Zone name (e.g., example.com)
-        zone: String,
+        #[arg(required_unless_present_any = ["zones", "zones_file", "all_zones", "zone_status"])]
+        zone: Option<String>,
+
+        #[command(flatten)]
+        selector: ZoneSelector,
 
         /// Filter by record type (A, AAAA, CNAME, MX, TXT, etc.)
         #[arg(long)]
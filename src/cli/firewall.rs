@@ -0,0 +1,113 @@
+use clap::{Args, Subcommand};
+
+use super::rules::RulesetTarget;
+
+#[derive(Subcommand)]
+pub enum FirewallCommand {
+    /// IP Access Rules (block, challenge or allow by IP, range, ASN or country)
+    #[command(subcommand)]
+    AccessRules(AccessRuleCommand),
+}
+
+/// What an access rule matches (exactly one is required)
+#[derive(Args, Debug, Clone, Default)]
+#[group(required = true, multiple = false)]
+pub struct AccessRuleTargetArgs {
+    /// Single IPv4 or IPv6 address
+    #[arg(long)]
+    pub ip: Option<String>,
+
+    /// IP range in CIDR notation (/16 or /24 for IPv4, /32, /48 or /64 for IPv6)
+    #[arg(long)]
+    pub ip_range: Option<String>,
+
+    /// Autonomous system number (e.g., AS13335 or 13335)
+    #[arg(long)]
+    pub asn: Option<String>,
+
+    /// Two-letter country code (ISO 3166-1 alpha-2)
+    #[arg(long)]
+    pub country: Option<String>,
+}
+
+impl AccessRuleTargetArgs {
+    /// Resolve the flags into an API target and value
+    pub fn target_and_value(&self) -> Option<(&'static str, String)> {
+        if let Some(ip) = &self.ip {
+            let target = if ip.contains(':') { "ip6" } else { "ip" };
+            return Some((target, ip.clone()));
+        }
+        if let Some(range) = &self.ip_range {
+            return Some(("ip_range", range.clone()));
+        }
+        if let Some(asn) = &self.asn {
+            let asn = asn.trim_start_matches("AS").trim_start_matches("as");
+            return Some(("asn", format!("AS{}", asn)));
+        }
+        self.country
+            .as_ref()
+            .map(|country| ("country", country.to_uppercase()))
+    }
+}
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"EXAMPLES:
+  cfad firewall access-rules list --zone example.com
+  cfad firewall access-rules add --zone example.com --mode block --ip 192.0.2.1 --notes "abuse"
+  cfad firewall access-rules add --account --mode challenge --asn AS64496
+  cfad firewall access-rules add --zone example.com --mode whitelist --ip-range 198.51.100.0/24
+  cfad firewall access-rules delete --zone example.com <RULE_ID> --confirm
+"#)]
+pub enum AccessRuleCommand {
+    /// List IP Access Rules
+    List {
+        #[command(flatten)]
+        target: RulesetTarget,
+
+        /// Filter by mode
+        #[arg(long, value_parser = ["block", "challenge", "js_challenge", "managed_challenge", "whitelist"])]
+        mode: Option<String>,
+
+        /// Filter by target type
+        #[arg(long = "target", value_parser = ["ip", "ip6", "ip_range", "asn", "country"])]
+        target_type: Option<String>,
+
+        /// Filter by target value
+        #[arg(long)]
+        value: Option<String>,
+
+        /// Filter by notes
+        #[arg(long)]
+        notes: Option<String>,
+    },
+
+    /// Create an IP Access Rule
+    Add {
+        #[command(flatten)]
+        target: RulesetTarget,
+
+        /// Action to apply
+        #[arg(long, value_parser = ["block", "challenge", "js_challenge", "managed_challenge", "whitelist"])]
+        mode: String,
+
+        #[command(flatten)]
+        matcher: AccessRuleTargetArgs,
+
+        /// Notes to attach to the rule
+        #[arg(long)]
+        notes: Option<String>,
+    },
+
+    /// Delete an IP Access Rule
+    Delete {
+        #[command(flatten)]
+        target: RulesetTarget,
+
+        /// Rule ID
+        rule_id: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+}
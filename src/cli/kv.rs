@@ -0,0 +1,207 @@
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"NAMESPACES:
+  <NAMESPACE> is a namespace ID or title.
+
+BULK FILES:
+  `bulk put` reads a JSON array in the wrangler format:
+    [{"key": "greeting", "value": "hello", "expiration_ttl": 3600, "metadata": {"lang": "en"}},
+     {"key": "logo", "value": "iVBORw0KGgo...", "base64": true}]
+  `bulk delete` reads a JSON array of key names (or the same objects).
+  Files larger than 10,000 keys are sent in several requests.
+
+EXAMPLES:
+  cfad kv namespace create SESSIONS
+  cfad kv key put SESSIONS user:42 '{"theme":"dark"}' --expiration-ttl 86400
+  cfad kv key put SESSIONS logo --path logo.png --metadata '{"type":"image/png"}'
+  cfad kv key get SESSIONS logo --output logo.png
+  cfad kv bulk put SESSIONS data.json
+  cfad kv export SESSIONS ./backup --prefix user:
+"#)]
+pub enum KvCommand {
+    /// Namespace management
+    #[command(subcommand)]
+    Namespace(KvNamespaceCommand),
+
+    /// Single-key operations
+    #[command(subcommand)]
+    Key(KvKeyCommand),
+
+    /// Write or delete many keys from a JSON file
+    #[command(subcommand)]
+    Bulk(KvBulkCommand),
+
+    /// Download every key and value of a namespace into a directory
+    Export {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Namespace ID or title
+        namespace: String,
+
+        /// Output directory (one file per key under values/ plus manifest.json)
+        dir: String,
+
+        /// Only export keys starting with this prefix
+        #[arg(long)]
+        prefix: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum KvNamespaceCommand {
+    /// List namespaces
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+    },
+
+    /// Create a namespace
+    Create {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Namespace title
+        title: String,
+    },
+
+    /// Rename a namespace
+    Rename {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Namespace ID or title
+        namespace: String,
+
+        /// New title
+        title: String,
+    },
+
+    /// Delete a namespace and all its keys
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Namespace ID or title
+        namespace: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum KvKeyCommand {
+    /// List keys
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Namespace ID or title
+        namespace: String,
+
+        /// Only list keys starting with this prefix
+        #[arg(long)]
+        prefix: Option<String>,
+    },
+
+    /// Read a value (printed to stdout unless --output is given)
+    Get {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Namespace ID or title
+        namespace: String,
+
+        /// Key name
+        key: String,
+
+        /// Write the value to this file
+        #[arg(long, short = 'o')]
+        output: Option<String>,
+    },
+
+    /// Write a value
+    Put {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Namespace ID or title
+        namespace: String,
+
+        /// Key name
+        key: String,
+
+        /// Value (use --path to read it from a file instead)
+        #[arg(required_unless_present = "path", conflicts_with = "path")]
+        value: Option<String>,
+
+        /// Read the value from a file
+        #[arg(long)]
+        path: Option<String>,
+
+        /// Expire the key after this many seconds (minimum 60)
+        #[arg(long)]
+        expiration_ttl: Option<u64>,
+
+        /// Arbitrary JSON metadata stored with the key
+        #[arg(long)]
+        metadata: Option<String>,
+    },
+
+    /// Delete a key
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Namespace ID or title
+        namespace: String,
+
+        /// Key name
+        key: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum KvBulkCommand {
+    /// Write keys from a JSON file
+    Put {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Namespace ID or title
+        namespace: String,
+
+        /// JSON file of {key, value, ...} objects
+        file: String,
+    },
+
+    /// Delete keys listed in a JSON file
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Namespace ID or title
+        namespace: String,
+
+        /// JSON file of key names
+        file: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+}
@@ -0,0 +1,418 @@
+use clap::{Args, Subcommand};
+
+#[derive(Subcommand)]
+pub enum LbCommand {
+    /// Health monitors (account-level)
+    #[command(subcommand)]
+    Monitors(MonitorCommand),
+
+    /// Origin pools (account-level)
+    #[command(subcommand)]
+    Pools(PoolCommand),
+
+    /// Load balancers (zone-level)
+    #[command(subcommand)]
+    Balancers(BalancerCommand),
+}
+
+/// Health check settings shared by monitor create and update
+#[derive(Args, Debug, Clone, Default)]
+pub struct MonitorArgs {
+    /// Description
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// HTTP method for HTTP(S) checks
+    #[arg(long)]
+    pub method: Option<String>,
+
+    /// Path for HTTP(S) checks
+    #[arg(long)]
+    pub path: Option<String>,
+
+    /// Expected status codes (e.g. 2xx, 200, 301)
+    #[arg(long)]
+    pub expected_codes: Option<String>,
+
+    /// Case-insensitive substring expected in the response body
+    #[arg(long)]
+    pub expected_body: Option<String>,
+
+    /// Seconds between checks
+    #[arg(long)]
+    pub interval: Option<u32>,
+
+    /// Seconds before a check times out
+    #[arg(long)]
+    pub timeout: Option<u32>,
+
+    /// Retries before an origin is marked unhealthy
+    #[arg(long)]
+    pub retries: Option<u32>,
+
+    /// Port to check (defaults to the protocol's port)
+    #[arg(long)]
+    pub port: Option<u16>,
+
+    /// Request header as 'Name: value' (repeatable)
+    #[arg(long = "header", value_name = "NAME: VALUE")]
+    pub headers: Vec<String>,
+
+    /// Follow redirects (on, off)
+    #[arg(long, value_parser = ["on", "off"])]
+    pub follow_redirects: Option<String>,
+
+    /// Skip certificate validation for HTTPS checks (on, off)
+    #[arg(long, value_parser = ["on", "off"])]
+    pub allow_insecure: Option<String>,
+}
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"EXAMPLES:
+  cfad lb monitors list
+  cfad lb monitors create --type https --path /healthz --expected-codes 200 --header "Host: app.example.com"
+  cfad lb monitors update <MONITOR_ID> --interval 30
+  cfad lb monitors delete <MONITOR_ID> --confirm
+"#)]
+pub enum MonitorCommand {
+    /// List monitors
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+    },
+
+    /// Show monitor details
+    Show {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Monitor ID
+        monitor_id: String,
+    },
+
+    /// Create a monitor
+    Create {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Check protocol
+        #[arg(long = "type", default_value = "http", value_parser = ["http", "https", "tcp", "udp_icmp", "icmp_ping", "smtp"])]
+        monitor_type: String,
+
+        #[command(flatten)]
+        options: MonitorArgs,
+    },
+
+    /// Update a monitor
+    Update {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Monitor ID
+        monitor_id: String,
+
+        #[command(flatten)]
+        options: MonitorArgs,
+    },
+
+    /// Delete a monitor
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Monitor ID
+        monitor_id: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+}
+
+/// Pool settings shared by pool create and update
+#[derive(Args, Debug, Clone, Default)]
+pub struct PoolArgs {
+    /// Description
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// Monitor ID used for health checks
+    #[arg(long)]
+    pub monitor: Option<String>,
+
+    /// Healthy origins required for the pool to be healthy
+    #[arg(long)]
+    pub minimum_origins: Option<u32>,
+
+    /// Email address for health notifications
+    #[arg(long)]
+    pub notification_email: Option<String>,
+
+    /// Regions to run health checks from, comma-separated (e.g. WNAM,ENAM,WEU)
+    #[arg(long, value_delimiter = ',')]
+    pub check_regions: Option<Vec<String>>,
+}
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"ORIGINS:
+  --origin accepts a bare address or comma-separated key=value pairs:
+    --origin 192.0.2.1
+    --origin name=web1,address=origin1.example.com,weight=0.5,enabled=true
+
+EXAMPLES:
+  cfad lb pools create primary --origin name=web1,address=192.0.2.1 --origin name=web2,address=192.0.2.2 --monitor <MONITOR_ID>
+  cfad lb pools health primary
+  cfad lb pools disable-origin primary web2
+  cfad lb pools enable-origin primary web2 --weight 1
+  cfad lb pools drain primary web1 --wait 300 --disable
+"#)]
+pub enum PoolCommand {
+    /// List pools
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+    },
+
+    /// Show pool details and origins
+    Show {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Pool name or ID
+        pool: String,
+    },
+
+    /// Create a pool
+    Create {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Pool name
+        name: String,
+
+        /// Origin server (repeatable, see ORIGINS below)
+        #[arg(long = "origin", required = true, value_name = "ORIGIN")]
+        origins: Vec<String>,
+
+        #[command(flatten)]
+        options: PoolArgs,
+    },
+
+    /// Update a pool
+    Update {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Pool name or ID
+        pool: String,
+
+        /// Rename the pool
+        #[arg(long)]
+        name: Option<String>,
+
+        /// Add an origin (repeatable, see ORIGINS below)
+        #[arg(long = "add-origin", value_name = "ORIGIN")]
+        add_origins: Vec<String>,
+
+        /// Remove an origin by name or address (repeatable)
+        #[arg(long = "remove-origin", value_name = "ORIGIN")]
+        remove_origins: Vec<String>,
+
+        /// Enable or disable the whole pool
+        #[arg(long, value_parser = ["on", "off"])]
+        enabled: Option<String>,
+
+        #[command(flatten)]
+        options: PoolArgs,
+    },
+
+    /// Delete a pool
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Pool name or ID
+        pool: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Show origin health per check region
+    Health {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Pool name or ID
+        pool: String,
+    },
+
+    /// Put an origin back into rotation
+    EnableOrigin {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Pool name or ID
+        pool: String,
+
+        /// Origin name or address
+        origin: String,
+
+        /// Also set the origin's weight (0-1), e.g. to restore it after a drain
+        #[arg(long)]
+        weight: Option<String>,
+    },
+
+    /// Take an origin out of rotation (e.g. for maintenance)
+    DisableOrigin {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Pool name or ID
+        pool: String,
+
+        /// Origin name or address
+        origin: String,
+    },
+
+    /// Set an origin's weight to 0 so it gets no new traffic, then wait
+    Drain {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Pool name or ID
+        pool: String,
+
+        /// Origin name or address
+        origin: String,
+
+        /// Seconds to wait for existing sessions to finish
+        #[arg(long, default_value = "60")]
+        wait: u64,
+
+        /// Disable the origin once the wait is over
+        #[arg(long)]
+        disable: bool,
+    },
+}
+
+/// Load balancer settings shared by balancer create and update
+#[derive(Args, Debug, Clone, Default)]
+pub struct BalancerArgs {
+    /// Account ID for resolving pool names (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+    #[arg(long)]
+    pub account_id: Option<String>,
+
+    /// Fallback pool used when every default pool is unhealthy
+    #[arg(long)]
+    pub fallback_pool: Option<String>,
+
+    /// Description
+    #[arg(long)]
+    pub description: Option<String>,
+
+    /// Proxy traffic through Cloudflare (on, off)
+    #[arg(long, value_parser = ["on", "off"])]
+    pub proxied: Option<String>,
+
+    /// DNS TTL for unproxied load balancers
+    #[arg(long)]
+    pub ttl: Option<u32>,
+
+    /// Steering policy
+    #[arg(long = "steering", value_parser = ["off", "geo", "random", "dynamic_latency", "proximity", "least_outstanding_requests", "least_connections"])]
+    pub steering_policy: Option<String>,
+
+    /// Session affinity
+    #[arg(long, value_parser = ["none", "cookie", "ip_cookie", "header"])]
+    pub session_affinity: Option<String>,
+}
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"EXAMPLES:
+  cfad lb balancers list example.com
+  cfad lb balancers create example.com app.example.com --default-pools primary,secondary --fallback-pool secondary
+  cfad lb balancers update example.com app.example.com --steering dynamic_latency
+  cfad lb balancers update example.com app.example.com --enabled off
+  cfad lb balancers delete example.com app.example.com --confirm
+"#)]
+pub enum BalancerCommand {
+    /// List load balancers in a zone
+    List {
+        /// Zone name or ID
+        zone: String,
+    },
+
+    /// Show load balancer details
+    Show {
+        /// Zone name or ID
+        zone: String,
+
+        /// Load balancer hostname or ID
+        load_balancer: String,
+    },
+
+    /// Create a load balancer
+    Create {
+        /// Zone name or ID
+        zone: String,
+
+        /// Hostname to balance (e.g. app.example.com)
+        hostname: String,
+
+        /// Pools in failover order, by name or ID (comma-separated)
+        #[arg(long, required = true, value_delimiter = ',')]
+        default_pools: Vec<String>,
+
+        #[command(flatten)]
+        options: BalancerArgs,
+    },
+
+    /// Update a load balancer
+    Update {
+        /// Zone name or ID
+        zone: String,
+
+        /// Load balancer hostname or ID
+        load_balancer: String,
+
+        /// Replace the pools in failover order, by name or ID (comma-separated)
+        #[arg(long, value_delimiter = ',')]
+        default_pools: Option<Vec<String>>,
+
+        /// Enable or disable the load balancer
+        #[arg(long, value_parser = ["on", "off"])]
+        enabled: Option<String>,
+
+        #[command(flatten)]
+        options: BalancerArgs,
+    },
+
+    /// Delete a load balancer
+    Delete {
+        /// Zone name or ID
+        zone: String,
+
+        /// Load balancer hostname or ID
+        load_balancer: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+}
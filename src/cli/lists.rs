@@ -0,0 +1,125 @@
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"ITEM FILES:
+  Text: one value per line, optionally followed by whitespace and a comment.
+        Lines starting with '#' are ignored.
+  CSV:  header row with a value (or ip/asn/hostname) column and an optional
+        comment column. Read as CSV when the file ends in .csv or its first
+        line is such a header.
+
+EXAMPLES:
+  cfad lists create blocklist --kind ip --description "SOC blocklist"
+  cfad lists add blocklist 192.0.2.1 198.51.100.0/24 --comment "scanner"
+  cfad lists add blocklist --file blocklist.txt
+  cfad lists add blocklist --file blocklist.csv --replace
+  cfad lists items blocklist
+"#)]
+pub enum ListsCommand {
+    /// List custom lists in the account
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+    },
+
+    /// Create a custom list
+    Create {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// List name (referenced from rule expressions as $name)
+        name: String,
+
+        /// Kind of items the list holds
+        #[arg(long, default_value = "ip", value_parser = ["ip", "asn", "hostname", "redirect"])]
+        kind: String,
+
+        /// List description
+        #[arg(long)]
+        description: Option<String>,
+    },
+
+    /// Delete a custom list
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// List name or ID
+        list: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Show items in a custom list
+    Items {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// List name or ID
+        list: String,
+    },
+
+    /// Add items to a custom list
+    Add {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// List name or ID
+        list: String,
+
+        /// Values to add (IP, CIDR, ASN or hostname depending on list kind)
+        #[arg(required_unless_present = "file")]
+        values: Vec<String>,
+
+        /// Read items from a text or CSV file
+        #[arg(long, conflicts_with = "values")]
+        file: Option<String>,
+
+        /// Comment applied to values given on the command line
+        #[arg(long)]
+        comment: Option<String>,
+
+        /// Replace all existing items instead of appending
+        #[arg(long)]
+        replace: bool,
+
+        /// Return after submitting instead of waiting for the operation to finish
+        #[arg(long)]
+        no_wait: bool,
+    },
+
+    /// Remove items from a custom list
+    Remove {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// List name or ID
+        list: String,
+
+        /// Item IDs or values to remove
+        #[arg(required = true)]
+        items: Vec<String>,
+
+        /// Return after submitting instead of waiting for the operation to finish
+        #[arg(long)]
+        no_wait: bool,
+    },
+
+    /// Show the status of an asynchronous list operation
+    Operation {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Operation ID
+        operation_id: String,
+    },
+}
@@ -4,9 +4,18 @@ pub mod cache;
 pub mod config;
 pub mod d1;
 pub mod dns;
+pub mod firewall;
+pub mod kv;
+pub mod lb;
+pub mod lists;
 pub mod pages;
+pub mod queues;
 pub mod r2;
+pub mod rules;
+pub mod ssl;
 pub mod token;
+pub mod tunnel;
+pub mod workers;
 pub mod zone;
 
 #[derive(Parser)]
@@ -71,18 +80,54 @@ pub enum Commands {
     #[command(subcommand)]
     D1(d1::D1Command),
 
+    /// Firewall management (IP Access Rules)
+    #[command(subcommand)]
+    Firewall(firewall::FirewallCommand),
+
+    /// Workers KV namespaces and keys
+    #[command(subcommand)]
+    Kv(kv::KvCommand),
+
+    /// Load Balancing (monitors, origin pools and load balancers)
+    #[command(subcommand)]
+    Lb(lb::LbCommand),
+
+    /// Account-level custom lists (IP, ASN, hostname, redirect)
+    #[command(subcommand)]
+    Lists(lists::ListsCommand),
+
     /// Pages deployment platform
     #[command(subcommand)]
     Pages(pages::PagesCommand),
 
+    /// Queues, consumers and message debugging
+    #[command(subcommand)]
+    Queues(queues::QueuesCommand),
+
     /// R2 object storage management
     #[command(subcommand)]
     R2(r2::R2Command),
 
+    /// Rulesets engine (WAF custom, redirect, transform, cache, origin and rate limiting rules)
+    #[command(subcommand)]
+    Rules(rules::RulesCommand),
+
+    /// SSL/TLS certificates and settings
+    #[command(subcommand)]
+    Ssl(ssl::SslCommand),
+
     /// API token management
     #[command(subcommand)]
     Token(token::TokenCommand),
 
+    /// Cloudflare Tunnel management (cloudflared)
+    #[command(subcommand)]
+    Tunnel(tunnel::TunnelCommand),
+
+    /// Workers scripts, routes, custom domains and cron triggers
+    #[command(subcommand)]
+    Workers(workers::WorkersCommand),
+
     /// Configuration management
     #[command(subcommand)]
     Config(config::ConfigCommand),
@@ -0,0 +1,220 @@
+use clap::{Args, Subcommand};
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"QUEUES:
+  <QUEUE> is a queue name or ID.
+
+EXAMPLES:
+  cfad queues create image-uploads
+  cfad queues consumer add image-uploads --script thumbnailer --batch-size 25 \
+    --max-wait-ms 5000 --dead-letter-queue image-uploads-dlq
+  cfad queues consumer add image-uploads --http-pull --visibility-timeout-ms 60000
+  cfad queues send image-uploads '{"key":"cat.png"}' --json
+  cfad queues pull image-uploads --batch-size 10
+  cfad queues ack image-uploads <LEASE_ID> --retry <LEASE_ID>
+"#)]
+pub enum QueuesCommand {
+    /// List queues
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+    },
+
+    /// Show a queue with its producers and consumers
+    Show {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Queue name or ID
+        queue: String,
+    },
+
+    /// Create a queue
+    Create {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Queue name (lowercase letters, digits and dashes)
+        name: String,
+    },
+
+    /// Delete a queue
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Queue name or ID
+        queue: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Worker and HTTP pull consumers
+    #[command(subcommand)]
+    Consumer(QueueConsumerCommand),
+
+    /// Send a message (for debugging)
+    Send {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Queue name or ID
+        queue: String,
+
+        /// Message body
+        body: String,
+
+        /// Send the body as JSON instead of text
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Lease messages through an HTTP pull consumer
+    Pull {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Queue name or ID
+        queue: String,
+
+        /// Number of messages to lease (1-100)
+        #[arg(long, default_value = "10")]
+        batch_size: u32,
+
+        /// How long the messages stay leased before redelivery
+        #[arg(long)]
+        visibility_timeout_ms: Option<u32>,
+    },
+
+    /// Acknowledge or retry messages leased with `pull`
+    Ack {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Queue name or ID
+        queue: String,
+
+        /// Lease IDs of processed messages
+        lease_ids: Vec<String>,
+
+        /// Lease ID of a message to redeliver (repeatable)
+        #[arg(long = "retry", value_name = "LEASE_ID")]
+        retries: Vec<String>,
+
+        /// Delay before retried messages are redelivered, in seconds
+        #[arg(long)]
+        retry_delay: Option<u32>,
+    },
+}
+
+/// Batching and retry options of a consumer
+#[derive(Args, Debug, Clone, Default)]
+pub struct ConsumerSettingsArgs {
+    /// Messages per batch (1-100)
+    #[arg(long)]
+    pub batch_size: Option<u32>,
+
+    /// Delivery attempts before a message is dropped or dead-lettered
+    #[arg(long)]
+    pub max_retries: Option<u32>,
+
+    /// Longest wait for a full batch (Worker consumers)
+    #[arg(long)]
+    pub max_wait_ms: Option<u32>,
+
+    /// Maximum concurrent invocations (Worker consumers)
+    #[arg(long)]
+    pub max_concurrency: Option<u32>,
+
+    /// Seconds before a retried message is redelivered
+    #[arg(long)]
+    pub retry_delay: Option<u32>,
+
+    /// How long pulled messages stay leased (HTTP pull consumers)
+    #[arg(long)]
+    pub visibility_timeout_ms: Option<u32>,
+
+    /// Queue that receives messages after the last retry
+    #[arg(long)]
+    pub dead_letter_queue: Option<String>,
+}
+
+#[derive(Subcommand)]
+pub enum QueueConsumerCommand {
+    /// List consumers of a queue
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Queue name or ID
+        queue: String,
+    },
+
+    /// Add a Worker (--script) or HTTP pull (--http-pull) consumer
+    Add {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Queue name or ID
+        queue: String,
+
+        /// Worker script that consumes the queue
+        #[arg(
+            long,
+            required_unless_present = "http_pull",
+            conflicts_with = "http_pull"
+        )]
+        script: Option<String>,
+
+        /// Consume over HTTP with `queues pull` / `queues ack`
+        #[arg(long)]
+        http_pull: bool,
+
+        #[command(flatten)]
+        settings: ConsumerSettingsArgs,
+    },
+
+    /// Change a consumer's settings, keeping the ones not given
+    Update {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Queue name or ID
+        queue: String,
+
+        /// Consumer ID, or script name of a Worker consumer
+        consumer: String,
+
+        #[command(flatten)]
+        settings: ConsumerSettingsArgs,
+    },
+
+    /// Remove a consumer
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Queue name or ID
+        queue: String,
+
+        /// Consumer ID, or script name of a Worker consumer
+        consumer: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+}
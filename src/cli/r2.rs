@@ -404,7 +404,8 @@ pub enum R2NotificationCommand {
         /// Bucket name
         bucket: String,
 
-        /// Queue ID
+        /// Queue name or ID
+        #[arg(value_name = "QUEUE")]
         queue_id: String,
     },
 
@@ -417,7 +418,8 @@ pub enum R2NotificationCommand {
         /// Bucket name
         bucket: String,
 
-        /// Queue ID to send notifications to
+        /// Queue name or ID to send notifications to
+        #[arg(value_name = "QUEUE")]
         queue_id: String,
 
         /// Event types (e.g., object:create, object:delete)
@@ -442,7 +444,8 @@ pub enum R2NotificationCommand {
         /// Bucket name
         bucket: String,
 
-        /// Queue ID
+        /// Queue name or ID
+        #[arg(value_name = "QUEUE")]
         queue_id: String,
 
         /// Skip confirmation
@@ -0,0 +1,250 @@
+use clap::{Args, Subcommand};
+
+/// Zone or account a ruleset command operates on
+#[derive(Args, Debug, Clone, Default)]
+pub struct RulesetTarget {
+    /// Zone name or ID
+    #[arg(long, required_unless_present = "account")]
+    pub zone: Option<String>,
+
+    /// Operate on account-level rulesets instead of a zone
+    #[arg(long, conflicts_with = "zone")]
+    pub account: bool,
+
+    /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+    #[arg(long)]
+    pub account_id: Option<String>,
+}
+
+/// Placement of a rule within its ruleset
+#[derive(Args, Debug, Clone, Default)]
+#[group(multiple = false)]
+pub struct RulePositionArgs {
+    /// Place the rule before the rule with this ID
+    #[arg(long)]
+    pub before: Option<String>,
+
+    /// Place the rule after the rule with this ID
+    #[arg(long)]
+    pub after: Option<String>,
+
+    /// Place the rule at this 1-based index
+    #[arg(long)]
+    pub index: Option<u32>,
+}
+
+impl RulePositionArgs {
+    pub fn is_set(&self) -> bool {
+        self.before.is_some() || self.after.is_some() || self.index.is_some()
+    }
+}
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"PHASES:
+  waf        http_request_firewall_custom   (WAF custom rules)
+  redirect   http_request_dynamic_redirect  (single redirects)
+  transform  http_request_late_transform    (header modification)
+  cache      http_request_cache_settings    (cache rules)
+  origin     http_request_origin            (origin rules)
+  ratelimit  http_ratelimit                 (rate limiting rules)
+  config     http_config_settings           (configuration rules)
+
+EXAMPLES:
+  cfad rules waf list --zone example.com
+  cfad rules waf add --zone example.com --action block \
+      --expression '(ip.src.country eq "XX")' --description "Block XX"
+  cfad rules redirect add --zone example.com --action redirect \
+      --expression '(http.request.uri.path eq "/old")' \
+      --action-parameters '{"from_value":{"target_url":{"value":"https://example.com/new"},"status_code":301}}'
+  cfad rules apply rules.yaml --zone example.com
+  cfad rules validate '(http.request.uri.path eq "/admin" and not cf.client.bot)'
+  cfad rules test 'ip.src in {192.0.2.0/24}' --request request.json
+"#)]
+pub enum RulesCommand {
+    /// List rulesets and phase entrypoints
+    Phases {
+        #[command(flatten)]
+        target: RulesetTarget,
+    },
+
+    /// WAF custom rules (http_request_firewall_custom)
+    #[command(subcommand)]
+    Waf(PhaseRuleCommand),
+
+    /// Single redirect rules (http_request_dynamic_redirect)
+    #[command(subcommand)]
+    Redirect(PhaseRuleCommand),
+
+    /// Header modification rules (http_request_late_transform)
+    #[command(subcommand)]
+    Transform(PhaseRuleCommand),
+
+    /// Cache rules (http_request_cache_settings)
+    #[command(subcommand)]
+    Cache(PhaseRuleCommand),
+
+    /// Origin rules (http_request_origin)
+    #[command(subcommand)]
+    Origin(PhaseRuleCommand),
+
+    /// Rate limiting rules (http_ratelimit)
+    #[command(subcommand)]
+    Ratelimit(PhaseRuleCommand),
+
+    /// Configuration rules (http_config_settings)
+    #[command(subcommand)]
+    Config(PhaseRuleCommand),
+
+    /// Replace the rules of one or more phases from a YAML or JSON file
+    Apply {
+        /// Path to rules file (.yaml, .yml or .json)
+        file: String,
+
+        #[command(flatten)]
+        target: RulesetTarget,
+
+        /// Show what would be applied without calling the API
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip local expression validation
+        #[arg(long)]
+        no_validate: bool,
+    },
+
+    /// Check a Rules language expression for syntax and type errors (offline)
+    Validate {
+        /// Rules language expression
+        expression: String,
+    },
+
+    /// Evaluate a Rules language expression against a sample request (offline)
+    #[command(after_long_help = r#"REQUEST FILE:
+  A JSON object mapping field names to values. Fields the expression uses but
+  the file omits are treated as empty/zero. Named lists go under "lists".
+
+  {
+    "http.request.uri.path": "/admin",
+    "ip.src": "192.0.2.1",
+    "http.request.headers": {"user-agent": ["curl/8.0"]},
+    "cf.client.bot": false,
+    "lists": {"blocklist": ["192.0.2.0/24"]}
+  }
+"#)]
+    Test {
+        /// Rules language expression
+        expression: String,
+
+        /// Path to a JSON file describing the request
+        #[arg(long)]
+        request: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum PhaseRuleCommand {
+    /// List rules in this phase
+    List {
+        #[command(flatten)]
+        target: RulesetTarget,
+    },
+
+    /// Add a rule
+    Add {
+        #[command(flatten)]
+        target: RulesetTarget,
+
+        /// Rules language expression
+        #[arg(long)]
+        expression: String,
+
+        /// Action (block, challenge, managed_challenge, skip, redirect, rewrite, set_cache_settings, route, ...)
+        #[arg(long)]
+        action: String,
+
+        /// Action parameters as JSON
+        #[arg(long)]
+        action_parameters: Option<String>,
+
+        /// Rate limiting characteristics as JSON (ratelimit phase)
+        #[arg(long)]
+        ratelimit: Option<String>,
+
+        /// Rule description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Create the rule disabled
+        #[arg(long)]
+        disabled: bool,
+
+        /// Skip local expression validation
+        #[arg(long)]
+        no_validate: bool,
+
+        #[command(flatten)]
+        position: RulePositionArgs,
+    },
+
+    /// Update a rule
+    Update {
+        #[command(flatten)]
+        target: RulesetTarget,
+
+        /// Rule ID
+        rule_id: String,
+
+        /// New expression
+        #[arg(long)]
+        expression: Option<String>,
+
+        /// New action
+        #[arg(long)]
+        action: Option<String>,
+
+        /// New action parameters as JSON
+        #[arg(long)]
+        action_parameters: Option<String>,
+
+        /// New rate limiting characteristics as JSON
+        #[arg(long)]
+        ratelimit: Option<String>,
+
+        /// New description
+        #[arg(long)]
+        description: Option<String>,
+
+        /// Enable or disable the rule
+        #[arg(long)]
+        enabled: Option<bool>,
+
+        /// Skip local expression validation
+        #[arg(long)]
+        no_validate: bool,
+    },
+
+    /// Delete a rule
+    Delete {
+        #[command(flatten)]
+        target: RulesetTarget,
+
+        /// Rule ID
+        rule_id: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Move a rule within the phase
+    Reorder {
+        #[command(flatten)]
+        target: RulesetTarget,
+
+        /// Rule ID
+        rule_id: String,
+
+        #[command(flatten)]
+        position: RulePositionArgs,
+    },
+}
@@ -0,0 +1,118 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"EXAMPLES:
+  cfad ssl status example.com
+  cfad ssl order-pack example.com --hosts example.com,*.example.com --ca google --validity 90
+  cfad ssl universal example.com off
+"#)]
+pub enum SslCommand {
+    /// Show a zone's TLS setup: SSL mode, TLS versions, HSTS, origin pulls and edge certificates
+    Status {
+        /// Zone name or ID
+        zone: String,
+    },
+
+    /// Order an advanced edge certificate pack
+    OrderPack {
+        /// Zone name or ID
+        zone: String,
+
+        /// Hostnames to cover, comma-separated (must include the zone apex)
+        #[arg(long, required = true, value_delimiter = ',')]
+        hosts: Vec<String>,
+
+        /// Certificate authority
+        #[arg(long = "ca", default_value = "lets_encrypt", value_parser = ["google", "lets_encrypt", "ssl_com"])]
+        certificate_authority: String,
+
+        /// Validity in days (14, 30, 90 or 365)
+        #[arg(long, default_value = "90")]
+        validity: u32,
+
+        /// Domain control validation method
+        #[arg(long, default_value = "txt", value_parser = ["txt", "http", "email"])]
+        validation_method: String,
+
+        /// Add a Cloudflare-branded hostname to the certificate
+        #[arg(long)]
+        cloudflare_branding: bool,
+    },
+
+    /// Enable or disable Universal SSL
+    Universal {
+        /// Zone name or ID
+        zone: String,
+
+        /// New state
+        #[arg(value_parser = ["on", "off"])]
+        state: String,
+    },
+
+    /// Origin CA certificates (trusted by Cloudflare between the edge and your origin)
+    #[command(subcommand)]
+    OriginCert(OriginCertCommand),
+}
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"EXAMPLES:
+  cfad ssl origin-cert create --hostnames example.com,*.example.com
+  cfad ssl origin-cert create --hostnames api.example.com --validity 365 --type ecdsa --out-dir /etc/ssl/private
+  cfad ssl origin-cert list --zone example.com
+  cfad ssl origin-cert revoke <CERT_ID> --confirm
+
+The private key and CSR are generated locally; only the CSR is sent to
+Cloudflare. Key and certificate files are written with 0600 permissions.
+Origin CA certificates are only trusted by Cloudflare, not by browsers.
+"#)]
+pub enum OriginCertCommand {
+    /// Generate a key locally and issue an Origin CA certificate for it
+    Create {
+        /// Hostnames to cover, comma-separated (wildcards like *.example.com allowed)
+        #[arg(long, required = true, value_delimiter = ',')]
+        hostnames: Vec<String>,
+
+        /// Validity in days (7, 30, 90, 365, 730, 1095 or 5475)
+        #[arg(long, default_value = "5475")]
+        validity: u32,
+
+        /// Private key type
+        #[arg(long = "type", default_value = "rsa", value_parser = ["rsa", "ecdsa"])]
+        key_type: String,
+
+        /// Directory for the key and certificate files
+        #[arg(long, default_value = ".")]
+        out_dir: PathBuf,
+
+        /// Private key path (default: <out-dir>/<hostname>.key)
+        #[arg(long)]
+        key_file: Option<PathBuf>,
+
+        /// Certificate path (default: <out-dir>/<hostname>.pem)
+        #[arg(long)]
+        cert_file: Option<PathBuf>,
+
+        /// Overwrite existing key and certificate files
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// List Origin CA certificates for a zone
+    List {
+        /// Zone name or ID
+        #[arg(long)]
+        zone: String,
+    },
+
+    /// Revoke an Origin CA certificate
+    Revoke {
+        /// Certificate ID
+        cert_id: String,
+
+        /// Skip confirmation prompt
+        #[arg(long)]
+        confirm: bool,
+    },
+}
@@ -0,0 +1,153 @@
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"EXAMPLES:
+  cfad tunnel list
+  cfad tunnel create prod-web
+  cfad tunnel show prod-web
+  cloudflared tunnel run --token "$(cfad tunnel token prod-web)"
+  cfad tunnel config get prod-web > ingress.yml
+  cfad tunnel config put prod-web ingress.yml
+  cfad tunnel route dns prod-web app.example.com
+  cfad tunnel delete prod-web --cleanup-connections --confirm
+"#)]
+pub enum TunnelCommand {
+    /// List tunnels
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+    },
+
+    /// Show tunnel details and connection status
+    Show {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Tunnel name or ID
+        tunnel: String,
+    },
+
+    /// Create a tunnel
+    Create {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Tunnel name
+        name: String,
+
+        /// Read ingress rules from cloudflared's local config.yml instead of Cloudflare
+        #[arg(long)]
+        locally_managed: bool,
+    },
+
+    /// Delete a tunnel
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Tunnel name or ID
+        tunnel: String,
+
+        /// Remove stale connections first (the API refuses to delete a tunnel that has any)
+        #[arg(long)]
+        cleanup_connections: bool,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Print the token used by `cloudflared tunnel run --token`
+    Token {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Tunnel name or ID
+        tunnel: String,
+    },
+
+    /// Remotely-managed configuration (ingress rules)
+    #[command(subcommand)]
+    Config(TunnelConfigCommand),
+
+    /// Route traffic to a tunnel
+    #[command(subcommand)]
+    Route(TunnelRouteCommand),
+}
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"FILE FORMAT:
+  The same layout as cloudflared's config.yml; `tunnel` and `credentials-file`
+  keys are ignored. The last rule must be a catch-all.
+
+    originRequest:
+      connectTimeout: 30s
+    ingress:
+      - hostname: app.example.com
+        service: http://localhost:8080
+      - hostname: app.example.com
+        path: ^/api/
+        service: http://localhost:9000
+      - service: http_status:404
+"#)]
+pub enum TunnelConfigCommand {
+    /// Print the tunnel's ingress configuration as YAML
+    Get {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Tunnel name or ID
+        tunnel: String,
+
+        /// Write to a file instead of stdout
+        #[arg(long, short = 'o')]
+        output: Option<String>,
+    },
+
+    /// Replace the tunnel's ingress configuration from a YAML (or JSON) file
+    Put {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Tunnel name or ID
+        tunnel: String,
+
+        /// Configuration file
+        file: String,
+
+        /// Validate the file and show the rules without uploading
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum TunnelRouteCommand {
+    /// Create a proxied CNAME from a hostname to the tunnel
+    Dns {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Tunnel name or ID
+        tunnel: String,
+
+        /// Hostname to route (e.g. app.example.com)
+        hostname: String,
+
+        /// Zone name or ID (inferred from the hostname if not provided)
+        #[arg(long)]
+        zone: Option<String>,
+
+        /// Replace an existing record for the hostname
+        #[arg(long)]
+        overwrite: bool,
+    },
+}
@@ -0,0 +1,311 @@
+use clap::Subcommand;
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"BINDINGS:
+  --binding TYPE:NAME=VALUE, repeatable. TYPE is one of:
+    kv:CACHE=<namespace_id>      r2:ASSETS=<bucket_name>    d1:DB=<database_id>
+    var:API_URL=https://...      service:AUTH=<worker>      queue:JOBS=<queue_name>
+  Secrets set with `wrangler secret` or the dashboard are kept on deploy.
+
+EXAMPLES:
+  cfad workers list
+  cfad workers deploy api --module dist/index.js --compat-date 2026-03-01 \
+    --binding kv:CACHE=0f2ac74b498b48028cb68387c421e279 --binding var:ENV=production
+  cfad workers deploy api --module dist/index.js --additional-module dist/chunks/util.js
+  cfad workers routes add example.com "example.com/api/*" --script api
+  cfad workers domains add api api.example.com
+  cfad workers cron set api "*/15 * * * *" "0 3 * * *"
+  printf '%s' "$TOKEN" | cfad workers secret put api API_TOKEN
+  cfad workers secret bulk api .env.production --dry-run
+"#)]
+pub enum WorkersCommand {
+    /// List Worker scripts
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+    },
+
+    /// Show a script's bindings, cron triggers and custom domains
+    Show {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        script: String,
+    },
+
+    /// Upload an ES-module script
+    Deploy {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        name: String,
+
+        /// Main module (exports the fetch/scheduled/queue handlers)
+        #[arg(long)]
+        module: String,
+
+        /// Additional module imported by the main module (repeatable)
+        #[arg(long = "additional-module", value_name = "FILE")]
+        additional_modules: Vec<String>,
+
+        /// Compatibility date (YYYY-MM-DD)
+        #[arg(long)]
+        compat_date: Option<String>,
+
+        /// Compatibility flag (repeatable, e.g. nodejs_compat)
+        #[arg(long = "compat-flag", value_name = "FLAG")]
+        compat_flags: Vec<String>,
+
+        /// Binding as TYPE:NAME=VALUE (repeatable, see BINDINGS below)
+        #[arg(long = "binding", value_name = "TYPE:NAME=VALUE")]
+        bindings: Vec<String>,
+
+        /// Show what would be uploaded without deploying
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Delete a script
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        script: String,
+
+        /// Delete even if other Workers are bound to it
+        #[arg(long)]
+        force: bool,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Zone routes that send matching requests to a script
+    #[command(subcommand)]
+    Routes(WorkerRouteCommand),
+
+    /// Custom domains served directly by a script
+    #[command(subcommand)]
+    Domains(WorkerDomainCommand),
+
+    /// Cron triggers
+    #[command(subcommand)]
+    Cron(WorkerCronCommand),
+
+    /// Secrets (values are read from stdin, a file or an env var)
+    #[command(subcommand)]
+    Secret(WorkerSecretCommand),
+}
+
+#[derive(Subcommand)]
+pub enum WorkerRouteCommand {
+    /// List routes in a zone
+    List {
+        /// Zone name or ID
+        zone: String,
+    },
+
+    /// Add a route
+    Add {
+        /// Zone name or ID
+        zone: String,
+
+        /// URL pattern (e.g. "example.com/api/*")
+        pattern: String,
+
+        /// Script name
+        #[arg(long)]
+        script: String,
+    },
+
+    /// Delete a route
+    Delete {
+        /// Zone name or ID
+        zone: String,
+
+        /// Route ID or pattern
+        route: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum WorkerDomainCommand {
+    /// List custom domains
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Only domains of this script
+        #[arg(long)]
+        script: Option<String>,
+    },
+
+    /// Serve a script on a hostname (creates the DNS record and certificate)
+    Add {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        script: String,
+
+        /// Hostname (e.g. api.example.com)
+        hostname: String,
+
+        /// Zone name or ID (inferred from the hostname if not provided)
+        #[arg(long)]
+        zone: Option<String>,
+    },
+
+    /// Detach a custom domain
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Hostname or domain ID
+        domain: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum WorkerCronCommand {
+    /// List cron triggers of a script
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        script: String,
+    },
+
+    /// Replace all cron triggers of a script
+    Set {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        script: String,
+
+        /// Cron expressions (e.g. "*/15 * * * *")
+        #[arg(required = true)]
+        crons: Vec<String>,
+    },
+
+    /// Add a cron trigger
+    Add {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        script: String,
+
+        /// Cron expression
+        cron: String,
+    },
+
+    /// Remove a cron trigger
+    Remove {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        script: String,
+
+        /// Cron expression
+        cron: String,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum WorkerSecretCommand {
+    /// List secret names of a script
+    List {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        script: String,
+    },
+
+    /// Create or overwrite a secret (value read from stdin by default)
+    Put {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        script: String,
+
+        /// Secret name
+        name: String,
+
+        /// Read the value from a file
+        #[arg(long, value_name = "FILE", conflicts_with = "from_env")]
+        from_file: Option<String>,
+
+        /// Read the value from an environment variable
+        #[arg(long, value_name = "VAR")]
+        from_env: Option<String>,
+    },
+
+    /// Delete a secret
+    Delete {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        script: String,
+
+        /// Secret name
+        name: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Make a script's secrets match a dotenv file
+    Bulk {
+        /// Account ID (uses CLOUDFLARE_ACCOUNT_ID env var or config if not provided)
+        #[arg(long)]
+        account_id: Option<String>,
+
+        /// Script name
+        script: String,
+
+        /// Dotenv file (NAME=value per line)
+        file: String,
+
+        /// Show which secrets would be added, changed or removed without applying
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Allow removing secrets that are not in the file
+        #[arg(long)]
+        confirm: bool,
+    },
+}
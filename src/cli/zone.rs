@@ -1,11 +1,49 @@
-use clap::Subcommand;
+use clap::{Args, Subcommand};
+
+/// Selects several zones at once for zone-scoped commands.
+///
+/// When any selector flag is given the command runs against every matching
+/// zone instead of the single positional zone.
+#[derive(Args, Debug, Clone, Default)]
+pub struct ZoneSelector {
+    /// Run against zones matching these names or globs (comma-separated, e.g. 'shop-*')
+    #[arg(long, value_delimiter = ',', conflicts_with = "zone")]
+    pub zones: Option<Vec<String>>,
+
+    /// Read zone names or globs from a file (one per line, '#' for comments)
+    #[arg(long, conflicts_with = "zone")]
+    pub zones_file: Option<String>,
+
+    /// Run against every zone in the account
+    #[arg(long, conflicts_with = "zone")]
+    pub all_zones: bool,
+
+    /// Run against zones with this status (active, pending, etc.)
+    #[arg(long, conflicts_with = "zone", value_parser = crate::ops::zone::ZONE_STATUSES)]
+    pub zone_status: Option<String>,
+
+    /// Maximum number of zones processed in parallel
+    #[arg(long, default_value_t = crate::ops::zone::DEFAULT_ZONE_CONCURRENCY)]
+    pub concurrency: usize,
+}
+
+impl ZoneSelector {
+    /// Whether any multi-zone selector flag was given
+    pub fn is_active(&self) -> bool {
+        self.zones.is_some()
+            || self.zones_file.is_some()
+            || self.all_zones
+            || self.zone_status.is_some()
+    }
+}
 
 #[derive(Subcommand)]
+#[allow(clippy::large_enum_variant)]
 pub enum ZoneCommand {
     /// List all zones
     List {
         /// Filter by status (active, pending, etc.)
-        #[arg(long)]
+        #[arg(long, value_parser = crate::ops::zone::ZONE_STATUSES)]
         status: Option<String>,
     },
 
@@ -44,7 +82,11 @@ pub enum ZoneCommand {
     /// Update zone settings
     Update {
         /// Zone name or ID
-        zone: String,
+        #[arg(required_unless_present_any = ["zones", "zones_file", "all_zones", "zone_status"])]
+        zone: Option<String>,
+
+        #[command(flatten)]
+        selector: ZoneSelector,
 
         /// Security level (off, low, medium, high, under_attack)
         #[arg(long, value_parser = ["off", "low", "medium", "high", "under_attack"])]
@@ -70,4 +112,256 @@ pub enum ZoneCommand {
         #[arg(long, value_parser = ["on", "off"])]
         always_https: Option<String>,
     },
+
+    /// Audit security posture (SSL, TLS, DNSSEC, email records, proxying) with a score
+    #[command(after_long_help = r#"EXAMPLES:
+  cfad zone audit example.com
+  cfad zone audit --all-zones --junit audit.xml
+  cfad zone audit --zones 'shop-*' --json audit.json --fail-on warn
+  cfad zone audit example.com --min-score 80 --fail-on never
+
+Each check passes, warns or fails. The score is the percentage of points
+earned, where a warning counts as half. The command exits non-zero when a
+check reaches the --fail-on level or a zone scores below --min-score.
+"#)]
+    Audit {
+        /// Zone name or ID
+        #[arg(required_unless_present_any = ["zones", "zones_file", "all_zones", "zone_status"])]
+        zone: Option<String>,
+
+        #[command(flatten)]
+        selector: ZoneSelector,
+
+        #[command(flatten)]
+        report: AuditReportArgs,
+    },
+
+    /// Manage Page Rules and migrate them to modern Rules
+    #[command(subcommand)]
+    PageRules(PageRuleCommand),
+
+    /// Manage custom hostnames (Cloudflare for SaaS)
+    #[command(subcommand)]
+    CustomHostnames(CustomHostnameCommand),
+}
+
+/// Report files and CI gating for `zone audit`
+#[derive(Args, Debug, Clone)]
+pub struct AuditReportArgs {
+    /// Write the results as JSON to this file
+    #[arg(long, value_name = "FILE")]
+    pub json: Option<String>,
+
+    /// Write the results as JUnit XML to this file
+    #[arg(long, value_name = "FILE")]
+    pub junit: Option<String>,
+
+    /// Exit non-zero when any check reaches this level
+    #[arg(long, default_value = "fail", value_parser = ["fail", "warn", "never"])]
+    pub fail_on: String,
+
+    /// Exit non-zero when a zone scores below this (0-100)
+    #[arg(long, default_value = "0", value_parser = clap::value_parser!(u32).range(0..=100))]
+    pub min_score: u32,
+}
+
+/// Certificate and origin options shared by create, update and import
+#[derive(Args, Debug, Clone, Default)]
+pub struct CustomHostnameArgs {
+    /// Custom origin server (defaults to the zone's fallback origin)
+    #[arg(long)]
+    pub origin: Option<String>,
+
+    /// Custom metadata as KEY=VALUE (repeatable)
+    #[arg(long = "metadata", value_name = "KEY=VALUE")]
+    pub metadata: Vec<String>,
+}
+
+/// Polling options for commands that can wait for SSL activation
+#[derive(Args, Debug, Clone)]
+pub struct SslWaitArgs {
+    /// Wait until the certificate is active
+    #[arg(long)]
+    pub wait: bool,
+
+    /// Maximum time to wait in seconds
+    #[arg(long, default_value = "900", requires = "wait")]
+    pub timeout: u64,
+}
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"CSV IMPORT FORMAT:
+  hostname,ssl_method,custom_origin_server,customer_id
+  app.acme.com,http,origin.example.com,acme
+  shop.globex.com,txt,,globex
+
+  Only 'hostname' is required. Empty ssl_method/ssl_type cells use
+  --ssl-method/--ssl-type; any other column becomes custom metadata.
+
+EXAMPLES:
+  cfad zone custom-hostnames create example.com app.acme.com --ssl-method txt
+  cfad zone custom-hostnames verify example.com app.acme.com --wait
+  cfad zone custom-hostnames update example.com app.acme.com --metadata tier=gold
+  cfad zone custom-hostnames import example.com customers.csv
+"#)]
+pub enum CustomHostnameCommand {
+    /// List custom hostnames
+    List {
+        /// Zone name or ID
+        zone: String,
+
+        /// Only show this hostname
+        #[arg(long)]
+        hostname: Option<String>,
+    },
+
+    /// Show custom hostname details
+    Show {
+        /// Zone name or ID
+        zone: String,
+
+        /// Custom hostname or its ID
+        hostname: String,
+    },
+
+    /// Create a custom hostname
+    Create {
+        /// Zone name or ID
+        zone: String,
+
+        /// Customer hostname (e.g. app.customer.com)
+        hostname: String,
+
+        /// Domain control validation method
+        #[arg(long, default_value = "http", value_parser = ["http", "txt", "email"])]
+        ssl_method: String,
+
+        /// Certificate type
+        #[arg(long, default_value = "dv", value_parser = ["dv"])]
+        ssl_type: String,
+
+        #[command(flatten)]
+        options: CustomHostnameArgs,
+
+        #[command(flatten)]
+        wait: SslWaitArgs,
+    },
+
+    /// Update a custom hostname
+    Update {
+        /// Zone name or ID
+        zone: String,
+
+        /// Custom hostname or its ID
+        hostname: String,
+
+        /// Domain control validation method
+        #[arg(long, value_parser = ["http", "txt", "email"])]
+        ssl_method: Option<String>,
+
+        /// Certificate type
+        #[arg(long, value_parser = ["dv"])]
+        ssl_type: Option<String>,
+
+        #[command(flatten)]
+        options: CustomHostnameArgs,
+    },
+
+    /// Delete a custom hostname
+    Delete {
+        /// Zone name or ID
+        zone: String,
+
+        /// Custom hostname or its ID
+        hostname: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Show the DNS/HTTP records the customer must publish
+    Verify {
+        /// Zone name or ID
+        zone: String,
+
+        /// Custom hostname or its ID
+        hostname: String,
+
+        #[command(flatten)]
+        wait: SslWaitArgs,
+    },
+
+    /// Create custom hostnames from a CSV file
+    Import {
+        /// Zone name or ID
+        zone: String,
+
+        /// Path to CSV file
+        file: String,
+
+        /// Default validation method for rows without ssl_method
+        #[arg(long, default_value = "http", value_parser = ["http", "txt", "email"])]
+        ssl_method: String,
+
+        /// Default certificate type for rows without ssl_type
+        #[arg(long, default_value = "dv", value_parser = ["dv"])]
+        ssl_type: String,
+    },
+}
+
+#[derive(Subcommand)]
+#[command(after_long_help = r#"MIGRATION:
+  Forwarding URLs and Always Use HTTPS become single redirect rules, cache
+  settings become cache rules, SSL and other setting overrides become
+  configuration rules, and Host header / resolve overrides become origin
+  rules. Anything without an equivalent is flagged in the plan.
+
+  Rules are appended to the existing phase rulesets; page rules are kept
+  until you delete them.
+
+EXAMPLES:
+  cfad zone page-rules list example.com
+  cfad zone page-rules migrate example.com
+  cfad zone page-rules migrate example.com --apply
+  cfad zone page-rules delete example.com <RULE_ID> --confirm
+"#)]
+pub enum PageRuleCommand {
+    /// List page rules in priority order
+    List {
+        /// Zone name or ID
+        zone: String,
+    },
+
+    /// Show a page rule
+    Show {
+        /// Zone name or ID
+        zone: String,
+
+        /// Page rule ID
+        rule_id: String,
+    },
+
+    /// Delete a page rule
+    Delete {
+        /// Zone name or ID
+        zone: String,
+
+        /// Page rule ID
+        rule_id: String,
+
+        /// Skip confirmation
+        #[arg(long)]
+        confirm: bool,
+    },
+
+    /// Translate page rules into redirect, cache, origin and configuration rules
+    Migrate {
+        /// Zone name or ID
+        zone: String,
+
+        /// Create the translated rules (default: show the plan only)
+        #[arg(long)]
+        apply: bool,
+    },
 }
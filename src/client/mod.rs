@@ -42,12 +42,30 @@ pub struct CfMessage {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct ResultInfo {
+    #[serde(default)]
     pub page: u32,
+    #[serde(default)]
     pub per_page: u32,
+    #[serde(default)]
     pub count: u32,
+    #[serde(default)]
     pub total_count: u32,
     #[serde(default)]
     pub total_pages: u32,
+    /// Cursor pagination (used by list items and other cursor-based endpoints)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursors: Option<ResultCursors>,
+    /// Single-cursor pagination (used by KV key listing)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ResultCursors {
+    #[serde(default)]
+    pub before: Option<String>,
+    #[serde(default)]
+    pub after: Option<String>,
 }
 
 impl CloudflareClient {
@@ -140,19 +158,41 @@ impl CloudflareClient {
         self.request(Method::DELETE, endpoint, None::<()>).await
     }
 
+    pub async fn delete_with_body<B: Serialize, T: for<'de> Deserialize<'de>>(
+        &self,
+        endpoint: &str,
+        body: B,
+    ) -> Result<CfResponse<T>> {
+        self.request(Method::DELETE, endpoint, Some(body)).await
+    }
+
+    /// GET an endpoint that returns a raw body instead of the JSON envelope
+    /// (e.g. KV values)
+    pub async fn get_raw(&self, endpoint: &str) -> Result<Vec<u8>> {
+        let url = format!("{}{}", self.base_url, endpoint);
+        let response = self
+            .execute(self.http_client.request(Method::GET, &url))
+            .await?;
+        Ok(response.bytes().await?.to_vec())
+    }
+
+    /// PUT a `multipart/form-data` body (overrides the default JSON content type)
+    pub async fn put_multipart<T: for<'de> Deserialize<'de>>(
+        &self,
+        endpoint: &str,
+        form: reqwest::multipart::Form,
+    ) -> Result<CfResponse<T>> {
+        let url = format!("{}{}", self.base_url, endpoint);
+        let request = self.http_client.request(Method::PUT, &url).multipart(form);
+        self.send(request).await
+    }
+
     async fn request<B: Serialize, T: for<'de> Deserialize<'de>>(
         &self,
         method: Method,
         endpoint: &str,
         body: Option<B>,
     ) -> Result<CfResponse<T>> {
-        // Rate limiting
-        let _permit = self
-            .rate_limiter
-            .acquire()
-            .await
-            .map_err(|_| CfadError::network("Rate limiter failed"))?;
-
         let url = format!("{}{}", self.base_url, endpoint);
 
         let mut request = self.http_client.request(method, &url);
@@ -161,6 +201,18 @@ impl CloudflareClient {
             request = request.json(&body);
         }
 
+        self.send(request).await
+    }
+
+    /// Send a request under the rate limiter; non-2xx statuses become errors
+    async fn execute(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response> {
+        // Rate limiting
+        let _permit = self
+            .rate_limiter
+            .acquire()
+            .await
+            .map_err(|_| CfadError::network("Rate limiter failed"))?;
+
         let response = request.send().await?;
         let status = response.status();
 
@@ -188,8 +240,18 @@ impl CloudflareClient {
             });
         }
 
+        Ok(response)
+    }
+
+    async fn send<T: for<'de> Deserialize<'de>>(
+        &self,
+        request: reqwest::RequestBuilder,
+    ) -> Result<CfResponse<T>> {
+        let response = self.execute(request).await?;
+
         // Get response text first for better error messages
         let text = response.text().await?;
+
         let cf_response: CfResponse<T> = serde_json::from_str(&text).map_err(|e| {
             // Log the first 500 chars of response for debugging
             let preview = if text.len() > 500 {
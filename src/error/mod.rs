@@ -50,6 +50,9 @@ pub enum CfadError {
     #[error("TOML serialize error: {0}")]
     TomlSer(toml::ser::Error),
 
+    #[error("YAML error: {0}")]
+    Yaml(#[from] serde_yaml::Error),
+
     #[error("HTTP error: {0}")]
     Http(#[from] reqwest::Error),
 
@@ -118,7 +121,9 @@ impl CfadError {
             Self::NotFound { .. } => ErrorCategory::NotFound,
             Self::Timeout(_) => ErrorCategory::Timeout,
             Self::Io(_) => ErrorCategory::FileSystem,
-            Self::Json(_) | Self::TomlDe(_) | Self::TomlSer(_) => ErrorCategory::Serialization,
+            Self::Json(_) | Self::TomlDe(_) | Self::TomlSer(_) | Self::Yaml(_) => {
+                ErrorCategory::Serialization
+            }
             Self::Http(_) => ErrorCategory::Network,
             Self::UrlParse(_) => ErrorCategory::Validation,
             Self::Other(_) => ErrorCategory::Other,
@@ -395,6 +400,14 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_error_category_yaml() {
+        let result: std::result::Result<serde_json::Value, serde_yaml::Error> =
+            serde_yaml::from_str("key: [unclosed");
+        let error = CfadError::from(result.unwrap_err());
+        assert!(matches!(error.category(), ErrorCategory::Serialization));
+    }
+
     #[test]
     fn test_error_category_http() {
         // Create a simple reqwest error by attempting to parse an invalid URL
@@ -0,0 +1,314 @@
+use super::fields::{field_type, suggest_field, Type};
+use super::parser::{CompareOp, Expr, InSet, IndexKey, Literal, SetItem};
+use super::ExprError;
+
+/// Type of a sub-expression. `unpacked` marks values produced by `[*]`, which
+/// must be folded back into a single Bool with `any()` or `all()`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ty {
+    pub ty: Type,
+    pub unpacked: bool,
+}
+
+impl Ty {
+    fn scalar(ty: Type) -> Self {
+        Self {
+            ty,
+            unpacked: false,
+        }
+    }
+}
+
+/// Type-check a whole expression: it must produce a single Bool
+pub fn check_root(expr: &Expr) -> Result<(), ExprError> {
+    let ty = check(expr)?;
+    if ty.unpacked {
+        return Err(ExprError::new(
+            "Comparisons on [*] values must be wrapped in any() or all()",
+            expr.pos(),
+        ));
+    }
+    if ty.ty != Type::Bool {
+        return Err(ExprError::new(
+            format!("Expression must evaluate to Bool, found {}", ty.ty),
+            expr.pos(),
+        ));
+    }
+    Ok(())
+}
+
+pub fn check(expr: &Expr) -> Result<Ty, ExprError> {
+    match expr {
+        Expr::Logical { lhs, rhs, .. } => {
+            let l = expect_bool(lhs)?;
+            let r = expect_bool(rhs)?;
+            Ok(Ty {
+                ty: Type::Bool,
+                unpacked: l.unpacked || r.unpacked,
+            })
+        }
+        Expr::Not { inner, .. } => expect_bool(inner),
+        Expr::Field { name, pos } => field_type(name).map(Ty::scalar).ok_or_else(|| {
+            let hint = suggest_field(name)
+                .map(|s| format!(" (did you mean '{}'?)", s))
+                .unwrap_or_default();
+            ExprError::new(format!("Unknown field '{}'{}", name, hint), *pos)
+        }),
+        Expr::Index { base, key, pos } => check_index(base, key, *pos),
+        Expr::Literal { value, pos } => match value {
+            Literal::Str(_) => Ok(Ty::scalar(Type::String)),
+            Literal::Int(_) => Ok(Ty::scalar(Type::Int)),
+            Literal::Bool(_) => Ok(Ty::scalar(Type::Bool)),
+            Literal::Ip(_, None) => Ok(Ty::scalar(Type::Ip)),
+            Literal::Ip(_, Some(_)) => Err(ExprError::new(
+                "IP ranges are only allowed inside 'in {...}' sets",
+                *pos,
+            )),
+        },
+        Expr::Call { name, args, pos } => check_call(name, args, *pos),
+        Expr::Compare { lhs, op, rhs, pos } => check_compare(lhs, *op, rhs, *pos),
+        Expr::In { lhs, set, pos } => check_in(lhs, set, *pos),
+    }
+}
+
+fn expect_bool(expr: &Expr) -> Result<Ty, ExprError> {
+    let ty = check(expr)?;
+    if ty.ty != Type::Bool {
+        return Err(ExprError::new(
+            format!(
+                "Expected a Bool here, found {} (add a comparison such as 'eq')",
+                ty.ty
+            ),
+            expr.pos(),
+        ));
+    }
+    Ok(ty)
+}
+
+fn check_index(base: &Expr, key: &IndexKey, pos: usize) -> Result<Ty, ExprError> {
+    let base_ty = check(base)?;
+    let (inner, unpacks) = match (&base_ty.ty, key) {
+        (Type::Map(inner), IndexKey::Str(_)) => (inner.as_ref().clone(), false),
+        (Type::Array(inner), IndexKey::Int(i)) if *i >= 0 => (inner.as_ref().clone(), false),
+        (Type::Map(inner), IndexKey::Star) | (Type::Array(inner), IndexKey::Star) => {
+            (inner.as_ref().clone(), true)
+        }
+        (Type::Map(_), _) => {
+            return Err(ExprError::new(
+                "Maps are indexed by a string key, e.g. [\"name\"], or [*]",
+                pos,
+            ))
+        }
+        (Type::Array(_), _) => {
+            return Err(ExprError::new(
+                "Arrays are indexed by a non-negative integer or [*]",
+                pos,
+            ))
+        }
+        (other, _) => {
+            return Err(ExprError::new(
+                format!("Cannot index into a {}", other),
+                pos,
+            ))
+        }
+    };
+    Ok(Ty {
+        ty: inner,
+        unpacked: base_ty.unpacked || unpacks,
+    })
+}
+
+fn check_compare(lhs: &Expr, op: CompareOp, rhs: &Expr, pos: usize) -> Result<Ty, ExprError> {
+    let l = check(lhs)?;
+    let r = check(rhs)?;
+    if r.unpacked {
+        return Err(ExprError::new(
+            "[*] values are only allowed on the left-hand side of a comparison",
+            rhs.pos(),
+        ));
+    }
+
+    let allowed: &[Type] = match op {
+        CompareOp::Eq | CompareOp::Ne => &[Type::String, Type::Int, Type::Bool, Type::Ip],
+        CompareOp::Lt | CompareOp::Le | CompareOp::Gt | CompareOp::Ge => &[Type::String, Type::Int],
+        CompareOp::Contains
+        | CompareOp::Matches
+        | CompareOp::Wildcard
+        | CompareOp::StrictWildcard => &[Type::String],
+    };
+    if !allowed.contains(&l.ty) {
+        return Err(ExprError::new(
+            format!("Operator '{}' cannot be used with {}", op.name(), l.ty),
+            pos,
+        ));
+    }
+    if l.ty != r.ty {
+        return Err(ExprError::new(
+            format!(
+                "Type mismatch: {} {} {} (both sides must have the same type)",
+                l.ty,
+                op.name(),
+                r.ty
+            ),
+            rhs.pos(),
+        ));
+    }
+
+    if matches!(
+        op,
+        CompareOp::Contains | CompareOp::Matches | CompareOp::Wildcard | CompareOp::StrictWildcard
+    ) {
+        let pattern = match rhs {
+            Expr::Literal {
+                value: Literal::Str(s),
+                ..
+            } => s,
+            _ => {
+                return Err(ExprError::new(
+                    format!("Operator '{}' requires a string literal", op.name()),
+                    rhs.pos(),
+                ))
+            }
+        };
+        if op == CompareOp::Matches {
+            regex::Regex::new(pattern).map_err(|e| {
+                ExprError::new(format!("Invalid regular expression: {}", e), rhs.pos())
+            })?;
+        }
+    }
+
+    Ok(Ty {
+        ty: Type::Bool,
+        unpacked: l.unpacked,
+    })
+}
+
+fn check_in(lhs: &Expr, set: &InSet, pos: usize) -> Result<Ty, ExprError> {
+    let l = check(lhs)?;
+    if !matches!(l.ty, Type::String | Type::Int | Type::Ip) {
+        return Err(ExprError::new(
+            format!("Operator 'in' cannot be used with {}", l.ty),
+            pos,
+        ));
+    }
+
+    if let InSet::Items(items) = set {
+        for item in items {
+            let ok = matches!(
+                (&l.ty, item),
+                (Type::String, SetItem::Str(_))
+                    | (Type::Int, SetItem::Int(_))
+                    | (Type::Int, SetItem::IntRange(..))
+                    | (Type::Ip, SetItem::Ip(..))
+            );
+            if !ok {
+                return Err(ExprError::new(
+                    format!("Set values must all be {} to match the field", l.ty),
+                    pos,
+                ));
+            }
+        }
+    }
+
+    Ok(Ty {
+        ty: Type::Bool,
+        unpacked: l.unpacked,
+    })
+}
+
+/// Parameter shape accepted by a function argument
+#[derive(Clone, Copy)]
+enum Param {
+    String,
+    Int,
+    Ip,
+    /// String or any Array (len)
+    Sized,
+    /// Int, Bool or IP address (to_string)
+    Stringable,
+    /// `[*]` Bool values or Array<Bool> (any / all)
+    Bools,
+}
+
+fn check_call(name: &str, args: &[Expr], pos: usize) -> Result<Ty, ExprError> {
+    use Param::*;
+
+    let (params, variadic, returns): (&[Param], bool, Type) = match name {
+        "lower" | "upper" | "url_decode" => (&[String], false, Type::String),
+        "len" => (&[Sized], false, Type::Int),
+        "starts_with" | "ends_with" => (&[String, String], false, Type::Bool),
+        "concat" => (&[String, String], true, Type::String),
+        "to_string" => (&[Stringable], false, Type::String),
+        "any" | "all" => (&[Bools], false, Type::Bool),
+        "remove_bytes" => (&[String, String], false, Type::String),
+        "regex_replace" => (&[String, String, String], false, Type::String),
+        "lookup_json_string" => (&[String, String], true, Type::String),
+        "cidr" => (&[Ip, Int, Int], false, Type::Ip),
+        _ => return Err(ExprError::new(format!("Unknown function '{}'", name), pos)),
+    };
+
+    let arity_ok = if variadic {
+        args.len() >= params.len()
+    } else {
+        args.len() == params.len()
+    };
+    if !arity_ok {
+        return Err(ExprError::new(
+            format!(
+                "Function '{}' takes {}{} argument(s), found {}",
+                name,
+                if variadic { "at least " } else { "" },
+                params.len(),
+                args.len()
+            ),
+            pos,
+        ));
+    }
+
+    let mut unpacked = false;
+    for (i, arg) in args.iter().enumerate() {
+        let param = params[i.min(params.len() - 1)];
+        let ty = check(arg)?;
+        let ok = match param {
+            String => ty.ty == Type::String,
+            Int => ty.ty == Type::Int,
+            Ip => ty.ty == Type::Ip,
+            Sized => matches!(ty.ty, Type::String | Type::Array(_)),
+            Stringable => matches!(ty.ty, Type::Int | Type::Bool | Type::Ip),
+            Bools => {
+                (ty.unpacked && ty.ty == Type::Bool)
+                    || (!ty.unpacked && ty.ty == Type::array(Type::Bool))
+            }
+        };
+        if !ok {
+            return Err(ExprError::new(
+                format!(
+                    "Argument {} of '{}' has the wrong type ({})",
+                    i + 1,
+                    name,
+                    ty.ty
+                ),
+                arg.pos(),
+            ));
+        }
+        if !matches!(param, Bools) {
+            unpacked |= ty.unpacked;
+        }
+    }
+
+    if name == "regex_replace" {
+        if let Some(Expr::Literal {
+            value: Literal::Str(pattern),
+            pos,
+        }) = args.get(1)
+        {
+            regex::Regex::new(pattern)
+                .map_err(|e| ExprError::new(format!("Invalid regular expression: {}", e), *pos))?;
+        }
+    }
+
+    Ok(Ty {
+        ty: returns,
+        unpacked,
+    })
+}
@@ -0,0 +1,572 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use super::fields::{field_type, Type};
+use super::parser::{CompareOp, Expr, InSet, IndexKey, Literal, LogicalOp, SetItem};
+
+/// Runtime value of a field or sub-expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Str(String),
+    Int(i64),
+    Bool(bool),
+    Ip(IpAddr),
+    Array(Vec<Value>),
+    Map(BTreeMap<String, Value>),
+}
+
+/// A single value, or one value per element after `[*]`
+#[derive(Debug, Clone)]
+enum Evaluated {
+    One(Value),
+    Many(Vec<Value>),
+}
+
+/// Sample request an expression is evaluated against.
+///
+/// Built from a JSON object mapping field names to values, plus an optional
+/// `lists` object giving the contents of named lists:
+///
+/// ```json
+/// {
+///   "http.request.uri.path": "/admin",
+///   "ip.src": "192.0.2.1",
+///   "http.request.headers": {"user-agent": ["curl/8.0"]},
+///   "lists": {"blocklist": ["192.0.2.0/24"]}
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RequestContext {
+    fields: HashMap<String, serde_json::Value>,
+    lists: HashMap<String, Vec<String>>,
+}
+
+impl RequestContext {
+    pub fn from_json(value: serde_json::Value) -> Result<Self, String> {
+        let serde_json::Value::Object(mut object) = value else {
+            return Err("Request must be a JSON object of field values".to_string());
+        };
+
+        let mut lists = HashMap::new();
+        if let Some(raw) = object.remove("lists") {
+            let serde_json::Value::Object(raw) = raw else {
+                return Err("'lists' must map list names to arrays of values".to_string());
+            };
+            for (name, items) in raw {
+                let items = items
+                    .as_array()
+                    .ok_or_else(|| format!("List '{}' must be an array", name))?
+                    .iter()
+                    .map(|item| match item {
+                        serde_json::Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    })
+                    .collect();
+                lists.insert(name, items);
+            }
+        }
+
+        for name in object.keys() {
+            if field_type(name).is_none() {
+                return Err(format!("Unknown field '{}' in request", name));
+            }
+        }
+
+        Ok(Self {
+            fields: object.into_iter().collect(),
+            lists,
+        })
+    }
+}
+
+pub struct Evaluator<'a> {
+    request: &'a RequestContext,
+    /// Fields the expression used that the request did not provide
+    pub defaulted: BTreeSet<String>,
+}
+
+impl<'a> Evaluator<'a> {
+    pub fn new(request: &'a RequestContext) -> Self {
+        Self {
+            request,
+            defaulted: BTreeSet::new(),
+        }
+    }
+
+    /// Evaluate a type-checked expression to a Bool
+    pub fn eval_bool(&mut self, expr: &Expr) -> Result<bool, String> {
+        match self.eval(expr)? {
+            Evaluated::One(Value::Bool(b)) => Ok(b),
+            other => Err(format!("Expression did not produce a Bool: {:?}", other)),
+        }
+    }
+
+    fn eval(&mut self, expr: &Expr) -> Result<Evaluated, String> {
+        match expr {
+            Expr::Logical { op, lhs, rhs } => {
+                let l = self.eval(lhs)?;
+                let r = self.eval(rhs)?;
+                zip_map(l, r, |a, b| {
+                    let (a, b) = (as_bool(&a), as_bool(&b));
+                    Ok(Value::Bool(match op {
+                        LogicalOp::And => a && b,
+                        LogicalOp::Or => a || b,
+                        LogicalOp::Xor => a ^ b,
+                    }))
+                })
+            }
+            Expr::Not { inner, .. } => {
+                let value = self.eval(inner)?;
+                map_each(value, |v| Ok(Value::Bool(!as_bool(&v))))
+            }
+            Expr::Field { name, .. } => self.field(name).map(Evaluated::One),
+            Expr::Index { base, key, .. } => {
+                let base = self.eval(base)?;
+                match base {
+                    Evaluated::One(value) => index(value, key),
+                    Evaluated::Many(values) => {
+                        let mut out = Vec::new();
+                        for value in values {
+                            match index(value, key)? {
+                                Evaluated::One(v) => out.push(v),
+                                Evaluated::Many(vs) => out.extend(vs),
+                            }
+                        }
+                        Ok(Evaluated::Many(out))
+                    }
+                }
+            }
+            Expr::Literal { value, .. } => Ok(Evaluated::One(match value {
+                Literal::Str(s) => Value::Str(s.clone()),
+                Literal::Int(i) => Value::Int(*i),
+                Literal::Bool(b) => Value::Bool(*b),
+                Literal::Ip(addr, _) => Value::Ip(*addr),
+            })),
+            Expr::Compare { lhs, op, rhs, .. } => {
+                let l = self.eval(lhs)?;
+                let r = match self.eval(rhs)? {
+                    Evaluated::One(v) => v,
+                    Evaluated::Many(_) => return Err("[*] on right-hand side".to_string()),
+                };
+                map_each(l, |v| compare(&v, *op, &r).map(Value::Bool))
+            }
+            Expr::In { lhs, set, .. } => {
+                let l = self.eval(lhs)?;
+                let items = match set {
+                    InSet::Items(items) => items.clone(),
+                    InSet::List(name) => self.list_items(name)?,
+                };
+                map_each(l, |v| Ok(Value::Bool(in_set(&v, &items))))
+            }
+            Expr::Call { name, args, .. } => {
+                let mut values = Vec::with_capacity(args.len());
+                for arg in args {
+                    values.push(self.eval(arg)?);
+                }
+                call(name, values)
+            }
+        }
+    }
+
+    fn field(&mut self, name: &str) -> Result<Value, String> {
+        let ty = field_type(name).ok_or_else(|| format!("Unknown field '{}'", name))?;
+        match self.request.fields.get(name) {
+            Some(raw) => from_json(raw, &ty).map_err(|e| format!("Field '{}': {}", name, e)),
+            None => {
+                self.defaulted.insert(name.to_string());
+                Ok(default_value(&ty))
+            }
+        }
+    }
+
+    fn list_items(&self, name: &str) -> Result<Vec<SetItem>, String> {
+        let raw =
+            self.request.lists.get(name).ok_or_else(|| {
+                format!("List ${} is not defined in the request's \"lists\"", name)
+            })?;
+        raw.iter()
+            .map(|item| {
+                if let Ok(asn) = item.trim_start_matches("AS").parse::<i64>() {
+                    return Ok(SetItem::Int(asn));
+                }
+                if let Some((addr, prefix)) = parse_cidr(item) {
+                    return Ok(SetItem::Ip(addr, prefix));
+                }
+                Ok(SetItem::Str(item.clone()))
+            })
+            .collect()
+    }
+}
+
+fn as_bool(value: &Value) -> bool {
+    matches!(value, Value::Bool(true))
+}
+
+fn map_each(
+    value: Evaluated,
+    mut f: impl FnMut(Value) -> Result<Value, String>,
+) -> Result<Evaluated, String> {
+    match value {
+        Evaluated::One(v) => f(v).map(Evaluated::One),
+        Evaluated::Many(vs) => vs
+            .into_iter()
+            .map(f)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Evaluated::Many),
+    }
+}
+
+fn zip_map(
+    l: Evaluated,
+    r: Evaluated,
+    f: impl Fn(Value, Value) -> Result<Value, String>,
+) -> Result<Evaluated, String> {
+    match (l, r) {
+        (Evaluated::One(a), Evaluated::One(b)) => f(a, b).map(Evaluated::One),
+        (Evaluated::Many(a), Evaluated::One(b)) => a
+            .into_iter()
+            .map(|a| f(a, b.clone()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Evaluated::Many),
+        (Evaluated::One(a), Evaluated::Many(b)) => b
+            .into_iter()
+            .map(|b| f(a.clone(), b))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Evaluated::Many),
+        (Evaluated::Many(a), Evaluated::Many(b)) => a
+            .into_iter()
+            .zip(b)
+            .map(|(a, b)| f(a, b))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Evaluated::Many),
+    }
+}
+
+fn index(value: Value, key: &IndexKey) -> Result<Evaluated, String> {
+    match (value, key) {
+        (Value::Map(map), IndexKey::Str(k)) => Ok(Evaluated::One(
+            map.get(k).cloned().unwrap_or(Value::Array(Vec::new())),
+        )),
+        (Value::Map(map), IndexKey::Star) => Ok(Evaluated::Many(map.into_values().collect())),
+        (Value::Array(items), IndexKey::Int(i)) => Ok(Evaluated::One(
+            items
+                .get(*i as usize)
+                .cloned()
+                .unwrap_or(Value::Str(String::new())),
+        )),
+        (Value::Array(items), IndexKey::Star) => Ok(Evaluated::Many(items)),
+        (value, _) => Err(format!("Cannot index into {:?}", value)),
+    }
+}
+
+fn compare(l: &Value, op: CompareOp, r: &Value) -> Result<bool, String> {
+    Ok(match (l, r) {
+        (Value::Str(a), Value::Str(b)) => match op {
+            CompareOp::Eq => a == b,
+            CompareOp::Ne => a != b,
+            CompareOp::Lt => a < b,
+            CompareOp::Le => a <= b,
+            CompareOp::Gt => a > b,
+            CompareOp::Ge => a >= b,
+            CompareOp::Contains => a.contains(b.as_str()),
+            CompareOp::Matches => regex::Regex::new(b).map_err(|e| e.to_string())?.is_match(a),
+            CompareOp::Wildcard => wildcard_match(b, a, false),
+            CompareOp::StrictWildcard => wildcard_match(b, a, true),
+        },
+        (Value::Int(a), Value::Int(b)) => match op {
+            CompareOp::Eq => a == b,
+            CompareOp::Ne => a != b,
+            CompareOp::Lt => a < b,
+            CompareOp::Le => a <= b,
+            CompareOp::Gt => a > b,
+            CompareOp::Ge => a >= b,
+            _ => return Err(format!("Operator '{}' not valid for Int", op.name())),
+        },
+        (Value::Bool(a), Value::Bool(b)) => match op {
+            CompareOp::Eq => a == b,
+            CompareOp::Ne => a != b,
+            _ => return Err(format!("Operator '{}' not valid for Bool", op.name())),
+        },
+        (Value::Ip(a), Value::Ip(b)) => match op {
+            CompareOp::Eq => a == b,
+            CompareOp::Ne => a != b,
+            _ => return Err(format!("Operator '{}' not valid for IP", op.name())),
+        },
+        _ => return Err(format!("Cannot compare {:?} with {:?}", l, r)),
+    })
+}
+
+fn in_set(value: &Value, items: &[SetItem]) -> bool {
+    items.iter().any(|item| match (value, item) {
+        (Value::Str(v), SetItem::Str(s)) => v == s,
+        (Value::Int(v), SetItem::Int(i)) => v == i,
+        (Value::Int(v), SetItem::IntRange(start, end)) => v >= start && v <= end,
+        (Value::Ip(v), SetItem::Ip(addr, prefix)) => ip_in_cidr(v, addr, *prefix),
+        _ => false,
+    })
+}
+
+fn ip_in_cidr(ip: &IpAddr, network: &IpAddr, prefix: Option<u8>) -> bool {
+    match (ip, network) {
+        (IpAddr::V4(ip), IpAddr::V4(net)) => {
+            let prefix = prefix.unwrap_or(32) as u32;
+            let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+            u32::from(*ip) & mask == u32::from(*net) & mask
+        }
+        (IpAddr::V6(ip), IpAddr::V6(net)) => {
+            let prefix = prefix.unwrap_or(128) as u32;
+            let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+            u128::from(*ip) & mask == u128::from(*net) & mask
+        }
+        _ => false,
+    }
+}
+
+fn parse_cidr(value: &str) -> Option<(IpAddr, Option<u8>)> {
+    match value.split_once('/') {
+        Some((addr, prefix)) => Some((addr.parse().ok()?, Some(prefix.parse().ok()?))),
+        None => Some((value.parse().ok()?, None)),
+    }
+}
+
+/// `*` matches any sequence of characters; `\*` and `\\` are literal
+fn wildcard_match(pattern: &str, value: &str, case_sensitive: bool) -> bool {
+    let mut regex = String::from(if case_sensitive { "^" } else { "(?i)^" });
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    regex.push_str(&regex::escape(&next.to_string()));
+                }
+            }
+            '*' => regex.push_str(".*"),
+            _ => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+    regex::Regex::new(&regex)
+        .map(|re| re.is_match(value))
+        .unwrap_or(false)
+}
+
+fn call(name: &str, args: Vec<Evaluated>) -> Result<Evaluated, String> {
+    if name == "any" || name == "all" {
+        let values = match args.into_iter().next() {
+            Some(Evaluated::Many(values)) | Some(Evaluated::One(Value::Array(values))) => values,
+            _ => return Err(format!("{}() expects [*] values", name)),
+        };
+        let result = if name == "any" {
+            values.iter().any(as_bool)
+        } else {
+            values.iter().all(as_bool)
+        };
+        return Ok(Evaluated::One(Value::Bool(result)));
+    }
+
+    // Other functions apply element-wise when an argument came from [*]
+    let many_len = args.iter().find_map(|a| match a {
+        Evaluated::Many(values) => Some(values.len()),
+        Evaluated::One(_) => None,
+    });
+    match many_len {
+        None => {
+            let values = args
+                .into_iter()
+                .map(|a| match a {
+                    Evaluated::One(v) => v,
+                    Evaluated::Many(_) => unreachable!("checked above"),
+                })
+                .collect();
+            call_scalar(name, values).map(Evaluated::One)
+        }
+        Some(len) => {
+            let mut out = Vec::with_capacity(len);
+            for i in 0..len {
+                let values = args
+                    .iter()
+                    .map(|a| match a {
+                        Evaluated::One(v) => v.clone(),
+                        Evaluated::Many(vs) => vs[i].clone(),
+                    })
+                    .collect();
+                out.push(call_scalar(name, values)?);
+            }
+            Ok(Evaluated::Many(out))
+        }
+    }
+}
+
+fn call_scalar(name: &str, args: Vec<Value>) -> Result<Value, String> {
+    let str_arg = |i: usize| match args.get(i) {
+        Some(Value::Str(s)) => Ok(s.as_str()),
+        other => Err(format!("{}(): expected string, found {:?}", name, other)),
+    };
+
+    Ok(match name {
+        "lower" => Value::Str(str_arg(0)?.to_lowercase()),
+        "upper" => Value::Str(str_arg(0)?.to_uppercase()),
+        "url_decode" => Value::Str(url_decode(str_arg(0)?)),
+        "len" => match &args[0] {
+            Value::Str(s) => Value::Int(s.len() as i64),
+            Value::Array(items) => Value::Int(items.len() as i64),
+            other => return Err(format!("len(): unsupported value {:?}", other)),
+        },
+        "starts_with" => Value::Bool(str_arg(0)?.starts_with(str_arg(1)?)),
+        "ends_with" => Value::Bool(str_arg(0)?.ends_with(str_arg(1)?)),
+        "concat" => {
+            let mut out = String::new();
+            for i in 0..args.len() {
+                out.push_str(str_arg(i)?);
+            }
+            Value::Str(out)
+        }
+        "to_string" => Value::Str(match &args[0] {
+            Value::Int(i) => i.to_string(),
+            Value::Bool(b) => b.to_string(),
+            Value::Ip(ip) => ip.to_string(),
+            Value::Str(s) => s.clone(),
+            other => return Err(format!("to_string(): unsupported value {:?}", other)),
+        }),
+        "remove_bytes" => {
+            let remove = str_arg(1)?;
+            Value::Str(
+                str_arg(0)?
+                    .chars()
+                    .filter(|c| !remove.contains(*c))
+                    .collect(),
+            )
+        }
+        "regex_replace" => {
+            let re = regex::Regex::new(str_arg(1)?).map_err(|e| e.to_string())?;
+            Value::Str(re.replace(str_arg(0)?, str_arg(2)?).into_owned())
+        }
+        "lookup_json_string" => {
+            let mut current: serde_json::Value =
+                serde_json::from_str(str_arg(0)?).unwrap_or(serde_json::Value::Null);
+            for i in 1..args.len() {
+                current = current.get(str_arg(i)?).cloned().unwrap_or_default();
+            }
+            Value::Str(current.as_str().unwrap_or_default().to_string())
+        }
+        "cidr" => {
+            let (Value::Ip(ip), Value::Int(v4), Value::Int(v6)) = (&args[0], &args[1], &args[2])
+            else {
+                return Err("cidr(): expected (IP, Int, Int)".to_string());
+            };
+            Value::Ip(match ip {
+                IpAddr::V4(addr) => {
+                    let bits = (*v4).clamp(0, 32) as u32;
+                    let mask = u32::MAX.checked_shl(32 - bits).unwrap_or(0);
+                    IpAddr::V4(Ipv4Addr::from(u32::from(*addr) & mask))
+                }
+                IpAddr::V6(addr) => {
+                    let bits = (*v6).clamp(0, 128) as u32;
+                    let mask = u128::MAX.checked_shl(128 - bits).unwrap_or(0);
+                    IpAddr::V6(Ipv6Addr::from(u128::from(*addr) & mask))
+                }
+            })
+        }
+        other => return Err(format!("Unknown function '{}'", other)),
+    })
+}
+
+fn url_decode(input: &str) -> String {
+    let bytes = input.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'%' if i + 2 < bytes.len() => {
+                let byte = std::str::from_utf8(&bytes[i + 1..i + 3])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+                match byte {
+                    Some(byte) => {
+                        out.push(byte);
+                        i += 3;
+                        continue;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            b'+' => out.push(b' '),
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+fn default_value(ty: &Type) -> Value {
+    match ty {
+        Type::String => Value::Str(String::new()),
+        Type::Int => Value::Int(0),
+        Type::Bool => Value::Bool(false),
+        Type::Ip => Value::Ip(IpAddr::V4(Ipv4Addr::UNSPECIFIED)),
+        Type::Array(_) => Value::Array(Vec::new()),
+        Type::Map(_) => Value::Map(BTreeMap::new()),
+    }
+}
+
+fn from_json(raw: &serde_json::Value, ty: &Type) -> Result<Value, String> {
+    use serde_json::Value as Json;
+
+    match (ty, raw) {
+        (Type::String, Json::String(s)) => Ok(Value::Str(s.clone())),
+        (Type::String, Json::Number(n)) => Ok(Value::Str(n.to_string())),
+        (Type::Int, Json::Number(n)) => n
+            .as_i64()
+            .map(Value::Int)
+            .ok_or_else(|| format!("expected an integer, found {}", n)),
+        (Type::Bool, Json::Bool(b)) => Ok(Value::Bool(*b)),
+        (Type::Ip, Json::String(s)) => s
+            .parse()
+            .map(Value::Ip)
+            .map_err(|_| format!("invalid IP address '{}'", s)),
+        (Type::Array(inner), Json::Array(items)) => items
+            .iter()
+            .map(|item| from_json(item, inner))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Value::Array),
+        // A single value where an array is expected is treated as a one-element array
+        (Type::Array(inner), scalar) => Ok(Value::Array(vec![from_json(scalar, inner)?])),
+        (Type::Map(inner), Json::Object(map)) => map
+            .iter()
+            .map(|(k, v)| Ok((k.clone(), from_json(v, inner)?)))
+            .collect::<Result<BTreeMap<_, _>, String>>()
+            .map(Value::Map),
+        (ty, raw) => Err(format!("expected {}, found {}", ty, raw)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.example.com", "www.EXAMPLE.com", false));
+        assert!(!wildcard_match("*.example.com", "www.EXAMPLE.com", true));
+        assert!(wildcard_match(r"/a\*b", "/a*b", true));
+        assert!(!wildcard_match(r"/a\*b", "/axxb", true));
+    }
+
+    #[test]
+    fn test_ip_in_cidr() {
+        let ip: IpAddr = "192.0.2.77".parse().unwrap();
+        assert!(ip_in_cidr(&ip, &"192.0.2.0".parse().unwrap(), Some(24)));
+        assert!(!ip_in_cidr(&ip, &"192.0.3.0".parse().unwrap(), Some(24)));
+        assert!(ip_in_cidr(&ip, &"0.0.0.0".parse().unwrap(), Some(0)));
+
+        let ip6: IpAddr = "2001:db8::1".parse().unwrap();
+        assert!(ip_in_cidr(&ip6, &"2001:db8::".parse().unwrap(), Some(32)));
+        assert!(!ip_in_cidr(&ip6, &ip, Some(24)));
+    }
+
+    #[test]
+    fn test_url_decode() {
+        assert_eq!(url_decode("%2Fadmin%20panel+x"), "/admin panel x");
+        assert_eq!(url_decode("100%"), "100%");
+    }
+}
@@ -0,0 +1,225 @@
+use std::fmt;
+
+/// Value types of the Rules language
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    /// Bytes / string values
+    String,
+    Int,
+    Bool,
+    Ip,
+    Array(Box<Type>),
+    Map(Box<Type>),
+}
+
+impl Type {
+    pub fn array(inner: Type) -> Self {
+        Type::Array(Box::new(inner))
+    }
+
+    pub fn map(inner: Type) -> Self {
+        Type::Map(Box::new(inner))
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::String => write!(f, "String"),
+            Type::Int => write!(f, "Int"),
+            Type::Bool => write!(f, "Bool"),
+            Type::Ip => write!(f, "IP address"),
+            Type::Array(inner) => write!(f, "Array<{}>", inner),
+            Type::Map(inner) => write!(f, "Map<{}>", inner),
+        }
+    }
+}
+
+/// Scalar field types, grouped to keep the table readable
+const STRING_FIELDS: &[&str] = &[
+    "http.cookie",
+    "http.host",
+    "http.referer",
+    "http.request.body.mime",
+    "http.request.body.raw",
+    "http.request.full_uri",
+    "http.request.method",
+    "http.request.uri",
+    "http.request.uri.path",
+    "http.request.uri.path.extension",
+    "http.request.uri.query",
+    "http.request.version",
+    "http.response.content_type.media_type",
+    "http.user_agent",
+    "http.x_forwarded_for",
+    "cf.bot_management.ja3_hash",
+    "cf.bot_management.ja4",
+    "cf.hostname.metadata",
+    "cf.ray_id",
+    "cf.tls_cipher",
+    "cf.tls_client_auth.cert_fingerprint_sha256",
+    "cf.tls_client_auth.cert_issuer_dn",
+    "cf.tls_client_auth.cert_subject_dn",
+    "cf.tls_version",
+    "cf.verified_bot_category",
+    "ip.geoip.continent",
+    "ip.geoip.country",
+    "ip.geoip.subdivision_1_iso_code",
+    "ip.geoip.subdivision_2_iso_code",
+    "ip.src.city",
+    "ip.src.continent",
+    "ip.src.country",
+    "ip.src.metro_code",
+    "ip.src.postal_code",
+    "ip.src.region",
+    "ip.src.region_code",
+    "ip.src.subdivision_1_iso_code",
+    "ip.src.subdivision_2_iso_code",
+    "ip.src.timezone.name",
+    "raw.http.request.full_uri",
+    "raw.http.request.uri",
+    "raw.http.request.uri.path",
+    "raw.http.request.uri.path.extension",
+    "raw.http.request.uri.query",
+];
+
+const INT_FIELDS: &[&str] = &[
+    "cf.bot_management.score",
+    "cf.edge.server_port",
+    "cf.threat_score",
+    "cf.waf.score",
+    "cf.waf.score.rce",
+    "cf.waf.score.sqli",
+    "cf.waf.score.xss",
+    "http.request.body.size",
+    "http.request.timestamp.sec",
+    "http.request.timestamp.msec",
+    "http.response.code",
+    "ip.geoip.asnum",
+    "ip.src.asnum",
+];
+
+const BOOL_FIELDS: &[&str] = &[
+    "cf.bot_management.corporate_proxy",
+    "cf.bot_management.js_detection.passed",
+    "cf.bot_management.static_resource",
+    "cf.bot_management.verified_bot",
+    "cf.client.bot",
+    "cf.tls_client_auth.cert_presented",
+    "cf.tls_client_auth.cert_revoked",
+    "cf.tls_client_auth.cert_verified",
+    "http.request.body.truncated",
+    "http.request.headers.truncated",
+    "ip.geoip.is_in_european_union",
+    "ip.src.is_in_european_union",
+    "ssl",
+];
+
+const IP_FIELDS: &[&str] = &["cf.edge.server_ip", "ip.src"];
+
+const STRING_ARRAY_FIELDS: &[&str] = &[
+    "http.request.accepted_languages",
+    "http.request.body.form.names",
+    "http.request.body.form.values",
+    "http.request.body.multipart.content_types",
+    "http.request.body.multipart.filenames",
+    "http.request.headers.names",
+    "http.request.headers.values",
+    "http.request.uri.args.names",
+    "http.request.uri.args.values",
+    "http.response.headers.names",
+    "http.response.headers.values",
+];
+
+const INT_ARRAY_FIELDS: &[&str] = &["cf.bot_management.detection_ids"];
+
+/// Maps from a name to every value sent under it (headers, query args, cookies)
+const STRING_ARRAY_MAP_FIELDS: &[&str] = &[
+    "http.request.body.form",
+    "http.request.cookies",
+    "http.request.headers",
+    "http.request.uri.args",
+    "http.response.headers",
+];
+
+/// Look up the type of a Rules language field
+pub fn field_type(name: &str) -> Option<Type> {
+    if STRING_FIELDS.contains(&name) {
+        Some(Type::String)
+    } else if INT_FIELDS.contains(&name) {
+        Some(Type::Int)
+    } else if BOOL_FIELDS.contains(&name) {
+        Some(Type::Bool)
+    } else if IP_FIELDS.contains(&name) {
+        Some(Type::Ip)
+    } else if STRING_ARRAY_FIELDS.contains(&name) {
+        Some(Type::array(Type::String))
+    } else if INT_ARRAY_FIELDS.contains(&name) {
+        Some(Type::array(Type::Int))
+    } else if STRING_ARRAY_MAP_FIELDS.contains(&name) {
+        Some(Type::map(Type::array(Type::String)))
+    } else {
+        None
+    }
+}
+
+/// Known field closest to an unknown name, for "did you mean" hints
+pub fn suggest_field(name: &str) -> Option<&'static str> {
+    all_fields()
+        .map(|field| (field, edit_distance(name, field)))
+        .filter(|(_, distance)| *distance <= 3)
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(field, _)| field)
+}
+
+fn all_fields() -> impl Iterator<Item = &'static str> {
+    STRING_FIELDS
+        .iter()
+        .chain(INT_FIELDS)
+        .chain(BOOL_FIELDS)
+        .chain(IP_FIELDS)
+        .chain(STRING_ARRAY_FIELDS)
+        .chain(INT_ARRAY_FIELDS)
+        .chain(STRING_ARRAY_MAP_FIELDS)
+        .copied()
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            row.push((prev[j] + cost).min(prev[j + 1] + 1).min(row[j] + 1));
+        }
+        prev = row;
+    }
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_types() {
+        assert_eq!(field_type("http.request.uri.path"), Some(Type::String));
+        assert_eq!(field_type("ip.src"), Some(Type::Ip));
+        assert_eq!(field_type("cf.client.bot"), Some(Type::Bool));
+        assert_eq!(
+            field_type("http.request.headers"),
+            Some(Type::map(Type::array(Type::String)))
+        );
+        assert_eq!(field_type("http.request.nope"), None);
+    }
+
+    #[test]
+    fn test_suggest_field() {
+        assert_eq!(
+            suggest_field("http.request.uri.pth"),
+            Some("http.request.uri.path")
+        );
+        assert_eq!(suggest_field("something.else.entirely"), None);
+    }
+}
@@ -0,0 +1,332 @@
+use std::net::IpAddr;
+
+use super::ExprError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    /// Field name, function name or keyword (`and`, `eq`, `contains`, ...)
+    Ident(String),
+    Str(String),
+    Int(i64),
+    /// IP address with an optional CIDR prefix length
+    Ip(IpAddr, Option<u8>),
+    /// Named list reference (`$name`)
+    List(String),
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    LBracket,
+    RBracket,
+    Comma,
+    Star,
+    DotDot,
+    /// Symbolic operator (`==`, `!=`, `<`, `<=`, `>`, `>=`, `~`, `&&`, `||`, `^^`, `!`)
+    Symbol(&'static str),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    /// Byte offset of the token in the source expression
+    pub pos: usize,
+}
+
+const SYMBOLS: &[&str] = &["==", "!=", "<=", ">=", "&&", "||", "^^", "<", ">", "~", "!"];
+
+pub fn tokenize(src: &str) -> Result<Vec<Token>, ExprError> {
+    let bytes = src.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while let Some(c) = src[i..].chars().next() {
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+
+        let start = i;
+        let single = match c {
+            '(' => Some(TokenKind::LParen),
+            ')' => Some(TokenKind::RParen),
+            '{' => Some(TokenKind::LBrace),
+            '}' => Some(TokenKind::RBrace),
+            '[' => Some(TokenKind::LBracket),
+            ']' => Some(TokenKind::RBracket),
+            ',' => Some(TokenKind::Comma),
+            '*' => Some(TokenKind::Star),
+            _ => None,
+        };
+        if let Some(kind) = single {
+            tokens.push(Token { kind, pos: start });
+            i += 1;
+            continue;
+        }
+
+        if src[i..].starts_with("..") {
+            tokens.push(Token {
+                kind: TokenKind::DotDot,
+                pos: start,
+            });
+            i += 2;
+            continue;
+        }
+
+        if c == '"' {
+            let (value, end) = lex_string(src, i + 1, start)?;
+            tokens.push(Token {
+                kind: TokenKind::Str(value),
+                pos: start,
+            });
+            i = end;
+            continue;
+        }
+
+        if c == 'r' && matches!(bytes.get(i + 1), Some(b'"') | Some(b'#')) {
+            let (value, end) = lex_raw_string(src, i + 1, start)?;
+            tokens.push(Token {
+                kind: TokenKind::Str(value),
+                pos: start,
+            });
+            i = end;
+            continue;
+        }
+
+        if c == '$' {
+            let end = word_end(src, i + 1);
+            let name = &src[i + 1..end];
+            if name.is_empty() {
+                return Err(ExprError::new("Expected a list name after '$'", start));
+            }
+            tokens.push(Token {
+                kind: TokenKind::List(name.to_string()),
+                pos: start,
+            });
+            i = end;
+            continue;
+        }
+
+        if let Some(symbol) = SYMBOLS.iter().find(|s| src[i..].starts_with(**s)) {
+            tokens.push(Token {
+                kind: TokenKind::Symbol(symbol),
+                pos: start,
+            });
+            i += symbol.len();
+            continue;
+        }
+
+        if c == '-' && bytes.get(i + 1).is_some_and(u8::is_ascii_digit) {
+            let end = word_end(src, i + 1);
+            let value = src[i..end].parse::<i64>().map_err(|_| {
+                ExprError::new(format!("Invalid integer '{}'", &src[i..end]), start)
+            })?;
+            tokens.push(Token {
+                kind: TokenKind::Int(value),
+                pos: start,
+            });
+            i = end;
+            continue;
+        }
+
+        if c.is_ascii_alphanumeric() || c == '_' || c == ':' {
+            let end = word_end(src, i);
+            let word = &src[i..end];
+            let (kind, end) = classify_word(src, word, start, end)?;
+            tokens.push(Token { kind, pos: start });
+            i = end;
+            continue;
+        }
+
+        return Err(ExprError::new(
+            format!("Unexpected character '{}'", c),
+            start,
+        ));
+    }
+
+    Ok(tokens)
+}
+
+/// End of a run of identifier / number / address characters, stopping at `..`
+fn word_end(src: &str, from: usize) -> usize {
+    let bytes = src.as_bytes();
+    let mut i = from;
+    while let Some(&b) = bytes.get(i) {
+        if b == b'.' && bytes.get(i + 1) == Some(&b'.') {
+            break;
+        }
+        if b.is_ascii_alphanumeric() || b == b'_' || b == b'.' || b == b':' {
+            i += 1;
+        } else {
+            break;
+        }
+    }
+    i
+}
+
+fn classify_word(
+    src: &str,
+    word: &str,
+    start: usize,
+    end: usize,
+) -> Result<(TokenKind, usize), ExprError> {
+    let starts_with_digit = word.starts_with(|c: char| c.is_ascii_digit());
+    let looks_like_ip = word.contains(':') || (starts_with_digit && word.contains('.'));
+
+    if looks_like_ip {
+        let addr: IpAddr = word
+            .parse()
+            .map_err(|_| ExprError::new(format!("Invalid IP address '{}'", word), start))?;
+        let bytes = src.as_bytes();
+        if bytes.get(end) == Some(&b'/') {
+            let prefix_end = word_end(src, end + 1);
+            let prefix_str = &src[end + 1..prefix_end];
+            let max = if addr.is_ipv4() { 32 } else { 128 };
+            let prefix = prefix_str
+                .parse::<u8>()
+                .ok()
+                .filter(|p| *p <= max)
+                .ok_or_else(|| {
+                    ExprError::new(format!("Invalid CIDR prefix '/{}'", prefix_str), end)
+                })?;
+            return Ok((TokenKind::Ip(addr, Some(prefix)), prefix_end));
+        }
+        return Ok((TokenKind::Ip(addr, None), end));
+    }
+
+    if starts_with_digit {
+        let value = word
+            .parse::<i64>()
+            .map_err(|_| ExprError::new(format!("Invalid integer '{}'", word), start))?;
+        return Ok((TokenKind::Int(value), end));
+    }
+
+    if word.split('.').any(str::is_empty) {
+        return Err(ExprError::new(
+            format!("Invalid field name '{}'", word),
+            start,
+        ));
+    }
+    Ok((TokenKind::Ident(word.to_string()), end))
+}
+
+/// Lex a quoted string. `\x` escapes give raw bytes, so the value is
+/// collected as bytes and must be valid UTF-8 once the string closes.
+fn lex_string(src: &str, from: usize, start: usize) -> Result<(String, usize), ExprError> {
+    let mut value = Vec::new();
+    let mut chars = src[from..].char_indices();
+    while let Some((offset, c)) = chars.next() {
+        match c {
+            '"' => {
+                let value = String::from_utf8(value)
+                    .map_err(|_| ExprError::new("String is not valid UTF-8", start))?;
+                return Ok((value, from + offset + 1));
+            }
+            '\\' => match chars.next() {
+                Some((_, '"')) => value.push(b'"'),
+                Some((_, '\\')) => value.push(b'\\'),
+                Some((_, 'x')) => {
+                    let hex: String = chars.by_ref().take(2).map(|(_, c)| c).collect();
+                    let byte = u8::from_str_radix(&hex, 16).map_err(|_| {
+                        ExprError::new(format!("Invalid escape '\\x{}'", hex), from + offset)
+                    })?;
+                    value.push(byte);
+                }
+                Some((_, other)) => {
+                    return Err(ExprError::new(
+                        format!("Invalid escape '\\{}' (use \\\\ for a backslash)", other),
+                        from + offset,
+                    ))
+                }
+                None => break,
+            },
+            _ => value.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+        }
+    }
+    Err(ExprError::new("Unterminated string", start))
+}
+
+fn lex_raw_string(src: &str, from: usize, start: usize) -> Result<(String, usize), ExprError> {
+    let hashes = src[from..].chars().take_while(|c| *c == '#').count();
+    let quote = from + hashes;
+    if src.as_bytes().get(quote) != Some(&b'"') {
+        return Err(ExprError::new("Expected '\"' to open raw string", start));
+    }
+    let terminator = format!("\"{}", "#".repeat(hashes));
+    let body_start = quote + 1;
+    match src[body_start..].find(&terminator) {
+        Some(offset) => Ok((
+            src[body_start..body_start + offset].to_string(),
+            body_start + offset + terminator.len(),
+        )),
+        None => Err(ExprError::new("Unterminated raw string", start)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(src: &str) -> Vec<TokenKind> {
+        tokenize(src).unwrap().into_iter().map(|t| t.kind).collect()
+    }
+
+    #[test]
+    fn test_tokenize_comparison() {
+        assert_eq!(
+            kinds(r#"http.host eq "example.com""#),
+            vec![
+                TokenKind::Ident("http.host".into()),
+                TokenKind::Ident("eq".into()),
+                TokenKind::Str("example.com".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_ips_and_ranges() {
+        let tokens = kinds("{192.0.2.0/24 2001:db8::1 80..443}");
+        assert!(matches!(tokens[1], TokenKind::Ip(IpAddr::V4(_), Some(24))));
+        assert!(matches!(tokens[2], TokenKind::Ip(IpAddr::V6(_), None)));
+        assert_eq!(tokens[3], TokenKind::Int(80));
+        assert_eq!(tokens[4], TokenKind::DotDot);
+        assert_eq!(tokens[5], TokenKind::Int(443));
+    }
+
+    #[test]
+    fn test_tokenize_raw_string_and_escapes() {
+        assert_eq!(
+            kinds(r###"r#"a"b"# "c\"d""###),
+            vec![TokenKind::Str("a\"b".into()), TokenKind::Str("c\"d".into())]
+        );
+    }
+
+    #[test]
+    fn test_tokenize_unterminated_string() {
+        let err = tokenize(r#"http.host eq "abc"#).unwrap_err();
+        assert_eq!(err.position, 13);
+    }
+
+    #[test]
+    fn test_tokenize_non_ascii_string() {
+        assert_eq!(
+            kinds(r#""exämple.com" "caf\xc3\xa9""#),
+            vec![
+                TokenKind::Str("exämple.com".into()),
+                TokenKind::Str("café".into())
+            ]
+        );
+        assert!(tokenize(r#""\xff""#).is_err());
+    }
+
+    #[test]
+    fn test_tokenize_non_ascii_unexpected_character() {
+        let err = tokenize("http.host eq é").unwrap_err();
+        assert_eq!(err.message, "Unexpected character 'é'");
+        assert_eq!(err.position, 13);
+    }
+
+    #[test]
+    fn test_tokenize_invalid_cidr_prefix() {
+        assert!(tokenize("ip.src in {10.0.0.0/40}").is_err());
+    }
+}
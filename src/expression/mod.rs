@@ -0,0 +1,278 @@
+//! Offline parser, type checker and evaluator for the Cloudflare Rules language.
+//!
+//! Covers the common subset used by WAF, redirect, transform, cache and origin
+//! rules: typed fields (`http.request.uri.path`, `ip.src`, `cf.client.bot`, ...),
+//! comparison operators, `in` sets and named lists, `[*]` with `any()`/`all()`,
+//! and the usual transformation functions. Expressions that pass here may
+//! still be rejected by the API if they use fields or functions not listed
+//! in [`fields`] or require a plan feature.
+
+pub mod check;
+pub mod eval;
+pub mod fields;
+pub mod lexer;
+pub mod parser;
+
+use std::fmt;
+
+use crate::error::{CfadError, Result};
+
+pub use eval::RequestContext;
+pub use parser::Expr;
+
+/// Syntax or type error with the byte offset it was found at
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExprError {
+    pub message: String,
+    pub position: usize,
+}
+
+impl ExprError {
+    pub fn new(message: impl Into<String>, position: usize) -> Self {
+        Self {
+            message: message.into(),
+            position,
+        }
+    }
+
+    /// Render the error under the expression with a caret at its position
+    pub fn render(&self, src: &str) -> String {
+        let column = src[..self.position.min(src.len())].chars().count();
+        format!("{}\n  {}\n  {}^", self, src, " ".repeat(column))
+    }
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (column {})", self.message, self.position + 1)
+    }
+}
+
+/// Result of evaluating an expression against a sample request
+#[derive(Debug, Clone)]
+pub struct Evaluation {
+    pub matched: bool,
+    /// Fields used by the expression but missing from the request (evaluated as empty/zero)
+    pub defaulted_fields: Vec<String>,
+}
+
+/// Parse an expression without type checking it
+pub fn parse(src: &str) -> std::result::Result<Expr, ExprError> {
+    let tokens = lexer::tokenize(src)?;
+    parser::parse(&tokens, src.len())
+}
+
+/// Parse and type-check an expression
+pub fn validate(src: &str) -> std::result::Result<Expr, ExprError> {
+    let expr = parse(src)?;
+    check::check_root(&expr)?;
+    Ok(expr)
+}
+
+/// Validate an expression, reporting failures as a [`CfadError::Validation`]
+pub fn check_expression(src: &str) -> Result<()> {
+    validate(src)
+        .map(|_| ())
+        .map_err(|e| CfadError::validation(format!("Invalid expression: {}", e.render(src))))
+}
+
+/// Validate an expression and evaluate it against a sample request
+pub fn evaluate(src: &str, request: &RequestContext) -> Result<Evaluation> {
+    let expr = validate(src)
+        .map_err(|e| CfadError::validation(format!("Invalid expression: {}", e.render(src))))?;
+    let mut evaluator = eval::Evaluator::new(request);
+    let matched = evaluator
+        .eval_bool(&expr)
+        .map_err(|e| CfadError::validation(format!("Evaluation failed: {}", e)))?;
+    Ok(Evaluation {
+        matched,
+        defaulted_fields: evaluator.defaulted.into_iter().collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(json: serde_json::Value) -> RequestContext {
+        RequestContext::from_json(json).unwrap()
+    }
+
+    fn matches(src: &str, json: serde_json::Value) -> bool {
+        evaluate(src, &request(json)).unwrap().matched
+    }
+
+    #[test]
+    fn test_validate_accepts_common_rules() {
+        let valid = [
+            r#"http.request.uri.path eq "/admin""#,
+            r#"(ip.src.country in {"XX" "YY"} and not cf.client.bot)"#,
+            r#"ip.src in {192.0.2.0/24 2001:db8::/32}"#,
+            r#"ip.src.asnum in {64496..64511 13335}"#,
+            r#"lower(http.request.uri.path) matches "^/wp-(admin|login)""#,
+            r#"starts_with(http.request.uri.path, "/api/") && http.request.method ne "GET""#,
+            r#"any(http.request.headers["x-debug"][*] == "1")"#,
+            r#"any(lower(http.request.headers.names[*]) contains "token")"#,
+            r#"http.host wildcard "*.example.com""#,
+            r#"http.request.uri.path strict wildcard "/static/*""#,
+            r#"ip.src in $blocklist"#,
+            r#"cf.threat_score gt 10 or cf.bot_management.score lt 30"#,
+            r#"len(http.request.uri.query) > 256"#,
+            "ssl",
+            "true",
+        ];
+        for src in valid {
+            assert!(
+                validate(src).is_ok(),
+                "expected valid: {} -> {:?}",
+                src,
+                validate(src)
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_unknown_field_suggests() {
+        let err = validate(r#"http.request.uri.pth eq "/""#).unwrap_err();
+        assert!(err.message.contains("Unknown field"));
+        assert!(err.message.contains("http.request.uri.path"));
+        assert_eq!(err.position, 0);
+    }
+
+    #[test]
+    fn test_validate_type_mismatch() {
+        let err = validate(r#"ip.src.asnum eq "13335""#).unwrap_err();
+        assert!(err.message.contains("Type mismatch"));
+
+        let err = validate(r#"ip.src contains "192""#).unwrap_err();
+        assert!(err.message.contains("cannot be used with IP address"));
+    }
+
+    #[test]
+    fn test_validate_requires_bool() {
+        let err = validate("http.request.uri.path").unwrap_err();
+        assert!(err.message.contains("must evaluate to Bool"));
+
+        let err = validate(r#"http.host eq "a" and http.request.uri.path"#).unwrap_err();
+        assert!(err.message.contains("Expected a Bool"));
+    }
+
+    #[test]
+    fn test_validate_star_needs_any() {
+        let err = validate(r#"http.request.headers.names[*] eq "x""#).unwrap_err();
+        assert!(err.message.contains("any() or all()"));
+    }
+
+    #[test]
+    fn test_validate_syntax_errors() {
+        assert!(validate(r#"(http.host eq "a""#).is_err());
+        assert!(validate(r#"http.host eq"#).is_err());
+        assert!(validate(r#"http.host eq "a" "b""#).is_err());
+        assert!(validate(r#"ip.src.country in {}"#).is_err());
+        assert!(validate(r#"http.host matches "(unclosed""#).is_err());
+        assert!(validate(r#"unknown_fn(http.host)"#).is_err());
+        assert!(validate(r#"starts_with(http.host)"#).is_err());
+        assert!(validate("").is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_deep_nesting() {
+        let parens = format!("{}true{}", "(".repeat(2000), ")".repeat(2000));
+        let err = validate(&parens).unwrap_err();
+        assert!(err.message.contains("nested more than"));
+        assert!(validate(&format!("{}true", "not ".repeat(2000))).is_err());
+
+        let shallow = format!(r#"{}http.host eq "a"{}"#, "(".repeat(50), ")".repeat(50));
+        assert!(validate(&shallow).is_ok());
+    }
+
+    #[test]
+    fn test_render_points_at_error() {
+        let src = r#"http.host eq 42"#;
+        let err = validate(src).unwrap_err();
+        let rendered = err.render(src);
+        assert!(rendered.ends_with(&format!("{}^", " ".repeat(13 + 2))));
+    }
+
+    #[test]
+    fn test_evaluate_comparisons() {
+        let req = serde_json::json!({
+            "http.request.uri.path": "/Admin/login",
+            "ip.src": "192.0.2.77",
+            "ip.src.asnum": 64500,
+            "cf.client.bot": false
+        });
+        assert!(matches(
+            r#"lower(http.request.uri.path) eq "/admin/login""#,
+            req.clone()
+        ));
+        assert!(matches(r#"ip.src in {192.0.2.0/24}"#, req.clone()));
+        assert!(matches(r#"ip.src.asnum in {64496..64511}"#, req.clone()));
+        assert!(matches(r#"not cf.client.bot"#, req.clone()));
+        assert!(!matches(
+            r#"http.request.uri.path matches "^/admin""#,
+            req.clone()
+        ));
+        assert!(matches(
+            r#"http.request.uri.path wildcard "/admin/*""#,
+            req.clone()
+        ));
+        assert!(!matches(
+            r#"http.request.uri.path strict wildcard "/admin/*""#,
+            req
+        ));
+    }
+
+    #[test]
+    fn test_evaluate_headers_and_any() {
+        let req = serde_json::json!({
+            "http.request.headers": {"x-debug": ["0", "1"], "user-agent": "curl/8.0"},
+            "http.request.headers.names": ["X-Debug", "User-Agent"]
+        });
+        assert!(matches(
+            r#"any(http.request.headers["x-debug"][*] eq "1")"#,
+            req.clone()
+        ));
+        assert!(!matches(
+            r#"all(http.request.headers["x-debug"][*] eq "1")"#,
+            req.clone()
+        ));
+        assert!(matches(
+            r#"any(lower(http.request.headers.names[*]) eq "user-agent")"#,
+            req.clone()
+        ));
+        assert!(matches(
+            r#"http.request.headers["user-agent"][0] contains "curl""#,
+            req
+        ));
+    }
+
+    #[test]
+    fn test_evaluate_named_list() {
+        let req = serde_json::json!({
+            "ip.src": "198.51.100.4",
+            "lists": {"blocklist": ["198.51.100.0/24"]}
+        });
+        assert!(matches("ip.src in $blocklist", req));
+
+        let missing = evaluate("ip.src in $other", &request(serde_json::json!({})));
+        assert!(missing.is_err());
+    }
+
+    #[test]
+    fn test_evaluate_reports_defaulted_fields() {
+        let result = evaluate(
+            r#"http.host eq "" and not ssl"#,
+            &request(serde_json::json!({})),
+        )
+        .unwrap();
+        assert!(result.matched);
+        assert_eq!(result.defaulted_fields, vec!["http.host", "ssl"]);
+    }
+
+    #[test]
+    fn test_request_rejects_unknown_field() {
+        assert!(RequestContext::from_json(serde_json::json!({"http.nope": "x"})).is_err());
+        assert!(RequestContext::from_json(serde_json::json!(["not", "an", "object"])).is_err());
+    }
+}
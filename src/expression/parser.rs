@@ -0,0 +1,555 @@
+use std::net::IpAddr;
+
+use super::lexer::{Token, TokenKind};
+use super::ExprError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogicalOp {
+    And,
+    Or,
+    Xor,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Contains,
+    Matches,
+    Wildcard,
+    StrictWildcard,
+}
+
+impl CompareOp {
+    pub fn name(&self) -> &'static str {
+        match self {
+            CompareOp::Eq => "eq",
+            CompareOp::Ne => "ne",
+            CompareOp::Lt => "lt",
+            CompareOp::Le => "le",
+            CompareOp::Gt => "gt",
+            CompareOp::Ge => "ge",
+            CompareOp::Contains => "contains",
+            CompareOp::Matches => "matches",
+            CompareOp::Wildcard => "wildcard",
+            CompareOp::StrictWildcard => "strict wildcard",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Literal {
+    Str(String),
+    Int(i64),
+    Bool(bool),
+    Ip(IpAddr, Option<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SetItem {
+    Str(String),
+    Int(i64),
+    IntRange(i64, i64),
+    Ip(IpAddr, Option<u8>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InSet {
+    Items(Vec<SetItem>),
+    /// Named list (`$name`)
+    List(String),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum IndexKey {
+    Str(String),
+    Int(i64),
+    /// `[*]` — apply to every element
+    Star,
+}
+
+/// Parsed Rules language expression. `pos` fields are byte offsets into the source.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Logical {
+        op: LogicalOp,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+    },
+    Not {
+        inner: Box<Expr>,
+        pos: usize,
+    },
+    Compare {
+        lhs: Box<Expr>,
+        op: CompareOp,
+        rhs: Box<Expr>,
+        pos: usize,
+    },
+    In {
+        lhs: Box<Expr>,
+        set: InSet,
+        pos: usize,
+    },
+    Field {
+        name: String,
+        pos: usize,
+    },
+    Index {
+        base: Box<Expr>,
+        key: IndexKey,
+        pos: usize,
+    },
+    Call {
+        name: String,
+        args: Vec<Expr>,
+        pos: usize,
+    },
+    Literal {
+        value: Literal,
+        pos: usize,
+    },
+}
+
+impl Expr {
+    /// Source position used when reporting errors about this expression
+    pub fn pos(&self) -> usize {
+        match self {
+            Expr::Logical { lhs, .. } => lhs.pos(),
+            Expr::Compare { lhs, .. } | Expr::In { lhs, .. } => lhs.pos(),
+            Expr::Index { base, .. } => base.pos(),
+            Expr::Not { pos, .. }
+            | Expr::Field { pos, .. }
+            | Expr::Call { pos, .. }
+            | Expr::Literal { pos, .. } => *pos,
+        }
+    }
+}
+
+/// Deepest nesting of parentheses, function calls and `not` accepted, well
+/// above what real rules use but low enough to keep recursion off the end
+/// of the stack
+const MAX_DEPTH: usize = 100;
+
+const KEYWORDS: &[&str] = &[
+    "and", "or", "xor", "not", "eq", "ne", "lt", "le", "gt", "ge", "contains", "matches",
+    "wildcard", "strict", "in",
+];
+
+pub fn parse(tokens: &[Token], src_len: usize) -> Result<Expr, ExprError> {
+    let mut parser = Parser {
+        tokens,
+        index: 0,
+        src_len,
+        depth: 0,
+    };
+    if tokens.is_empty() {
+        return Err(ExprError::new("Expression is empty", 0));
+    }
+    let expr = parser.parse_or()?;
+    if let Some(token) = parser.peek() {
+        return Err(ExprError::new(
+            format!("Unexpected {}", describe(&token.kind)),
+            token.pos,
+        ));
+    }
+    Ok(expr)
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+    index: usize,
+    src_len: usize,
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&'a Token> {
+        self.tokens.get(self.index)
+    }
+
+    fn next(&mut self) -> Option<&'a Token> {
+        let token = self.tokens.get(self.index);
+        self.index += 1;
+        token
+    }
+
+    fn end_pos(&self) -> usize {
+        self.src_len
+    }
+
+    fn peek_ident(&self) -> Option<&'a str> {
+        match self.peek() {
+            Some(Token {
+                kind: TokenKind::Ident(name),
+                ..
+            }) => Some(name.as_str()),
+            _ => None,
+        }
+    }
+
+    fn peek_symbol(&self) -> Option<&'static str> {
+        match self.peek() {
+            Some(Token {
+                kind: TokenKind::Symbol(symbol),
+                ..
+            }) => Some(symbol),
+            _ => None,
+        }
+    }
+
+    /// Consume the next token if it is one of the given keyword or symbol spellings
+    fn eat_operator(&mut self, keyword: &str, symbol: &str) -> bool {
+        if self.peek_ident() == Some(keyword) || self.peek_symbol() == Some(symbol) {
+            self.index += 1;
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, kind: TokenKind, what: &str) -> Result<usize, ExprError> {
+        match self.next() {
+            Some(token) if token.kind == kind => Ok(token.pos),
+            Some(token) => Err(ExprError::new(
+                format!("Expected {}, found {}", what, describe(&token.kind)),
+                token.pos,
+            )),
+            None => Err(ExprError::new(
+                format!("Expected {}, found end of expression", what),
+                self.end_pos(),
+            )),
+        }
+    }
+
+    /// Run `parse` one nesting level deeper, failing past `MAX_DEPTH`
+    fn nested<T>(
+        &mut self,
+        parse: impl FnOnce(&mut Self) -> Result<T, ExprError>,
+    ) -> Result<T, ExprError> {
+        if self.depth >= MAX_DEPTH {
+            let pos = self.peek().map(|t| t.pos).unwrap_or(self.end_pos());
+            return Err(ExprError::new(
+                format!("Expression is nested more than {} levels deep", MAX_DEPTH),
+                pos,
+            ));
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn parse_or(&mut self) -> Result<Expr, ExprError> {
+        self.nested(|parser| {
+            let mut lhs = parser.parse_xor()?;
+            while parser.eat_operator("or", "||") {
+                let rhs = parser.parse_xor()?;
+                lhs = logical(LogicalOp::Or, lhs, rhs);
+            }
+            Ok(lhs)
+        })
+    }
+
+    fn parse_xor(&mut self) -> Result<Expr, ExprError> {
+        let mut lhs = self.parse_and()?;
+        while self.eat_operator("xor", "^^") {
+            let rhs = self.parse_and()?;
+            lhs = logical(LogicalOp::Xor, lhs, rhs);
+        }
+        Ok(lhs)
+    }
+
+    fn parse_and(&mut self) -> Result<Expr, ExprError> {
+        let mut lhs = self.parse_unary()?;
+        while self.eat_operator("and", "&&") {
+            let rhs = self.parse_unary()?;
+            lhs = logical(LogicalOp::And, lhs, rhs);
+        }
+        Ok(lhs)
+    }
+
+    fn parse_unary(&mut self) -> Result<Expr, ExprError> {
+        let pos = self.peek().map(|t| t.pos).unwrap_or(self.end_pos());
+        if self.eat_operator("not", "!") {
+            let inner = self.nested(Self::parse_unary)?;
+            return Ok(Expr::Not {
+                inner: Box::new(inner),
+                pos,
+            });
+        }
+        self.parse_comparison()
+    }
+
+    fn parse_comparison(&mut self) -> Result<Expr, ExprError> {
+        if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::LParen)) {
+            self.index += 1;
+            let inner = self.parse_or()?;
+            self.expect(TokenKind::RParen, "')'")?;
+            return Ok(inner);
+        }
+
+        let lhs = self.parse_term()?;
+        let pos = match self.peek() {
+            Some(token) => token.pos,
+            None => return Ok(lhs),
+        };
+
+        if self.peek_ident() == Some("in") {
+            self.index += 1;
+            let set = self.parse_set()?;
+            return Ok(Expr::In {
+                lhs: Box::new(lhs),
+                set,
+                pos,
+            });
+        }
+
+        let op = match (self.peek_ident(), self.peek_symbol()) {
+            (Some("eq"), _) | (_, Some("==")) => CompareOp::Eq,
+            (Some("ne"), _) | (_, Some("!=")) => CompareOp::Ne,
+            (Some("lt"), _) | (_, Some("<")) => CompareOp::Lt,
+            (Some("le"), _) | (_, Some("<=")) => CompareOp::Le,
+            (Some("gt"), _) | (_, Some(">")) => CompareOp::Gt,
+            (Some("ge"), _) | (_, Some(">=")) => CompareOp::Ge,
+            (Some("contains"), _) => CompareOp::Contains,
+            (Some("matches"), _) | (_, Some("~")) => CompareOp::Matches,
+            (Some("wildcard"), _) => CompareOp::Wildcard,
+            (Some("strict"), _) => {
+                self.index += 1;
+                if self.peek_ident() != Some("wildcard") {
+                    return Err(ExprError::new("Expected 'wildcard' after 'strict'", pos));
+                }
+                CompareOp::StrictWildcard
+            }
+            _ => return Ok(lhs),
+        };
+        self.index += 1;
+
+        let rhs = self.parse_term()?;
+        Ok(Expr::Compare {
+            lhs: Box::new(lhs),
+            op,
+            rhs: Box::new(rhs),
+            pos,
+        })
+    }
+
+    fn parse_term(&mut self) -> Result<Expr, ExprError> {
+        let token = self.next().ok_or_else(|| {
+            ExprError::new(
+                "Expected a field, function or value, found end of expression",
+                self.end_pos(),
+            )
+        })?;
+        let pos = token.pos;
+
+        let mut expr = match &token.kind {
+            TokenKind::Str(s) => Expr::Literal {
+                value: Literal::Str(s.clone()),
+                pos,
+            },
+            TokenKind::Int(i) => Expr::Literal {
+                value: Literal::Int(*i),
+                pos,
+            },
+            TokenKind::Ip(addr, prefix) => Expr::Literal {
+                value: Literal::Ip(*addr, *prefix),
+                pos,
+            },
+            TokenKind::Ident(name) if name == "true" || name == "false" => Expr::Literal {
+                value: Literal::Bool(name == "true"),
+                pos,
+            },
+            TokenKind::Ident(name) if KEYWORDS.contains(&name.as_str()) => {
+                return Err(ExprError::new(
+                    format!("Expected a field or value, found operator '{}'", name),
+                    pos,
+                ))
+            }
+            TokenKind::Ident(name) => {
+                if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::LParen)) {
+                    self.index += 1;
+                    let args = self.parse_args()?;
+                    Expr::Call {
+                        name: name.clone(),
+                        args,
+                        pos,
+                    }
+                } else {
+                    Expr::Field {
+                        name: name.clone(),
+                        pos,
+                    }
+                }
+            }
+            other => {
+                return Err(ExprError::new(
+                    format!(
+                        "Expected a field, function or value, found {}",
+                        describe(other)
+                    ),
+                    pos,
+                ))
+            }
+        };
+
+        while matches!(self.peek().map(|t| &t.kind), Some(TokenKind::LBracket)) {
+            let bracket = self.next().map(|t| t.pos).unwrap_or(pos);
+            let key = match self.next().map(|t| &t.kind) {
+                Some(TokenKind::Str(s)) => IndexKey::Str(s.clone()),
+                Some(TokenKind::Int(i)) => IndexKey::Int(*i),
+                Some(TokenKind::Star) => IndexKey::Star,
+                _ => {
+                    return Err(ExprError::new(
+                        "Expected a string key, integer index or '*' inside '[]'",
+                        bracket,
+                    ))
+                }
+            };
+            self.expect(TokenKind::RBracket, "']'")?;
+            expr = Expr::Index {
+                base: Box::new(expr),
+                key,
+                pos: bracket,
+            };
+        }
+
+        Ok(expr)
+    }
+
+    fn parse_args(&mut self) -> Result<Vec<Expr>, ExprError> {
+        let mut args = Vec::new();
+        if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::RParen)) {
+            self.index += 1;
+            return Ok(args);
+        }
+        loop {
+            args.push(self.parse_or()?);
+            match self.next() {
+                Some(Token {
+                    kind: TokenKind::Comma,
+                    ..
+                }) => continue,
+                Some(Token {
+                    kind: TokenKind::RParen,
+                    ..
+                }) => return Ok(args),
+                Some(token) => {
+                    return Err(ExprError::new(
+                        format!("Expected ',' or ')', found {}", describe(&token.kind)),
+                        token.pos,
+                    ))
+                }
+                None => {
+                    return Err(ExprError::new(
+                        "Expected ')' to close function call",
+                        self.end_pos(),
+                    ))
+                }
+            }
+        }
+    }
+
+    fn parse_set(&mut self) -> Result<InSet, ExprError> {
+        match self.next() {
+            Some(Token {
+                kind: TokenKind::List(name),
+                ..
+            }) => return Ok(InSet::List(name.clone())),
+            Some(Token {
+                kind: TokenKind::LBrace,
+                ..
+            }) => {}
+            Some(token) => {
+                return Err(ExprError::new(
+                    format!(
+                        "Expected '{{' or a $list after 'in', found {}",
+                        describe(&token.kind)
+                    ),
+                    token.pos,
+                ))
+            }
+            None => {
+                return Err(ExprError::new(
+                    "Expected '{' or a $list after 'in'",
+                    self.end_pos(),
+                ))
+            }
+        }
+
+        let mut items = Vec::new();
+        loop {
+            let token = self
+                .next()
+                .ok_or_else(|| ExprError::new("Expected '}' to close set", self.end_pos()))?;
+            let item = match &token.kind {
+                TokenKind::RBrace => break,
+                TokenKind::Str(s) => SetItem::Str(s.clone()),
+                TokenKind::Ip(addr, prefix) => SetItem::Ip(*addr, *prefix),
+                TokenKind::Int(start) => {
+                    if matches!(self.peek().map(|t| &t.kind), Some(TokenKind::DotDot)) {
+                        self.index += 1;
+                        match self.next().map(|t| &t.kind) {
+                            Some(TokenKind::Int(end)) if end >= start => {
+                                SetItem::IntRange(*start, *end)
+                            }
+                            _ => {
+                                return Err(ExprError::new(
+                                    "Invalid integer range (expected 'start..end')",
+                                    token.pos,
+                                ))
+                            }
+                        }
+                    } else {
+                        SetItem::Int(*start)
+                    }
+                }
+                other => {
+                    return Err(ExprError::new(
+                        format!("Unexpected {} in set", describe(other)),
+                        token.pos,
+                    ))
+                }
+            };
+            items.push(item);
+        }
+
+        if items.is_empty() {
+            return Err(ExprError::new("Set must not be empty", self.end_pos()));
+        }
+        Ok(InSet::Items(items))
+    }
+}
+
+fn logical(op: LogicalOp, lhs: Expr, rhs: Expr) -> Expr {
+    Expr::Logical {
+        op,
+        lhs: Box::new(lhs),
+        rhs: Box::new(rhs),
+    }
+}
+
+fn describe(kind: &TokenKind) -> String {
+    match kind {
+        TokenKind::Ident(name) => format!("'{}'", name),
+        TokenKind::Str(s) => format!("string \"{}\"", s),
+        TokenKind::Int(i) => format!("integer {}", i),
+        TokenKind::Ip(addr, Some(prefix)) => format!("IP range {}/{}", addr, prefix),
+        TokenKind::Ip(addr, None) => format!("IP address {}", addr),
+        TokenKind::List(name) => format!("list ${}", name),
+        TokenKind::LParen => "'('".to_string(),
+        TokenKind::RParen => "')'".to_string(),
+        TokenKind::LBrace => "'{'".to_string(),
+        TokenKind::RBrace => "'}'".to_string(),
+        TokenKind::LBracket => "'['".to_string(),
+        TokenKind::RBracket => "']'".to_string(),
+        TokenKind::Comma => "','".to_string(),
+        TokenKind::Star => "'*'".to_string(),
+        TokenKind::DotDot => "'..'".to_string(),
+        TokenKind::Symbol(symbol) => format!("'{}'", symbol),
+    }
+}
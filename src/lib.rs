@@ -5,6 +5,7 @@ pub mod cli;
 pub mod client;
 pub mod config;
 pub mod error;
+pub mod expression;
 pub mod metrics;
 pub mod ops;
 pub mod output;
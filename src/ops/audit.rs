@@ -0,0 +1,515 @@
+use serde::Serialize;
+
+use crate::api::certificates::CertificatePack;
+use crate::api::dns::{DnsRecord, Dnssec};
+use crate::api::zone::{Zone, ZoneSetting};
+use crate::client::CloudflareClient;
+use crate::error::Result;
+use crate::ops::{certificates, dns, ssl, zone};
+
+/// Edge certificates expiring sooner than this are flagged
+const CERT_EXPIRY_WARN_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    /// Points toward the score: a warning counts half
+    fn points(&self) -> u32 {
+        match self {
+            Self::Pass => 2,
+            Self::Warn => 1,
+            Self::Fail => 0,
+        }
+    }
+}
+
+/// Outcome of one checklist item
+#[derive(Debug, Clone, Serialize)]
+pub struct AuditCheck {
+    /// Stable identifier (used as the JUnit test case name)
+    pub id: &'static str,
+    pub title: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+/// Audit result for one zone
+#[derive(Debug, Clone, Serialize)]
+pub struct ZoneAudit {
+    pub zone: String,
+    /// 0-100, where a warning counts as half a pass
+    pub score: u32,
+    pub checks: Vec<AuditCheck>,
+}
+
+impl ZoneAudit {
+    pub fn new(zone: &str, checks: Vec<AuditCheck>) -> Self {
+        Self {
+            zone: zone.to_string(),
+            score: score(&checks),
+            checks,
+        }
+    }
+
+    /// Worst status across all checks
+    pub fn worst(&self) -> CheckStatus {
+        self.checks
+            .iter()
+            .map(|check| check.status)
+            .max()
+            .unwrap_or(CheckStatus::Pass)
+    }
+
+    pub fn count(&self, status: CheckStatus) -> usize {
+        self.checks.iter().filter(|c| c.status == status).count()
+    }
+
+    /// Whether the zone clears a CI gate: no check at or above `fail_on`
+    /// (when set) and a score of at least `min_score`
+    pub fn meets(&self, fail_on: Option<CheckStatus>, min_score: u32) -> bool {
+        let below_threshold = fail_on.is_none_or(|threshold| self.worst() < threshold);
+        below_threshold && self.score >= min_score
+    }
+}
+
+/// Everything the checklist looks at, fetched up front
+#[derive(Debug, Clone, Default)]
+pub struct AuditInput {
+    pub settings: Vec<ZoneSetting>,
+    pub records: Vec<DnsRecord>,
+    pub dnssec: Option<Dnssec>,
+    pub certificate_packs: Vec<CertificatePack>,
+}
+
+/// Fetch a zone's settings, DNS records, DNSSEC state and edge certificates
+pub async fn fetch_audit_input(client: &CloudflareClient, zone_id: &str) -> Result<AuditInput> {
+    Ok(AuditInput {
+        settings: zone::get_zone_settings(client, zone_id).await?,
+        records: dns::list_records(client, zone_id, None, None).await?,
+        dnssec: Some(dns::get_dnssec(client, zone_id).await?),
+        certificate_packs: certificates::list_certificate_packs(client, zone_id).await?,
+    })
+}
+
+pub async fn audit_zone(client: &CloudflareClient, zone: &Zone) -> Result<ZoneAudit> {
+    let input = fetch_audit_input(client, &zone.id).await?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    Ok(ZoneAudit::new(
+        &zone.name,
+        evaluate(&zone.name, &input, now),
+    ))
+}
+
+/// Run the checklist against pre-fetched data (`now` in Unix seconds)
+pub fn evaluate(zone_name: &str, input: &AuditInput, now: u64) -> Vec<AuditCheck> {
+    let setting = |id: &str| {
+        input
+            .settings
+            .iter()
+            .find(|s| s.id == id)
+            .and_then(|s| s.value.as_str())
+            .unwrap_or("")
+            .to_string()
+    };
+
+    let mut checks = Vec::new();
+
+    let ssl_mode = setting("ssl");
+    checks.push(AuditCheck {
+        id: "ssl_mode",
+        title: "SSL mode is Full (strict)",
+        status: match ssl_mode.as_str() {
+            "strict" => CheckStatus::Pass,
+            "full" => CheckStatus::Warn,
+            _ => CheckStatus::Fail,
+        },
+        detail: format!("ssl = {}", or_unknown(&ssl_mode)),
+    });
+
+    let always_https = setting("always_use_https");
+    checks.push(AuditCheck {
+        id: "always_use_https",
+        title: "Always Use HTTPS is on",
+        status: if always_https == "on" {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Fail
+        },
+        detail: format!("always_use_https = {}", or_unknown(&always_https)),
+    });
+
+    let min_tls = setting("min_tls_version");
+    checks.push(AuditCheck {
+        id: "min_tls_version",
+        title: "Minimum TLS version is 1.2 or higher",
+        status: match min_tls.as_str() {
+            "1.2" | "1.3" => CheckStatus::Pass,
+            _ => CheckStatus::Fail,
+        },
+        detail: format!("min_tls_version = {}", or_unknown(&min_tls)),
+    });
+
+    let dev_mode = setting("development_mode");
+    checks.push(AuditCheck {
+        id: "development_mode",
+        title: "Development mode is off",
+        status: if dev_mode == "on" {
+            CheckStatus::Warn
+        } else {
+            CheckStatus::Pass
+        },
+        detail: format!("development_mode = {}", or_unknown(&dev_mode)),
+    });
+
+    let dnssec = input.dnssec.as_ref().map(|d| d.status.as_str());
+    checks.push(AuditCheck {
+        id: "dnssec",
+        title: "DNSSEC is enabled",
+        status: if input.dnssec.as_ref().is_some_and(Dnssec::is_active) {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warn
+        },
+        detail: format!("dnssec = {}", dnssec.unwrap_or("unknown")),
+    });
+
+    let has_txt = |prefix: &str, name: &str| {
+        input.records.iter().any(|r| {
+            r.record_type == "TXT"
+                && r.name.eq_ignore_ascii_case(name)
+                && r.content.trim_matches('"').starts_with(prefix)
+        })
+    };
+
+    let has_caa = input
+        .records
+        .iter()
+        .any(|r| r.record_type == "CAA" && r.name.eq_ignore_ascii_case(zone_name));
+    checks.push(presence_check(
+        "caa_record",
+        "CAA record restricts certificate issuance",
+        has_caa,
+        CheckStatus::Warn,
+        format!("CAA at {}", zone_name),
+    ));
+
+    checks.push(presence_check(
+        "spf_record",
+        "SPF record is published",
+        has_txt("v=spf1", zone_name),
+        CheckStatus::Fail,
+        format!("TXT v=spf1 at {}", zone_name),
+    ));
+
+    let dmarc_name = format!("_dmarc.{}", zone_name);
+    checks.push(presence_check(
+        "dmarc_record",
+        "DMARC record is published",
+        has_txt("v=DMARC1", &dmarc_name),
+        CheckStatus::Fail,
+        format!("TXT v=DMARC1 at {}", dmarc_name),
+    ));
+
+    let unproxied: Vec<&str> = input
+        .records
+        .iter()
+        .filter(|r| (r.record_type == "A" || r.record_type == "AAAA") && r.proxiable)
+        .filter(|r| !r.proxied)
+        .map(|r| r.name.as_str())
+        .collect();
+    checks.push(AuditCheck {
+        id: "proxied_records",
+        title: "Proxiable A/AAAA records are proxied",
+        status: if unproxied.is_empty() {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warn
+        },
+        detail: if unproxied.is_empty() {
+            "all proxiable A/AAAA records are proxied".to_string()
+        } else {
+            format!("not proxied: {}", unproxied.join(", "))
+        },
+    });
+
+    checks.push(certificate_check(&input.certificate_packs, now));
+    checks
+}
+
+fn presence_check(
+    id: &'static str,
+    title: &'static str,
+    present: bool,
+    missing: CheckStatus,
+    what: String,
+) -> AuditCheck {
+    AuditCheck {
+        id,
+        title,
+        status: if present { CheckStatus::Pass } else { missing },
+        detail: if present {
+            format!("found {}", what)
+        } else {
+            format!("missing {}", what)
+        },
+    }
+}
+
+fn certificate_check(packs: &[CertificatePack], now: u64) -> AuditCheck {
+    let soonest = packs
+        .iter()
+        .filter(|pack| pack.status.as_deref() == Some("active"))
+        .filter_map(|pack| pack.expires_on())
+        .filter_map(|expires_on| ssl::days_until(expires_on, now))
+        .min();
+
+    let (status, detail) = match soonest {
+        None => (CheckStatus::Fail, "no active edge certificate".to_string()),
+        Some(days) if days < 0 => (
+            CheckStatus::Fail,
+            format!("certificate expired {} days ago", -days),
+        ),
+        Some(days) if days < CERT_EXPIRY_WARN_DAYS => (
+            CheckStatus::Warn,
+            format!("certificate expires in {} days", days),
+        ),
+        Some(days) => (CheckStatus::Pass, format!("next expiry in {} days", days)),
+    };
+    AuditCheck {
+        id: "edge_certificate",
+        title: "Edge certificate is active and not expiring soon",
+        status,
+        detail,
+    }
+}
+
+/// Percentage of available points, where a warning earns half
+pub fn score(checks: &[AuditCheck]) -> u32 {
+    if checks.is_empty() {
+        return 100;
+    }
+    let earned: u32 = checks.iter().map(|c| c.status.points()).sum();
+    let possible = checks.len() as u32 * CheckStatus::Pass.points();
+    (earned * 100 + possible / 2) / possible
+}
+
+fn or_unknown(value: &str) -> &str {
+    if value.is_empty() {
+        "unknown"
+    } else {
+        value
+    }
+}
+
+/// Render audits as a JUnit XML report: one test suite per zone, one test
+/// case per check. Failures become `<failure>`; warnings are reported in
+/// `<system-out>` so they show up without breaking the build.
+pub fn to_junit(audits: &[ZoneAudit]) -> String {
+    let tests: usize = audits.iter().map(|a| a.checks.len()).sum();
+    let failures: usize = audits.iter().map(|a| a.count(CheckStatus::Fail)).sum();
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"cfad zone audit\" tests=\"{}\" failures=\"{}\">\n",
+        tests, failures
+    ));
+    for audit in audits {
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\">\n",
+            xml_escape(&audit.zone),
+            audit.checks.len(),
+            audit.count(CheckStatus::Fail)
+        ));
+        xml.push_str(&format!(
+            "    <properties>\n      <property name=\"score\" value=\"{}\"/>\n    </properties>\n",
+            audit.score
+        ));
+        for check in &audit.checks {
+            let open = format!(
+                "    <testcase classname=\"{}\" name=\"{}\"",
+                xml_escape(&audit.zone),
+                check.id
+            );
+            match check.status {
+                CheckStatus::Pass => xml.push_str(&format!("{}/>\n", open)),
+                CheckStatus::Warn => xml.push_str(&format!(
+                    "{}>\n      <system-out>WARN: {}</system-out>\n    </testcase>\n",
+                    open,
+                    xml_escape(&check.detail)
+                )),
+                CheckStatus::Fail => xml.push_str(&format!(
+                    "{}>\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                    open,
+                    xml_escape(check.title),
+                    xml_escape(&check.detail)
+                )),
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2025-01-01T00:00:00Z
+    const NOW: u64 = 1_735_689_600;
+
+    fn setting(id: &str, value: &str) -> ZoneSetting {
+        ZoneSetting {
+            id: id.to_string(),
+            value: serde_json::json!(value),
+            editable: true,
+            modified_on: None,
+        }
+    }
+
+    fn record(record_type: &str, name: &str, content: &str, proxied: bool) -> DnsRecord {
+        serde_json::from_value(serde_json::json!({
+            "id": format!("{}-{}", record_type, name),
+            "name": name,
+            "type": record_type,
+            "content": content,
+            "ttl": 1,
+            "proxiable": record_type == "A" || record_type == "AAAA",
+            "proxied": proxied
+        }))
+        .unwrap()
+    }
+
+    fn pack(expires_on: &str) -> CertificatePack {
+        serde_json::from_value(serde_json::json!({
+            "id": "pack1",
+            "status": "active",
+            "certificates": [{"id": "c1", "expires_on": expires_on}]
+        }))
+        .unwrap()
+    }
+
+    fn hardened() -> AuditInput {
+        AuditInput {
+            settings: vec![
+                setting("ssl", "strict"),
+                setting("always_use_https", "on"),
+                setting("min_tls_version", "1.2"),
+                setting("development_mode", "off"),
+            ],
+            records: vec![
+                record("A", "example.com", "192.0.2.1", true),
+                record("CAA", "example.com", "0 issue \"letsencrypt.org\"", false),
+                record("TXT", "example.com", "\"v=spf1 -all\"", false),
+                record("TXT", "_dmarc.example.com", "v=DMARC1; p=reject", false),
+            ],
+            dnssec: Some(Dnssec {
+                status: "active".to_string(),
+                ds: None,
+            }),
+            certificate_packs: vec![pack("2025-06-01T00:00:00Z")],
+        }
+    }
+
+    fn status_of(checks: &[AuditCheck], id: &str) -> CheckStatus {
+        checks.iter().find(|c| c.id == id).unwrap().status
+    }
+
+    #[test]
+    fn test_hardened_zone_passes_everything() {
+        let checks = evaluate("example.com", &hardened(), NOW);
+        assert!(checks.iter().all(|c| c.status == CheckStatus::Pass));
+        assert_eq!(score(&checks), 100);
+    }
+
+    #[test]
+    fn test_weak_zone_findings() {
+        let mut input = hardened();
+        input.settings = vec![
+            setting("ssl", "flexible"),
+            setting("always_use_https", "off"),
+            setting("min_tls_version", "1.0"),
+            setting("development_mode", "on"),
+        ];
+        input.records = vec![record("A", "www.example.com", "192.0.2.1", false)];
+        input.dnssec = Some(Dnssec {
+            status: "disabled".to_string(),
+            ds: None,
+        });
+        input.certificate_packs = vec![pack("2025-01-10T00:00:00Z")];
+
+        let checks = evaluate("example.com", &input, NOW);
+        assert_eq!(status_of(&checks, "ssl_mode"), CheckStatus::Fail);
+        assert_eq!(status_of(&checks, "always_use_https"), CheckStatus::Fail);
+        assert_eq!(status_of(&checks, "min_tls_version"), CheckStatus::Fail);
+        assert_eq!(status_of(&checks, "development_mode"), CheckStatus::Warn);
+        assert_eq!(status_of(&checks, "dnssec"), CheckStatus::Warn);
+        assert_eq!(status_of(&checks, "caa_record"), CheckStatus::Warn);
+        assert_eq!(status_of(&checks, "spf_record"), CheckStatus::Fail);
+        assert_eq!(status_of(&checks, "dmarc_record"), CheckStatus::Fail);
+        assert_eq!(status_of(&checks, "proxied_records"), CheckStatus::Warn);
+        assert_eq!(status_of(&checks, "edge_certificate"), CheckStatus::Warn);
+        assert!(checks
+            .iter()
+            .find(|c| c.id == "proxied_records")
+            .unwrap()
+            .detail
+            .contains("www.example.com"));
+    }
+
+    #[test]
+    fn test_score_counts_warnings_as_half() {
+        let mut input = hardened();
+        input.settings[0] = setting("ssl", "full");
+        input.settings[2] = setting("min_tls_version", "1.1");
+        let audit = ZoneAudit::new("example.com", evaluate("example.com", &input, NOW));
+        // 8 passes, 1 warning, 1 failure: 17 of 20 points
+        assert_eq!(audit.score, 85);
+        assert_eq!(audit.worst(), CheckStatus::Fail);
+    }
+
+    #[test]
+    fn test_meets_gate() {
+        let mut input = hardened();
+        input.settings[3] = setting("development_mode", "on");
+        let audit = ZoneAudit::new("example.com", evaluate("example.com", &input, NOW));
+        assert_eq!(audit.worst(), CheckStatus::Warn);
+        assert!(audit.meets(Some(CheckStatus::Fail), 0));
+        assert!(!audit.meets(Some(CheckStatus::Warn), 0));
+        assert!(audit.meets(None, 95));
+        assert!(!audit.meets(None, 100));
+    }
+
+    #[test]
+    fn test_to_junit() {
+        let mut input = hardened();
+        input.settings[1] = setting("always_use_https", "off");
+        input.settings[3] = setting("development_mode", "on");
+        let audit = ZoneAudit::new("a&b.example", evaluate("a&b.example", &input, NOW));
+        let xml = to_junit(&[audit]);
+        assert!(xml.starts_with("<?xml"));
+        assert!(xml.contains("<testsuite name=\"a&amp;b.example\""));
+        assert!(xml.contains("<failure message=\"Always Use HTTPS is on\">"));
+        assert!(xml.contains("<system-out>WARN: development_mode = on</system-out>"));
+        // always_use_https, plus SPF and DMARC (records are for example.com)
+        assert!(xml.contains("failures=\"3\""));
+    }
+}
@@ -1,6 +1,32 @@
-use crate::api::cache::{PurgeAll, PurgeFiles, PurgeHosts, PurgePrefixes, PurgeTags};
+use crate::api::cache::{
+    CacheReserveClear, PurgeAll, PurgeFile, PurgeFiles, PurgeHosts, PurgePrefixes, PurgeTags,
+    SetCacheSetting,
+};
+use crate::api::zone::{Zone, ZoneSetting};
 use crate::client::{CfResponse, CloudflareClient};
-use crate::error::Result;
+use crate::error::{CfadError, Result};
+use crate::ops::zone::zone_for_hostname;
+use serde::Serialize;
+use std::collections::HashSet;
+use std::time::{Duration, Instant};
+
+/// Most URLs, tags, hosts or prefixes the API accepts in one purge request
+pub const PURGE_BATCH_SIZE: usize = 30;
+
+/// Pause between purge requests so large purges stay under the rate limit
+pub const PURGE_REQUEST_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Totals of a purge split across several requests
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PurgeSummary {
+    pub requests: usize,
+    pub purged: usize,
+    pub failed: usize,
+}
+
+// ============================================================================
+// Purge
+// ============================================================================
 
 pub async fn purge_all(client: &CloudflareClient, zone_id: &str) -> Result<()> {
     let endpoint = format!("/zones/{}/purge_cache", zone_id);
@@ -18,55 +44,735 @@ pub async fn purge_all(client: &CloudflareClient, zone_id: &str) -> Result<()> {
 pub async fn purge_files(
     client: &CloudflareClient,
     zone_id: &str,
-    urls: Vec<String>,
-) -> Result<()> {
-    let endpoint = format!("/zones/{}/purge_cache", zone_id);
+    urls: impl IntoIterator<Item = String>,
+) -> Result<PurgeSummary> {
+    purge_file_entries(client, zone_id, urls.into_iter().map(PurgeFile::Url)).await
+}
+
+/// Purge URLs, some of them possibly narrowed to header variants
+pub async fn purge_file_entries(
+    client: &CloudflareClient,
+    zone_id: &str,
+    files: impl IntoIterator<Item = PurgeFile>,
+) -> Result<PurgeSummary> {
+    let mut seen = HashSet::new();
+    let files: Vec<PurgeFile> = files
+        .into_iter()
+        .map(|file| match file {
+            PurgeFile::Url(url) => PurgeFile::Url(url.trim().to_string()),
+            PurgeFile::WithHeaders { url, headers } => PurgeFile::WithHeaders {
+                url: url.trim().to_string(),
+                headers,
+            },
+        })
+        .filter(|file| !file.url().is_empty() && seen.insert(file.clone()))
+        .collect();
+    purge_in_batches(client, zone_id, files, "URLs", |files| PurgeFiles { files }).await
+}
+
+pub async fn purge_tags(
+    client: &CloudflareClient,
+    zone_id: &str,
+    tags: impl IntoIterator<Item = String>,
+) -> Result<PurgeSummary> {
+    purge_in_batches(client, zone_id, collect_unique(tags), "tags", |tags| {
+        PurgeTags { tags }
+    })
+    .await
+}
 
-    let payload = PurgeFiles { files: urls };
+pub async fn purge_hosts(
+    client: &CloudflareClient,
+    zone_id: &str,
+    hosts: impl IntoIterator<Item = String>,
+) -> Result<PurgeSummary> {
+    purge_in_batches(client, zone_id, collect_unique(hosts), "hosts", |hosts| {
+        PurgeHosts { hosts }
+    })
+    .await
+}
 
-    let _response: CfResponse<serde_json::Value> = client.post(&endpoint, &payload).await?;
+pub async fn purge_prefixes(
+    client: &CloudflareClient,
+    zone_id: &str,
+    prefixes: impl IntoIterator<Item = String>,
+) -> Result<PurgeSummary> {
+    purge_in_batches(
+        client,
+        zone_id,
+        collect_unique(prefixes),
+        "prefixes",
+        |prefixes| PurgePrefixes { prefixes },
+    )
+    .await
+}
 
-    println!("✓ Purged {} URLs from cache", payload.files.len());
-    Ok(())
+/// Drop blank and repeated entries, keeping the first occurrence's position
+fn collect_unique(items: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut seen = HashSet::new();
+    items
+        .into_iter()
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty() && seen.insert(item.clone()))
+        .collect()
 }
 
-pub async fn purge_tags(client: &CloudflareClient, zone_id: &str, tags: Vec<String>) -> Result<()> {
+/// Send `items` in requests of at most `PURGE_BATCH_SIZE`, pacing them by
+/// `PURGE_REQUEST_INTERVAL`. A failed request does not stop the remaining
+/// ones; the purge fails once every request has been tried.
+async fn purge_in_batches<T, P>(
+    client: &CloudflareClient,
+    zone_id: &str,
+    items: Vec<T>,
+    noun: &str,
+    payload: impl Fn(Vec<T>) -> P,
+) -> Result<PurgeSummary>
+where
+    T: Clone,
+    P: Serialize,
+{
     let endpoint = format!("/zones/{}/purge_cache", zone_id);
+    let chunks: Vec<&[T]> = items.chunks(PURGE_BATCH_SIZE).collect();
+    let total = chunks.len();
+    let mut summary = PurgeSummary::default();
 
-    let payload = PurgeTags { tags };
+    for (index, chunk) in chunks.into_iter().enumerate() {
+        if index > 0 {
+            tokio::time::sleep(PURGE_REQUEST_INTERVAL).await;
+        }
+        summary.requests += 1;
+        let result: Result<CfResponse<serde_json::Value>> =
+            client.post(&endpoint, payload(chunk.to_vec())).await;
+        match result {
+            Ok(_) => {
+                summary.purged += chunk.len();
+                if total > 1 {
+                    println!(
+                        "  ✓ Request {}/{}: purged {} {}",
+                        index + 1,
+                        total,
+                        chunk.len(),
+                        noun
+                    );
+                }
+            }
+            Err(e) if total == 1 => return Err(e),
+            Err(e) => {
+                summary.failed += chunk.len();
+                println!("  ✗ Request {}/{}: {}", index + 1, total, e);
+            }
+        }
+    }
 
-    let _response: CfResponse<serde_json::Value> = client.post(&endpoint, &payload).await?;
+    if summary.failed > 0 {
+        return Err(CfadError::api(format!(
+            "Purged {} {}, {} failed",
+            summary.purged, noun, summary.failed
+        )));
+    }
 
-    println!("✓ Purged cache by tags");
-    Ok(())
+    if total > 1 {
+        println!(
+            "✓ Purged {} {} from cache in {} requests",
+            summary.purged, noun, summary.requests
+        );
+    } else {
+        println!("✓ Purged {} {} from cache", summary.purged, noun);
+    }
+    Ok(summary)
 }
 
-pub async fn purge_hosts(
+// ============================================================================
+// Purge lists
+// ============================================================================
+
+/// Sitemap indexes nested deeper than this are not followed
+const MAX_SITEMAP_DEPTH: usize = 3;
+
+/// `<loc>` entries of a sitemap. For a sitemap index they are the child
+/// sitemaps rather than page URLs.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Sitemap {
+    pub is_index: bool,
+    pub locations: Vec<String>,
+}
+
+pub fn parse_sitemap(xml: &str) -> Result<Sitemap> {
+    let is_index = xml.contains("<sitemapindex");
+    if !is_index && !xml.contains("<urlset") {
+        return Err(CfadError::validation(
+            "Not a sitemap: expected a <urlset> or <sitemapindex> document",
+        ));
+    }
+    let loc = regex::Regex::new(r"(?s)<loc>\s*(?:<!\[CDATA\[)?(.*?)(?:\]\]>)?\s*</loc>")
+        .expect("valid sitemap regex");
+    let locations = loc
+        .captures_iter(xml)
+        .map(|c| unescape_xml(c[1].trim()))
+        .filter(|l| !l.is_empty())
+        .collect();
+    Ok(Sitemap {
+        is_index,
+        locations,
+    })
+}
+
+fn unescape_xml(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Page URLs of a sitemap given as a local file or an http(s) URL,
+/// following sitemap indexes
+pub async fn load_sitemap(source: &str) -> Result<Vec<String>> {
+    let mut urls = Vec::new();
+    let mut visited = HashSet::new();
+    let mut pending = vec![(source.to_string(), 0)];
+
+    while let Some((location, depth)) = pending.pop() {
+        if !visited.insert(location.clone()) {
+            continue;
+        }
+        let sitemap = parse_sitemap(&read_sitemap(&location).await?).map_err(|_| {
+            CfadError::validation(format!(
+                "{} is not a sitemap: expected a <urlset> or <sitemapindex> document",
+                location
+            ))
+        })?;
+        if !sitemap.is_index {
+            urls.extend(sitemap.locations);
+            continue;
+        }
+        if depth >= MAX_SITEMAP_DEPTH {
+            return Err(CfadError::validation(format!(
+                "Sitemap indexes nested deeper than {} levels",
+                MAX_SITEMAP_DEPTH
+            )));
+        }
+        // Reversed so children are visited in document order
+        for child in sitemap.locations.into_iter().rev() {
+            pending.push((child, depth + 1));
+        }
+    }
+
+    Ok(urls)
+}
+
+async fn read_sitemap(location: &str) -> Result<String> {
+    if location.starts_with("http://") || location.starts_with("https://") {
+        let response = reqwest::get(location).await?.error_for_status()?;
+        Ok(response.text().await?)
+    } else {
+        Ok(std::fs::read_to_string(location)?)
+    }
+}
+
+/// Map paths of changed files (relative to the site root) to the URLs that
+/// serve them. `index.html` also maps to its directory URL, with and without
+/// the trailing slash. Lines of `git diff --name-status` are accepted too.
+pub fn changed_file_urls(paths: &[String], base_url: &str) -> Result<Vec<String>> {
+    let base = url::Url::parse(&format!("{}/", base_url.trim_end_matches('/')))
+        .map_err(|e| CfadError::validation(format!("Invalid base URL '{}': {}", base_url, e)))?;
+    if !matches!(base.scheme(), "http" | "https") {
+        return Err(CfadError::validation(format!(
+            "Invalid base URL '{}': expected http or https",
+            base_url
+        )));
+    }
+
+    let mut urls = Vec::new();
+    for line in paths {
+        // `git diff --name-status` puts the path after the last tab
+        let path = line.rsplit('\t').next().unwrap_or(line).trim();
+        let path = path.replace('\\', "/");
+        let path = path.trim_start_matches("./").trim_start_matches('/');
+        if path.is_empty() {
+            continue;
+        }
+        let join = |p: &str| {
+            base.join(p)
+                .map(String::from)
+                .map_err(|e| CfadError::validation(format!("Invalid path '{}': {}", p, e)))
+        };
+
+        urls.push(join(path)?);
+        let file_name = path.rsplit('/').next().unwrap_or(path);
+        if file_name == "index.html" || file_name == "index.htm" {
+            let dir = &path[..path.len() - file_name.len()];
+            urls.push(join(dir)?);
+            if !dir.is_empty() {
+                urls.push(join(dir.trim_end_matches('/'))?);
+            }
+        }
+    }
+    Ok(urls)
+}
+
+/// Parse a purge list: a JSON array of URLs and `{"url", "headers"}`
+/// objects, or plain text with one URL per line (`#` starts a comment)
+pub fn parse_purge_list(contents: &str) -> Result<Vec<PurgeFile>> {
+    if contents.trim_start().starts_with('[') {
+        return serde_json::from_str(contents).map_err(|e| {
+            CfadError::validation(format!(
+                "Invalid purge list: expected URLs or {{\"url\", \"headers\"}} objects: {}",
+                e
+            ))
+        });
+    }
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(|line| PurgeFile::Url(line.to_string()))
+        .collect())
+}
+
+/// Parse a `--header 'Name: value'` argument
+pub fn parse_purge_header(header: &str) -> Result<(String, String)> {
+    match header.split_once(':') {
+        Some((name, value)) if !name.trim().is_empty() && !name.trim().contains(' ') => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(CfadError::validation(format!(
+            "Invalid header '{}': expected 'Name: value'",
+            header
+        ))),
+    }
+}
+
+/// Narrow plain URLs to the cached variants selected by `headers`. Values
+/// given for the same header name each produce a variant, so
+/// `CF-Device-Type: mobile` plus `CF-Device-Type: tablet` purges both.
+/// Entries that already carry headers are left alone; an entry with an
+/// empty header map counts as a plain URL.
+pub fn apply_purge_headers(files: Vec<PurgeFile>, headers: &[(String, String)]) -> Vec<PurgeFile> {
+    if headers.is_empty() {
+        return files;
+    }
+
+    // Group values by header name (case-insensitive, first spelling wins)
+    let mut names: Vec<(String, Vec<String>)> = Vec::new();
+    for (name, value) in headers {
+        match names.iter_mut().find(|(n, _)| n.eq_ignore_ascii_case(name)) {
+            Some((_, values)) => values.push(value.clone()),
+            None => names.push((name.clone(), vec![value.clone()])),
+        }
+    }
+    let mut variants = vec![std::collections::BTreeMap::new()];
+    for (name, values) in &names {
+        variants = variants
+            .into_iter()
+            .flat_map(|variant| {
+                values.iter().map(move |value| {
+                    let mut variant = variant.clone();
+                    variant.insert(name.clone(), value.clone());
+                    variant
+                })
+            })
+            .collect();
+    }
+
+    files
+        .into_iter()
+        .flat_map(|file| {
+            if file.headers().is_some_and(|h| !h.is_empty()) {
+                return vec![file];
+            }
+            variants
+                .iter()
+                .map(|headers| PurgeFile::WithHeaders {
+                    url: file.url().to_string(),
+                    headers: headers.clone(),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// ============================================================================
+// Routing URLs to zones
+// ============================================================================
+
+/// URLs grouped by the zone that serves them
+#[derive(Debug, Default)]
+pub struct UrlRouting {
+    /// Zones in the order their first URL appeared, with their URLs
+    pub zones: Vec<(Zone, Vec<PurgeFile>)>,
+    /// URLs whose host belongs to none of the zones, or that do not parse
+    pub unrouted: Vec<PurgeFile>,
+}
+
+/// Group URLs by owning zone: the zone whose name is the URL's host or its
+/// longest matching parent domain (`a.b.example.com` → `b.example.com`
+/// before `example.com`).
+pub fn route_urls_to_zones(
+    files: impl IntoIterator<Item = PurgeFile>,
+    zones: &[Zone],
+) -> UrlRouting {
+    let mut routing = UrlRouting::default();
+
+    for file in files {
+        let host = url::Url::parse(file.url().trim())
+            .ok()
+            .and_then(|u| u.host_str().map(str::to_string));
+        let zone = host.and_then(|host| zone_for_hostname(zones, &host));
+
+        match zone {
+            Some(zone) => match routing.zones.iter_mut().find(|(z, _)| z.id == zone.id) {
+                Some((_, files)) => files.push(file),
+                None => routing.zones.push((zone.clone(), vec![file])),
+            },
+            None => routing.unrouted.push(file),
+        }
+    }
+
+    routing
+}
+
+// ============================================================================
+// Cache settings
+// ============================================================================
+
+/// Zone-level caching and routing toggles, as (CLI name, endpoint under the zone)
+pub const CACHE_SETTINGS: [(&str, &str); 4] = [
+    ("tiered-caching", "argo/tiered_caching"),
+    ("smart-topology", "cache/tiered_cache_smart_topology_enable"),
+    ("cache-reserve", "cache/cache_reserve"),
+    ("argo", "argo/smart_routing"),
+];
+
+/// A cache setting's state, or why it could not be read (e.g. not on the plan)
+#[derive(Debug)]
+pub struct CacheSettingState {
+    pub name: &'static str,
+    pub setting: Result<ZoneSetting>,
+}
+
+fn cache_setting_endpoint(zone_id: &str, name: &str) -> Result<String> {
+    CACHE_SETTINGS
+        .iter()
+        .find(|(n, _)| *n == name)
+        .map(|(_, path)| format!("/zones/{}/{}", zone_id, path))
+        .ok_or_else(|| CfadError::validation(format!("Unknown cache setting '{}'", name)))
+}
+
+/// Read every cache setting; one unavailable setting does not hide the others
+pub async fn get_cache_settings(
     client: &CloudflareClient,
     zone_id: &str,
-    hosts: Vec<String>,
-) -> Result<()> {
-    let endpoint = format!("/zones/{}/purge_cache", zone_id);
+) -> Vec<CacheSettingState> {
+    let mut states = Vec::new();
+    for (name, _) in CACHE_SETTINGS {
+        states.push(CacheSettingState {
+            name,
+            setting: get_cache_setting(client, zone_id, name).await,
+        });
+    }
+    states
+}
 
-    let payload = PurgeHosts { hosts };
+pub async fn get_cache_setting(
+    client: &CloudflareClient,
+    zone_id: &str,
+    name: &str,
+) -> Result<ZoneSetting> {
+    let endpoint = cache_setting_endpoint(zone_id, name)?;
+    let response: CfResponse<ZoneSetting> = client.get(&endpoint).await?;
+    response
+        .result
+        .ok_or_else(|| CfadError::not_found("Cache setting", name))
+}
 
-    let _response: CfResponse<serde_json::Value> = client.post(&endpoint, &payload).await?;
+pub async fn set_cache_setting(
+    client: &CloudflareClient,
+    zone_id: &str,
+    name: &str,
+    enabled: bool,
+) -> Result<ZoneSetting> {
+    let endpoint = cache_setting_endpoint(zone_id, name)?;
+    let body = SetCacheSetting {
+        value: if enabled { "on" } else { "off" }.to_string(),
+    };
+    let response: CfResponse<ZoneSetting> = client.patch(&endpoint, body).await?;
+    let setting = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from update cache setting"))?;
+    println!(
+        "✓ Set {} to {}",
+        name,
+        setting.value.as_str().unwrap_or("-")
+    );
+    Ok(setting)
+}
 
-    println!("✓ Purged cache by hosts");
-    Ok(())
+/// Start deleting everything stored in Cache Reserve. The API only allows
+/// this while Cache Reserve is off.
+pub async fn start_cache_reserve_clear(
+    client: &CloudflareClient,
+    zone_id: &str,
+) -> Result<CacheReserveClear> {
+    let reserve = get_cache_setting(client, zone_id, "cache-reserve").await?;
+    if reserve.value.as_str() == Some("on") {
+        return Err(CfadError::validation(
+            "Cache Reserve must be off before it can be cleared (cache settings set <zone> --cache-reserve off)",
+        ));
+    }
+
+    let endpoint = format!("/zones/{}/cache/cache_reserve_clear", zone_id);
+    let response: CfResponse<CacheReserveClear> =
+        client.post(&endpoint, serde_json::json!({})).await?;
+    let operation = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from Cache Reserve clear"))?;
+    println!("✓ Started Cache Reserve clear ({})", operation.state);
+    Ok(operation)
 }
 
-pub async fn purge_prefixes(
+pub async fn get_cache_reserve_clear(
     client: &CloudflareClient,
     zone_id: &str,
-    prefixes: Vec<String>,
-) -> Result<()> {
-    let endpoint = format!("/zones/{}/purge_cache", zone_id);
+) -> Result<CacheReserveClear> {
+    let endpoint = format!("/zones/{}/cache/cache_reserve_clear", zone_id);
+    let response: CfResponse<CacheReserveClear> = client.get(&endpoint).await?;
+    response
+        .result
+        .ok_or_else(|| CfadError::not_found("Cache Reserve clear", zone_id))
+}
 
-    let payload = PurgePrefixes { prefixes };
+/// Poll the Cache Reserve clear operation until it completes or times out
+pub async fn wait_for_cache_reserve_clear(
+    client: &CloudflareClient,
+    zone_id: &str,
+    interval: Duration,
+    timeout: Duration,
+) -> Result<CacheReserveClear> {
+    let started = Instant::now();
+    loop {
+        let operation = get_cache_reserve_clear(client, zone_id).await?;
+        if operation.is_completed() {
+            println!("✓ Cache Reserve cleared");
+            return Ok(operation);
+        }
+        if started.elapsed() >= timeout {
+            return Err(CfadError::Timeout(timeout));
+        }
+        println!("  Cache Reserve clear: {}", operation.state);
+        tokio::time::sleep(interval).await;
+    }
+}
 
-    let _response: CfResponse<serde_json::Value> = client.post(&endpoint, &payload).await?;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    println!("✓ Purged cache by prefixes");
-    Ok(())
+    #[test]
+    fn test_collect_unique_keeps_order_and_drops_blanks() {
+        let items = vec![
+            "https://a.com/x".to_string(),
+            " ".to_string(),
+            "https://a.com/y".to_string(),
+            "https://a.com/x ".to_string(),
+        ];
+        assert_eq!(
+            collect_unique(items),
+            vec!["https://a.com/x", "https://a.com/y"]
+        );
+    }
+
+    #[test]
+    fn test_parse_sitemap_urlset_and_index() {
+        let urlset = r#"<?xml version="1.0" encoding="UTF-8"?>
+<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
+  <url><loc>https://example.com/</loc></url>
+  <url>
+    <loc>
+      https://example.com/search?q=a&amp;page=2
+    </loc>
+    <lastmod>2026-01-01</lastmod>
+  </url>
+  <url><loc><![CDATA[https://example.com/docs/]]></loc></url>
+</urlset>"#;
+        let sitemap = parse_sitemap(urlset).unwrap();
+        assert!(!sitemap.is_index);
+        assert_eq!(
+            sitemap.locations,
+            vec![
+                "https://example.com/",
+                "https://example.com/search?q=a&page=2",
+                "https://example.com/docs/"
+            ]
+        );
+
+        let index = r#"<sitemapindex><sitemap><loc>https://example.com/a.xml</loc></sitemap></sitemapindex>"#;
+        let sitemap = parse_sitemap(index).unwrap();
+        assert!(sitemap.is_index);
+        assert_eq!(sitemap.locations, vec!["https://example.com/a.xml"]);
+
+        assert!(parse_sitemap("<html></html>").is_err());
+    }
+
+    #[test]
+    fn test_changed_file_urls_maps_index_to_directory() {
+        let paths = vec![
+            "index.html".to_string(),
+            "./docs/index.html".to_string(),
+            "M\tassets/app v2.js".to_string(),
+        ];
+        let urls = changed_file_urls(&paths, "https://example.com").unwrap();
+        assert_eq!(
+            urls,
+            vec![
+                "https://example.com/index.html",
+                "https://example.com/",
+                "https://example.com/docs/index.html",
+                "https://example.com/docs/",
+                "https://example.com/docs",
+                "https://example.com/assets/app%20v2.js",
+            ]
+        );
+
+        let nested =
+            changed_file_urls(&["a.css".to_string()], "https://example.com/blog/").unwrap();
+        assert_eq!(nested, vec!["https://example.com/blog/a.css"]);
+        assert!(changed_file_urls(&paths, "ftp://example.com").is_err());
+    }
+
+    fn zone(id: &str, name: &str) -> Zone {
+        Zone {
+            id: id.to_string(),
+            name: name.to_string(),
+            status: "active".to_string(),
+            paused: false,
+            development_mode: 0,
+            name_servers: vec![],
+            original_name_servers: vec![],
+            owner: crate::api::zone::Owner {
+                id: None,
+                owner_type: "user".to_string(),
+                email: None,
+            },
+            account: crate::api::zone::Account {
+                id: "acc1".to_string(),
+                name: "Account".to_string(),
+            },
+            created_on: "2026-01-01T00:00:00Z".to_string(),
+            modified_on: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_route_urls_to_zones_longest_suffix() {
+        let zones = vec![
+            zone("z1", "example.com"),
+            zone("z2", "shop.example.com"),
+            zone("z3", "other.org"),
+        ];
+        let urls = [
+            "https://cdn.shop.example.com/a.js",
+            "https://example.com/",
+            "https://WWW.Example.com/b.css",
+            "https://notexample.com/c",
+            "not a url",
+            "https://shop.example.com/d",
+        ];
+        let routing =
+            route_urls_to_zones(urls.iter().map(|u| PurgeFile::Url(u.to_string())), &zones);
+
+        let routed: Vec<(&str, usize)> = routing
+            .zones
+            .iter()
+            .map(|(z, urls)| (z.name.as_str(), urls.len()))
+            .collect();
+        assert_eq!(routed, vec![("shop.example.com", 2), ("example.com", 2)]);
+        let unrouted: Vec<&str> = routing.unrouted.iter().map(|f| f.url()).collect();
+        assert_eq!(unrouted, vec!["https://notexample.com/c", "not a url"]);
+    }
+
+    #[test]
+    fn test_parse_purge_list_text_and_json() {
+        let text = parse_purge_list("# deploy\nhttps://example.com/a\n\n").unwrap();
+        assert_eq!(
+            text,
+            vec![PurgeFile::Url("https://example.com/a".to_string())]
+        );
+
+        let json = parse_purge_list(
+            r#"[{"url": "https://example.com/b", "headers": {"Origin": "https://app.example.com"}}]"#,
+        )
+        .unwrap();
+        assert_eq!(
+            json[0].headers().unwrap()["Origin"],
+            "https://app.example.com"
+        );
+        assert!(parse_purge_list("[1, 2]").is_err());
+    }
+
+    #[test]
+    fn test_parse_purge_header() {
+        assert_eq!(
+            parse_purge_header("CF-Device-Type: mobile").unwrap(),
+            ("CF-Device-Type".to_string(), "mobile".to_string())
+        );
+        assert!(parse_purge_header("mobile").is_err());
+        assert!(parse_purge_header(": mobile").is_err());
+    }
+
+    #[test]
+    fn test_apply_purge_headers_expands_variants() {
+        let files = vec![
+            PurgeFile::Url("https://example.com/a".to_string()),
+            PurgeFile::WithHeaders {
+                url: "https://example.com/b".to_string(),
+                headers: [("Origin".to_string(), "https://x.com".to_string())].into(),
+            },
+        ];
+        let headers = vec![
+            ("CF-Device-Type".to_string(), "mobile".to_string()),
+            ("Accept-Language".to_string(), "en".to_string()),
+            ("cf-device-type".to_string(), "tablet".to_string()),
+        ];
+        let described: Vec<String> = apply_purge_headers(files, &headers)
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        assert_eq!(
+            described,
+            vec![
+                "https://example.com/a [Accept-Language: en, CF-Device-Type: mobile]",
+                "https://example.com/a [Accept-Language: en, CF-Device-Type: tablet]",
+                "https://example.com/b [Origin: https://x.com]",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_apply_purge_headers_fills_empty_header_map() {
+        let files: Vec<PurgeFile> =
+            serde_json::from_str(r#"[{"url": "https://example.com/a"}]"#).unwrap();
+        let headers = vec![("CF-Device-Type".to_string(), "mobile".to_string())];
+        let described: Vec<String> = apply_purge_headers(files, &headers)
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        assert_eq!(
+            described,
+            vec!["https://example.com/a [CF-Device-Type: mobile]"]
+        );
+    }
+
+    #[test]
+    fn test_cache_setting_endpoint() {
+        assert_eq!(
+            cache_setting_endpoint("z1", "smart-topology").unwrap(),
+            "/zones/z1/cache/tiered_cache_smart_topology_enable"
+        );
+        assert_eq!(
+            cache_setting_endpoint("z1", "argo").unwrap(),
+            "/zones/z1/argo/smart_routing"
+        );
+        assert!(cache_setting_endpoint("z1", "always-online").is_err());
+    }
 }
@@ -0,0 +1,318 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::api::certificates::{
+    CertificatePack, CreateOriginCertificate, OrderCertificatePack, OriginCertificate,
+    OriginKeyType, UniversalSslSettings, CERTIFICATE_AUTHORITIES,
+    CERTIFICATE_PACK_VALIDATION_METHODS, CERTIFICATE_PACK_VALIDITY_DAYS, ORIGIN_CERT_VALIDITY_DAYS,
+};
+use crate::client::{CfResponse, CloudflareClient};
+use crate::error::{CfadError, Result};
+
+/// Private key and CSR generated locally; the key never leaves this machine
+pub struct GeneratedKey {
+    pub private_key_pem: String,
+    pub csr_pem: String,
+}
+
+/// Generate a private key and a CSR covering `hostnames`
+pub fn generate_key_and_csr(hostnames: &[String], key_type: OriginKeyType) -> Result<GeneratedKey> {
+    let key_pair = match key_type {
+        OriginKeyType::Rsa => {
+            rcgen::KeyPair::generate_rsa_for(&rcgen::PKCS_RSA_SHA256, rcgen::RsaKeySize::_2048)
+        }
+        OriginKeyType::Ecdsa => rcgen::KeyPair::generate_for(&rcgen::PKCS_ECDSA_P256_SHA256),
+    }
+    .map_err(|e| CfadError::validation(format!("Failed to generate private key: {}", e)))?;
+
+    let mut params = rcgen::CertificateParams::new(hostnames.to_vec())
+        .map_err(|e| CfadError::validation(format!("Invalid hostname: {}", e)))?;
+    let mut subject = rcgen::DistinguishedName::new();
+    subject.push(rcgen::DnType::CommonName, hostnames[0].as_str());
+    params.distinguished_name = subject;
+
+    let csr = params
+        .serialize_request(&key_pair)
+        .and_then(|csr| csr.pem())
+        .map_err(|e| CfadError::validation(format!("Failed to generate CSR: {}", e)))?;
+
+    Ok(GeneratedKey {
+        private_key_pem: key_pair.serialize_pem(),
+        csr_pem: csr,
+    })
+}
+
+/// Check hostnames and validity before generating anything
+pub fn validate_origin_cert_request(hostnames: &[String], validity: u32) -> Result<()> {
+    if hostnames.is_empty() {
+        return Err(CfadError::validation("At least one hostname is required"));
+    }
+    for hostname in hostnames {
+        let bare = hostname.strip_prefix("*.").unwrap_or(hostname);
+        if bare.is_empty() || bare.contains('*') || !bare.contains('.') {
+            return Err(CfadError::validation(format!(
+                "Invalid hostname '{}': use a domain or a '*.' wildcard",
+                hostname
+            )));
+        }
+    }
+    if !ORIGIN_CERT_VALIDITY_DAYS.contains(&validity) {
+        let allowed: Vec<String> = ORIGIN_CERT_VALIDITY_DAYS
+            .iter()
+            .map(|d| d.to_string())
+            .collect();
+        return Err(CfadError::validation(format!(
+            "Invalid validity {} days. Must be one of: {}",
+            validity,
+            allowed.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+/// Issue an Origin CA certificate for a CSR
+pub async fn create_origin_certificate(
+    client: &CloudflareClient,
+    create: CreateOriginCertificate,
+) -> Result<OriginCertificate> {
+    let response: CfResponse<OriginCertificate> = client.post("/certificates", create).await?;
+    let certificate = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create certificate"))?;
+    println!("✓ Issued Origin CA certificate: {}", certificate.id);
+    Ok(certificate)
+}
+
+/// List Origin CA certificates issued for a zone
+pub async fn list_origin_certificates(
+    client: &CloudflareClient,
+    zone_id: &str,
+) -> Result<Vec<OriginCertificate>> {
+    let endpoint = format!("/certificates?zone_id={}", zone_id);
+    let response: CfResponse<Vec<OriginCertificate>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Revoke an Origin CA certificate
+pub async fn revoke_origin_certificate(
+    client: &CloudflareClient,
+    certificate_id: &str,
+) -> Result<()> {
+    let endpoint = format!("/certificates/{}", certificate_id);
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Revoked Origin CA certificate: {}", certificate_id);
+    Ok(())
+}
+
+/// List every edge certificate pack for a zone, including inactive ones
+pub async fn list_certificate_packs(
+    client: &CloudflareClient,
+    zone_id: &str,
+) -> Result<Vec<CertificatePack>> {
+    let endpoint = format!("/zones/{}/ssl/certificate_packs?status=all", zone_id);
+    let response: CfResponse<Vec<CertificatePack>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Order an advanced certificate pack
+pub async fn order_certificate_pack(
+    client: &CloudflareClient,
+    zone_id: &str,
+    order: OrderCertificatePack,
+) -> Result<CertificatePack> {
+    validate_pack_order(&order)?;
+    let endpoint = format!("/zones/{}/ssl/certificate_packs/order", zone_id);
+    let response: CfResponse<CertificatePack> = client.post(&endpoint, order).await?;
+    let pack = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from order certificate pack"))?;
+    println!("✓ Ordered advanced certificate pack: {}", pack.id);
+    Ok(pack)
+}
+
+pub async fn get_universal_ssl(client: &CloudflareClient, zone_id: &str) -> Result<bool> {
+    let endpoint = format!("/zones/{}/ssl/universal/settings", zone_id);
+    let response: CfResponse<UniversalSslSettings> = client.get(&endpoint).await?;
+    Ok(response
+        .result
+        .map(|settings| settings.enabled)
+        .unwrap_or(false))
+}
+
+pub async fn set_universal_ssl(
+    client: &CloudflareClient,
+    zone_id: &str,
+    enabled: bool,
+) -> Result<()> {
+    let endpoint = format!("/zones/{}/ssl/universal/settings", zone_id);
+    let _response: CfResponse<UniversalSslSettings> = client
+        .patch(&endpoint, UniversalSslSettings { enabled })
+        .await?;
+    println!(
+        "✓ Universal SSL {}",
+        if enabled { "enabled" } else { "disabled" }
+    );
+    Ok(())
+}
+
+fn validate_pack_order(order: &OrderCertificatePack) -> Result<()> {
+    if order.hosts.is_empty() {
+        return Err(CfadError::validation("At least one host is required"));
+    }
+    if !CERTIFICATE_AUTHORITIES.contains(&order.certificate_authority.as_str()) {
+        return Err(CfadError::validation(format!(
+            "Invalid certificate authority '{}'. Must be one of: {}",
+            order.certificate_authority,
+            CERTIFICATE_AUTHORITIES.join(", ")
+        )));
+    }
+    if !CERTIFICATE_PACK_VALIDATION_METHODS.contains(&order.validation_method.as_str()) {
+        return Err(CfadError::validation(format!(
+            "Invalid validation method '{}'. Must be one of: {}",
+            order.validation_method,
+            CERTIFICATE_PACK_VALIDATION_METHODS.join(", ")
+        )));
+    }
+    if !CERTIFICATE_PACK_VALIDITY_DAYS.contains(&order.validity_days) {
+        let allowed: Vec<String> = CERTIFICATE_PACK_VALIDITY_DAYS
+            .iter()
+            .map(|d| d.to_string())
+            .collect();
+        return Err(CfadError::validation(format!(
+            "Invalid validity {} days. Must be one of: {}",
+            order.validity_days,
+            allowed.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+/// Default key and certificate paths for `hostnames` inside `dir`
+/// (`*.example.com` becomes `wildcard.example.com.key` / `.pem`)
+pub fn default_output_paths(dir: &Path, hostnames: &[String]) -> (PathBuf, PathBuf) {
+    let base = hostnames[0].replace("*.", "wildcard.");
+    (
+        dir.join(format!("{}.key", base)),
+        dir.join(format!("{}.pem", base)),
+    )
+}
+
+/// Fail early if an output file exists, so an issued certificate is never
+/// left without somewhere to write it
+pub fn ensure_writable(paths: &[&Path], force: bool) -> Result<()> {
+    if force {
+        return Ok(());
+    }
+    for path in paths {
+        if path.exists() {
+            return Err(CfadError::validation(format!(
+                "{} already exists (use --force to overwrite)",
+                path.display()
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Write a file readable only by the current user (0600 on Unix)
+pub fn write_private_file(path: &Path, contents: &str) -> Result<()> {
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    // `mode` only applies to new files; tighten permissions on overwrite too
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(contents.as_bytes())?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hostnames() -> Vec<String> {
+        vec!["example.com".to_string(), "*.example.com".to_string()]
+    }
+
+    #[test]
+    fn test_generate_ecdsa_key_and_csr() {
+        let generated = generate_key_and_csr(&hostnames(), OriginKeyType::Ecdsa).unwrap();
+        assert!(generated.private_key_pem.contains("BEGIN PRIVATE KEY"));
+        assert!(generated
+            .csr_pem
+            .starts_with("-----BEGIN CERTIFICATE REQUEST-----"));
+    }
+
+    #[test]
+    fn test_generate_rsa_key_and_csr() {
+        let generated = generate_key_and_csr(&hostnames(), OriginKeyType::Rsa).unwrap();
+        assert!(generated.private_key_pem.contains("BEGIN PRIVATE KEY"));
+        assert!(generated.csr_pem.contains("CERTIFICATE REQUEST"));
+    }
+
+    #[test]
+    fn test_validate_origin_cert_request() {
+        assert!(validate_origin_cert_request(&hostnames(), 5475).is_ok());
+        assert!(validate_origin_cert_request(&hostnames(), 100).is_err());
+        assert!(validate_origin_cert_request(&[], 365).is_err());
+        assert!(validate_origin_cert_request(&["a.*.example.com".to_string()], 365).is_err());
+        assert!(validate_origin_cert_request(&["localhost".to_string()], 365).is_err());
+    }
+
+    #[test]
+    fn test_validate_pack_order() {
+        let mut order = OrderCertificatePack {
+            r#type: "advanced".to_string(),
+            hosts: hostnames(),
+            certificate_authority: "lets_encrypt".to_string(),
+            validation_method: "txt".to_string(),
+            validity_days: 90,
+            cloudflare_branding: false,
+        };
+        assert!(validate_pack_order(&order).is_ok());
+        order.validity_days = 60;
+        assert!(validate_pack_order(&order).is_err());
+        order.validity_days = 90;
+        order.certificate_authority = "digicert".to_string();
+        assert!(validate_pack_order(&order).is_err());
+    }
+
+    #[test]
+    fn test_default_output_paths() {
+        let (key, cert) = default_output_paths(
+            Path::new("/tmp"),
+            &["*.example.com".to_string(), "example.com".to_string()],
+        );
+        assert_eq!(key, Path::new("/tmp/wildcard.example.com.key"));
+        assert_eq!(cert, Path::new("/tmp/wildcard.example.com.pem"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_private_file_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = std::env::temp_dir().join(format!("cfad-cert-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("test.key");
+        std::fs::write(&path, "old").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_private_file(&path, "secret").unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(contents, "secret");
+        assert!(ensure_writable(&[path.as_path()], true).is_ok());
+    }
+}
@@ -0,0 +1,359 @@
+use std::time::{Duration, Instant};
+
+use serde_json::{Map, Value};
+
+use crate::api::custom_hostnames::{
+    CreateCustomHostname, CustomHostname, CustomHostnameSsl, UpdateCustomHostname, SSL_METHODS,
+    SSL_TYPES,
+};
+use crate::client::{CfResponse, CloudflareClient};
+use crate::error::{CfadError, Result};
+use crate::ops::dns::ImportStats;
+
+const CUSTOM_HOSTNAMES_PER_PAGE: u32 = 50;
+
+/// SSL statuses that will never become active without a new request
+const SSL_FAILED_STATUSES: &[&str] = &[
+    "validation_timed_out",
+    "issuance_timed_out",
+    "deployment_timed_out",
+    "expired",
+    "deleted",
+];
+
+/// List custom hostnames for a zone, optionally filtered by hostname
+pub async fn list_custom_hostnames(
+    client: &CloudflareClient,
+    zone_id: &str,
+    hostname: Option<&str>,
+) -> Result<Vec<CustomHostname>> {
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    query.append_pair("per_page", &CUSTOM_HOSTNAMES_PER_PAGE.to_string());
+    if let Some(hostname) = hostname {
+        query.append_pair("hostname", hostname);
+    }
+    let query = query.finish();
+
+    let mut hostnames = Vec::new();
+    let mut page = 1;
+    loop {
+        let endpoint = format!(
+            "/zones/{}/custom_hostnames?page={}&{}",
+            zone_id, page, query
+        );
+        let response: CfResponse<Vec<CustomHostname>> = client.get(&endpoint).await?;
+        hostnames.extend(response.result.unwrap_or_default());
+
+        match response.result_info {
+            Some(info) if page < info.total_pages => page += 1,
+            _ => break,
+        }
+    }
+    Ok(hostnames)
+}
+
+/// Get a custom hostname by ID or by hostname
+pub async fn get_custom_hostname(
+    client: &CloudflareClient,
+    zone_id: &str,
+    identifier: &str,
+) -> Result<CustomHostname> {
+    if is_id(identifier) {
+        let endpoint = format!("/zones/{}/custom_hostnames/{}", zone_id, identifier);
+        let response: CfResponse<CustomHostname> = client.get(&endpoint).await?;
+        return response
+            .result
+            .ok_or_else(|| CfadError::not_found("Custom hostname", identifier));
+    }
+
+    list_custom_hostnames(client, zone_id, Some(identifier))
+        .await?
+        .into_iter()
+        .find(|h| h.hostname.eq_ignore_ascii_case(identifier))
+        .ok_or_else(|| CfadError::not_found("Custom hostname", identifier))
+}
+
+pub async fn create_custom_hostname(
+    client: &CloudflareClient,
+    zone_id: &str,
+    create: CreateCustomHostname,
+) -> Result<CustomHostname> {
+    validate_ssl(&create.ssl)?;
+    let endpoint = format!("/zones/{}/custom_hostnames", zone_id);
+    let response: CfResponse<CustomHostname> = client.post(&endpoint, create).await?;
+    let hostname = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create custom hostname"))?;
+    println!("✓ Created custom hostname: {}", hostname.hostname);
+    Ok(hostname)
+}
+
+pub async fn update_custom_hostname(
+    client: &CloudflareClient,
+    zone_id: &str,
+    hostname_id: &str,
+    update: UpdateCustomHostname,
+) -> Result<CustomHostname> {
+    if update.is_empty() {
+        return Err(CfadError::validation("No changes specified"));
+    }
+    if let Some(ssl) = &update.ssl {
+        validate_ssl(ssl)?;
+    }
+    let endpoint = format!("/zones/{}/custom_hostnames/{}", zone_id, hostname_id);
+    let response: CfResponse<CustomHostname> = client.patch(&endpoint, update).await?;
+    let hostname = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from update custom hostname"))?;
+    println!("✓ Updated custom hostname: {}", hostname.hostname);
+    Ok(hostname)
+}
+
+pub async fn delete_custom_hostname(
+    client: &CloudflareClient,
+    zone_id: &str,
+    hostname_id: &str,
+) -> Result<()> {
+    let endpoint = format!("/zones/{}/custom_hostnames/{}", zone_id, hostname_id);
+    let _response: CfResponse<Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted custom hostname: {}", hostname_id);
+    Ok(())
+}
+
+/// Poll a custom hostname until its certificate is active
+pub async fn wait_for_ssl_active(
+    client: &CloudflareClient,
+    zone_id: &str,
+    hostname_id: &str,
+    interval: Duration,
+    timeout: Duration,
+) -> Result<CustomHostname> {
+    let started = Instant::now();
+    let mut last_status = String::new();
+    loop {
+        let hostname = get_custom_hostname(client, zone_id, hostname_id).await?;
+        let status = hostname.ssl_status().to_string();
+        if hostname.is_ssl_active() {
+            println!("✓ SSL is active for {}", hostname.hostname);
+            return Ok(hostname);
+        }
+        if SSL_FAILED_STATUSES.contains(&status.as_str()) {
+            return Err(CfadError::api(format!(
+                "SSL for {} did not become active: {}",
+                hostname.hostname, status
+            )));
+        }
+        if status != last_status {
+            println!("  SSL status for {}: {}", hostname.hostname, status);
+            last_status = status;
+        }
+        if started.elapsed() >= timeout {
+            return Err(CfadError::Timeout(timeout));
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Create every hostname in order, continuing past failures
+pub async fn import_custom_hostnames(
+    client: &CloudflareClient,
+    zone_id: &str,
+    hostnames: Vec<CreateCustomHostname>,
+) -> ImportStats {
+    let mut stats = ImportStats {
+        total: hostnames.len(),
+        ..Default::default()
+    };
+
+    println!("\nCreating {} custom hostnames...\n", stats.total);
+
+    for (i, create) in hostnames.into_iter().enumerate() {
+        print!("[{}/{}] {}... ", i + 1, stats.total, create.hostname);
+        match create_custom_hostname(client, zone_id, create).await {
+            Ok(_) => stats.success += 1,
+            Err(e) => {
+                println!("✗ {}", e);
+                stats.failed += 1;
+            }
+        }
+    }
+
+    println!("\nImport complete!");
+    println!("  Success: {}", stats.success);
+    println!("  Failed: {}", stats.failed);
+    println!("  Total: {}", stats.total);
+    stats
+}
+
+/// Parse `KEY=VALUE` pairs into custom metadata.
+///
+/// Values that parse as JSON (numbers, booleans, objects) keep their type;
+/// anything else is stored as a string.
+pub fn parse_metadata(pairs: &[String]) -> Result<Map<String, Value>> {
+    let mut metadata = Map::new();
+    for pair in pairs {
+        let (key, value) = pair.split_once('=').ok_or_else(|| {
+            CfadError::validation(format!("Invalid metadata '{}': expected KEY=VALUE", pair))
+        })?;
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(CfadError::validation(format!(
+                "Invalid metadata '{}': key is empty",
+                pair
+            )));
+        }
+        let value = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.into()));
+        metadata.insert(key.to_string(), value);
+    }
+    Ok(metadata)
+}
+
+/// Parse a bulk onboarding CSV.
+///
+/// Requires a `hostname` column. `ssl_method`, `ssl_type` and
+/// `custom_origin_server` are optional (falling back to the defaults given);
+/// every other non-empty column becomes custom metadata.
+pub fn parse_hostnames_csv(
+    contents: &str,
+    default_method: &str,
+    default_type: &str,
+) -> Result<Vec<CreateCustomHostname>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(contents.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| CfadError::validation(format!("Invalid CSV format: {}", e)))?
+        .clone();
+    if !headers.iter().any(|h| h == "hostname") {
+        return Err(CfadError::validation(
+            "CSV file must have a 'hostname' column",
+        ));
+    }
+
+    let mut hostnames = Vec::new();
+    for (line, result) in reader.records().enumerate() {
+        let record =
+            result.map_err(|e| CfadError::validation(format!("Invalid CSV format: {}", e)))?;
+        let mut hostname = None;
+        let mut method = default_method.to_string();
+        let mut ssl_type = default_type.to_string();
+        let mut origin = None;
+        let mut metadata = Map::new();
+
+        for (header, value) in headers.iter().zip(record.iter()) {
+            if value.is_empty() {
+                continue;
+            }
+            match header {
+                "hostname" => hostname = Some(value.to_string()),
+                "ssl_method" => method = value.to_string(),
+                "ssl_type" => ssl_type = value.to_string(),
+                "custom_origin_server" => origin = Some(value.to_string()),
+                key => {
+                    metadata.insert(key.to_string(), Value::String(value.to_string()));
+                }
+            }
+        }
+
+        let hostname = hostname
+            .ok_or_else(|| CfadError::validation(format!("Row {}: hostname is empty", line + 2)))?;
+        let ssl = CustomHostnameSsl {
+            method: Some(method),
+            r#type: Some(ssl_type),
+            ..Default::default()
+        };
+        validate_ssl(&ssl)
+            .map_err(|e| CfadError::validation(format!("Row {}: {}", line + 2, e)))?;
+        hostnames.push(CreateCustomHostname {
+            hostname,
+            ssl,
+            custom_origin_server: origin,
+            custom_metadata: (!metadata.is_empty()).then_some(metadata),
+        });
+    }
+    Ok(hostnames)
+}
+
+fn validate_ssl(ssl: &CustomHostnameSsl) -> Result<()> {
+    if let Some(method) = &ssl.method {
+        if !SSL_METHODS.contains(&method.as_str()) {
+            return Err(CfadError::validation(format!(
+                "Invalid SSL method '{}'. Must be one of: {}",
+                method,
+                SSL_METHODS.join(", ")
+            )));
+        }
+    }
+    if let Some(ssl_type) = &ssl.r#type {
+        if !SSL_TYPES.contains(&ssl_type.as_str()) {
+            return Err(CfadError::validation(format!(
+                "Invalid SSL type '{}'. Must be one of: {}",
+                ssl_type,
+                SSL_TYPES.join(", ")
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Custom hostname IDs are UUIDs; hostnames always contain a dot
+fn is_id(identifier: &str) -> bool {
+    !identifier.contains('.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_metadata() {
+        let metadata = parse_metadata(&[
+            "customer=acme".to_string(),
+            "tier=2".to_string(),
+            "beta=true".to_string(),
+        ])
+        .unwrap();
+        assert_eq!(metadata["customer"], "acme");
+        assert_eq!(metadata["tier"], 2);
+        assert_eq!(metadata["beta"], true);
+
+        assert!(parse_metadata(&["novalue".to_string()]).is_err());
+        assert!(parse_metadata(&["=x".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_parse_hostnames_csv() {
+        let csv = "hostname,ssl_method,custom_origin_server,customer\n\
+                   app.acme.com,,origin.example.com,acme\n\
+                   shop.globex.com,txt,,\n";
+        let hostnames = parse_hostnames_csv(csv, "http", "dv").unwrap();
+        assert_eq!(hostnames.len(), 2);
+        assert_eq!(hostnames[0].ssl.method.as_deref(), Some("http"));
+        assert_eq!(
+            hostnames[0].custom_origin_server.as_deref(),
+            Some("origin.example.com")
+        );
+        assert_eq!(
+            hostnames[0].custom_metadata.as_ref().unwrap()["customer"],
+            "acme"
+        );
+        assert_eq!(hostnames[1].ssl.method.as_deref(), Some("txt"));
+        assert!(hostnames[1].custom_metadata.is_none());
+    }
+
+    #[test]
+    fn test_parse_hostnames_csv_errors() {
+        assert!(parse_hostnames_csv("name\napp.acme.com\n", "http", "dv").is_err());
+        assert!(
+            parse_hostnames_csv("hostname,ssl_method\napp.acme.com,dns\n", "http", "dv").is_err()
+        );
+        assert!(parse_hostnames_csv("hostname,customer\n,acme\n", "http", "dv").is_err());
+    }
+
+    #[test]
+    fn test_is_id() {
+        assert!(is_id("0d89c70d-ad9f-4843-b99f-6cc0252067e9"));
+        assert!(!is_id("app.customer.com"));
+    }
+}
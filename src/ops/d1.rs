@@ -1,10 +1,15 @@
 use crate::api::d1::{
-    CreateD1Database, D1Bookmark, D1Database, D1ExportRequest, D1ExportResponse, D1ImportRequest,
-    D1ImportResponse, D1Query, D1QueryResult, D1RawQueryResult, D1RestoreRequest,
-    D1RestoreResponse, UpdateD1Database,
+    CreateD1Database, D1Bookmark, D1Database, D1DumpOptions, D1ExportRequest, D1ExportResponse,
+    D1ImportAction, D1ImportRequest, D1ImportResponse, D1Query, D1QueryResult, D1RawQueryResult,
+    D1RestoreRequest, D1RestoreResponse, UpdateD1Database,
 };
 use crate::client::{CfResponse, CloudflareClient};
-use crate::error::Result;
+use crate::error::{CfadError, Result};
+use futures::StreamExt;
+use md5::{Digest, Md5};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
 /// List all D1 databases for an account
 pub async fn list_databases(
@@ -106,11 +111,162 @@ pub async fn query_database_raw(
     Ok(response.result.unwrap_or_default())
 }
 
+/// Parse a typed `--param` value: `null`, `int:5`, `float:1.5`,
+/// `bool:true`, `json:<value>`, `str:<string>`, or a plain string
+pub fn parse_query_param(param: &str) -> Result<serde_json::Value> {
+    let invalid =
+        |kind: &str| CfadError::validation(format!("Invalid {} parameter '{}'", kind, param));
+    if param == "null" {
+        return Ok(serde_json::Value::Null);
+    }
+    match param.split_once(':') {
+        Some(("int", value)) => value
+            .trim()
+            .parse::<i64>()
+            .map(Into::into)
+            .map_err(|_| invalid("int")),
+        Some(("float", value)) => value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(serde_json::Value::Number)
+            .ok_or_else(|| invalid("float")),
+        Some(("bool", value)) => value
+            .trim()
+            .parse::<bool>()
+            .map(Into::into)
+            .map_err(|_| invalid("bool")),
+        Some(("json", value)) => serde_json::from_str(value).map_err(|_| invalid("json")),
+        Some(("str", value)) => Ok(value.into()),
+        _ => Ok(param.into()),
+    }
+}
+
+/// Query parameters from repeated `--param` values or a `--params-json` array
+pub fn query_params(
+    params: &[String],
+    params_json: Option<&str>,
+) -> Result<Option<Vec<serde_json::Value>>> {
+    if let Some(json) = params_json {
+        let values: Vec<serde_json::Value> = serde_json::from_str(json).map_err(|e| {
+            CfadError::validation(format!("--params-json must be a JSON array: {}", e))
+        })?;
+        return Ok(Some(values));
+    }
+    if params.is_empty() {
+        return Ok(None);
+    }
+    params
+        .iter()
+        .map(|p| parse_query_param(p))
+        .collect::<Result<Vec<_>>>()
+        .map(Some)
+}
+
+/// Number of parameters a statement binds, following SQLite numbering:
+/// `?` takes the next index, `?NNN` an explicit one, and each distinct
+/// `:name`, `@name` or `$name` the next index on first use. Placeholders in
+/// strings, quoted identifiers and comments are ignored.
+pub fn count_placeholders(sql: &str) -> usize {
+    let mut count = 0;
+    let mut named: Vec<String> = Vec::new();
+    let mut chars = sql.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' | '"' | '`' => {
+                for next in chars.by_ref() {
+                    if next == c {
+                        break;
+                    }
+                }
+            }
+            '[' => {
+                for next in chars.by_ref() {
+                    if next == ']' {
+                        break;
+                    }
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for next in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for next in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            '?' => {
+                let mut digits = String::new();
+                while let Some(d) = chars.peek().filter(|d| d.is_ascii_digit()) {
+                    digits.push(*d);
+                    chars.next();
+                }
+                count = match digits.parse::<usize>() {
+                    Ok(index) => count.max(index),
+                    Err(_) => count + 1,
+                };
+            }
+            ':' | '@' | '$'
+                if chars
+                    .peek()
+                    .is_some_and(|n| n.is_alphanumeric() || *n == '_') =>
+            {
+                let mut name = c.to_string();
+                while let Some(n) = chars.peek().filter(|n| n.is_alphanumeric() || **n == '_') {
+                    name.push(*n);
+                    chars.next();
+                }
+                if !named.contains(&name) {
+                    named.push(name);
+                    count += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+    count
+}
+
+/// Check that the parameters given match the placeholders in the SQL
+pub fn validate_query_params(sql: &str, params: Option<&[serde_json::Value]>) -> Result<()> {
+    let expected = count_placeholders(sql);
+    let given = params.map_or(0, |p| p.len());
+    if expected != given {
+        return Err(CfadError::validation(format!(
+            "SQL has {} placeholder(s) but {} parameter(s) were given",
+            expected, given
+        )));
+    }
+    Ok(())
+}
+
 /// Export a D1 database to SQL
 pub async fn export_database(
     client: &CloudflareClient,
     account_id: &str,
     database_id: &str,
+) -> Result<D1ExportResponse> {
+    request_export(client, account_id, database_id, None, None).await
+}
+
+/// Start an export, or poll it by passing the bookmark of the previous response
+pub async fn request_export(
+    client: &CloudflareClient,
+    account_id: &str,
+    database_id: &str,
+    current_bookmark: Option<&str>,
+    dump_options: Option<&D1DumpOptions>,
 ) -> Result<D1ExportResponse> {
     let endpoint = format!(
         "/accounts/{}/d1/database/{}/export",
@@ -118,6 +274,10 @@ pub async fn export_database(
     );
     let request = D1ExportRequest {
         output_format: "polling".to_string(),
+        current_bookmark: current_bookmark.map(String::from),
+        dump_options: dump_options
+            .filter(|o| **o != D1DumpOptions::default())
+            .cloned(),
     };
     let response: CfResponse<D1ExportResponse> = client.post(&endpoint, request).await?;
     response
@@ -125,6 +285,104 @@ pub async fn export_database(
         .ok_or_else(|| crate::error::CfadError::api("No result returned from export database"))
 }
 
+/// Start an export and poll it with its bookmark until the dump is ready
+pub async fn wait_for_export(
+    client: &CloudflareClient,
+    account_id: &str,
+    database_id: &str,
+    dump_options: &D1DumpOptions,
+    interval: Duration,
+    timeout: Duration,
+) -> Result<D1ExportResponse> {
+    if dump_options.no_data && dump_options.no_schema {
+        return Err(CfadError::validation(
+            "--no-data and --no-schema together would export nothing",
+        ));
+    }
+
+    let started = Instant::now();
+    let mut bookmark: Option<String> = None;
+    let mut shown_messages = 0;
+    loop {
+        let export = request_export(
+            client,
+            account_id,
+            database_id,
+            bookmark.as_deref(),
+            Some(dump_options),
+        )
+        .await?;
+        for message in export.messages.iter().skip(shown_messages) {
+            println!("  {}", message);
+        }
+        shown_messages = shown_messages.max(export.messages.len());
+
+        match export.status.as_str() {
+            "complete" => return Ok(export),
+            "error" => {
+                return Err(CfadError::api(format!(
+                    "Export failed: {}",
+                    export.error.as_deref().unwrap_or("unknown error")
+                )))
+            }
+            _ => {}
+        }
+        if export.at_bookmark.is_some() {
+            bookmark = export.at_bookmark;
+        }
+        if started.elapsed() >= timeout {
+            return Err(CfadError::Timeout(timeout));
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// Stream a finished export from its signed URL into `output`. The dump is
+/// written next to `output` first and renamed into place once complete, so
+/// an interrupted download never leaves a truncated file behind.
+/// `on_progress` receives the bytes written so far and the expected total.
+pub async fn download_export(
+    url: &str,
+    output: &Path,
+    mut on_progress: impl FnMut(u64, Option<u64>),
+) -> Result<u64> {
+    let response = reqwest::get(url).await?.error_for_status()?;
+    let total = response.content_length();
+
+    let partial = partial_path(output);
+    let result = async {
+        let mut file = std::fs::File::create(&partial)?;
+        let mut written = 0u64;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            file.write_all(&chunk)?;
+            written += chunk.len() as u64;
+            on_progress(written, total);
+        }
+        file.sync_all()?;
+        Ok::<u64, CfadError>(written)
+    }
+    .await;
+
+    match result {
+        Ok(written) => {
+            std::fs::rename(&partial, output)?;
+            Ok(written)
+        }
+        Err(e) => {
+            std::fs::remove_file(&partial).ok();
+            Err(e)
+        }
+    }
+}
+
+fn partial_path(output: &Path) -> PathBuf {
+    let mut name = output.file_name().unwrap_or_default().to_os_string();
+    name.push(".partial");
+    output.with_file_name(name)
+}
+
 /// Import SQL into a D1 database
 pub async fn import_database(
     client: &CloudflareClient,
@@ -148,6 +406,148 @@ pub async fn import_database(
     Ok(result)
 }
 
+/// SQL files up to this size are sent inline in one import request; larger
+/// ones go through the upload-and-ingest import
+pub const D1_INLINE_IMPORT_LIMIT: usize = 1024 * 1024;
+
+/// Etag of an import file: the hex MD5 of its contents
+pub fn import_etag(sql: &[u8]) -> String {
+    Md5::digest(sql)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+async fn import_action(
+    client: &CloudflareClient,
+    account_id: &str,
+    database_id: &str,
+    action: D1ImportAction,
+) -> Result<D1ImportResponse> {
+    let endpoint = format!(
+        "/accounts/{}/d1/database/{}/import",
+        account_id, database_id
+    );
+    let step = action.action.clone();
+    let response: CfResponse<D1ImportResponse> = client.post(&endpoint, action).await?;
+    let result = response
+        .result
+        .ok_or_else(|| CfadError::api(format!("No result returned from import {}", step)))?;
+    if result.status.as_deref() == Some("error") || (!result.success && result.error.is_some()) {
+        return Err(CfadError::api(format!(
+            "Import {} failed: {}",
+            step,
+            result.error.as_deref().unwrap_or("unknown error")
+        )));
+    }
+    Ok(result)
+}
+
+/// Import a SQL file of any size: upload it, ingest it and poll until done.
+///
+/// The upload is keyed by the file's MD5 etag. When the API already holds a
+/// file with the same etag (e.g. an earlier import failed after uploading),
+/// init returns no upload URL and the upload is skipped.
+pub async fn import_database_via_upload(
+    client: &CloudflareClient,
+    account_id: &str,
+    database_id: &str,
+    sql: Vec<u8>,
+    interval: Duration,
+    timeout: Duration,
+) -> Result<D1ImportResponse> {
+    let etag = import_etag(&sql);
+    let init = import_action(
+        client,
+        account_id,
+        database_id,
+        D1ImportAction {
+            action: "init".to_string(),
+            etag: Some(etag.clone()),
+            filename: None,
+            current_bookmark: None,
+        },
+    )
+    .await?;
+    let filename = init
+        .filename
+        .ok_or_else(|| CfadError::api("Import init returned no filename"))?;
+
+    match init.upload_url {
+        Some(upload_url) => {
+            println!("  Uploading {} bytes (etag {})", sql.len(), etag);
+            let response = reqwest::Client::new()
+                .put(&upload_url)
+                .body(sql)
+                .send()
+                .await?
+                .error_for_status()?;
+            let uploaded = response
+                .headers()
+                .get(reqwest::header::ETAG)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.trim_matches('"').to_string());
+            if uploaded.as_deref().is_some_and(|u| u != etag) {
+                return Err(CfadError::api(format!(
+                    "Upload corrupted: expected etag {}, storage reported {}",
+                    etag,
+                    uploaded.unwrap_or_default()
+                )));
+            }
+        }
+        None => println!("  File already uploaded (etag {}), resuming", etag),
+    }
+
+    let mut status = import_action(
+        client,
+        account_id,
+        database_id,
+        D1ImportAction {
+            action: "ingest".to_string(),
+            etag: Some(etag),
+            filename: Some(filename),
+            current_bookmark: None,
+        },
+    )
+    .await?;
+
+    let started = Instant::now();
+    let mut shown_messages = 0;
+    loop {
+        for message in status.messages.iter().skip(shown_messages) {
+            println!("  {}", message);
+        }
+        shown_messages = shown_messages.max(status.messages.len());
+        if status.status.as_deref() == Some("complete") {
+            break;
+        }
+        if started.elapsed() >= timeout {
+            return Err(CfadError::Timeout(timeout));
+        }
+        tokio::time::sleep(interval).await;
+        let bookmark = status.at_bookmark.clone();
+        status = import_action(
+            client,
+            account_id,
+            database_id,
+            D1ImportAction {
+                action: "poll".to_string(),
+                etag: None,
+                filename: None,
+                current_bookmark: bookmark,
+            },
+        )
+        .await?;
+    }
+
+    let totals = status.result.clone().unwrap_or_default();
+    println!(
+        "✓ Imported {} queries into database ({} rows read, {} rows written)",
+        totals.num_queries, totals.meta.rows_read, totals.meta.rows_written
+    );
+    Ok(status)
+}
+
 /// Get the current time travel bookmark for a D1 database
 pub async fn get_bookmark(
     client: &CloudflareClient,
@@ -194,3 +594,83 @@ pub async fn restore_database(
     );
     Ok(result)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_path_is_next_to_output() {
+        assert_eq!(
+            partial_path(Path::new("backups/prod.sql")),
+            PathBuf::from("backups/prod.sql.partial")
+        );
+    }
+
+    #[test]
+    fn test_parse_query_param() {
+        assert_eq!(parse_query_param("null").unwrap(), serde_json::Value::Null);
+        assert_eq!(parse_query_param("int:5").unwrap(), serde_json::json!(5));
+        assert_eq!(
+            parse_query_param("float:1.5").unwrap(),
+            serde_json::json!(1.5)
+        );
+        assert_eq!(
+            parse_query_param("bool:true").unwrap(),
+            serde_json::json!(true)
+        );
+        assert_eq!(
+            parse_query_param("json:{\"a\":[1]}").unwrap(),
+            serde_json::json!({"a": [1]})
+        );
+        assert_eq!(
+            parse_query_param("str:null").unwrap(),
+            serde_json::json!("null")
+        );
+        assert_eq!(
+            parse_query_param("https://example.com").unwrap(),
+            serde_json::json!("https://example.com")
+        );
+        assert!(parse_query_param("int:five").is_err());
+        assert!(parse_query_param("json:{").is_err());
+    }
+
+    #[test]
+    fn test_count_placeholders() {
+        assert_eq!(count_placeholders("SELECT 1"), 0);
+        assert_eq!(
+            count_placeholders("SELECT * FROM t WHERE a = ? AND b = ? AND c = '?'"),
+            2
+        );
+        assert_eq!(count_placeholders("SELECT ?2, ?1, ?"), 3);
+        assert_eq!(
+            count_placeholders("SELECT :id, @id, :id -- ?\n/* ? */ FROM \"a?\""),
+            2
+        );
+    }
+
+    #[test]
+    fn test_query_params() {
+        assert_eq!(query_params(&[], None).unwrap(), None);
+        assert_eq!(
+            query_params(&["int:1".to_string(), "x".to_string()], None).unwrap(),
+            Some(vec![serde_json::json!(1), serde_json::json!("x")])
+        );
+        assert_eq!(
+            query_params(&[], Some("[1, null]")).unwrap(),
+            Some(vec![serde_json::json!(1), serde_json::Value::Null])
+        );
+        assert!(query_params(&[], Some("{\"a\": 1}")).is_err());
+        assert!(validate_query_params("SELECT ?", None).is_err());
+        assert!(validate_query_params("SELECT ?", Some(&[serde_json::json!(1)])).is_ok());
+    }
+
+    #[test]
+    fn test_import_etag_is_hex_md5() {
+        assert_eq!(import_etag(b""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(
+            import_etag(b"The quick brown fox jumps over the lazy dog"),
+            "9e107d9d372bb6826bd81d3542a419d6"
+        );
+    }
+}
@@ -0,0 +1,259 @@
+//! Numbered SQL migrations for D1, compatible with wrangler's
+//! `migrations/NNNN_name.sql` layout and `d1_migrations` tracking table
+
+use crate::client::CloudflareClient;
+use crate::error::{CfadError, Result};
+use crate::ops::d1::{get_bookmark, query_database};
+use std::path::{Path, PathBuf};
+
+/// Table wrangler records applied migrations in
+pub const MIGRATIONS_TABLE: &str = "d1_migrations";
+
+/// Migration file in the migrations directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Migration {
+    pub number: u32,
+    /// File name, which is also the name recorded in `d1_migrations`
+    pub name: String,
+    pub path: PathBuf,
+}
+
+/// Migration with the time it was applied, if it was
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub name: String,
+    pub applied_at: Option<String>,
+}
+
+/// Migration files of `dir` in order; a missing directory has none
+pub fn migration_files(dir: &Path) -> Result<Vec<Migration>> {
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let pattern = regex::Regex::new(r"^(\d{4,})_.+\.sql$").expect("valid regex");
+    let mut migrations = Vec::new();
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        if let Some(captures) = pattern.captures(name) {
+            let number = captures[1].parse().map_err(|_| {
+                CfadError::validation(format!("Migration number too large: {}", name))
+            })?;
+            migrations.push(Migration {
+                number,
+                name: name.to_string(),
+                path: path.clone(),
+            });
+        }
+    }
+    migrations.sort_by(|a, b| (a.number, &a.name).cmp(&(b.number, &b.name)));
+    Ok(migrations)
+}
+
+/// Create the next numbered migration file, e.g. `0003_add_users.sql`
+pub fn create_migration(dir: &Path, name: &str) -> Result<PathBuf> {
+    let slug: String = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    let slug = slug.trim_matches('_');
+    if slug.is_empty() {
+        return Err(CfadError::validation(format!(
+            "Invalid migration name '{}'",
+            name
+        )));
+    }
+
+    let number = migration_files(dir)?
+        .last()
+        .map(|m| m.number + 1)
+        .unwrap_or(1);
+    std::fs::create_dir_all(dir)?;
+    let path = dir.join(format!("{:04}_{}.sql", number, slug));
+    std::fs::write(&path, format!("-- Migration number: {:04}\n\n", number))?;
+    println!("✓ Created migration: {}", path.display());
+    Ok(path)
+}
+
+/// Names of applied migrations with their timestamps, creating the
+/// tracking table on first use
+pub async fn applied_migrations(
+    client: &CloudflareClient,
+    account_id: &str,
+    database_id: &str,
+) -> Result<Vec<MigrationStatus>> {
+    let sql = format!(
+        "CREATE TABLE IF NOT EXISTS {table} (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT UNIQUE,
+    applied_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+SELECT name, applied_at FROM {table} ORDER BY id;",
+        table = MIGRATIONS_TABLE
+    );
+    let results = query_database(client, account_id, database_id, &sql, None).await?;
+    Ok(results
+        .last()
+        .map(|r| r.results.as_slice())
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|row| {
+            Some(MigrationStatus {
+                name: row.get("name")?.as_str()?.to_string(),
+                applied_at: row
+                    .get("applied_at")
+                    .and_then(|v| v.as_str())
+                    .map(str::to_string),
+            })
+        })
+        .collect())
+}
+
+/// Every migration file in order with its applied time, followed by
+/// applied migrations whose file no longer exists
+pub fn migration_statuses(
+    files: &[Migration],
+    applied: &[MigrationStatus],
+) -> Vec<MigrationStatus> {
+    let mut statuses: Vec<MigrationStatus> = files
+        .iter()
+        .map(|file| MigrationStatus {
+            name: file.name.clone(),
+            applied_at: applied
+                .iter()
+                .find(|a| a.name == file.name)
+                .map(|a| a.applied_at.clone().unwrap_or_default()),
+        })
+        .collect();
+    statuses.extend(
+        applied
+            .iter()
+            .filter(|a| !files.iter().any(|f| f.name == a.name))
+            .cloned(),
+    );
+    statuses
+}
+
+/// Apply pending migrations in order, stopping at the first failure.
+///
+/// Each file runs in one request together with the `d1_migrations` insert,
+/// so a migration is only recorded when all its statements succeed. With
+/// `bookmark`, the current time travel bookmark is taken first so the
+/// database can be restored if a migration fails. Returns the number of
+/// migrations applied.
+pub async fn apply_migrations(
+    client: &CloudflareClient,
+    account_id: &str,
+    database_id: &str,
+    dir: &Path,
+    bookmark: bool,
+) -> Result<usize> {
+    let files = migration_files(dir)?;
+    let applied = applied_migrations(client, account_id, database_id).await?;
+    let pending: Vec<&Migration> = files
+        .iter()
+        .filter(|f| !applied.iter().any(|a| a.name == f.name))
+        .collect();
+    if pending.is_empty() {
+        println!("✓ No migrations to apply");
+        return Ok(0);
+    }
+
+    let restore_point = if bookmark {
+        let bookmark = get_bookmark(client, account_id, database_id, None).await?;
+        println!("✓ Bookmark before migrations: {}", bookmark.bookmark);
+        Some(bookmark.bookmark)
+    } else {
+        None
+    };
+
+    for (i, migration) in pending.iter().enumerate() {
+        let sql = std::fs::read_to_string(&migration.path)?;
+        let sql = format!(
+            "{}\nINSERT INTO {} (name) VALUES ('{}');",
+            sql.trim_end(),
+            MIGRATIONS_TABLE,
+            migration.name.replace('\'', "''")
+        );
+        if let Err(e) = query_database(client, account_id, database_id, &sql, None).await {
+            let mut message = format!(
+                "Migration {} failed: {} ({} applied, {} not run)",
+                migration.name,
+                e,
+                i,
+                pending.len() - i - 1
+            );
+            if let Some(bookmark) = &restore_point {
+                message.push_str(&format!(
+                    "\nRoll back with: cfad d1 restore {} --bookmark {}",
+                    database_id, bookmark
+                ));
+            }
+            return Err(CfadError::api(message));
+        }
+        println!("✓ Applied migration: {}", migration.name);
+    }
+    Ok(pending.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_migration_files_sorted_by_number() {
+        let dir = std::env::temp_dir().join(format!("cfad-migrations-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        for name in ["0010_tags.sql", "0002_users.sql", "notes.txt", "seed.sql"] {
+            std::fs::write(dir.join(name), "").unwrap();
+        }
+        let created = create_migration(&dir, "Add Posts!").unwrap();
+        let names: Vec<String> = migration_files(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|m| m.name)
+            .collect();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert_eq!(created.file_name().unwrap(), "0011_add_posts.sql");
+        assert_eq!(
+            names,
+            vec!["0002_users.sql", "0010_tags.sql", "0011_add_posts.sql"]
+        );
+    }
+
+    #[test]
+    fn test_migration_statuses_include_missing_files() {
+        let files = vec![
+            Migration {
+                number: 1,
+                name: "0001_init.sql".to_string(),
+                path: PathBuf::from("migrations/0001_init.sql"),
+            },
+            Migration {
+                number: 2,
+                name: "0002_users.sql".to_string(),
+                path: PathBuf::from("migrations/0002_users.sql"),
+            },
+        ];
+        let applied = vec![
+            MigrationStatus {
+                name: "0001_init.sql".to_string(),
+                applied_at: Some("2026-01-01 10:00:00".to_string()),
+            },
+            MigrationStatus {
+                name: "0000_legacy.sql".to_string(),
+                applied_at: Some("2025-12-01 10:00:00".to_string()),
+            },
+        ];
+        let statuses = migration_statuses(&files, &applied);
+        assert_eq!(statuses.len(), 3);
+        assert!(statuses[0].applied_at.is_some());
+        assert!(statuses[1].applied_at.is_none());
+        assert_eq!(statuses[2].name, "0000_legacy.sql");
+    }
+}
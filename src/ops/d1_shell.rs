@@ -0,0 +1,424 @@
+//! Interactive SQL shell for D1 databases (`cfad d1 shell`)
+
+use crate::api::d1::D1QueryMeta;
+use crate::client::CloudflareClient;
+use crate::error::{CfadError, Result};
+use crate::ops::d1::{query_database, query_database_raw};
+use crate::output;
+use std::path::Path;
+use std::str::FromStr;
+
+pub const SHELL_HELP: &str = "\
+.tables              List tables
+.schema [TABLE]      Show CREATE statements
+.indexes             List indexes
+.mode table|json|csv Set the output format
+.timer on|off        Show duration and rows read/written after each statement
+.read FILE           Execute SQL from a file
+.help                Show this help
+.quit                Exit the shell
+
+Statements run once terminated by ';' and may span several lines.";
+
+/// Output format of query results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputMode {
+    Table,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputMode {
+    type Err = CfadError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "table" => Ok(OutputMode::Table),
+            "json" => Ok(OutputMode::Json),
+            "csv" => Ok(OutputMode::Csv),
+            _ => Err(CfadError::validation(format!(
+                "Unknown mode '{}': expected table, json or csv",
+                s
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MetaCommand {
+    Tables,
+    Schema(Option<String>),
+    Indexes,
+    Mode(OutputMode),
+    Timer(bool),
+    Read(String),
+    Help,
+    Quit,
+}
+
+/// Parse a dot-command such as `.schema users`
+pub fn parse_meta_command(line: &str) -> Result<MetaCommand> {
+    let mut parts = line.split_whitespace();
+    let command = parts.next().unwrap_or_default();
+    let arg = parts.next().map(|a| a.trim_end_matches(';').to_string());
+    if parts.next().is_some() {
+        return Err(CfadError::validation(format!(
+            "Too many arguments for {}",
+            command
+        )));
+    }
+
+    let required = |arg: Option<String>| {
+        arg.ok_or_else(|| {
+            CfadError::validation(format!("{} needs an argument (see .help)", command))
+        })
+    };
+    match command {
+        ".tables" => Ok(MetaCommand::Tables),
+        ".schema" => Ok(MetaCommand::Schema(arg)),
+        ".indexes" | ".indices" => Ok(MetaCommand::Indexes),
+        ".mode" => Ok(MetaCommand::Mode(required(arg)?.parse()?)),
+        ".timer" => match required(arg)?.as_str() {
+            "on" => Ok(MetaCommand::Timer(true)),
+            "off" => Ok(MetaCommand::Timer(false)),
+            other => Err(CfadError::validation(format!(
+                "Expected .timer on|off, got '{}'",
+                other
+            ))),
+        },
+        ".read" => Ok(MetaCommand::Read(required(arg)?)),
+        ".help" => Ok(MetaCommand::Help),
+        ".quit" | ".exit" => Ok(MetaCommand::Quit),
+        _ => Err(CfadError::validation(format!(
+            "Unknown command '{}' (try .help)",
+            command
+        ))),
+    }
+}
+
+/// Split SQL into statements terminated by `;`, returning the complete
+/// statements and the unterminated remainder.
+///
+/// Semicolons inside quotes, identifiers, comments and trigger bodies
+/// (`CREATE TRIGGER ... BEGIN ...; END;`) do not end a statement.
+pub fn split_statements(input: &str) -> (Vec<String>, String) {
+    let mut statements = Vec::new();
+    let mut start = 0;
+    let mut chars = input.char_indices().peekable();
+
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '\'' | '"' | '`' => {
+                for (_, next) in chars.by_ref() {
+                    if next == c {
+                        break;
+                    }
+                }
+            }
+            '[' => {
+                for (_, next) in chars.by_ref() {
+                    if next == ']' {
+                        break;
+                    }
+                }
+            }
+            '-' if matches!(chars.peek(), Some((_, '-'))) => {
+                for (_, next) in chars.by_ref() {
+                    if next == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if matches!(chars.peek(), Some((_, '*'))) => {
+                chars.next();
+                let mut previous = ' ';
+                for (_, next) in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            ';' => {
+                let statement = input[start..=i].trim();
+                if is_open_trigger(statement) {
+                    continue;
+                }
+                if statement != ";" {
+                    statements.push(statement.to_string());
+                }
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+
+    (statements, input[start..].trim().to_string())
+}
+
+/// A `CREATE TRIGGER` statement whose body has not reached `END;` yet
+fn is_open_trigger(statement: &str) -> bool {
+    let words: Vec<String> = statement
+        .split_whitespace()
+        .take(4)
+        .map(|w| w.to_ascii_uppercase())
+        .collect();
+    let is_trigger = words.first().map(String::as_str) == Some("CREATE")
+        && words.iter().skip(1).take(2).any(|w| w == "TRIGGER");
+    let body = statement.trim_end_matches(';').trim_end();
+    is_trigger && !body.to_ascii_uppercase().ends_with("END")
+}
+
+pub enum ShellFlow {
+    Continue,
+    Quit,
+}
+
+/// State of an interactive session against one database
+pub struct D1Shell<'a> {
+    client: &'a CloudflareClient,
+    account_id: String,
+    database_id: String,
+    pub mode: OutputMode,
+    pub timer: bool,
+    pending: String,
+}
+
+impl<'a> D1Shell<'a> {
+    pub fn new(client: &'a CloudflareClient, account_id: &str, database_id: &str) -> Self {
+        Self {
+            client,
+            account_id: account_id.to_string(),
+            database_id: database_id.to_string(),
+            mode: OutputMode::Table,
+            timer: false,
+            pending: String::new(),
+        }
+    }
+
+    /// Whether a statement is waiting for its terminating `;`
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Handle one line of input: a dot-command, or SQL that runs once a
+    /// statement is complete. Stops at the first failing statement.
+    pub async fn feed_line(&mut self, line: &str) -> Result<ShellFlow> {
+        if self.pending.is_empty() && line.trim_start().starts_with('.') {
+            let command = parse_meta_command(line)?;
+            return self.run_meta(command).await;
+        }
+
+        if !self.pending.is_empty() {
+            self.pending.push('\n');
+        }
+        self.pending.push_str(line);
+        let (statements, rest) = split_statements(&self.pending);
+        self.pending = rest;
+        for statement in statements {
+            self.execute(&statement).await?;
+        }
+        Ok(ShellFlow::Continue)
+    }
+
+    /// Run SQL and print the results in the current mode
+    pub async fn execute(&self, sql: &str) -> Result<()> {
+        let metas: Vec<D1QueryMeta> = match self.mode {
+            OutputMode::Csv => {
+                let results =
+                    query_database_raw(self.client, &self.account_id, &self.database_id, sql, None)
+                        .await?;
+                output::csv::write_d1_raw_query_results(&results, std::io::stdout())?;
+                results.into_iter().map(|r| r.meta).collect()
+            }
+            OutputMode::Table | OutputMode::Json => {
+                let results =
+                    query_database(self.client, &self.account_id, &self.database_id, sql, None)
+                        .await?;
+                if self.mode == OutputMode::Table {
+                    output::table::print_d1_query_results(&results);
+                } else {
+                    for result in &results {
+                        println!("{}", serde_json::to_string_pretty(&result.results)?);
+                    }
+                }
+                results.into_iter().map(|r| r.meta).collect()
+            }
+        };
+
+        if self.timer {
+            println!(
+                "Run Time: {:.3} ms, {} rows read, {} rows written",
+                metas.iter().map(|m| m.duration).sum::<f64>(),
+                metas.iter().map(|m| m.rows_read).sum::<u64>(),
+                metas.iter().map(|m| m.rows_written).sum::<u64>()
+            );
+        }
+        Ok(())
+    }
+
+    async fn run_meta(&mut self, command: MetaCommand) -> Result<ShellFlow> {
+        match command {
+            MetaCommand::Tables => {
+                let sql = "SELECT name FROM sqlite_master WHERE type = 'table' \
+                           AND name NOT LIKE 'sqlite_%' AND name NOT LIKE '_cf_%' ORDER BY name";
+                for name in self.column_values(sql, None, "name").await? {
+                    println!("{}", name);
+                }
+            }
+            MetaCommand::Schema(table) => {
+                let mut sql = "SELECT sql FROM sqlite_master WHERE sql IS NOT NULL \
+                               AND name NOT LIKE 'sqlite_%' AND name NOT LIKE '_cf_%'"
+                    .to_string();
+                let params = table.map(|t| {
+                    sql.push_str(" AND tbl_name = ?");
+                    vec![serde_json::Value::String(t)]
+                });
+                sql.push_str(" ORDER BY tbl_name, type DESC, name");
+                for statement in self.column_values(&sql, params, "sql").await? {
+                    println!("{};", statement);
+                }
+            }
+            MetaCommand::Indexes => {
+                self.execute(
+                    "SELECT name, tbl_name AS \"table\", sql FROM sqlite_master \
+                     WHERE type = 'index' AND name NOT LIKE 'sqlite_%' ORDER BY tbl_name, name",
+                )
+                .await?;
+            }
+            MetaCommand::Mode(mode) => self.mode = mode,
+            MetaCommand::Timer(on) => self.timer = on,
+            MetaCommand::Read(file) => {
+                let sql = std::fs::read_to_string(&file)?;
+                if !sql.trim().is_empty() {
+                    self.execute(&sql).await?;
+                }
+            }
+            MetaCommand::Help => println!("{}", SHELL_HELP),
+            MetaCommand::Quit => return Ok(ShellFlow::Quit),
+        }
+        Ok(ShellFlow::Continue)
+    }
+
+    async fn column_values(
+        &self,
+        sql: &str,
+        params: Option<Vec<serde_json::Value>>,
+        column: &str,
+    ) -> Result<Vec<String>> {
+        let results = query_database(
+            self.client,
+            &self.account_id,
+            &self.database_id,
+            sql,
+            params,
+        )
+        .await?;
+        Ok(results
+            .iter()
+            .flat_map(|r| &r.results)
+            .filter_map(|row| row.get(column).and_then(|v| v.as_str()))
+            .map(str::to_string)
+            .collect())
+    }
+}
+
+/// Run the read-eval-print loop until `.quit` or end of input, keeping
+/// line history in `history_path`.
+pub async fn run_shell(
+    client: &CloudflareClient,
+    account_id: &str,
+    database_id: &str,
+    database_name: &str,
+    history_path: &Path,
+) -> Result<()> {
+    let mut editor = rustyline::DefaultEditor::new()
+        .map_err(|e| CfadError::validation(format!("Cannot start shell: {}", e)))?;
+    // A missing history file just means this is the first session
+    let _ = editor.load_history(history_path);
+
+    let mut shell = D1Shell::new(client, account_id, database_id);
+    println!(
+        "Connected to {}. Enter SQL terminated by ';' or .help for commands.",
+        database_name
+    );
+
+    loop {
+        let prompt = if shell.is_pending() {
+            "   ...> ".to_string()
+        } else {
+            format!("{}> ", database_name)
+        };
+        let line = match editor.readline(&prompt) {
+            Ok(line) => line,
+            // Ctrl-C drops the statement being typed
+            Err(rustyline::error::ReadlineError::Interrupted) => {
+                shell.pending.clear();
+                continue;
+            }
+            Err(rustyline::error::ReadlineError::Eof) => break,
+            Err(e) => return Err(CfadError::validation(format!("Cannot read input: {}", e))),
+        };
+        if !line.trim().is_empty() {
+            let _ = editor.add_history_entry(line.as_str());
+        }
+        match shell.feed_line(&line).await {
+            Ok(ShellFlow::Continue) => {}
+            Ok(ShellFlow::Quit) => break,
+            Err(e) => eprintln!("Error: {}", e),
+        }
+    }
+
+    if let Some(dir) = history_path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    editor
+        .save_history(history_path)
+        .map_err(|e| CfadError::validation(format!("Cannot save shell history: {}", e)))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_statements_keeps_remainder() {
+        let (statements, rest) = split_statements("SELECT 1; SELECT 'a;b';\nSELECT");
+        assert_eq!(statements, vec!["SELECT 1;", "SELECT 'a;b';"]);
+        assert_eq!(rest, "SELECT");
+    }
+
+    #[test]
+    fn test_split_statements_ignores_comments_and_trigger_bodies() {
+        let (statements, rest) = split_statements("-- note; here\nSELECT /* ; */ 2;");
+        assert_eq!(statements, vec!["-- note; here\nSELECT /* ; */ 2;"]);
+        assert_eq!(rest, "");
+
+        let trigger = "CREATE TRIGGER t AFTER INSERT ON a BEGIN\n  UPDATE b SET n = n + 1;";
+        let (statements, rest) = split_statements(trigger);
+        assert!(statements.is_empty());
+        assert_eq!(rest, trigger);
+        let (statements, _) = split_statements(&format!("{}\nEND;", trigger));
+        assert_eq!(statements.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_meta_command() {
+        assert_eq!(
+            parse_meta_command(".schema users").unwrap(),
+            MetaCommand::Schema(Some("users".to_string()))
+        );
+        assert_eq!(
+            parse_meta_command(".mode csv").unwrap(),
+            MetaCommand::Mode(OutputMode::Csv)
+        );
+        assert_eq!(
+            parse_meta_command(".timer on").unwrap(),
+            MetaCommand::Timer(true)
+        );
+        assert!(parse_meta_command(".mode xml").is_err());
+        assert!(parse_meta_command(".read").is_err());
+        assert!(parse_meta_command(".drop").is_err());
+    }
+}
@@ -1,31 +1,43 @@
-use crate::api::dns::{CreateDnsRecord, DnsRecord, UpdateDnsRecord};
+use crate::api::dns::{CreateDnsRecord, DnsRecord, Dnssec, UpdateDnsRecord};
 use crate::client::{CfResponse, CloudflareClient};
 use crate::error::Result;
 use serde::Deserialize;
 
+/// Page size used when walking a zone's DNS records
+const DNS_RECORDS_PER_PAGE: u32 = 100;
+
+/// List a zone's DNS records, following every page
 pub async fn list_records(
     client: &CloudflareClient,
     zone_id: &str,
     record_type: Option<&str>,
     name: Option<&str>,
 ) -> Result<Vec<DnsRecord>> {
-    let mut endpoint = format!("/zones/{}/dns_records", zone_id);
-    let mut params = Vec::new();
-
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    query.append_pair("per_page", &DNS_RECORDS_PER_PAGE.to_string());
     if let Some(rtype) = record_type {
-        params.push(format!("type={}", rtype));
+        query.append_pair("type", rtype);
     }
     if let Some(n) = name {
-        params.push(format!("name={}", n));
+        query.append_pair("name", n);
     }
+    let query = query.finish();
 
-    if !params.is_empty() {
-        endpoint.push_str(&format!("?{}", params.join("&")));
+    let mut records = Vec::new();
+    let mut page = 1;
+    loop {
+        let endpoint = format!("/zones/{}/dns_records?page={}&{}", zone_id, page, query);
+        let response: CfResponse<Vec<DnsRecord>> = client.get(&endpoint).await?;
+        records.extend(response.result.unwrap_or_default());
+
+        // Responses without result_info are treated as a single page
+        match response.result_info {
+            Some(info) if page < info.total_pages => page += 1,
+            _ => break,
+        }
     }
 
-    let response: CfResponse<Vec<DnsRecord>> = client.get(&endpoint).await?;
-
-    Ok(response.result.unwrap_or_default())
+    Ok(records)
 }
 
 pub async fn get_record(
@@ -86,6 +98,14 @@ pub async fn delete_record(
     Ok(())
 }
 
+pub async fn get_dnssec(client: &CloudflareClient, zone_id: &str) -> Result<Dnssec> {
+    let endpoint = format!("/zones/{}/dnssec", zone_id);
+    let response: CfResponse<Dnssec> = client.get(&endpoint).await?;
+    response
+        .result
+        .ok_or_else(|| crate::error::CfadError::not_found("DNSSEC settings", zone_id))
+}
+
 #[derive(Debug, Deserialize)]
 struct CsvRecord {
     r#type: String,
@@ -1 +1,468 @@
-// Placeholder module
+use std::time::{Duration, Instant};
+
+use crate::api::firewall::{
+    AccessRule, AccessRuleFilter, CreateAccessRule, CreateCustomList, CreateListItem, CustomList,
+    DeleteListItems, ListItem, ListItemId, ListOperation, ListOperationRef, ACCESS_RULE_MODES,
+    ACCESS_RULE_TARGETS, LIST_KINDS,
+};
+use crate::api::rulesets::RulesetScope;
+use crate::client::{CfResponse, CloudflareClient};
+use crate::error::{CfadError, Result};
+
+const ACCESS_RULES_PER_PAGE: u32 = 100;
+
+// ============================================================================
+// IP Access Rules
+// ============================================================================
+
+/// List IP Access Rules for a zone or account, following pagination
+pub async fn list_access_rules(
+    client: &CloudflareClient,
+    scope: &RulesetScope,
+    filter: &AccessRuleFilter,
+) -> Result<Vec<AccessRule>> {
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    query.append_pair("per_page", &ACCESS_RULES_PER_PAGE.to_string());
+    if let Some(mode) = &filter.mode {
+        query.append_pair("mode", mode);
+    }
+    if let Some(target) = &filter.target {
+        query.append_pair("configuration.target", target);
+    }
+    if let Some(value) = &filter.value {
+        query.append_pair("configuration.value", value);
+    }
+    if let Some(notes) = &filter.notes {
+        query.append_pair("notes", notes);
+    }
+    let query = query.finish();
+
+    let mut rules = Vec::new();
+    let mut page = 1;
+    loop {
+        let endpoint = format!(
+            "{}/firewall/access_rules/rules?page={}&{}",
+            scope.path(),
+            page,
+            query
+        );
+        let response: CfResponse<Vec<AccessRule>> = client.get(&endpoint).await?;
+        rules.extend(response.result.unwrap_or_default());
+
+        match response.result_info {
+            Some(info) if page < info.total_pages => page += 1,
+            _ => break,
+        }
+    }
+    Ok(rules)
+}
+
+/// Create an IP Access Rule
+pub async fn create_access_rule(
+    client: &CloudflareClient,
+    scope: &RulesetScope,
+    rule: CreateAccessRule,
+) -> Result<AccessRule> {
+    validate_access_rule(&rule)?;
+    let endpoint = format!("{}/firewall/access_rules/rules", scope.path());
+    let response: CfResponse<AccessRule> = client.post(&endpoint, rule).await?;
+    let rule = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create access rule"))?;
+    println!(
+        "✓ Created access rule: {} {} {}",
+        rule.mode, rule.configuration.target, rule.configuration.value
+    );
+    Ok(rule)
+}
+
+/// Delete an IP Access Rule
+pub async fn delete_access_rule(
+    client: &CloudflareClient,
+    scope: &RulesetScope,
+    rule_id: &str,
+) -> Result<()> {
+    let endpoint = format!("{}/firewall/access_rules/rules/{}", scope.path(), rule_id);
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted access rule: {}", rule_id);
+    Ok(())
+}
+
+fn validate_access_rule(rule: &CreateAccessRule) -> Result<()> {
+    if !ACCESS_RULE_MODES.contains(&rule.mode.as_str()) {
+        return Err(CfadError::validation(format!(
+            "Invalid mode '{}' (expected one of: {})",
+            rule.mode,
+            ACCESS_RULE_MODES.join(", ")
+        )));
+    }
+    let target = rule.configuration.target.as_str();
+    if !ACCESS_RULE_TARGETS.contains(&target) {
+        return Err(CfadError::validation(format!(
+            "Invalid target '{}' (expected one of: {})",
+            target,
+            ACCESS_RULE_TARGETS.join(", ")
+        )));
+    }
+    if target == "country" && rule.configuration.value.len() != 2 {
+        return Err(CfadError::validation(
+            "Country targets take a two-letter ISO 3166-1 code",
+        ));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Custom Lists
+// ============================================================================
+
+/// List all custom lists in an account
+pub async fn list_lists(client: &CloudflareClient, account_id: &str) -> Result<Vec<CustomList>> {
+    let endpoint = format!("/accounts/{}/rules/lists", account_id);
+    let response: CfResponse<Vec<CustomList>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Find a custom list by ID or name
+pub async fn get_list(
+    client: &CloudflareClient,
+    account_id: &str,
+    list: &str,
+) -> Result<CustomList> {
+    list_lists(client, account_id)
+        .await?
+        .into_iter()
+        .find(|l| l.id == list || l.name == list)
+        .ok_or_else(|| CfadError::not_found("List", list))
+}
+
+/// Create a custom list
+pub async fn create_list(
+    client: &CloudflareClient,
+    account_id: &str,
+    list: CreateCustomList,
+) -> Result<CustomList> {
+    if !LIST_KINDS.contains(&list.kind.as_str()) {
+        return Err(CfadError::validation(format!(
+            "Invalid list kind '{}' (expected one of: {})",
+            list.kind,
+            LIST_KINDS.join(", ")
+        )));
+    }
+    let endpoint = format!("/accounts/{}/rules/lists", account_id);
+    let response: CfResponse<CustomList> = client.post(&endpoint, list).await?;
+    let list = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create list"))?;
+    println!("✓ Created list: {} ({})", list.name, list.id);
+    Ok(list)
+}
+
+/// Delete a custom list
+pub async fn delete_list(client: &CloudflareClient, account_id: &str, list_id: &str) -> Result<()> {
+    let endpoint = format!("/accounts/{}/rules/lists/{}", account_id, list_id);
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted list: {}", list_id);
+    Ok(())
+}
+
+/// List every item in a custom list, following cursor pagination
+pub async fn list_items(
+    client: &CloudflareClient,
+    account_id: &str,
+    list_id: &str,
+) -> Result<Vec<ListItem>> {
+    let mut items = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let mut endpoint = format!("/accounts/{}/rules/lists/{}/items", account_id, list_id);
+        if let Some(cursor) = &cursor {
+            let query = url::form_urlencoded::Serializer::new(String::new())
+                .append_pair("cursor", cursor)
+                .finish();
+            endpoint.push_str(&format!("?{}", query));
+        }
+        let response: CfResponse<Vec<ListItem>> = client.get(&endpoint).await?;
+        items.extend(response.result.unwrap_or_default());
+
+        cursor = response
+            .result_info
+            .and_then(|info| info.cursors)
+            .and_then(|c| c.after)
+            .filter(|c| !c.is_empty());
+        if cursor.is_none() {
+            break;
+        }
+    }
+    Ok(items)
+}
+
+/// Append items to a custom list. Returns the asynchronous operation ID.
+pub async fn add_items(
+    client: &CloudflareClient,
+    account_id: &str,
+    list_id: &str,
+    items: Vec<CreateListItem>,
+) -> Result<String> {
+    let endpoint = format!("/accounts/{}/rules/lists/{}/items", account_id, list_id);
+    let response: CfResponse<ListOperationRef> = client.post(&endpoint, items).await?;
+    operation_id(response)
+}
+
+/// Replace every item in a custom list. Returns the asynchronous operation ID.
+pub async fn replace_items(
+    client: &CloudflareClient,
+    account_id: &str,
+    list_id: &str,
+    items: Vec<CreateListItem>,
+) -> Result<String> {
+    let endpoint = format!("/accounts/{}/rules/lists/{}/items", account_id, list_id);
+    let response: CfResponse<ListOperationRef> = client.put(&endpoint, items).await?;
+    operation_id(response)
+}
+
+/// Remove items from a custom list by item ID. Returns the asynchronous operation ID.
+pub async fn delete_items(
+    client: &CloudflareClient,
+    account_id: &str,
+    list_id: &str,
+    item_ids: Vec<String>,
+) -> Result<String> {
+    let endpoint = format!("/accounts/{}/rules/lists/{}/items", account_id, list_id);
+    let body = DeleteListItems {
+        items: item_ids.into_iter().map(|id| ListItemId { id }).collect(),
+    };
+    let response: CfResponse<ListOperationRef> = client.delete_with_body(&endpoint, body).await?;
+    operation_id(response)
+}
+
+fn operation_id(response: CfResponse<ListOperationRef>) -> Result<String> {
+    response
+        .result
+        .map(|r| r.operation_id)
+        .ok_or_else(|| CfadError::api("No operation ID returned from list items request"))
+}
+
+/// Get the status of an asynchronous list operation
+pub async fn get_operation(
+    client: &CloudflareClient,
+    account_id: &str,
+    operation_id: &str,
+) -> Result<ListOperation> {
+    let endpoint = format!(
+        "/accounts/{}/rules/lists/bulk_operations/{}",
+        account_id, operation_id
+    );
+    let response: CfResponse<ListOperation> = client.get(&endpoint).await?;
+    response
+        .result
+        .ok_or_else(|| CfadError::not_found("List operation", operation_id))
+}
+
+/// Poll an asynchronous list operation until it completes, fails or times out
+pub async fn wait_for_operation(
+    client: &CloudflareClient,
+    account_id: &str,
+    operation_id: &str,
+    interval: Duration,
+    timeout: Duration,
+) -> Result<ListOperation> {
+    let started = Instant::now();
+    loop {
+        let operation = get_operation(client, account_id, operation_id).await?;
+        if operation.is_finished() {
+            if operation.status == "failed" {
+                return Err(CfadError::api(format!(
+                    "List operation {} failed: {}",
+                    operation_id,
+                    operation.error.as_deref().unwrap_or("unknown error")
+                )));
+            }
+            return Ok(operation);
+        }
+        if started.elapsed() >= timeout {
+            return Err(CfadError::Timeout(timeout));
+        }
+        tokio::time::sleep(interval).await;
+    }
+}
+
+/// CSV columns that hold the item value
+const LIST_VALUE_COLUMNS: [&str; 4] = ["value", "ip", "asn", "hostname"];
+
+/// Parse list items from a file: one value per line, or CSV with a header.
+///
+/// Text files take a value optionally followed by whitespace and a comment;
+/// `#` lines are skipped. The contents are read as CSV when `csv` is set
+/// (e.g. for a `.csv` file) or the first line is a header naming a `value`
+/// (or `ip`/`asn`/`hostname`) column; CSV may also have a `comment` column.
+pub fn parse_list_items(contents: &str, kind: &str, csv: bool) -> Result<Vec<CreateListItem>> {
+    let first_line = contents
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty() && !l.starts_with('#'))
+        .unwrap_or_default();
+    let has_header = first_line
+        .split(',')
+        .any(|column| LIST_VALUE_COLUMNS.contains(&column.trim()));
+
+    if csv || has_header {
+        parse_list_items_csv(contents, kind)
+    } else {
+        contents
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'))
+            .map(|line| {
+                let (value, comment) = match line.split_once(char::is_whitespace) {
+                    Some((value, comment)) => (value, Some(comment.trim().to_string())),
+                    None => (line, None),
+                };
+                list_item(kind, value, comment)
+            })
+            .collect()
+    }
+}
+
+fn parse_list_items_csv(contents: &str, kind: &str) -> Result<Vec<CreateListItem>> {
+    let mut reader = csv::ReaderBuilder::new()
+        .comment(Some(b'#'))
+        .trim(csv::Trim::All)
+        .from_reader(contents.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| CfadError::validation(format!("Invalid CSV format: {}", e)))?
+        .clone();
+    let value_col = headers
+        .iter()
+        .position(|h| LIST_VALUE_COLUMNS.contains(&h))
+        .ok_or_else(|| {
+            CfadError::validation("CSV must have a value, ip, asn or hostname column")
+        })?;
+    let comment_col = headers.iter().position(|h| h == "comment");
+
+    let mut items = Vec::new();
+    for record in reader.records() {
+        let record =
+            record.map_err(|e| CfadError::validation(format!("Invalid CSV format: {}", e)))?;
+        let value = record.get(value_col).unwrap_or_default();
+        let comment = comment_col
+            .and_then(|i| record.get(i))
+            .filter(|c| !c.is_empty())
+            .map(String::from);
+        items.push(list_item(kind, value, comment)?);
+    }
+    Ok(items)
+}
+
+/// Build a list item of the list's kind from a value and optional comment
+pub fn list_item(kind: &str, value: &str, comment: Option<String>) -> Result<CreateListItem> {
+    let mut item = CreateListItem {
+        comment: comment.filter(|c| !c.is_empty()),
+        ..Default::default()
+    };
+    match kind {
+        "ip" => item.ip = Some(value.to_string()),
+        "asn" => {
+            let digits = value.trim_start_matches("AS").trim_start_matches("as");
+            let asn = digits
+                .parse::<u32>()
+                .map_err(|_| CfadError::validation(format!("Invalid ASN: {}", value)))?;
+            item.asn = Some(asn);
+        }
+        "hostname" => item.hostname = Some(serde_json::json!({ "url_hostname": value })),
+        other => {
+            return Err(CfadError::validation(format!(
+                "Adding items to '{}' lists is not supported (only ip, asn and hostname lists)",
+                other
+            )))
+        }
+    }
+    Ok(item)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::firewall::AccessRuleConfiguration;
+
+    #[test]
+    fn test_parse_list_items_text() {
+        let items = parse_list_items(
+            "# blocklist\n192.0.2.1\n198.51.100.0/24  scanner\n\n",
+            "ip",
+            false,
+        )
+        .unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].ip.as_deref(), Some("192.0.2.1"));
+        assert_eq!(items[1].comment.as_deref(), Some("scanner"));
+    }
+
+    #[test]
+    fn test_parse_list_items_csv() {
+        let items = parse_list_items("asn,comment\nAS13335,cf\n64496,\n", "asn", false).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].asn, Some(13335));
+        assert_eq!(items[0].comment.as_deref(), Some("cf"));
+        assert_eq!(items[1].comment, None);
+    }
+
+    #[test]
+    fn test_parse_list_items_invalid_asn() {
+        assert!(parse_list_items("not-an-asn\n", "asn", false).is_err());
+    }
+
+    #[test]
+    fn test_parse_list_items_text_comment_with_comma() {
+        let items = parse_list_items("192.0.2.1 scanner, tor\n", "ip", false).unwrap();
+        assert_eq!(items[0].ip.as_deref(), Some("192.0.2.1"));
+        assert_eq!(items[0].comment.as_deref(), Some("scanner, tor"));
+    }
+
+    #[test]
+    fn test_parse_list_items_single_column_csv_header() {
+        let items = parse_list_items("ip\n192.0.2.1\n192.0.2.2\n", "ip", false).unwrap();
+        let ips: Vec<_> = items.iter().map(|i| i.ip.as_deref().unwrap()).collect();
+        assert_eq!(ips, vec!["192.0.2.1", "192.0.2.2"]);
+    }
+
+    #[test]
+    fn test_parse_list_items_csv_requires_value_column() {
+        assert!(parse_list_items("name,comment\nfoo,bar\n", "ip", true).is_err());
+    }
+
+    #[test]
+    fn test_list_item_unsupported_kind() {
+        let err = list_item("redirect", "example.com", None).unwrap_err();
+        assert!(err
+            .to_string()
+            .contains("Adding items to 'redirect' lists is not supported"));
+    }
+
+    #[test]
+    fn test_validate_access_rule_mode() {
+        let rule = CreateAccessRule {
+            mode: "allow".to_string(),
+            configuration: AccessRuleConfiguration {
+                target: "ip".to_string(),
+                value: "192.0.2.1".to_string(),
+            },
+            notes: None,
+        };
+        assert!(validate_access_rule(&rule).is_err());
+    }
+
+    #[test]
+    fn test_validate_access_rule_country() {
+        let rule = CreateAccessRule {
+            mode: "block".to_string(),
+            configuration: AccessRuleConfiguration {
+                target: "country".to_string(),
+                value: "XX".to_string(),
+            },
+            notes: None,
+        };
+        assert!(validate_access_rule(&rule).is_ok());
+    }
+}
@@ -0,0 +1,496 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use crate::api::kv::{
+    KvBulkEntry, KvBulkResult, KvExportEntry, KvKey, KvNamespace, KvNamespaceTitle, KV_BULK_LIMIT,
+};
+use crate::client::{CfResponse, CloudflareClient};
+use crate::error::{CfadError, Result};
+use reqwest::multipart::{Form, Part};
+
+/// Namespaces requested per page when listing
+const NAMESPACES_PER_PAGE: u32 = 100;
+
+/// Keys requested per page when listing (the API maximum)
+const KEYS_PER_PAGE: u32 = 1000;
+
+/// Shortest expiration TTL KV accepts, in seconds
+pub const MIN_EXPIRATION_TTL: u64 = 60;
+
+/// Subdirectory of an export holding the values
+const EXPORT_VALUES_DIR: &str = "values";
+
+/// Longest file name most filesystems accept, in bytes
+const MAX_FILE_NAME: usize = 255;
+
+/// Bytes of the encoded key kept in the file name of a key too long to use
+/// whole
+const EXPORT_NAME_STEM: usize = 200;
+
+// ============================================================================
+// Namespaces
+// ============================================================================
+
+pub async fn list_namespaces(
+    client: &CloudflareClient,
+    account_id: &str,
+) -> Result<Vec<KvNamespace>> {
+    let mut namespaces = Vec::new();
+    let mut page = 1;
+
+    loop {
+        let endpoint = format!(
+            "/accounts/{}/storage/kv/namespaces?page={}&per_page={}",
+            account_id, page, NAMESPACES_PER_PAGE
+        );
+        let response: CfResponse<Vec<KvNamespace>> = client.get(&endpoint).await?;
+        namespaces.extend(response.result.unwrap_or_default());
+
+        match response.result_info {
+            Some(info) if page < info.total_pages => page += 1,
+            _ => break,
+        }
+    }
+
+    Ok(namespaces)
+}
+
+pub async fn create_namespace(
+    client: &CloudflareClient,
+    account_id: &str,
+    title: &str,
+) -> Result<KvNamespace> {
+    let endpoint = format!("/accounts/{}/storage/kv/namespaces", account_id);
+    let body = KvNamespaceTitle {
+        title: title.to_string(),
+    };
+    let response: CfResponse<KvNamespace> = client.post(&endpoint, body).await?;
+    let namespace = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create namespace"))?;
+    println!(
+        "✓ Created KV namespace: {} ({})",
+        namespace.title, namespace.id
+    );
+    Ok(namespace)
+}
+
+pub async fn rename_namespace(
+    client: &CloudflareClient,
+    account_id: &str,
+    namespace_id: &str,
+    title: &str,
+) -> Result<()> {
+    let endpoint = format!(
+        "/accounts/{}/storage/kv/namespaces/{}",
+        account_id, namespace_id
+    );
+    let body = KvNamespaceTitle {
+        title: title.to_string(),
+    };
+    let _response: CfResponse<serde_json::Value> = client.put(&endpoint, body).await?;
+    println!("✓ Renamed KV namespace {} to {}", namespace_id, title);
+    Ok(())
+}
+
+pub async fn delete_namespace(
+    client: &CloudflareClient,
+    account_id: &str,
+    namespace_id: &str,
+) -> Result<()> {
+    let endpoint = format!(
+        "/accounts/{}/storage/kv/namespaces/{}",
+        account_id, namespace_id
+    );
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted KV namespace: {}", namespace_id);
+    Ok(())
+}
+
+// ============================================================================
+// Keys
+// ============================================================================
+
+/// Fetch one page of keys. Returns the keys and the cursor of the next page.
+pub async fn list_keys_page(
+    client: &CloudflareClient,
+    account_id: &str,
+    namespace_id: &str,
+    prefix: Option<&str>,
+    cursor: Option<&str>,
+) -> Result<(Vec<KvKey>, Option<String>)> {
+    let mut query = url::form_urlencoded::Serializer::new(String::new());
+    query.append_pair("limit", &KEYS_PER_PAGE.to_string());
+    if let Some(prefix) = prefix {
+        query.append_pair("prefix", prefix);
+    }
+    if let Some(cursor) = cursor {
+        query.append_pair("cursor", cursor);
+    }
+    let endpoint = format!(
+        "/accounts/{}/storage/kv/namespaces/{}/keys?{}",
+        account_id,
+        namespace_id,
+        query.finish()
+    );
+    let response: CfResponse<Vec<KvKey>> = client.get(&endpoint).await?;
+    let next = response
+        .result_info
+        .and_then(|info| info.cursor)
+        .filter(|c| !c.is_empty());
+    Ok((response.result.unwrap_or_default(), next))
+}
+
+/// List every key in a namespace, following cursor pagination
+pub async fn list_keys(
+    client: &CloudflareClient,
+    account_id: &str,
+    namespace_id: &str,
+    prefix: Option<&str>,
+) -> Result<Vec<KvKey>> {
+    let mut keys = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let (page, next) =
+            list_keys_page(client, account_id, namespace_id, prefix, cursor.as_deref()).await?;
+        keys.extend(page);
+        cursor = next;
+        if cursor.is_none() {
+            break;
+        }
+    }
+    Ok(keys)
+}
+
+fn value_endpoint(account_id: &str, namespace_id: &str, key: &str) -> String {
+    format!(
+        "/accounts/{}/storage/kv/namespaces/{}/values/{}",
+        account_id,
+        namespace_id,
+        encode_key(key)
+    )
+}
+
+/// Read a value as raw bytes
+pub async fn get_value(
+    client: &CloudflareClient,
+    account_id: &str,
+    namespace_id: &str,
+    key: &str,
+) -> Result<Vec<u8>> {
+    client
+        .get_raw(&value_endpoint(account_id, namespace_id, key))
+        .await
+}
+
+/// Write a value, optionally with a TTL (seconds) and JSON metadata
+pub async fn put_value(
+    client: &CloudflareClient,
+    account_id: &str,
+    namespace_id: &str,
+    key: &str,
+    value: Vec<u8>,
+    expiration_ttl: Option<u64>,
+    metadata: Option<&serde_json::Value>,
+) -> Result<()> {
+    validate_key(key)?;
+    let mut endpoint = value_endpoint(account_id, namespace_id, key);
+    if let Some(ttl) = expiration_ttl {
+        validate_expiration_ttl(ttl)?;
+        endpoint.push_str(&format!("?expiration_ttl={}", ttl));
+    }
+
+    let mut form = Form::new().part(
+        "value",
+        Part::bytes(value).mime_str("application/octet-stream")?,
+    );
+    if let Some(metadata) = metadata {
+        form = form.part(
+            "metadata",
+            Part::text(serde_json::to_string(metadata)?).mime_str("application/json")?,
+        );
+    }
+    let _response: CfResponse<serde_json::Value> = client.put_multipart(&endpoint, form).await?;
+    println!("✓ Wrote key: {}", key);
+    Ok(())
+}
+
+pub async fn delete_value(
+    client: &CloudflareClient,
+    account_id: &str,
+    namespace_id: &str,
+    key: &str,
+) -> Result<()> {
+    let endpoint = value_endpoint(account_id, namespace_id, key);
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted key: {}", key);
+    Ok(())
+}
+
+/// Reject keys the API cannot store
+pub fn validate_key(key: &str) -> Result<()> {
+    if key.is_empty() || key == "." || key == ".." {
+        return Err(CfadError::validation(format!(
+            "Invalid KV key '{}': keys must not be empty, '.' or '..'",
+            key
+        )));
+    }
+    if key.len() > 512 {
+        return Err(CfadError::validation(format!(
+            "Invalid KV key '{}...': keys are limited to 512 bytes",
+            key.chars().take(32).collect::<String>()
+        )));
+    }
+    Ok(())
+}
+
+pub fn validate_expiration_ttl(ttl: u64) -> Result<()> {
+    if ttl < MIN_EXPIRATION_TTL {
+        return Err(CfadError::validation(format!(
+            "Invalid expiration TTL {}: KV requires at least {} seconds",
+            ttl, MIN_EXPIRATION_TTL
+        )));
+    }
+    Ok(())
+}
+
+/// Parse a `--metadata` flag; KV metadata must be a JSON value
+pub fn parse_metadata(metadata: &str) -> Result<serde_json::Value> {
+    serde_json::from_str(metadata)
+        .map_err(|e| CfadError::validation(format!("Invalid --metadata JSON: {}", e)))
+}
+
+/// Percent-encode a key for use as a URL path segment or file name
+pub fn encode_key(key: &str) -> String {
+    let mut encoded = String::with_capacity(key.len());
+    for byte in key.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+// ============================================================================
+// Bulk operations
+// ============================================================================
+
+/// Parse a bulk write file: a JSON array of `{key, value, expiration?,
+/// expiration_ttl?, metadata?, base64?}` objects (the wrangler format)
+pub fn parse_bulk_entries(contents: &str) -> Result<Vec<KvBulkEntry>> {
+    let entries: Vec<KvBulkEntry> = serde_json::from_str(contents).map_err(|e| {
+        CfadError::validation(format!(
+            "Invalid bulk file: expected a JSON array of {{\"key\", \"value\"}} objects ({})",
+            e
+        ))
+    })?;
+    for entry in &entries {
+        validate_key(&entry.key)?;
+        if let Some(ttl) = entry.expiration_ttl {
+            validate_expiration_ttl(ttl)?;
+        }
+    }
+    Ok(entries)
+}
+
+/// Parse a bulk delete file: a JSON array of key names, or of objects with a
+/// `key` field (so a bulk write file can be reused to delete its keys)
+pub fn parse_bulk_delete_keys(contents: &str) -> Result<Vec<String>> {
+    let invalid = |detail: String| {
+        CfadError::validation(format!(
+            "Invalid bulk delete file: expected a JSON array of keys or {{\"key\"}} objects ({})",
+            detail
+        ))
+    };
+    let values: Vec<serde_json::Value> =
+        serde_json::from_str(contents).map_err(|e| invalid(e.to_string()))?;
+    values
+        .into_iter()
+        .map(|value| match value {
+            serde_json::Value::String(key) => Ok(key),
+            serde_json::Value::Object(mut object) => match object.remove("key") {
+                Some(serde_json::Value::String(key)) => Ok(key),
+                _ => Err(invalid("object without a string \"key\"".to_string())),
+            },
+            other => Err(invalid(format!("unexpected entry {}", other))),
+        })
+        .collect()
+}
+
+/// Write entries in requests of at most [`KV_BULK_LIMIT`] keys
+pub async fn bulk_put(
+    client: &CloudflareClient,
+    account_id: &str,
+    namespace_id: &str,
+    entries: &[KvBulkEntry],
+) -> Result<KvBulkResult> {
+    let endpoint = format!(
+        "/accounts/{}/storage/kv/namespaces/{}/bulk",
+        account_id, namespace_id
+    );
+    let mut total = KvBulkResult::default();
+    for (i, chunk) in entries.chunks(KV_BULK_LIMIT).enumerate() {
+        let response: CfResponse<KvBulkResult> = client.put(&endpoint, chunk).await?;
+        add_chunk_result(&mut total, response.result, chunk.len());
+        println!("✓ Wrote batch {} ({} keys)", i + 1, chunk.len());
+    }
+    Ok(total)
+}
+
+/// Delete keys in requests of at most [`KV_BULK_LIMIT`] keys
+pub async fn bulk_delete(
+    client: &CloudflareClient,
+    account_id: &str,
+    namespace_id: &str,
+    keys: &[String],
+) -> Result<KvBulkResult> {
+    let endpoint = format!(
+        "/accounts/{}/storage/kv/namespaces/{}/bulk/delete",
+        account_id, namespace_id
+    );
+    let mut total = KvBulkResult::default();
+    for (i, chunk) in keys.chunks(KV_BULK_LIMIT).enumerate() {
+        let response: CfResponse<KvBulkResult> = client.post(&endpoint, chunk).await?;
+        add_chunk_result(&mut total, response.result, chunk.len());
+        println!("✓ Deleted batch {} ({} keys)", i + 1, chunk.len());
+    }
+    Ok(total)
+}
+
+/// Accumulate a chunk's result; a null result means every key succeeded
+fn add_chunk_result(total: &mut KvBulkResult, result: Option<KvBulkResult>, chunk_len: usize) {
+    match result {
+        Some(result) => {
+            total.successful_key_count += result.successful_key_count;
+            total.unsuccessful_keys.extend(result.unsuccessful_keys);
+        }
+        None => total.successful_key_count += chunk_len,
+    }
+}
+
+// ============================================================================
+// Export
+// ============================================================================
+
+/// Write every value of a namespace to `dir/values`, one file per key, plus
+/// a `manifest.json` mapping file names back to keys with their expiration
+/// and metadata. Keys are fetched a page at a time, so memory use is bounded
+/// by the page size rather than the namespace size. Returns the number of
+/// keys.
+pub async fn export_namespace(
+    client: &CloudflareClient,
+    account_id: &str,
+    namespace_id: &str,
+    prefix: Option<&str>,
+    dir: &Path,
+) -> Result<usize> {
+    std::fs::create_dir_all(dir.join(EXPORT_VALUES_DIR))?;
+    let mut manifest = Vec::new();
+    let mut used_names = HashSet::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let (keys, next) =
+            list_keys_page(client, account_id, namespace_id, prefix, cursor.as_deref()).await?;
+        for key in keys {
+            let value = get_value(client, account_id, namespace_id, &key.name).await?;
+            let file = format!(
+                "{}/{}",
+                EXPORT_VALUES_DIR,
+                export_file_name(&key.name, &mut used_names)
+            );
+            std::fs::write(dir.join(&file), value)?;
+            manifest.push(KvExportEntry {
+                key: key.name,
+                file,
+                expiration: key.expiration,
+                metadata: key.metadata,
+            });
+        }
+        println!("  {} keys exported", manifest.len());
+        cursor = next;
+        if cursor.is_none() {
+            break;
+        }
+    }
+
+    std::fs::write(
+        dir.join("manifest.json"),
+        serde_json::to_string_pretty(&manifest)?,
+    )?;
+    println!("✓ Exported {} keys to {}", manifest.len(), dir.display());
+    Ok(manifest.len())
+}
+
+/// File name for an exported value: the percent-encoded key, or when that is
+/// too long for the filesystem (or already taken), a truncated stem with a
+/// `~N` suffix. The manifest maps names back to keys either way.
+fn export_file_name(key: &str, used: &mut HashSet<String>) -> String {
+    let encoded = encode_key(key);
+    let stem = &encoded[..encoded.len().min(EXPORT_NAME_STEM)];
+    let mut name = encoded.clone();
+    let mut suffix = used.len();
+    while name.len() > MAX_FILE_NAME || used.contains(&name) {
+        suffix += 1;
+        name = format!("{}~{}", stem, suffix);
+    }
+    used.insert(name.clone());
+    name
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_key() {
+        assert_eq!(encode_key("user:42/profile"), "user%3A42%2Fprofile");
+        assert_eq!(encode_key("a b~c.d"), "a%20b~c.d");
+        assert_eq!(encode_key("é"), "%C3%A9");
+    }
+
+    #[test]
+    fn test_export_file_name_fits_filesystem() {
+        let mut used = HashSet::new();
+        assert_eq!(export_file_name("user:42", &mut used), "user%3A42");
+
+        let long = "/".repeat(512);
+        let name = export_file_name(&long, &mut used);
+        assert!(name.len() <= MAX_FILE_NAME);
+        assert_ne!(export_file_name(&long, &mut used), name);
+    }
+
+    #[test]
+    fn test_validate_key_and_ttl() {
+        assert!(validate_key("config").is_ok());
+        assert!(validate_key("").is_err());
+        assert!(validate_key("..").is_err());
+        assert!(validate_key(&"k".repeat(513)).is_err());
+
+        assert!(validate_expiration_ttl(60).is_ok());
+        assert!(validate_expiration_ttl(30).is_err());
+    }
+
+    #[test]
+    fn test_parse_bulk_delete_keys() {
+        let keys = parse_bulk_delete_keys(r#"["a", {"key": "b", "value": "x"}]"#).unwrap();
+        assert_eq!(keys, vec!["a", "b"]);
+        assert!(parse_bulk_delete_keys(r#"[1]"#).is_err());
+        assert!(parse_bulk_delete_keys(r#"{"key": "a"}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_bulk_entries_rejects_short_ttl() {
+        assert_eq!(
+            parse_bulk_entries(r#"[{"key": "a", "value": "1"}]"#)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(
+            parse_bulk_entries(r#"[{"key": "a", "value": "1", "expiration_ttl": 10}]"#).is_err()
+        );
+        assert!(parse_bulk_entries(r#"[{"key": "a"}]"#).is_err());
+    }
+}
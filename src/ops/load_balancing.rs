@@ -0,0 +1,538 @@
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use crate::api::load_balancing::{
+    LoadBalancer, LoadBalancerPayload, Monitor, MonitorPayload, Origin, Pool, PoolHealth,
+    PoolPayload, MONITOR_TYPES, STEERING_POLICIES,
+};
+use crate::client::{CfResponse, CloudflareClient};
+use crate::error::{CfadError, Result};
+
+// ============================================================================
+// Monitors
+// ============================================================================
+
+pub async fn list_monitors(client: &CloudflareClient, account_id: &str) -> Result<Vec<Monitor>> {
+    let endpoint = format!("/accounts/{}/load_balancers/monitors", account_id);
+    let response: CfResponse<Vec<Monitor>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+pub async fn get_monitor(
+    client: &CloudflareClient,
+    account_id: &str,
+    monitor_id: &str,
+) -> Result<Monitor> {
+    let endpoint = format!(
+        "/accounts/{}/load_balancers/monitors/{}",
+        account_id, monitor_id
+    );
+    let response: CfResponse<Monitor> = client.get(&endpoint).await?;
+    response
+        .result
+        .ok_or_else(|| CfadError::not_found("Monitor", monitor_id))
+}
+
+pub async fn create_monitor(
+    client: &CloudflareClient,
+    account_id: &str,
+    monitor: MonitorPayload,
+) -> Result<Monitor> {
+    validate_monitor(&monitor)?;
+    let endpoint = format!("/accounts/{}/load_balancers/monitors", account_id);
+    let response: CfResponse<Monitor> = client.post(&endpoint, monitor).await?;
+    let monitor = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create monitor"))?;
+    println!("✓ Created monitor: {}", monitor.id);
+    Ok(monitor)
+}
+
+pub async fn update_monitor(
+    client: &CloudflareClient,
+    account_id: &str,
+    monitor_id: &str,
+    monitor: MonitorPayload,
+) -> Result<Monitor> {
+    if monitor.is_empty() {
+        return Err(CfadError::validation("No changes specified"));
+    }
+    validate_monitor(&monitor)?;
+    let endpoint = format!(
+        "/accounts/{}/load_balancers/monitors/{}",
+        account_id, monitor_id
+    );
+    let response: CfResponse<Monitor> = client.patch(&endpoint, monitor).await?;
+    let monitor = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from update monitor"))?;
+    println!("✓ Updated monitor: {}", monitor.id);
+    Ok(monitor)
+}
+
+pub async fn delete_monitor(
+    client: &CloudflareClient,
+    account_id: &str,
+    monitor_id: &str,
+) -> Result<()> {
+    let endpoint = format!(
+        "/accounts/{}/load_balancers/monitors/{}",
+        account_id, monitor_id
+    );
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted monitor: {}", monitor_id);
+    Ok(())
+}
+
+fn validate_monitor(monitor: &MonitorPayload) -> Result<()> {
+    if let Some(monitor_type) = &monitor.r#type {
+        if !MONITOR_TYPES.contains(&monitor_type.as_str()) {
+            return Err(CfadError::validation(format!(
+                "Invalid monitor type '{}'. Must be one of: {}",
+                monitor_type,
+                MONITOR_TYPES.join(", ")
+            )));
+        }
+    }
+    if let (Some(interval), Some(timeout)) = (monitor.interval, monitor.timeout) {
+        if timeout >= interval {
+            return Err(CfadError::validation(
+                "Monitor timeout must be shorter than the interval",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Parse repeated `Name: value` header flags into the API's header map
+pub fn parse_headers(headers: &[String]) -> Result<Option<BTreeMap<String, Vec<String>>>> {
+    if headers.is_empty() {
+        return Ok(None);
+    }
+    let mut map: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for header in headers {
+        let (name, value) = header.split_once(':').ok_or_else(|| {
+            CfadError::validation(format!(
+                "Invalid header '{}': expected 'Name: value'",
+                header
+            ))
+        })?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(CfadError::validation(format!(
+                "Invalid header '{}': name is empty",
+                header
+            )));
+        }
+        map.entry(name.to_string())
+            .or_default()
+            .push(value.trim().to_string());
+    }
+    Ok(Some(map))
+}
+
+// ============================================================================
+// Pools
+// ============================================================================
+
+pub async fn list_pools(client: &CloudflareClient, account_id: &str) -> Result<Vec<Pool>> {
+    let endpoint = format!("/accounts/{}/load_balancers/pools", account_id);
+    let response: CfResponse<Vec<Pool>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Find a pool by ID or name
+pub async fn get_pool(client: &CloudflareClient, account_id: &str, pool: &str) -> Result<Pool> {
+    list_pools(client, account_id)
+        .await?
+        .into_iter()
+        .find(|p| p.id == pool || p.name == pool)
+        .ok_or_else(|| CfadError::not_found("Pool", pool))
+}
+
+pub async fn create_pool(
+    client: &CloudflareClient,
+    account_id: &str,
+    pool: PoolPayload,
+) -> Result<Pool> {
+    if pool.origins.as_ref().is_none_or(Vec::is_empty) {
+        return Err(CfadError::validation("A pool needs at least one origin"));
+    }
+    let endpoint = format!("/accounts/{}/load_balancers/pools", account_id);
+    let response: CfResponse<Pool> = client.post(&endpoint, pool).await?;
+    let pool = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create pool"))?;
+    println!("✓ Created pool: {} ({})", pool.name, pool.id);
+    Ok(pool)
+}
+
+pub async fn update_pool(
+    client: &CloudflareClient,
+    account_id: &str,
+    pool_id: &str,
+    pool: PoolPayload,
+) -> Result<Pool> {
+    if pool.is_empty() {
+        return Err(CfadError::validation("No changes specified"));
+    }
+    if pool.origins.as_ref().is_some_and(Vec::is_empty) {
+        return Err(CfadError::validation("A pool needs at least one origin"));
+    }
+    let endpoint = format!("/accounts/{}/load_balancers/pools/{}", account_id, pool_id);
+    let response: CfResponse<Pool> = client.patch(&endpoint, pool).await?;
+    let pool = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from update pool"))?;
+    println!("✓ Updated pool: {}", pool.name);
+    Ok(pool)
+}
+
+pub async fn delete_pool(client: &CloudflareClient, account_id: &str, pool_id: &str) -> Result<()> {
+    let endpoint = format!("/accounts/{}/load_balancers/pools/{}", account_id, pool_id);
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted pool: {}", pool_id);
+    Ok(())
+}
+
+pub async fn get_pool_health(
+    client: &CloudflareClient,
+    account_id: &str,
+    pool_id: &str,
+) -> Result<PoolHealth> {
+    let endpoint = format!(
+        "/accounts/{}/load_balancers/pools/{}/health",
+        account_id, pool_id
+    );
+    let response: CfResponse<PoolHealth> = client.get(&endpoint).await?;
+    response
+        .result
+        .ok_or_else(|| CfadError::not_found("Pool health", pool_id))
+}
+
+/// Change one origin in a pool and save the pool's origin list.
+///
+/// Returns the origin as it was before the change.
+pub async fn modify_origin(
+    client: &CloudflareClient,
+    account_id: &str,
+    pool: &str,
+    origin: &str,
+    change: impl FnOnce(&mut Origin),
+) -> Result<Origin> {
+    let mut pool = get_pool(client, account_id, pool).await?;
+    let target = pool
+        .origin_mut(origin)
+        .ok_or_else(|| CfadError::not_found("Origin", origin))?;
+    let previous = target.clone();
+    change(target);
+
+    let payload = PoolPayload {
+        origins: Some(pool.origins),
+        ..Default::default()
+    };
+    let endpoint = format!("/accounts/{}/load_balancers/pools/{}", account_id, pool.id);
+    let _response: CfResponse<Pool> = client.patch(&endpoint, payload).await?;
+    Ok(previous)
+}
+
+/// Stop sending new traffic to an origin by setting its weight to 0, then
+/// wait for existing (session-affinity) traffic to move off it.
+///
+/// Returns the origin as it was before draining, so callers can restore it.
+pub async fn drain_origin(
+    client: &CloudflareClient,
+    account_id: &str,
+    pool: &str,
+    origin: &str,
+    wait: Duration,
+    disable: bool,
+) -> Result<Origin> {
+    let previous = modify_origin(client, account_id, pool, origin, |o| o.weight = 0.0).await?;
+    println!(
+        "✓ Set weight of {} to 0 (was {})",
+        previous.name, previous.weight
+    );
+
+    if !wait.is_zero() {
+        println!(
+            "  Waiting {}s for existing sessions to finish...",
+            wait.as_secs()
+        );
+        tokio::time::sleep(wait).await;
+    }
+
+    if disable {
+        modify_origin(client, account_id, pool, origin, |o| o.enabled = false).await?;
+        println!("✓ Disabled origin: {}", previous.name);
+    }
+    println!("✓ Drained origin: {}", previous.name);
+    Ok(previous)
+}
+
+/// Parse an `--origin` flag.
+///
+/// Accepts a bare address (`192.0.2.1`, named after the address) or
+/// comma-separated `key=value` pairs: `name=web1,address=192.0.2.1,weight=0.5`.
+pub fn parse_origin(spec: &str) -> Result<Origin> {
+    if !spec.contains('=') {
+        let address = spec.trim();
+        if address.is_empty() {
+            return Err(CfadError::validation("Origin address is empty"));
+        }
+        return Ok(Origin {
+            name: address.to_string(),
+            address: address.to_string(),
+            enabled: true,
+            weight: 1.0,
+            header: None,
+            extra: Default::default(),
+        });
+    }
+
+    let mut name = None;
+    let mut address = None;
+    let mut enabled = true;
+    let mut weight = 1.0;
+    for pair in spec.split(',') {
+        let (key, value) = pair.split_once('=').ok_or_else(|| {
+            CfadError::validation(format!("Invalid origin '{}': expected key=value", spec))
+        })?;
+        let value = value.trim();
+        match key.trim() {
+            "name" => name = Some(value.to_string()),
+            "address" => address = Some(value.to_string()),
+            "weight" => weight = parse_weight(value)?,
+            "enabled" => {
+                enabled = value.parse().map_err(|_| {
+                    CfadError::validation(format!(
+                        "Invalid origin '{}': enabled must be true or false",
+                        spec
+                    ))
+                })?
+            }
+            other => {
+                return Err(CfadError::validation(format!(
+                "Invalid origin '{}': unknown key '{}' (expected name, address, weight, enabled)",
+                spec, other
+            )))
+            }
+        }
+    }
+
+    let address = address.ok_or_else(|| {
+        CfadError::validation(format!("Invalid origin '{}': address is required", spec))
+    })?;
+    Ok(Origin {
+        name: name.unwrap_or_else(|| address.clone()),
+        address,
+        enabled,
+        weight,
+        header: None,
+        extra: Default::default(),
+    })
+}
+
+/// Parse an origin weight, which must be between 0 and 1
+pub fn parse_weight(value: &str) -> Result<f64> {
+    let weight: f64 = value
+        .parse()
+        .map_err(|_| CfadError::validation(format!("Invalid weight '{}'", value)))?;
+    if !(0.0..=1.0).contains(&weight) {
+        return Err(CfadError::validation(format!(
+            "Invalid weight {}: must be between 0 and 1",
+            weight
+        )));
+    }
+    Ok(weight)
+}
+
+// ============================================================================
+// Load Balancers
+// ============================================================================
+
+pub async fn list_load_balancers(
+    client: &CloudflareClient,
+    zone_id: &str,
+) -> Result<Vec<LoadBalancer>> {
+    let endpoint = format!("/zones/{}/load_balancers", zone_id);
+    let response: CfResponse<Vec<LoadBalancer>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Find a load balancer by ID or hostname
+pub async fn get_load_balancer(
+    client: &CloudflareClient,
+    zone_id: &str,
+    load_balancer: &str,
+) -> Result<LoadBalancer> {
+    list_load_balancers(client, zone_id)
+        .await?
+        .into_iter()
+        .find(|lb| lb.id == load_balancer || lb.name.eq_ignore_ascii_case(load_balancer))
+        .ok_or_else(|| CfadError::not_found("Load balancer", load_balancer))
+}
+
+pub async fn create_load_balancer(
+    client: &CloudflareClient,
+    zone_id: &str,
+    load_balancer: LoadBalancerPayload,
+) -> Result<LoadBalancer> {
+    validate_load_balancer(&load_balancer)?;
+    if load_balancer
+        .default_pools
+        .as_ref()
+        .is_none_or(Vec::is_empty)
+    {
+        return Err(CfadError::validation(
+            "A load balancer needs at least one default pool",
+        ));
+    }
+    let endpoint = format!("/zones/{}/load_balancers", zone_id);
+    let response: CfResponse<LoadBalancer> = client.post(&endpoint, load_balancer).await?;
+    let load_balancer = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create load balancer"))?;
+    println!("✓ Created load balancer: {}", load_balancer.name);
+    Ok(load_balancer)
+}
+
+pub async fn update_load_balancer(
+    client: &CloudflareClient,
+    zone_id: &str,
+    load_balancer_id: &str,
+    load_balancer: LoadBalancerPayload,
+) -> Result<LoadBalancer> {
+    if load_balancer.is_empty() {
+        return Err(CfadError::validation("No changes specified"));
+    }
+    validate_load_balancer(&load_balancer)?;
+    let endpoint = format!("/zones/{}/load_balancers/{}", zone_id, load_balancer_id);
+    let response: CfResponse<LoadBalancer> = client.patch(&endpoint, load_balancer).await?;
+    let load_balancer = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from update load balancer"))?;
+    println!("✓ Updated load balancer: {}", load_balancer.name);
+    Ok(load_balancer)
+}
+
+pub async fn delete_load_balancer(
+    client: &CloudflareClient,
+    zone_id: &str,
+    load_balancer_id: &str,
+) -> Result<()> {
+    let endpoint = format!("/zones/{}/load_balancers/{}", zone_id, load_balancer_id);
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted load balancer: {}", load_balancer_id);
+    Ok(())
+}
+
+fn validate_load_balancer(load_balancer: &LoadBalancerPayload) -> Result<()> {
+    if let Some(policy) = &load_balancer.steering_policy {
+        if !STEERING_POLICIES.contains(&policy.as_str()) {
+            return Err(CfadError::validation(format!(
+                "Invalid steering policy '{}'. Must be one of: {}",
+                policy,
+                STEERING_POLICIES.join(", ")
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// Resolve pool names or IDs to pool IDs
+pub fn resolve_pool_ids(pools: &[Pool], identifiers: &[String]) -> Result<Vec<String>> {
+    identifiers
+        .iter()
+        .map(|identifier| {
+            pools
+                .iter()
+                .find(|p| &p.id == identifier || &p.name == identifier)
+                .map(|p| p.id.clone())
+                .ok_or_else(|| CfadError::not_found("Pool", identifier))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_origin_bare_address() {
+        let origin = parse_origin("192.0.2.1").unwrap();
+        assert_eq!(origin.name, "192.0.2.1");
+        assert_eq!(origin.address, "192.0.2.1");
+        assert!(origin.enabled);
+        assert_eq!(origin.weight, 1.0);
+    }
+
+    #[test]
+    fn test_parse_origin_key_values() {
+        let origin = parse_origin("name=web1, address=origin1.example.com, weight=0.25").unwrap();
+        assert_eq!(origin.name, "web1");
+        assert_eq!(origin.address, "origin1.example.com");
+        assert_eq!(origin.weight, 0.25);
+
+        let origin = parse_origin("address=192.0.2.2,enabled=false").unwrap();
+        assert_eq!(origin.name, "192.0.2.2");
+        assert!(!origin.enabled);
+    }
+
+    #[test]
+    fn test_parse_origin_errors() {
+        assert!(parse_origin("name=web1").is_err());
+        assert!(parse_origin("address=192.0.2.1,weight=2").is_err());
+        assert!(parse_origin("address=192.0.2.1,port=80").is_err());
+        assert!(parse_origin("").is_err());
+    }
+
+    #[test]
+    fn test_parse_headers() {
+        let headers = parse_headers(&[
+            "Host: example.com".to_string(),
+            "X-Check: a".to_string(),
+            "X-Check: b".to_string(),
+        ])
+        .unwrap()
+        .unwrap();
+        assert_eq!(headers["Host"], vec!["example.com"]);
+        assert_eq!(headers["X-Check"], vec!["a", "b"]);
+        assert!(parse_headers(&[]).unwrap().is_none());
+        assert!(parse_headers(&["no-colon".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_validate_monitor() {
+        let monitor = MonitorPayload {
+            r#type: Some("https".to_string()),
+            interval: Some(60),
+            timeout: Some(5),
+            ..Default::default()
+        };
+        assert!(validate_monitor(&monitor).is_ok());
+        let monitor = MonitorPayload {
+            interval: Some(10),
+            timeout: Some(10),
+            ..Default::default()
+        };
+        assert!(validate_monitor(&monitor).is_err());
+        let monitor = MonitorPayload {
+            r#type: Some("ftp".to_string()),
+            ..Default::default()
+        };
+        assert!(validate_monitor(&monitor).is_err());
+    }
+
+    #[test]
+    fn test_resolve_pool_ids() {
+        let pools: Vec<Pool> = serde_json::from_value(serde_json::json!([
+            {"id": "p1", "name": "primary"},
+            {"id": "p2", "name": "secondary"}
+        ]))
+        .unwrap();
+        let ids = resolve_pool_ids(&pools, &["secondary".to_string(), "p1".to_string()]).unwrap();
+        assert_eq!(ids, vec!["p2", "p1"]);
+        assert!(resolve_pool_ids(&pools, &["missing".to_string()]).is_err());
+    }
+}
@@ -1,9 +1,22 @@
 pub mod analytics;
+pub mod audit;
 pub mod cache;
+pub mod certificates;
+pub mod custom_hostnames;
 pub mod d1;
+pub mod d1_migrations;
+pub mod d1_shell;
 pub mod dns;
 pub mod firewall;
+pub mod kv;
+pub mod load_balancing;
+pub mod page_rules;
 pub mod pages;
+pub mod queues;
 pub mod r2;
+pub mod rulesets;
+pub mod ssl;
 pub mod token;
+pub mod tunnel;
+pub mod workers;
 pub mod zone;
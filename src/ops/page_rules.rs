@@ -0,0 +1,658 @@
+use serde_json::{json, Map, Value};
+
+use crate::api::page_rules::{PageRule, PageRuleAction};
+use crate::api::rulesets::{
+    RuleInput, RulesetScope, UpdateEntrypoint, PHASE_CACHE_SETTINGS, PHASE_CONFIG_SETTINGS,
+    PHASE_DYNAMIC_REDIRECT, PHASE_ORIGIN,
+};
+use crate::client::{CfResponse, CloudflareClient};
+use crate::error::{CfadError, Result};
+use crate::ops::rulesets;
+
+/// List page rules for a zone, highest priority (evaluated first) first
+pub async fn list_page_rules(client: &CloudflareClient, zone_id: &str) -> Result<Vec<PageRule>> {
+    let endpoint = format!("/zones/{}/pagerules?order=priority&direction=desc", zone_id);
+    let response: CfResponse<Vec<PageRule>> = client.get(&endpoint).await?;
+    let mut rules = response.result.unwrap_or_default();
+    rules.sort_by_key(|r| std::cmp::Reverse(r.priority));
+    Ok(rules)
+}
+
+pub async fn get_page_rule(
+    client: &CloudflareClient,
+    zone_id: &str,
+    rule_id: &str,
+) -> Result<PageRule> {
+    let endpoint = format!("/zones/{}/pagerules/{}", zone_id, rule_id);
+    let response: CfResponse<PageRule> = client.get(&endpoint).await?;
+    response
+        .result
+        .ok_or_else(|| CfadError::not_found("Page rule", rule_id))
+}
+
+pub async fn delete_page_rule(
+    client: &CloudflareClient,
+    zone_id: &str,
+    rule_id: &str,
+) -> Result<()> {
+    let endpoint = format!("/zones/{}/pagerules/{}", zone_id, rule_id);
+    let _response: CfResponse<Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted page rule: {}", rule_id);
+    Ok(())
+}
+
+/// A rule generated from (part of) a page rule
+#[derive(Debug, Clone)]
+pub struct MigratedRule {
+    pub phase: &'static str,
+    pub rule: RuleInput,
+}
+
+/// Translation of a single page rule into modern Rules
+#[derive(Debug, Clone)]
+pub struct PageRuleMigration {
+    pub page_rule: PageRule,
+    pub rules: Vec<MigratedRule>,
+    /// Actions (or pattern features) with no equivalent, with the reason
+    pub untranslated: Vec<String>,
+    /// Actions dropped because they match the default behaviour
+    pub notes: Vec<String>,
+}
+
+/// Translate page rules into redirect, cache, origin and configuration rules.
+///
+/// The result is ordered by page rule priority, highest first.
+pub fn plan_migration(page_rules: &[PageRule]) -> Vec<PageRuleMigration> {
+    let mut sorted: Vec<&PageRule> = page_rules.iter().collect();
+    sorted.sort_by_key(|r| std::cmp::Reverse(r.priority));
+    sorted.into_iter().map(translate_page_rule).collect()
+}
+
+/// Group planned rules by phase in the order they should be appended.
+///
+/// Only the first matching page rule applies, while settings from every
+/// matching cache, origin and configuration rule are merged with later rules
+/// winning. Those phases therefore get the highest priority page rule last;
+/// redirects stop at the first match and keep priority order.
+pub fn rules_by_phase(plan: &[PageRuleMigration]) -> Vec<(&'static str, Vec<RuleInput>)> {
+    [
+        PHASE_DYNAMIC_REDIRECT,
+        PHASE_ORIGIN,
+        PHASE_CACHE_SETTINGS,
+        PHASE_CONFIG_SETTINGS,
+    ]
+    .into_iter()
+    .filter_map(|phase| {
+        let mut rules: Vec<RuleInput> = plan
+            .iter()
+            .flat_map(|m| &m.rules)
+            .filter(|r| r.phase == phase)
+            .map(|r| r.rule.clone())
+            .collect();
+        if phase != PHASE_DYNAMIC_REDIRECT {
+            rules.reverse();
+        }
+        (!rules.is_empty()).then_some((phase, rules))
+    })
+    .collect()
+}
+
+/// Prefix of the description given to every rule migrated from a page rule
+const MIGRATED_PREFIX: &str = "Migrated from page rule ";
+
+/// ID of the page rule a ruleset rule was migrated from, read from its
+/// description
+fn migrated_from(description: Option<&str>) -> Option<&str> {
+    description?
+        .strip_prefix(MIGRATED_PREFIX)?
+        .split_once(':')
+        .map(|(id, _)| id)
+}
+
+/// Append the planned rules to each phase entrypoint, keeping existing rules.
+///
+/// Rules are added one at a time to an existing entrypoint, so the rules it
+/// already holds keep their IDs and every field; the entrypoint is only
+/// written as a whole when the phase has none yet. Page rules already
+/// migrated to a phase (by an earlier run) are skipped, so applying the same
+/// plan twice does not duplicate rules. Page rules are left in place so they
+/// can be removed once the new rules have been verified.
+pub async fn apply_migration(
+    client: &CloudflareClient,
+    zone_id: &str,
+    plan: &[PageRuleMigration],
+) -> Result<usize> {
+    let scope = RulesetScope::Zone(zone_id.to_string());
+    let mut added = 0;
+    for (phase, new_rules) in rules_by_phase(plan) {
+        let existing = match rulesets::get_entrypoint(client, &scope, phase).await {
+            Ok(ruleset) => Some(ruleset),
+            Err(CfadError::NotFound { .. }) => None,
+            Err(e) => return Err(e),
+        };
+        let migrated: Vec<&str> = existing
+            .iter()
+            .flat_map(|ruleset| &ruleset.rules)
+            .filter_map(|r| migrated_from(r.description.as_deref()))
+            .collect();
+        let (skipped, new_rules): (Vec<RuleInput>, Vec<RuleInput>) =
+            new_rules.into_iter().partition(|r| {
+                migrated_from(r.description.as_deref()).is_some_and(|id| migrated.contains(&id))
+            });
+        if !skipped.is_empty() {
+            println!(
+                "  Skipping {} rule(s) already migrated to {}",
+                skipped.len(),
+                phase
+            );
+        }
+        if new_rules.is_empty() {
+            continue;
+        }
+
+        added += new_rules.len();
+        if existing.is_some() {
+            for rule in new_rules {
+                rulesets::add_rule(client, &scope, phase, rule).await?;
+            }
+        } else {
+            let update = UpdateEntrypoint {
+                description: None,
+                rules: new_rules,
+            };
+            rulesets::put_entrypoint(client, &scope, phase, update).await?;
+        }
+    }
+    Ok(added)
+}
+
+pub fn translate_page_rule(page_rule: &PageRule) -> PageRuleMigration {
+    let mut migration = PageRuleMigration {
+        page_rule: page_rule.clone(),
+        rules: Vec::new(),
+        untranslated: Vec::new(),
+        notes: Vec::new(),
+    };
+
+    let pattern = match page_rule.url_pattern() {
+        Some(pattern) => pattern,
+        None => {
+            migration
+                .untranslated
+                .push("rule has no URL target".to_string());
+            return migration;
+        }
+    };
+    let matcher = match UrlMatcher::new(pattern) {
+        Ok(matcher) => matcher,
+        Err(reason) => {
+            migration.untranslated.push(reason);
+            return migration;
+        }
+    };
+    if matcher.has_port {
+        migration
+            .untranslated
+            .push("port in URL pattern (http.request.full_uri has no port)".to_string());
+    }
+
+    let enabled = Some(page_rule.is_active());
+    let description = Some(format!("{}{}: {}", MIGRATED_PREFIX, page_rule.id, pattern));
+    let rule = |action: &str, expression: String, params: Value| RuleInput {
+        action: action.to_string(),
+        expression,
+        description: description.clone(),
+        enabled,
+        action_parameters: Some(params),
+        ..Default::default()
+    };
+
+    let mut cache = Map::new();
+    let mut config = Map::new();
+    let mut origin = Map::new();
+
+    for action in &page_rule.actions {
+        match translate_action(action, &matcher, &mut cache, &mut config, &mut origin) {
+            Translation::Redirect {
+                expression,
+                from_value,
+            } => migration.rules.push(MigratedRule {
+                phase: PHASE_DYNAMIC_REDIRECT,
+                rule: rule("redirect", expression, json!({ "from_value": from_value })),
+            }),
+            Translation::Merged => {}
+            Translation::Default(note) => {
+                migration
+                    .notes
+                    .push(format!("{}: {}", action.summary(), note))
+            }
+            Translation::Unsupported(reason) => {
+                migration
+                    .untranslated
+                    .push(format!("{}: {}", action.summary(), reason))
+            }
+        }
+    }
+
+    if !origin.is_empty() {
+        migration.rules.push(MigratedRule {
+            phase: PHASE_ORIGIN,
+            rule: rule("route", matcher.expression(), Value::Object(origin)),
+        });
+    }
+    if !cache.is_empty() {
+        migration.rules.push(MigratedRule {
+            phase: PHASE_CACHE_SETTINGS,
+            rule: rule(
+                "set_cache_settings",
+                matcher.expression(),
+                Value::Object(cache),
+            ),
+        });
+    }
+    if !config.is_empty() {
+        migration.rules.push(MigratedRule {
+            phase: PHASE_CONFIG_SETTINGS,
+            rule: rule("set_config", matcher.expression(), Value::Object(config)),
+        });
+    }
+
+    migration
+}
+
+enum Translation {
+    /// A standalone redirect rule
+    Redirect {
+        expression: String,
+        from_value: Value,
+    },
+    /// Merged into the cache, config or origin rule for this page rule
+    Merged,
+    /// Nothing to migrate; the modern default already behaves this way
+    Default(&'static str),
+    Unsupported(&'static str),
+}
+
+fn translate_action(
+    action: &PageRuleAction,
+    matcher: &UrlMatcher,
+    cache: &mut Map<String, Value>,
+    config: &mut Map<String, Value>,
+    origin: &mut Map<String, Value>,
+) -> Translation {
+    let value = &action.value;
+    let text = value.as_str();
+    let on = text.map(|s| s == "on");
+
+    match (action.id.as_str(), text) {
+        ("forwarding_url", _) => {
+            let url = value.get("url").and_then(Value::as_str);
+            let status = value.get("status_code").and_then(Value::as_u64);
+            match (url, status) {
+                (Some(url), Some(status)) => Translation::Redirect {
+                    expression: matcher.expression(),
+                    from_value: matcher.redirect_target(url, status),
+                },
+                _ => Translation::Unsupported("missing url or status_code"),
+            }
+        }
+        ("always_use_https", _) => Translation::Redirect {
+            expression: format!("{} and not ssl", matcher.expression()),
+            from_value: json!({
+                "status_code": 301,
+                "target_url": {
+                    "expression": "concat(\"https://\", http.host, http.request.uri.path)"
+                },
+                "preserve_query_string": true
+            }),
+        },
+
+        ("cache_level", Some("bypass")) => merge(cache, "cache", json!(false)),
+        ("cache_level", Some("cache_everything")) => merge(cache, "cache", json!(true)),
+        ("cache_level", Some("aggressive")) => {
+            Translation::Default("standard caching is the default")
+        }
+        ("cache_level", _) => {
+            Translation::Unsupported("query string caching needs a custom cache key")
+        }
+        ("edge_cache_ttl", _) => merge(
+            cache,
+            "edge_ttl",
+            json!({ "mode": "override_origin", "default": value }),
+        ),
+        ("browser_cache_ttl", _) if value.as_u64() == Some(0) => {
+            merge(cache, "browser_ttl", json!({ "mode": "respect_origin" }))
+        }
+        ("browser_cache_ttl", _) => merge(
+            cache,
+            "browser_ttl",
+            json!({ "mode": "override_origin", "default": value }),
+        ),
+        ("cache_deception_armor", Some(_)) => merge_object(
+            cache,
+            "cache_key",
+            "cache_deception_armor",
+            json!(on == Some(true)),
+        ),
+        ("origin_error_page_pass_thru", Some(_)) => {
+            merge(cache, "origin_error_page_passthru", json!(on == Some(true)))
+        }
+        ("respect_strong_etag", Some(_)) => {
+            merge(cache, "respect_strong_etags", json!(on == Some(true)))
+        }
+
+        ("ssl", Some(mode)) => merge(config, "ssl", json!(mode)),
+        ("security_level", Some(level)) => merge(config, "security_level", json!(level)),
+        ("polish", Some(mode)) => merge(config, "polish", json!(mode)),
+        ("automatic_https_rewrites", Some(_)) => {
+            merge(config, "automatic_https_rewrites", json!(on == Some(true)))
+        }
+        ("browser_check", Some(_)) => merge(config, "bic", json!(on == Some(true))),
+        ("email_obfuscation", Some(_)) => {
+            merge(config, "email_obfuscation", json!(on == Some(true)))
+        }
+        ("rocket_loader", Some(_)) => merge(config, "rocket_loader", json!(on == Some(true))),
+        ("mirage", Some(_)) => merge(config, "mirage", json!(on == Some(true))),
+        ("opportunistic_encryption", Some(_)) => {
+            merge(config, "opportunistic_encryption", json!(on == Some(true)))
+        }
+        ("server_side_exclude", Some(_)) => {
+            merge(config, "server_side_excludes", json!(on == Some(true)))
+        }
+        ("hotlink_protection", Some(_)) => {
+            merge(config, "hotlink_protection", json!(on == Some(true)))
+        }
+        ("disable_apps", _) => merge(config, "disable_apps", json!(true)),
+        ("disable_zaraz", _) => merge(config, "disable_zaraz", json!(true)),
+        ("disable_performance", _) => {
+            config.insert("rocket_loader".to_string(), json!(false));
+            config.insert("mirage".to_string(), json!(false));
+            merge(config, "polish", json!("off"))
+        }
+
+        ("host_header_override", Some(host)) => merge(origin, "host_header", json!(host)),
+        ("resolve_override", Some(host)) => merge_object(origin, "origin", "host", json!(host)),
+
+        ("disable_security", _) => Translation::Unsupported("deprecated; no equivalent setting"),
+        ("minify", _) => Translation::Unsupported("Auto Minify has been retired"),
+        _ => Translation::Unsupported("no modern Rules equivalent; migrate manually"),
+    }
+}
+
+fn merge(target: &mut Map<String, Value>, key: &str, value: Value) -> Translation {
+    target.insert(key.to_string(), value);
+    Translation::Merged
+}
+
+fn merge_object(
+    target: &mut Map<String, Value>,
+    key: &str,
+    field: &str,
+    value: Value,
+) -> Translation {
+    let entry = target.entry(key.to_string()).or_insert_with(|| json!({}));
+    if let Some(object) = entry.as_object_mut() {
+        object.insert(field.to_string(), value);
+    }
+    Translation::Merged
+}
+
+/// Page rule URL pattern converted to an `http.request.full_uri` wildcard
+struct UrlMatcher {
+    /// Pattern matched against the full URI, always starting with a scheme or `*`
+    pattern: String,
+    /// Wildcards added in front of the original pattern, shifting `$N` references
+    shift: usize,
+    has_port: bool,
+}
+
+impl UrlMatcher {
+    fn new(pattern: &str) -> std::result::Result<Self, String> {
+        let lower = pattern.to_ascii_lowercase();
+        let (mut normalized, shift, rest) =
+            if lower.starts_with("http://") || lower.starts_with("https://") {
+                let split = pattern.find("://").unwrap_or(0) + 3;
+                (pattern[..split].to_string(), 0, &pattern[split..])
+            } else if pattern.starts_with('*') {
+                (String::new(), 0, pattern)
+            } else {
+                ("http*://".to_string(), 1, pattern)
+            };
+
+        let host = rest.split('/').next().unwrap_or_default();
+        let has_port = host.contains(':');
+        normalized.push_str(rest);
+        if !rest.contains('/') {
+            normalized.push('/');
+        }
+
+        let matcher = Self {
+            pattern: normalized,
+            shift,
+            has_port,
+        };
+        crate::expression::validate(&matcher.expression())
+            .map_err(|e| format!("URL pattern '{}' could not be converted: {}", pattern, e))?;
+        Ok(matcher)
+    }
+
+    fn expression(&self) -> String {
+        format!("http.request.full_uri wildcard {}", quote(&self.pattern))
+    }
+
+    /// `from_value` for a redirect to `url`, rewriting `$N` wildcard references
+    fn redirect_target(&self, url: &str, status: u64) -> Value {
+        let references = regex::Regex::new(r"\$(\d+)").expect("valid regex");
+        if !references.is_match(url) {
+            return json!({
+                "status_code": status,
+                "target_url": { "value": url },
+                "preserve_query_string": true
+            });
+        }
+
+        let replacement = references.replace_all(url, |caps: &regex::Captures| {
+            let index: usize = caps[1].parse().unwrap_or(0);
+            format!("${{{}}}", index + self.shift)
+        });
+        json!({
+            "status_code": status,
+            "target_url": {
+                "expression": format!(
+                    "wildcard_replace(http.request.full_uri, {}, {})",
+                    quote(&self.pattern),
+                    quote(&replacement)
+                )
+            },
+            "preserve_query_string": false
+        })
+    }
+}
+
+/// Quote a value as a Rules language string literal
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_rule(id: &str, priority: i64, pattern: &str, actions: Value) -> PageRule {
+        serde_json::from_value(json!({
+            "id": id,
+            "targets": [{"target": "url", "constraint": {"operator": "matches", "value": pattern}}],
+            "actions": actions,
+            "priority": priority,
+            "status": "active"
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_forwarding_url_with_references() {
+        let rule = page_rule(
+            "pr1",
+            1,
+            "example.com/old/*",
+            json!([{"id": "forwarding_url", "value": {"url": "https://example.com/new/$1", "status_code": 301}}]),
+        );
+        let migration = translate_page_rule(&rule);
+        assert!(migration.untranslated.is_empty());
+        assert_eq!(migration.rules.len(), 1);
+        let migrated = &migration.rules[0];
+        assert_eq!(migrated.phase, PHASE_DYNAMIC_REDIRECT);
+        assert_eq!(
+            migrated.rule.expression,
+            r#"http.request.full_uri wildcard "http*://example.com/old/*""#
+        );
+        let params = migrated.rule.action_parameters.as_ref().unwrap();
+        assert_eq!(
+            params["from_value"]["target_url"]["expression"],
+            r#"wildcard_replace(http.request.full_uri, "http*://example.com/old/*", "https://example.com/new/${2}")"#
+        );
+        assert_eq!(params["from_value"]["status_code"], 301);
+    }
+
+    #[test]
+    fn test_forwarding_url_static_target() {
+        let rule = page_rule(
+            "pr1",
+            1,
+            "*example.com/promo",
+            json!([{"id": "forwarding_url", "value": {"url": "https://example.com/sale", "status_code": 302}}]),
+        );
+        let migration = translate_page_rule(&rule);
+        let params = migration.rules[0].rule.action_parameters.as_ref().unwrap();
+        assert_eq!(
+            params["from_value"]["target_url"]["value"],
+            "https://example.com/sale"
+        );
+        assert_eq!(
+            migration.rules[0].rule.expression,
+            r#"http.request.full_uri wildcard "*example.com/promo""#
+        );
+    }
+
+    #[test]
+    fn test_cache_and_config_actions_merge() {
+        let rule = page_rule(
+            "pr2",
+            1,
+            "https://example.com/static/*",
+            json!([
+                {"id": "cache_level", "value": "cache_everything"},
+                {"id": "edge_cache_ttl", "value": 7200},
+                {"id": "browser_cache_ttl", "value": 0},
+                {"id": "ssl", "value": "strict"},
+                {"id": "rocket_loader", "value": "off"},
+                {"id": "host_header_override", "value": "origin.example.net"}
+            ]),
+        );
+        let migration = translate_page_rule(&rule);
+        assert!(migration.untranslated.is_empty());
+        let phases: Vec<&str> = migration.rules.iter().map(|r| r.phase).collect();
+        assert_eq!(
+            phases,
+            vec![PHASE_ORIGIN, PHASE_CACHE_SETTINGS, PHASE_CONFIG_SETTINGS]
+        );
+
+        let cache = migration.rules[1].rule.action_parameters.as_ref().unwrap();
+        assert_eq!(cache["cache"], true);
+        assert_eq!(cache["edge_ttl"]["default"], 7200);
+        assert_eq!(cache["browser_ttl"]["mode"], "respect_origin");
+
+        let config = migration.rules[2].rule.action_parameters.as_ref().unwrap();
+        assert_eq!(config["ssl"], "strict");
+        assert_eq!(config["rocket_loader"], false);
+
+        let origin = migration.rules[0].rule.action_parameters.as_ref().unwrap();
+        assert_eq!(origin["host_header"], "origin.example.net");
+    }
+
+    #[test]
+    fn test_untranslatable_actions_are_flagged() {
+        let rule = page_rule(
+            "pr3",
+            1,
+            "example.com:8443/*",
+            json!([
+                {"id": "cache_level", "value": "aggressive"},
+                {"id": "disable_security"},
+                {"id": "cache_key_fields", "value": {"query_string": {"exclude": "*"}}}
+            ]),
+        );
+        let migration = translate_page_rule(&rule);
+        assert!(migration.rules.is_empty());
+        assert_eq!(migration.untranslated.len(), 3);
+        assert!(migration.untranslated[0].contains("port"));
+        assert_eq!(migration.notes.len(), 1);
+    }
+
+    #[test]
+    fn test_disabled_page_rule_creates_disabled_rules() {
+        let mut rule = page_rule(
+            "pr4",
+            1,
+            "example.com/*",
+            json!([{"id": "always_use_https"}]),
+        );
+        rule.status = "disabled".to_string();
+        let migration = translate_page_rule(&rule);
+        assert_eq!(migration.rules[0].rule.enabled, Some(false));
+        assert!(migration.rules[0].rule.expression.ends_with("and not ssl"));
+    }
+
+    #[test]
+    fn test_rules_by_phase_ordering() {
+        let rules = vec![
+            page_rule(
+                "low",
+                1,
+                "example.com/*",
+                json!([{"id": "ssl", "value": "full"}]),
+            ),
+            page_rule(
+                "high",
+                2,
+                "example.com/admin/*",
+                json!([{"id": "ssl", "value": "strict"}]),
+            ),
+            page_rule(
+                "redirect-low",
+                3,
+                "example.com/a",
+                json!([{"id": "forwarding_url", "value": {"url": "https://example.com/b", "status_code": 301}}]),
+            ),
+            page_rule(
+                "redirect-high",
+                4,
+                "example.com/c",
+                json!([{"id": "forwarding_url", "value": {"url": "https://example.com/d", "status_code": 301}}]),
+            ),
+        ];
+        let plan = plan_migration(&rules);
+        assert_eq!(plan[0].page_rule.id, "redirect-high");
+
+        let phases = rules_by_phase(&plan);
+        assert_eq!(phases.len(), 2);
+        let (phase, redirects) = &phases[0];
+        assert_eq!(*phase, PHASE_DYNAMIC_REDIRECT);
+        assert!(redirects[0]
+            .description
+            .as_deref()
+            .unwrap()
+            .contains("redirect-high"));
+
+        let (phase, config) = &phases[1];
+        assert_eq!(*phase, PHASE_CONFIG_SETTINGS);
+        // Highest priority last so its settings win
+        assert!(config[1].description.as_deref().unwrap().contains("high"));
+        assert!(config[0].description.as_deref().unwrap().contains("low"));
+    }
+
+    #[test]
+    fn test_quote_escapes() {
+        assert_eq!(quote(r#"a"b\c"#), r#""a\"b\\c""#);
+    }
+}
@@ -0,0 +1,347 @@
+use crate::api::queues::{
+    AckMessages, AckResult, ConsumerSettings, CreateQueue, CreateQueueConsumer, LeaseRef,
+    PullMessages, PulledMessages, Queue, QueueConsumer, RetryLease, SendMessage,
+};
+use crate::client::{CfResponse, CloudflareClient};
+use crate::error::{CfadError, Result};
+
+/// Largest batch an HTTP pull consumer may request
+pub const MAX_PULL_BATCH_SIZE: u32 = 100;
+
+// ============================================================================
+// Queues
+// ============================================================================
+
+pub async fn list_queues(client: &CloudflareClient, account_id: &str) -> Result<Vec<Queue>> {
+    let mut queues = Vec::new();
+    let mut page = 1;
+
+    loop {
+        let endpoint = format!("/accounts/{}/queues?page={}", account_id, page);
+        let response: CfResponse<Vec<Queue>> = client.get(&endpoint).await?;
+        queues.extend(response.result.unwrap_or_default());
+
+        match response.result_info {
+            Some(info) if page < info.total_pages => page += 1,
+            _ => break,
+        }
+    }
+
+    Ok(queues)
+}
+
+pub async fn get_queue(
+    client: &CloudflareClient,
+    account_id: &str,
+    queue_id: &str,
+) -> Result<Queue> {
+    let endpoint = format!("/accounts/{}/queues/{}", account_id, queue_id);
+    let response: CfResponse<Queue> = client.get(&endpoint).await?;
+    response
+        .result
+        .ok_or_else(|| CfadError::not_found("Queue", queue_id))
+}
+
+pub async fn create_queue(
+    client: &CloudflareClient,
+    account_id: &str,
+    name: &str,
+) -> Result<Queue> {
+    validate_queue_name(name)?;
+    let endpoint = format!("/accounts/{}/queues", account_id);
+    let body = CreateQueue {
+        queue_name: name.to_string(),
+    };
+    let response: CfResponse<Queue> = client.post(&endpoint, body).await?;
+    let queue = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create queue"))?;
+    println!("✓ Created queue: {} ({})", queue.queue_name, queue.queue_id);
+    Ok(queue)
+}
+
+pub async fn delete_queue(
+    client: &CloudflareClient,
+    account_id: &str,
+    queue_id: &str,
+) -> Result<()> {
+    let endpoint = format!("/accounts/{}/queues/{}", account_id, queue_id);
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted queue: {}", queue_id);
+    Ok(())
+}
+
+/// Queue names are lowercase letters, digits and dashes, up to 63 characters
+pub fn validate_queue_name(name: &str) -> Result<()> {
+    let valid = !name.is_empty()
+        && name.len() <= 63
+        && !name.starts_with('-')
+        && !name.ends_with('-')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if !valid {
+        return Err(CfadError::validation(format!(
+            "Invalid queue name '{}': use up to 63 lowercase letters, digits and dashes",
+            name
+        )));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Consumers
+// ============================================================================
+
+pub async fn list_consumers(
+    client: &CloudflareClient,
+    account_id: &str,
+    queue_id: &str,
+) -> Result<Vec<QueueConsumer>> {
+    let endpoint = format!("/accounts/{}/queues/{}/consumers", account_id, queue_id);
+    let response: CfResponse<Vec<QueueConsumer>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Build a consumer payload. `script` selects a Worker consumer; without it
+/// the consumer is an HTTP pull consumer.
+pub fn consumer_payload(
+    script: Option<String>,
+    dead_letter_queue: Option<String>,
+    settings: ConsumerSettings,
+) -> Result<CreateQueueConsumer> {
+    let is_worker = script.is_some();
+    if let Some(batch_size) = settings.batch_size {
+        if !(1..=MAX_PULL_BATCH_SIZE).contains(&batch_size) {
+            return Err(CfadError::validation(format!(
+                "Invalid batch size {}: expected 1-{}",
+                batch_size, MAX_PULL_BATCH_SIZE
+            )));
+        }
+    }
+    if is_worker && settings.visibility_timeout_ms.is_some() {
+        return Err(CfadError::validation(
+            "--visibility-timeout-ms only applies to HTTP pull consumers",
+        ));
+    }
+    if !is_worker && (settings.max_wait_time_ms.is_some() || settings.max_concurrency.is_some()) {
+        return Err(CfadError::validation(
+            "--max-wait-ms and --max-concurrency only apply to Worker consumers",
+        ));
+    }
+    Ok(CreateQueueConsumer {
+        r#type: if is_worker { "worker" } else { "http_pull" }.to_string(),
+        script_name: script,
+        dead_letter_queue,
+        settings,
+    })
+}
+
+pub async fn create_consumer(
+    client: &CloudflareClient,
+    account_id: &str,
+    queue_id: &str,
+    consumer: CreateQueueConsumer,
+) -> Result<QueueConsumer> {
+    let endpoint = format!("/accounts/{}/queues/{}/consumers", account_id, queue_id);
+    let response: CfResponse<QueueConsumer> = client.post(&endpoint, consumer).await?;
+    let consumer = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create consumer"))?;
+    println!(
+        "✓ Added {} consumer to queue {}",
+        consumer.target(),
+        queue_id
+    );
+    Ok(consumer)
+}
+
+pub async fn update_consumer(
+    client: &CloudflareClient,
+    account_id: &str,
+    queue_id: &str,
+    consumer_id: &str,
+    consumer: CreateQueueConsumer,
+) -> Result<QueueConsumer> {
+    let endpoint = format!(
+        "/accounts/{}/queues/{}/consumers/{}",
+        account_id, queue_id, consumer_id
+    );
+    let response: CfResponse<QueueConsumer> = client.put(&endpoint, consumer).await?;
+    let consumer = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from update consumer"))?;
+    println!("✓ Updated consumer: {}", consumer_id);
+    Ok(consumer)
+}
+
+pub async fn delete_consumer(
+    client: &CloudflareClient,
+    account_id: &str,
+    queue_id: &str,
+    consumer_id: &str,
+) -> Result<()> {
+    let endpoint = format!(
+        "/accounts/{}/queues/{}/consumers/{}",
+        account_id, queue_id, consumer_id
+    );
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted consumer: {}", consumer_id);
+    Ok(())
+}
+
+/// Find a consumer by ID or, for Worker consumers, by script name
+pub fn find_consumer<'a>(
+    consumers: &'a [QueueConsumer],
+    identifier: &str,
+) -> Option<&'a QueueConsumer> {
+    consumers.iter().find(|c| {
+        c.consumer_id.as_deref() == Some(identifier) || c.script_name.as_deref() == Some(identifier)
+    })
+}
+
+// ============================================================================
+// Messages
+// ============================================================================
+
+/// Send one message. With `json`, the body must parse as JSON.
+pub async fn send_message(
+    client: &CloudflareClient,
+    account_id: &str,
+    queue_id: &str,
+    body: &str,
+    json: bool,
+) -> Result<()> {
+    let message = if json {
+        SendMessage {
+            body: serde_json::from_str(body).map_err(|e| {
+                CfadError::validation(format!("Message body is not valid JSON: {}", e))
+            })?,
+            content_type: "json".to_string(),
+        }
+    } else {
+        SendMessage {
+            body: serde_json::Value::String(body.to_string()),
+            content_type: "text".to_string(),
+        }
+    };
+    let endpoint = format!("/accounts/{}/queues/{}/messages", account_id, queue_id);
+    let _response: CfResponse<serde_json::Value> = client.post(&endpoint, message).await?;
+    println!("✓ Sent message to queue {}", queue_id);
+    Ok(())
+}
+
+/// Lease a batch of messages (requires an HTTP pull consumer on the queue)
+pub async fn pull_messages(
+    client: &CloudflareClient,
+    account_id: &str,
+    queue_id: &str,
+    batch_size: u32,
+    visibility_timeout_ms: Option<u32>,
+) -> Result<PulledMessages> {
+    if !(1..=MAX_PULL_BATCH_SIZE).contains(&batch_size) {
+        return Err(CfadError::validation(format!(
+            "Invalid batch size {}: expected 1-{}",
+            batch_size, MAX_PULL_BATCH_SIZE
+        )));
+    }
+    let endpoint = format!("/accounts/{}/queues/{}/messages/pull", account_id, queue_id);
+    let body = PullMessages {
+        batch_size,
+        visibility_timeout_ms,
+    };
+    let response: CfResponse<PulledMessages> = client.post(&endpoint, body).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Acknowledge leased messages and/or mark others for redelivery
+pub async fn ack_messages(
+    client: &CloudflareClient,
+    account_id: &str,
+    queue_id: &str,
+    acks: &[String],
+    retries: &[String],
+    retry_delay: Option<u32>,
+) -> Result<AckResult> {
+    if acks.is_empty() && retries.is_empty() {
+        return Err(CfadError::validation(
+            "Nothing to acknowledge: pass lease IDs or --retry lease IDs",
+        ));
+    }
+    let endpoint = format!("/accounts/{}/queues/{}/messages/ack", account_id, queue_id);
+    let body = AckMessages {
+        acks: acks
+            .iter()
+            .map(|lease_id| LeaseRef {
+                lease_id: lease_id.clone(),
+            })
+            .collect(),
+        retries: retries
+            .iter()
+            .map(|lease_id| RetryLease {
+                lease_id: lease_id.clone(),
+                delay_seconds: retry_delay,
+            })
+            .collect(),
+    };
+    let response: CfResponse<AckResult> = client.post(&endpoint, body).await?;
+    let result = response.result.unwrap_or_default();
+    println!(
+        "✓ Acknowledged {} messages, {} marked for retry",
+        result.ack_count, result.retry_count
+    );
+    for warning in &result.warnings {
+        println!("⚠ {}", warning);
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_queue_name() {
+        assert!(validate_queue_name("image-uploads").is_ok());
+        assert!(validate_queue_name("Uploads").is_err());
+        assert!(validate_queue_name("-uploads").is_err());
+        assert!(validate_queue_name(&"q".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn test_consumer_payload_type_and_settings() {
+        let worker = consumer_payload(
+            Some("processor".to_string()),
+            Some("uploads-dlq".to_string()),
+            ConsumerSettings {
+                batch_size: Some(50),
+                max_wait_time_ms: Some(5000),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(worker.r#type, "worker");
+
+        let pull = consumer_payload(
+            None,
+            None,
+            ConsumerSettings {
+                visibility_timeout_ms: Some(30000),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(pull.r#type, "http_pull");
+
+        let mixed = ConsumerSettings {
+            max_concurrency: Some(2),
+            ..Default::default()
+        };
+        assert!(consumer_payload(None, None, mixed).is_err());
+        let too_big = ConsumerSettings {
+            batch_size: Some(500),
+            ..Default::default()
+        };
+        assert!(consumer_payload(Some("w".to_string()), None, too_big).is_err());
+    }
+}
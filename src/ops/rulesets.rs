@@ -0,0 +1,158 @@
+use crate::api::rulesets::{RuleInput, RulePosition, Ruleset, RulesetScope, UpdateEntrypoint};
+use crate::client::{CfResponse, CloudflareClient};
+use crate::error::{CfadError, Result};
+
+/// List all rulesets (including phase entrypoints) for a zone or account
+pub async fn list_rulesets(
+    client: &CloudflareClient,
+    scope: &RulesetScope,
+) -> Result<Vec<Ruleset>> {
+    let endpoint = format!("{}/rulesets", scope.path());
+    let response: CfResponse<Vec<Ruleset>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Get the entrypoint ruleset for a phase
+pub async fn get_entrypoint(
+    client: &CloudflareClient,
+    scope: &RulesetScope,
+    phase: &str,
+) -> Result<Ruleset> {
+    let endpoint = format!("{}/rulesets/phases/{}/entrypoint", scope.path(), phase);
+    let response: Result<CfResponse<Ruleset>> = client.get(&endpoint).await;
+    match response {
+        Ok(response) => response
+            .result
+            .ok_or_else(|| CfadError::not_found("Entrypoint ruleset", phase)),
+        // A phase has no entrypoint until its first rule is created
+        Err(CfadError::Api { status: 404, .. }) => {
+            Err(CfadError::not_found("Entrypoint ruleset", phase))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+/// Replace every rule of a phase entrypoint ruleset (creating it if needed)
+pub async fn put_entrypoint(
+    client: &CloudflareClient,
+    scope: &RulesetScope,
+    phase: &str,
+    update: UpdateEntrypoint,
+) -> Result<Ruleset> {
+    let endpoint = format!("{}/rulesets/phases/{}/entrypoint", scope.path(), phase);
+    let response: CfResponse<Ruleset> = client.put(&endpoint, update).await?;
+    let ruleset = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from update entrypoint"))?;
+    println!("✓ Applied {} rules to {}", ruleset.rules.len(), phase);
+    Ok(ruleset)
+}
+
+/// Add a rule to a phase, creating the entrypoint ruleset on first use
+pub async fn add_rule(
+    client: &CloudflareClient,
+    scope: &RulesetScope,
+    phase: &str,
+    rule: RuleInput,
+) -> Result<Ruleset> {
+    let entrypoint = match get_entrypoint(client, scope, phase).await {
+        Ok(ruleset) => ruleset,
+        Err(CfadError::NotFound { .. }) => {
+            let update = UpdateEntrypoint {
+                description: None,
+                rules: vec![RuleInput {
+                    position: None,
+                    ..rule
+                }],
+            };
+            let endpoint = format!("{}/rulesets/phases/{}/entrypoint", scope.path(), phase);
+            let response: CfResponse<Ruleset> = client.put(&endpoint, update).await?;
+            let ruleset = response
+                .result
+                .ok_or_else(|| CfadError::api("No result returned from create entrypoint"))?;
+            println!("✓ Added rule to {}", phase);
+            return Ok(ruleset);
+        }
+        Err(e) => return Err(e),
+    };
+
+    let endpoint = format!("{}/rulesets/{}/rules", scope.path(), entrypoint.id);
+    let response: CfResponse<Ruleset> = client.post(&endpoint, rule).await?;
+    let ruleset = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from add rule"))?;
+    println!("✓ Added rule to {}", phase);
+    Ok(ruleset)
+}
+
+/// Update a rule in a phase entrypoint ruleset.
+///
+/// `apply` receives the current rule definition and edits it in place, so
+/// callers only need to change the fields the user provided.
+pub async fn update_rule<F>(
+    client: &CloudflareClient,
+    scope: &RulesetScope,
+    phase: &str,
+    rule_id: &str,
+    apply: F,
+) -> Result<Ruleset>
+where
+    F: FnOnce(&mut RuleInput),
+{
+    let entrypoint = get_entrypoint(client, scope, phase).await?;
+    let current = entrypoint
+        .rules
+        .iter()
+        .find(|r| r.id.as_deref() == Some(rule_id))
+        .cloned()
+        .ok_or_else(|| CfadError::not_found("Rule", rule_id))?;
+
+    let mut rule = RuleInput::from(current);
+    apply(&mut rule);
+
+    let endpoint = format!(
+        "{}/rulesets/{}/rules/{}",
+        scope.path(),
+        entrypoint.id,
+        rule_id
+    );
+    let response: CfResponse<Ruleset> = client.patch(&endpoint, rule).await?;
+    let ruleset = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from update rule"))?;
+    println!("✓ Updated rule {}", rule_id);
+    Ok(ruleset)
+}
+
+/// Move a rule to a new position within its phase
+pub async fn reorder_rule(
+    client: &CloudflareClient,
+    scope: &RulesetScope,
+    phase: &str,
+    rule_id: &str,
+    position: RulePosition,
+) -> Result<Ruleset> {
+    update_rule(client, scope, phase, rule_id, |rule| {
+        rule.position = Some(position);
+    })
+    .await
+}
+
+/// Delete a rule from a phase entrypoint ruleset
+pub async fn delete_rule(
+    client: &CloudflareClient,
+    scope: &RulesetScope,
+    phase: &str,
+    rule_id: &str,
+) -> Result<()> {
+    let entrypoint = get_entrypoint(client, scope, phase).await?;
+    let endpoint = format!(
+        "{}/rulesets/{}/rules/{}",
+        scope.path(),
+        entrypoint.id,
+        rule_id
+    );
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted rule {}", rule_id);
+    Ok(())
+}
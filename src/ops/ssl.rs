@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+
+use crate::api::certificates::CertificatePack;
+use crate::api::zone::ZoneSetting;
+use crate::client::{CfResponse, CloudflareClient};
+use crate::error::Result;
+use crate::ops::{certificates, zone};
+
+/// Combined view of a zone's TLS configuration
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SslStatus {
+    /// Encryption mode between Cloudflare and the origin (off, flexible, full, strict)
+    pub ssl_mode: Option<String>,
+    pub min_tls_version: Option<String>,
+    pub tls_1_3: Option<String>,
+    pub always_use_https: Option<String>,
+    pub hsts: Option<HstsStatus>,
+    pub universal_ssl: bool,
+    pub authenticated_origin_pulls: bool,
+    pub certificate_packs: Vec<CertificatePack>,
+}
+
+/// HSTS header settings (`security_header` zone setting)
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct HstsStatus {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub max_age: u64,
+    #[serde(default)]
+    pub include_subdomains: bool,
+    #[serde(default)]
+    pub preload: bool,
+}
+
+#[derive(Deserialize)]
+struct OriginPullSettings {
+    #[serde(default)]
+    enabled: bool,
+}
+
+impl SslStatus {
+    /// Fill in the TLS-related values from the zone settings list
+    pub fn from_settings(settings: &[ZoneSetting]) -> Self {
+        let value = |id: &str| {
+            settings
+                .iter()
+                .find(|setting| setting.id == id)
+                .and_then(|setting| setting.value.as_str())
+                .map(str::to_string)
+        };
+        let hsts = settings
+            .iter()
+            .find(|setting| setting.id == "security_header")
+            .and_then(|setting| setting.value.get("strict_transport_security"))
+            .and_then(|hsts| serde_json::from_value(hsts.clone()).ok());
+
+        Self {
+            ssl_mode: value("ssl"),
+            min_tls_version: value("min_tls_version"),
+            tls_1_3: value("tls_1_3"),
+            always_use_https: value("always_use_https"),
+            hsts,
+            ..Default::default()
+        }
+    }
+}
+
+/// Whether Cloudflare presents a client certificate to the origin
+pub async fn get_authenticated_origin_pulls(
+    client: &CloudflareClient,
+    zone_id: &str,
+) -> Result<bool> {
+    let endpoint = format!("/zones/{}/origin_tls_client_auth/settings", zone_id);
+    let response: CfResponse<OriginPullSettings> = client.get(&endpoint).await?;
+    Ok(response
+        .result
+        .map(|settings| settings.enabled)
+        .unwrap_or(false))
+}
+
+/// Gather settings, universal SSL, origin pulls and certificate packs for a zone
+pub async fn get_ssl_status(client: &CloudflareClient, zone_id: &str) -> Result<SslStatus> {
+    let settings = zone::get_zone_settings(client, zone_id).await?;
+    let mut status = SslStatus::from_settings(&settings);
+    status.universal_ssl = certificates::get_universal_ssl(client, zone_id).await?;
+    status.authenticated_origin_pulls = get_authenticated_origin_pulls(client, zone_id).await?;
+    status.certificate_packs = certificates::list_certificate_packs(client, zone_id).await?;
+    Ok(status)
+}
+
+/// Whole days from `now` (Unix seconds) until an API timestamp.
+///
+/// Only the leading `YYYY-MM-DD` is read, which covers both the RFC 3339
+/// and the "2039-01-01 00:00:00 +0000 UTC" formats the API returns.
+pub fn days_until(timestamp: &str, now: u64) -> Option<i64> {
+    let date = timestamp.get(..10)?;
+    let mut parts = date.split('-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: i64 = parts.next()?.parse().ok()?;
+    let day: i64 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    Some(days_from_civil(year, month, day) - (now / 86_400) as i64)
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month + 9) % 12;
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn setting(id: &str, value: serde_json::Value) -> ZoneSetting {
+        ZoneSetting {
+            id: id.to_string(),
+            value,
+            editable: true,
+            modified_on: None,
+        }
+    }
+
+    #[test]
+    fn test_from_settings() {
+        let status = SslStatus::from_settings(&[
+            setting("ssl", serde_json::json!("strict")),
+            setting("min_tls_version", serde_json::json!("1.2")),
+            setting("tls_1_3", serde_json::json!("on")),
+            setting(
+                "security_header",
+                serde_json::json!({"strict_transport_security": {
+                    "enabled": true, "max_age": 31536000, "include_subdomains": true
+                }}),
+            ),
+        ]);
+        assert_eq!(status.ssl_mode.as_deref(), Some("strict"));
+        assert_eq!(status.min_tls_version.as_deref(), Some("1.2"));
+        assert!(status.always_use_https.is_none());
+        let hsts = status.hsts.unwrap();
+        assert!(hsts.enabled && hsts.include_subdomains && !hsts.preload);
+        assert_eq!(hsts.max_age, 31_536_000);
+    }
+
+    #[test]
+    fn test_days_until() {
+        // 2025-01-01T00:00:00Z
+        let now = 1_735_689_600;
+        assert_eq!(days_until("2025-01-31T12:00:00Z", now), Some(30));
+        assert_eq!(days_until("2039-01-01 00:00:00 +0000 UTC", now), Some(5113));
+        assert_eq!(days_until("2024-12-25T00:00:00Z", now), Some(-7));
+        assert_eq!(days_until("soon", now), None);
+    }
+}
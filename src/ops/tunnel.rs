@@ -0,0 +1,321 @@
+use crate::api::dns::{CreateDnsRecord, DnsRecord, UpdateDnsRecord};
+use crate::api::tunnel::{
+    CreateTunnel, PutTunnelConfiguration, Tunnel, TunnelConfig, TunnelConfigurationResponse,
+    TunnelConnector,
+};
+use crate::client::{CfResponse, CloudflareClient};
+use crate::error::{CfadError, Result};
+
+/// Special services cloudflared accepts besides URLs and `http_status:<code>`
+const BUILTIN_SERVICES: &[&str] = &["hello_world", "bastion", "socks5"];
+
+// ============================================================================
+// Tunnels
+// ============================================================================
+
+pub async fn list_tunnels(client: &CloudflareClient, account_id: &str) -> Result<Vec<Tunnel>> {
+    let endpoint = format!("/accounts/{}/cfd_tunnel?is_deleted=false", account_id);
+    let response: CfResponse<Vec<Tunnel>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Find a (non-deleted) tunnel by ID or name
+pub async fn get_tunnel(
+    client: &CloudflareClient,
+    account_id: &str,
+    tunnel: &str,
+) -> Result<Tunnel> {
+    list_tunnels(client, account_id)
+        .await?
+        .into_iter()
+        .find(|t| t.id == tunnel || t.name == tunnel)
+        .ok_or_else(|| CfadError::not_found("Tunnel", tunnel))
+}
+
+/// Create a tunnel. Remotely-managed tunnels get their ingress rules from
+/// Cloudflare (`cfad tunnel config put`); locally-managed ones read them
+/// from cloudflared's config.yml.
+pub async fn create_tunnel(
+    client: &CloudflareClient,
+    account_id: &str,
+    name: &str,
+    remotely_managed: bool,
+) -> Result<Tunnel> {
+    let endpoint = format!("/accounts/{}/cfd_tunnel", account_id);
+    let create = CreateTunnel {
+        name: name.to_string(),
+        config_src: if remotely_managed {
+            "cloudflare"
+        } else {
+            "local"
+        }
+        .to_string(),
+    };
+    let response: CfResponse<Tunnel> = client.post(&endpoint, create).await?;
+    let tunnel = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create tunnel"))?;
+    println!("✓ Created tunnel: {} ({})", tunnel.name, tunnel.id);
+    Ok(tunnel)
+}
+
+/// Delete a tunnel. With `cleanup_connections`, stale connections are
+/// removed first; the API refuses to delete a tunnel that still has any.
+pub async fn delete_tunnel(
+    client: &CloudflareClient,
+    account_id: &str,
+    tunnel_id: &str,
+    cleanup_connections: bool,
+) -> Result<()> {
+    if cleanup_connections {
+        let endpoint = format!(
+            "/accounts/{}/cfd_tunnel/{}/connections",
+            account_id, tunnel_id
+        );
+        let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    }
+    let endpoint = format!("/accounts/{}/cfd_tunnel/{}", account_id, tunnel_id);
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted tunnel: {}", tunnel_id);
+    Ok(())
+}
+
+/// Token for `cloudflared tunnel run --token`
+pub async fn get_tunnel_token(
+    client: &CloudflareClient,
+    account_id: &str,
+    tunnel_id: &str,
+) -> Result<String> {
+    let endpoint = format!("/accounts/{}/cfd_tunnel/{}/token", account_id, tunnel_id);
+    let response: CfResponse<String> = client.get(&endpoint).await?;
+    response
+        .result
+        .ok_or_else(|| CfadError::api("No token returned for tunnel"))
+}
+
+/// Connectors (cloudflared instances) currently attached to a tunnel
+pub async fn list_connections(
+    client: &CloudflareClient,
+    account_id: &str,
+    tunnel_id: &str,
+) -> Result<Vec<TunnelConnector>> {
+    let endpoint = format!(
+        "/accounts/{}/cfd_tunnel/{}/connections",
+        account_id, tunnel_id
+    );
+    let response: CfResponse<Vec<TunnelConnector>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+// ============================================================================
+// Remotely-managed configuration
+// ============================================================================
+
+pub async fn get_tunnel_config(
+    client: &CloudflareClient,
+    account_id: &str,
+    tunnel_id: &str,
+) -> Result<TunnelConfigurationResponse> {
+    let endpoint = format!(
+        "/accounts/{}/cfd_tunnel/{}/configurations",
+        account_id, tunnel_id
+    );
+    let response: CfResponse<TunnelConfigurationResponse> = client.get(&endpoint).await?;
+    response
+        .result
+        .ok_or_else(|| CfadError::not_found("Tunnel configuration", tunnel_id))
+}
+
+pub async fn put_tunnel_config(
+    client: &CloudflareClient,
+    account_id: &str,
+    tunnel_id: &str,
+    config: TunnelConfig,
+) -> Result<TunnelConfigurationResponse> {
+    validate_tunnel_config(&config)?;
+    let endpoint = format!(
+        "/accounts/{}/cfd_tunnel/{}/configurations",
+        account_id, tunnel_id
+    );
+    let response: CfResponse<TunnelConfigurationResponse> = client
+        .put(&endpoint, PutTunnelConfiguration { config })
+        .await?;
+    let updated = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from update tunnel configuration"))?;
+    match updated.version {
+        Some(version) => println!("✓ Updated tunnel configuration (version {})", version),
+        None => println!("✓ Updated tunnel configuration"),
+    }
+    Ok(updated)
+}
+
+/// Parse a cloudflared-style config.yml (JSON is valid YAML, so .json works too)
+pub fn parse_tunnel_config(contents: &str) -> Result<TunnelConfig> {
+    let config: TunnelConfig = serde_yaml::from_str(contents)?;
+    validate_tunnel_config(&config)?;
+    Ok(config)
+}
+
+/// Check ingress rules the way cloudflared does before it would reject them
+pub fn validate_tunnel_config(config: &TunnelConfig) -> Result<()> {
+    let Some(last) = config.ingress.last() else {
+        return Err(CfadError::validation(
+            "Tunnel configuration has no ingress rules",
+        ));
+    };
+    if !last.is_catch_all() {
+        return Err(CfadError::validation(
+            "The last ingress rule must be a catch-all without hostname or path (e.g. service: http_status:404)",
+        ));
+    }
+
+    for (i, rule) in config.ingress.iter().enumerate() {
+        let position = i + 1;
+        if rule.is_catch_all() && position < config.ingress.len() {
+            return Err(CfadError::validation(format!(
+                "Ingress rule {} matches every request, so the rules after it are unreachable",
+                position
+            )));
+        }
+        if let Some(hostname) = &rule.hostname {
+            let bare = hostname.strip_prefix("*.").unwrap_or(hostname);
+            if bare.is_empty() || bare.contains('*') {
+                return Err(CfadError::validation(format!(
+                    "Ingress rule {}: invalid hostname '{}' (wildcards are only allowed as a leading '*.')",
+                    position, hostname
+                )));
+            }
+        }
+        validate_service(&rule.service).map_err(|reason| {
+            CfadError::validation(format!("Ingress rule {}: {}", position, reason))
+        })?;
+    }
+    Ok(())
+}
+
+fn validate_service(service: &str) -> std::result::Result<(), String> {
+    if service.is_empty() {
+        return Err("service is required".to_string());
+    }
+    if let Some(code) = service.strip_prefix("http_status:") {
+        return match code.parse::<u16>() {
+            Ok(100..=599) => Ok(()),
+            _ => Err(format!("invalid status code in '{}'", service)),
+        };
+    }
+    if service.starts_with("unix:")
+        || service.starts_with("unix+tls:")
+        || BUILTIN_SERVICES.contains(&service)
+    {
+        return Ok(());
+    }
+    match url::Url::parse(service) {
+        Ok(url) if url.has_host() => Ok(()),
+        _ => Err(format!(
+            "invalid service '{}' (expected e.g. http://localhost:8080 or http_status:404)",
+            service
+        )),
+    }
+}
+
+// ============================================================================
+// DNS routing
+// ============================================================================
+
+/// Point `hostname` at a tunnel with a proxied CNAME.
+///
+/// An existing record for the hostname is only replaced with `overwrite`.
+pub async fn route_dns(
+    client: &CloudflareClient,
+    zone_id: &str,
+    tunnel: &Tunnel,
+    hostname: &str,
+    overwrite: bool,
+) -> Result<DnsRecord> {
+    let target = tunnel.cname_target();
+    let existing = crate::ops::dns::list_records(client, zone_id, None, Some(hostname)).await?;
+
+    if let Some(record) = existing.first() {
+        if record.record_type == "CNAME" && record.content == target {
+            println!("✓ {} already routes to tunnel {}", hostname, tunnel.name);
+            return Ok(record.clone());
+        }
+        if !overwrite {
+            return Err(CfadError::validation(format!(
+                "{} already has a {} record ({}); use --overwrite to replace it",
+                hostname, record.record_type, record.content
+            )));
+        }
+        let update = UpdateDnsRecord {
+            record_type: Some("CNAME".to_string()),
+            name: Some(hostname.to_string()),
+            content: Some(target),
+            ttl: Some(1),
+            proxied: Some(true),
+            priority: None,
+        };
+        return crate::ops::dns::update_record(client, zone_id, &record.id, update).await;
+    }
+
+    let record = CreateDnsRecord {
+        record_type: "CNAME".to_string(),
+        name: hostname.to_string(),
+        content: target,
+        ttl: Some(1),
+        proxied: Some(true),
+        priority: None,
+        data: None,
+    };
+    crate::ops::dns::create_record(client, zone_id, record).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+ingress:
+  - hostname: app.example.com
+    service: http://localhost:8080
+  - hostname: "*.internal.example.com"
+    service: https://10.0.0.5
+  - service: http_status:404
+"#;
+
+    #[test]
+    fn test_parse_tunnel_config() {
+        let config = parse_tunnel_config(CONFIG).unwrap();
+        assert_eq!(config.ingress.len(), 3);
+    }
+
+    #[test]
+    fn test_validate_requires_catch_all_last() {
+        let err = parse_tunnel_config(
+            "ingress:\n  - hostname: app.example.com\n    service: http://localhost:8080\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("catch-all"));
+    }
+
+    #[test]
+    fn test_validate_rejects_early_catch_all() {
+        let err = parse_tunnel_config(
+            "ingress:\n  - service: http://localhost:8080\n  - service: http_status:404\n",
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("unreachable"));
+    }
+
+    #[test]
+    fn test_validate_rejects_bad_services_and_hostnames() {
+        for config in [
+            "ingress:\n  - service: localhost:8080\n",
+            "ingress:\n  - service: http_status:999\n",
+            "ingress:\n  - hostname: app.*.example.com\n    service: http://x\n  - service: http_status:404\n",
+        ] {
+            assert!(parse_tunnel_config(config).is_err(), "{}", config);
+        }
+        assert!(parse_tunnel_config("ingress:\n  - service: hello_world\n").is_ok());
+    }
+}
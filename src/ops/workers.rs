@@ -0,0 +1,718 @@
+use std::path::{Path, PathBuf};
+
+use crate::api::workers::{
+    AttachWorkerDomain, CreateWorkerRoute, CronSchedule, CronTrigger, PutWorkerSecret,
+    WorkerBinding, WorkerDomain, WorkerMetadata, WorkerRoute, WorkerSchedules, WorkerScript,
+    WorkerSecret, WorkerSettings,
+};
+use crate::client::{CfResponse, CloudflareClient};
+use crate::error::{CfadError, Result};
+use reqwest::multipart::{Form, Part};
+
+/// Accepted `--binding` types: (flag prefix, Workers binding type, field holding the value)
+pub const BINDING_TYPES: &[(&str, &str, &str)] = &[
+    ("kv", "kv_namespace", "namespace_id"),
+    ("r2", "r2_bucket", "bucket_name"),
+    ("d1", "d1", "id"),
+    ("var", "plain_text", "text"),
+    ("service", "service", "service"),
+    ("queue", "queue", "queue_name"),
+];
+
+// ============================================================================
+// Scripts
+// ============================================================================
+
+pub async fn list_scripts(
+    client: &CloudflareClient,
+    account_id: &str,
+) -> Result<Vec<WorkerScript>> {
+    let endpoint = format!("/accounts/{}/workers/scripts", account_id);
+    let response: CfResponse<Vec<WorkerScript>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+pub async fn get_script(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: &str,
+) -> Result<WorkerScript> {
+    list_scripts(client, account_id)
+        .await?
+        .into_iter()
+        .find(|s| s.id == script_name)
+        .ok_or_else(|| CfadError::not_found("Worker", script_name))
+}
+
+pub async fn get_script_settings(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: &str,
+) -> Result<WorkerSettings> {
+    let endpoint = format!(
+        "/accounts/{}/workers/scripts/{}/settings",
+        account_id, script_name
+    );
+    let response: CfResponse<WorkerSettings> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Delete a script. `force` also deletes it while other Workers bind to it.
+pub async fn delete_script(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: &str,
+    force: bool,
+) -> Result<()> {
+    let mut endpoint = format!("/accounts/{}/workers/scripts/{}", account_id, script_name);
+    if force {
+        endpoint.push_str("?force=true");
+    }
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted worker: {}", script_name);
+    Ok(())
+}
+
+/// A file uploaded as part of a script
+#[derive(Debug, Clone)]
+pub struct ModuleFile {
+    /// Name the runtime imports it by (e.g. "index.js", "lib/util.js")
+    pub name: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+/// Upload an ES-module script. `modules[0]` must be `metadata.main_module`.
+pub async fn deploy_script(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: &str,
+    metadata: WorkerMetadata,
+    modules: Vec<ModuleFile>,
+) -> Result<WorkerScript> {
+    if modules.first().map(|m| &m.name) != Some(&metadata.main_module) {
+        return Err(CfadError::validation(
+            "The main module must be the first uploaded module",
+        ));
+    }
+
+    let mut form = Form::new().part(
+        "metadata",
+        Part::text(serde_json::to_string(&metadata)?).mime_str("application/json")?,
+    );
+    let module_count = modules.len();
+    for module in modules {
+        let part = Part::bytes(module.data)
+            .file_name(module.name.clone())
+            .mime_str(&module.content_type)?;
+        form = form.part(module.name, part);
+    }
+
+    let endpoint = format!("/accounts/{}/workers/scripts/{}", account_id, script_name);
+    let response: CfResponse<WorkerScript> = client.put_multipart(&endpoint, form).await?;
+    let script = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from deploy worker"))?;
+    println!(
+        "✓ Deployed worker: {} ({} modules, {} bindings)",
+        script_name,
+        module_count,
+        metadata.bindings.len()
+    );
+    Ok(script)
+}
+
+/// Read the main module and any additional modules from disk.
+///
+/// Additional modules under the main module's directory keep their relative
+/// path as module name so imports like `./lib/util.js` resolve.
+pub fn load_modules(main: &Path, additional: &[PathBuf]) -> Result<Vec<ModuleFile>> {
+    let base = main.parent().unwrap_or_else(|| Path::new(""));
+    let mut modules = Vec::with_capacity(additional.len() + 1);
+
+    for path in std::iter::once(main).chain(additional.iter().map(PathBuf::as_path)) {
+        let name = path
+            .strip_prefix(base)
+            .ok()
+            .filter(|rel| !rel.as_os_str().is_empty())
+            .unwrap_or_else(|| Path::new(path.file_name().unwrap_or_default()))
+            .to_string_lossy()
+            .replace('\\', "/");
+        if modules.iter().any(|m: &ModuleFile| m.name == name) {
+            return Err(CfadError::validation(format!(
+                "Duplicate module name '{}'",
+                name
+            )));
+        }
+        let data = std::fs::read(path).map_err(|e| {
+            CfadError::validation(format!("Cannot read module {}: {}", path.display(), e))
+        })?;
+        modules.push(ModuleFile {
+            content_type: module_content_type(&name)?.to_string(),
+            name,
+            data,
+        });
+    }
+    Ok(modules)
+}
+
+/// Content type the Workers runtime expects for a module, by file extension
+pub fn module_content_type(name: &str) -> Result<&'static str> {
+    let extension = Path::new(name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or_default();
+    match extension {
+        "js" | "mjs" => Ok("application/javascript+module"),
+        "cjs" => Ok("application/javascript"),
+        "py" => Ok("text/x-python"),
+        "wasm" => Ok("application/wasm"),
+        "txt" | "html" => Ok("text/plain"),
+        "bin" => Ok("application/octet-stream"),
+        "map" => Ok("application/source-map"),
+        _ => Err(CfadError::validation(format!(
+            "Unsupported module type '{}' (expected .js, .mjs, .cjs, .py, .wasm, .txt, .html, .bin or .map)",
+            name
+        ))),
+    }
+}
+
+/// Parse a `--binding TYPE:NAME=VALUE` flag (e.g. `kv:CACHE=<namespace_id>`)
+pub fn parse_binding(spec: &str) -> Result<WorkerBinding> {
+    let invalid = || {
+        CfadError::validation(format!(
+            "Invalid binding '{}': expected TYPE:NAME=VALUE with TYPE one of {}",
+            spec,
+            BINDING_TYPES
+                .iter()
+                .map(|(flag, _, _)| *flag)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ))
+    };
+    let (kind, rest) = spec.split_once(':').ok_or_else(invalid)?;
+    let (name, value) = rest.split_once('=').ok_or_else(invalid)?;
+    let (name, value) = (name.trim(), value.trim());
+    if name.is_empty() || value.is_empty() {
+        return Err(invalid());
+    }
+
+    let (_, binding_type, field) = BINDING_TYPES
+        .iter()
+        .find(|(flag, _, _)| *flag == kind.trim())
+        .ok_or_else(invalid)?;
+    let mut fields = serde_json::Map::new();
+    fields.insert(
+        field.to_string(),
+        serde_json::Value::String(value.to_string()),
+    );
+    Ok(WorkerBinding {
+        r#type: binding_type.to_string(),
+        name: name.to_string(),
+        fields,
+    })
+}
+
+/// Check a `--compat-date` is a YYYY-MM-DD date
+pub fn validate_compat_date(date: &str) -> Result<()> {
+    let parts: Vec<&str> = date.split('-').collect();
+    let valid = parts.len() == 3
+        && [4, 2, 2]
+            .iter()
+            .zip(&parts)
+            .all(|(len, p)| p.len() == *len && p.chars().all(|c| c.is_ascii_digit()))
+        && (1..=12).contains(&parts[1].parse::<u32>().unwrap_or(0))
+        && (1..=31).contains(&parts[2].parse::<u32>().unwrap_or(0));
+    if !valid {
+        return Err(CfadError::validation(format!(
+            "Invalid compatibility date '{}': expected YYYY-MM-DD",
+            date
+        )));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Routes
+// ============================================================================
+
+pub async fn list_routes(client: &CloudflareClient, zone_id: &str) -> Result<Vec<WorkerRoute>> {
+    let endpoint = format!("/zones/{}/workers/routes", zone_id);
+    let response: CfResponse<Vec<WorkerRoute>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+pub async fn create_route(
+    client: &CloudflareClient,
+    zone_id: &str,
+    pattern: &str,
+    script_name: &str,
+) -> Result<WorkerRoute> {
+    if !pattern.contains('/') {
+        return Err(CfadError::validation(format!(
+            "Invalid route pattern '{}': expected e.g. example.com/* or *.example.com/api/*",
+            pattern
+        )));
+    }
+    let endpoint = format!("/zones/{}/workers/routes", zone_id);
+    let route = CreateWorkerRoute {
+        pattern: pattern.to_string(),
+        script: script_name.to_string(),
+    };
+    let response: CfResponse<WorkerRoute> = client.post(&endpoint, route).await?;
+    let route = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from create route"))?;
+    println!("✓ Added route: {} → {}", pattern, script_name);
+    Ok(route)
+}
+
+pub async fn delete_route(client: &CloudflareClient, zone_id: &str, route_id: &str) -> Result<()> {
+    let endpoint = format!("/zones/{}/workers/routes/{}", zone_id, route_id);
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted route: {}", route_id);
+    Ok(())
+}
+
+// ============================================================================
+// Custom domains
+// ============================================================================
+
+pub async fn list_domains(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: Option<&str>,
+) -> Result<Vec<WorkerDomain>> {
+    let mut endpoint = format!("/accounts/{}/workers/domains", account_id);
+    if let Some(script_name) = script_name {
+        endpoint.push_str(&format!("?service={}", script_name));
+    }
+    let response: CfResponse<Vec<WorkerDomain>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Serve a script on a hostname; Cloudflare creates the DNS record and certificate
+pub async fn attach_domain(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: &str,
+    hostname: &str,
+    zone_id: &str,
+) -> Result<WorkerDomain> {
+    let endpoint = format!("/accounts/{}/workers/domains", account_id);
+    let domain = AttachWorkerDomain {
+        hostname: hostname.to_string(),
+        service: script_name.to_string(),
+        environment: "production".to_string(),
+        zone_id: zone_id.to_string(),
+    };
+    let response: CfResponse<WorkerDomain> = client.put(&endpoint, domain).await?;
+    let domain = response
+        .result
+        .ok_or_else(|| CfadError::api("No result returned from attach domain"))?;
+    println!("✓ Attached {} to worker {}", hostname, script_name);
+    Ok(domain)
+}
+
+pub async fn detach_domain(
+    client: &CloudflareClient,
+    account_id: &str,
+    domain_id: &str,
+) -> Result<()> {
+    let endpoint = format!("/accounts/{}/workers/domains/{}", account_id, domain_id);
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Detached domain: {}", domain_id);
+    Ok(())
+}
+
+// ============================================================================
+// Cron triggers
+// ============================================================================
+
+pub async fn get_schedules(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: &str,
+) -> Result<Vec<CronTrigger>> {
+    let endpoint = format!(
+        "/accounts/{}/workers/scripts/{}/schedules",
+        account_id, script_name
+    );
+    let response: CfResponse<WorkerSchedules> = client.get(&endpoint).await?;
+    Ok(response.result.map(|r| r.schedules).unwrap_or_default())
+}
+
+/// Replace all cron triggers of a script (an empty list removes them)
+pub async fn set_schedules(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: &str,
+    crons: &[String],
+) -> Result<Vec<CronTrigger>> {
+    for cron in crons {
+        validate_cron(cron)?;
+    }
+    let endpoint = format!(
+        "/accounts/{}/workers/scripts/{}/schedules",
+        account_id, script_name
+    );
+    let body: Vec<CronSchedule> = crons
+        .iter()
+        .map(|cron| CronSchedule { cron: cron.clone() })
+        .collect();
+    let response: CfResponse<WorkerSchedules> = client.put(&endpoint, body).await?;
+    println!(
+        "✓ Updated cron triggers of {} ({} total)",
+        script_name,
+        crons.len()
+    );
+    Ok(response.result.map(|r| r.schedules).unwrap_or_default())
+}
+
+/// Basic shape check of a five-field cron expression
+pub fn validate_cron(cron: &str) -> Result<()> {
+    let fields: Vec<&str> = cron.split_whitespace().collect();
+    let allowed = |c: char| c.is_ascii_alphanumeric() || "*/,-#?".contains(c);
+    if fields.len() != 5 || !fields.iter().all(|f| f.chars().all(allowed)) {
+        return Err(CfadError::validation(format!(
+            "Invalid cron expression '{}': expected 5 fields (minute hour day month weekday), e.g. \"*/15 * * * *\"",
+            cron
+        )));
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Secrets
+// ============================================================================
+
+pub async fn list_secrets(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: &str,
+) -> Result<Vec<WorkerSecret>> {
+    let endpoint = format!(
+        "/accounts/{}/workers/scripts/{}/secrets",
+        account_id, script_name
+    );
+    let response: CfResponse<Vec<WorkerSecret>> = client.get(&endpoint).await?;
+    Ok(response.result.unwrap_or_default())
+}
+
+/// Create or overwrite a secret
+pub async fn put_secret(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: &str,
+    name: &str,
+    value: &str,
+) -> Result<()> {
+    validate_secret_name(name)?;
+    let endpoint = format!(
+        "/accounts/{}/workers/scripts/{}/secrets",
+        account_id, script_name
+    );
+    let secret = PutWorkerSecret {
+        name: name.to_string(),
+        text: value.to_string(),
+        r#type: "secret_text".to_string(),
+    };
+    let _response: CfResponse<serde_json::Value> = client.put(&endpoint, secret).await?;
+    println!("✓ Set secret {} on {}", name, script_name);
+    Ok(())
+}
+
+pub async fn delete_secret(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: &str,
+    name: &str,
+) -> Result<()> {
+    let endpoint = format!(
+        "/accounts/{}/workers/scripts/{}/secrets/{}",
+        account_id, script_name, name
+    );
+    let _response: CfResponse<serde_json::Value> = client.delete(&endpoint).await?;
+    println!("✓ Deleted secret {} from {}", name, script_name);
+    Ok(())
+}
+
+/// Secret names become JavaScript bindings, so they must be identifiers
+pub fn validate_secret_name(name: &str) -> Result<()> {
+    let mut chars = name.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !valid {
+        return Err(CfadError::validation(format!(
+            "Invalid secret name '{}': use letters, digits and underscores, not starting with a digit",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Strip the newline left by `echo` or a trailing line in a file, and
+/// reject empty values
+pub fn trim_secret_value(mut value: String) -> Result<String> {
+    if value.ends_with('\n') {
+        value.pop();
+        if value.ends_with('\r') {
+            value.pop();
+        }
+    }
+    if value.is_empty() {
+        return Err(CfadError::validation("Secret value is empty"));
+    }
+    Ok(value)
+}
+
+/// Parse a dotenv file into (name, value) pairs in file order.
+///
+/// Supports `#` comments, an optional `export ` prefix, single-quoted
+/// (literal) and double-quoted (`\n`, `\"`, `\\` escapes) values. Unquoted
+/// values end at ` #`. Empty values are rejected, as with `secret put`.
+pub fn parse_dotenv(contents: &str) -> Result<Vec<(String, String)>> {
+    let mut entries: Vec<(String, String)> = Vec::new();
+    for (index, line) in contents.lines().enumerate() {
+        let line_no = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (name, raw) = line.split_once('=').ok_or_else(|| {
+            CfadError::validation(format!("Line {}: expected NAME=value", line_no))
+        })?;
+        let name = name.trim();
+        validate_secret_name(name)
+            .map_err(|e| CfadError::validation(format!("Line {}: {}", line_no, e)))?;
+        if entries.iter().any(|(existing, _)| existing == name) {
+            return Err(CfadError::validation(format!(
+                "Line {}: '{}' is defined more than once",
+                line_no, name
+            )));
+        }
+        let value = parse_dotenv_value(raw.trim()).ok_or_else(|| {
+            CfadError::validation(format!("Line {}: unterminated quote", line_no))
+        })?;
+        if value.is_empty() {
+            return Err(CfadError::validation(format!(
+                "Line {}: secret value for '{}' is empty",
+                line_no, name
+            )));
+        }
+        entries.push((name.to_string(), value));
+    }
+    Ok(entries)
+}
+
+fn parse_dotenv_value(raw: &str) -> Option<String> {
+    if let Some(rest) = raw.strip_prefix('\'') {
+        return rest.find('\'').map(|end| rest[..end].to_string());
+    }
+    if let Some(rest) = raw.strip_prefix('"') {
+        let mut value = String::new();
+        let mut chars = rest.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => return Some(value),
+                '\\' => match chars.next()? {
+                    'n' => value.push('\n'),
+                    'r' => value.push('\r'),
+                    't' => value.push('\t'),
+                    other => value.push(other),
+                },
+                c => value.push(c),
+            }
+        }
+        return None;
+    }
+    let value = raw.split(" #").next().unwrap_or_default();
+    Some(value.trim_end().to_string())
+}
+
+/// Changes needed to make a script's secrets match a dotenv file.
+///
+/// Secret values can't be read back, so every name already present is
+/// listed under `change` and re-uploaded.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SecretSyncPlan {
+    pub add: Vec<String>,
+    pub change: Vec<String>,
+    pub remove: Vec<String>,
+}
+
+impl SecretSyncPlan {
+    pub fn is_empty(&self) -> bool {
+        self.add.is_empty() && self.change.is_empty() && self.remove.is_empty()
+    }
+}
+
+pub fn plan_secret_sync(existing: &[WorkerSecret], desired: &[(String, String)]) -> SecretSyncPlan {
+    let mut plan = SecretSyncPlan::default();
+    for (name, _) in desired {
+        if existing.iter().any(|s| &s.name == name) {
+            plan.change.push(name.clone());
+        } else {
+            plan.add.push(name.clone());
+        }
+    }
+    plan.remove = existing
+        .iter()
+        .filter(|s| !desired.iter().any(|(name, _)| name == &s.name))
+        .map(|s| s.name.clone())
+        .collect();
+    plan
+}
+
+/// Apply a sync plan: upload added and changed secrets, then delete removed ones
+pub async fn sync_secrets(
+    client: &CloudflareClient,
+    account_id: &str,
+    script_name: &str,
+    desired: &[(String, String)],
+    plan: &SecretSyncPlan,
+) -> Result<()> {
+    for (name, value) in desired {
+        put_secret(client, account_id, script_name, name, value).await?;
+    }
+    for name in &plan.remove {
+        delete_secret(client, account_id, script_name, name).await?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_binding() {
+        let binding = parse_binding("kv:CACHE=0f2ac74b498b48028cb68387c421e279").unwrap();
+        assert_eq!(binding.r#type, "kv_namespace");
+        assert_eq!(binding.name, "CACHE");
+        assert_eq!(
+            binding.fields["namespace_id"],
+            "0f2ac74b498b48028cb68387c421e279"
+        );
+
+        let binding = parse_binding("var:GREETING=hello=world").unwrap();
+        assert_eq!(binding.r#type, "plain_text");
+        assert_eq!(binding.fields["text"], "hello=world");
+
+        assert!(parse_binding("kv:CACHE").is_err());
+        assert!(parse_binding("blob:X=y").is_err());
+        assert!(parse_binding("r2:=bucket").is_err());
+    }
+
+    #[test]
+    fn test_module_content_type() {
+        assert_eq!(
+            module_content_type("index.js").unwrap(),
+            "application/javascript+module"
+        );
+        assert_eq!(module_content_type("add.wasm").unwrap(), "application/wasm");
+        assert!(module_content_type("style.css").is_err());
+    }
+
+    #[test]
+    fn test_load_modules_keeps_relative_names() {
+        let dir = std::env::temp_dir().join(format!("cfad-workers-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("lib")).unwrap();
+        std::fs::write(dir.join("index.js"), "import './lib/util.js'").unwrap();
+        std::fs::write(dir.join("lib/util.js"), "export {}").unwrap();
+
+        let modules = load_modules(&dir.join("index.js"), &[dir.join("lib/util.js")]).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        let names: Vec<&str> = modules.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["index.js", "lib/util.js"]);
+    }
+
+    #[test]
+    fn test_validate_compat_date_and_cron() {
+        assert!(validate_compat_date("2026-03-01").is_ok());
+        assert!(validate_compat_date("2026-3-1").is_err());
+        assert!(validate_compat_date("2026-13-01").is_err());
+
+        assert!(validate_cron("*/15 * * * *").is_ok());
+        assert!(validate_cron("0 12 * * MON-FRI").is_ok());
+        assert!(validate_cron("* * * *").is_err());
+        assert!(validate_cron("0 0 * * * rm").is_err());
+    }
+
+    #[test]
+    fn test_parse_dotenv() {
+        let contents = r#"
+# comment
+export API_KEY=abc123
+DB_URL="postgres://u:p@host/db"
+MULTI="line1\nline2 \"quoted\""
+LITERAL='no $expansion # here'
+TRAILING=value # comment
+"#;
+        let entries = parse_dotenv(contents).unwrap();
+        let get = |name: &str| {
+            entries
+                .iter()
+                .find(|(n, _)| n == name)
+                .map(|(_, v)| v.as_str())
+                .unwrap()
+        };
+        assert_eq!(entries.len(), 5);
+        assert_eq!(get("API_KEY"), "abc123");
+        assert_eq!(get("DB_URL"), "postgres://u:p@host/db");
+        assert_eq!(get("MULTI"), "line1\nline2 \"quoted\"");
+        assert_eq!(get("LITERAL"), "no $expansion # here");
+        assert_eq!(get("TRAILING"), "value");
+
+        assert!(parse_dotenv("1BAD=x").is_err());
+        assert!(parse_dotenv("A=1\nA=2").is_err());
+        assert!(parse_dotenv("A=\"open").is_err());
+        assert!(parse_dotenv("just text").is_err());
+    }
+
+    #[test]
+    fn test_parse_dotenv_rejects_empty_values() {
+        let err = parse_dotenv("A=1\nEMPTY=\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            CfadError::validation("Line 2: secret value for 'EMPTY' is empty").to_string()
+        );
+        assert!(parse_dotenv("QUOTED=\"\"").is_err());
+    }
+
+    #[test]
+    fn test_plan_secret_sync() {
+        let existing = vec![
+            WorkerSecret {
+                name: "KEEP".to_string(),
+                r#type: Some("secret_text".to_string()),
+            },
+            WorkerSecret {
+                name: "OLD".to_string(),
+                r#type: Some("secret_text".to_string()),
+            },
+        ];
+        let desired = vec![
+            ("KEEP".to_string(), "1".to_string()),
+            ("NEW".to_string(), "2".to_string()),
+        ];
+        let plan = plan_secret_sync(&existing, &desired);
+        assert_eq!(plan.add, vec!["NEW"]);
+        assert_eq!(plan.change, vec!["KEEP"]);
+        assert_eq!(plan.remove, vec!["OLD"]);
+    }
+
+    #[test]
+    fn test_trim_secret_value() {
+        assert_eq!(
+            trim_secret_value("s3cret\r\n".to_string()).unwrap(),
+            "s3cret"
+        );
+        assert_eq!(trim_secret_value("a\n\n".to_string()).unwrap(), "a\n");
+        assert!(trim_secret_value("\n".to_string()).is_err());
+    }
+}
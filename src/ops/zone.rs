@@ -1,17 +1,122 @@
 use crate::api::zone::{Zone, ZoneSetting, ZoneSettings};
 use crate::client::{CfResponse, CloudflareClient};
 use crate::error::Result;
+use crate::utils::filters::{glob_match, is_glob};
+use futures::stream::{self, StreamExt};
 use serde::Serialize;
+use std::future::Future;
+
+/// Page size used when walking the full zone list
+const ZONES_PER_PAGE: u32 = 50;
+
+/// Default number of zones processed in parallel by `for_each_zone`
+pub const DEFAULT_ZONE_CONCURRENCY: usize = 4;
+
+/// Statuses zones can be filtered by
+pub const ZONE_STATUSES: [&str; 6] = [
+    "active",
+    "pending",
+    "initializing",
+    "moved",
+    "deleted",
+    "deactivated",
+];
 
 pub async fn list_zones(client: &CloudflareClient, status: Option<&str>) -> Result<Vec<Zone>> {
-    let mut endpoint = "/zones".to_string();
+    if let Some(s) = status.filter(|s| !ZONE_STATUSES.contains(s)) {
+        return Err(crate::error::CfadError::validation(format!(
+            "Invalid zone status '{}': expected one of {}",
+            s,
+            ZONE_STATUSES.join(", ")
+        )));
+    }
+
+    let mut zones = Vec::new();
+    let mut page = 1;
+
+    loop {
+        let mut endpoint = format!("/zones?page={}&per_page={}", page, ZONES_PER_PAGE);
+        if let Some(s) = status {
+            endpoint.push_str(&format!("&status={}", s));
+        }
 
-    if let Some(s) = status {
-        endpoint.push_str(&format!("?status={}", s));
+        let response: CfResponse<Vec<Zone>> = client.get(&endpoint).await?;
+        zones.extend(response.result.unwrap_or_default());
+
+        // Responses without result_info are treated as a single page
+        match response.result_info {
+            Some(info) if page < info.total_pages => page += 1,
+            _ => break,
+        }
     }
 
-    let response: CfResponse<Vec<Zone>> = client.get(&endpoint).await?;
-    Ok(response.result.unwrap_or_default())
+    Ok(zones)
+}
+
+/// Select zones from the account by name or shell-style glob.
+///
+/// An empty pattern list selects every zone (optionally narrowed by
+/// `status`). A literal name that matches nothing is reported as not found
+/// so typos in a zones file don't silently shrink the target set.
+pub async fn select_zones(
+    client: &CloudflareClient,
+    patterns: &[String],
+    status: Option<&str>,
+) -> Result<Vec<Zone>> {
+    let zones = list_zones(client, status).await?;
+    filter_zones(zones, patterns)
+}
+
+fn filter_zones(zones: Vec<Zone>, patterns: &[String]) -> Result<Vec<Zone>> {
+    if patterns.is_empty() {
+        return Ok(zones);
+    }
+
+    for pattern in patterns.iter().filter(|p| !is_glob(p)) {
+        if !zones.iter().any(|z| z.name.eq_ignore_ascii_case(pattern)) {
+            return Err(crate::error::CfadError::not_found("Zone", pattern));
+        }
+    }
+
+    Ok(zones
+        .into_iter()
+        .filter(|z| patterns.iter().any(|p| glob_match(p, &z.name)))
+        .collect())
+}
+
+/// Result of running an operation against one zone of a fan-out
+#[derive(Debug)]
+pub struct ZoneOutcome<T> {
+    pub zone: Zone,
+    pub result: Result<T>,
+}
+
+/// Run `op` against every zone with at most `concurrency` requests in flight.
+///
+/// Outcomes are returned in the same order as `zones`; a failure in one zone
+/// does not stop the others.
+pub async fn for_each_zone<T, F, Fut>(
+    zones: Vec<Zone>,
+    concurrency: usize,
+    op: F,
+) -> Vec<ZoneOutcome<T>>
+where
+    F: Fn(Zone) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    stream::iter(zones)
+        .map(|zone| {
+            let fut = op(zone.clone());
+            async move {
+                ZoneOutcome {
+                    zone,
+                    result: fut.await,
+                }
+            }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
 }
 
 pub async fn get_zone(client: &CloudflareClient, zone_identifier: &str) -> Result<Zone> {
@@ -34,6 +139,19 @@ pub async fn get_zone(client: &CloudflareClient, zone_identifier: &str) -> Resul
         .ok_or_else(|| crate::error::CfadError::not_found("Zone", zone_identifier))
 }
 
+/// Find the zone a hostname belongs to: the longest zone name that equals
+/// the hostname or is a parent domain of it.
+pub fn zone_for_hostname<'a>(zones: &'a [Zone], hostname: &str) -> Option<&'a Zone> {
+    let hostname = hostname.trim_end_matches('.').to_ascii_lowercase();
+    zones
+        .iter()
+        .filter(|z| {
+            let name = z.name.to_ascii_lowercase();
+            hostname == name || hostname.ends_with(&format!(".{}", name))
+        })
+        .max_by_key(|z| z.name.len())
+}
+
 pub async fn create_zone(client: &CloudflareClient, name: &str, account_id: &str) -> Result<Zone> {
     #[derive(Serialize)]
     struct CreateZone<'a> {
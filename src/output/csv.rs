@@ -1,2 +1,64 @@
 // CSV formatting module
-// To be implemented for CSV export support
+
+use crate::api::d1::D1RawQueryResult;
+use crate::error::Result;
+use std::io::Write;
+
+/// Write D1 raw query results as CSV, one header row per result set.
+/// Result sets may have different column counts, so the writer is flexible.
+pub fn write_d1_raw_query_results<W: Write>(results: &[D1RawQueryResult], out: W) -> Result<()> {
+    let mut writer = csv::WriterBuilder::new().flexible(true).from_writer(out);
+    for result in results {
+        if result.columns.is_empty() {
+            continue;
+        }
+        writer
+            .write_record(&result.columns)
+            .map_err(std::io::Error::from)?;
+        for row in &result.rows {
+            writer
+                .write_record(row.iter().map(|value| match value {
+                    serde_json::Value::Null => String::new(),
+                    serde_json::Value::String(s) => s.clone(),
+                    other => other.to_string(),
+                }))
+                .map_err(std::io::Error::from)?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_d1_raw_query_results() {
+        let result: D1RawQueryResult = serde_json::from_value(serde_json::json!({
+            "columns": ["id", "name", "note"],
+            "rows": [[1, "a,b", null]],
+            "success": true
+        }))
+        .unwrap();
+        let mut out = Vec::new();
+        write_d1_raw_query_results(&[result], &mut out).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "id,name,note\n1,\"a,b\",\n"
+        );
+    }
+
+    #[test]
+    fn test_write_d1_raw_query_results_with_different_column_counts() {
+        let results: Vec<D1RawQueryResult> = serde_json::from_value(serde_json::json!([
+            {"columns": ["id", "name"], "rows": [[1, "a"]], "success": true},
+            {"columns": [], "rows": [], "success": true},
+            {"columns": ["count"], "rows": [[2]], "success": true}
+        ]))
+        .unwrap();
+        let mut out = Vec::new();
+        write_d1_raw_query_results(&results, &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "id,name\n1,a\ncount\n2\n");
+    }
+}
@@ -1,2 +1,77 @@
-// Progress tracking module
-// To be implemented with indicatif support
+use std::io::{IsTerminal, Write};
+
+/// Redraw at most every this many bytes
+const REDRAW_BYTES: u64 = 256 * 1024;
+
+/// Byte progress of a transfer, redrawn in place on stderr. Nothing is drawn
+/// when stderr is not a terminal, so logs and pipes stay clean.
+pub struct TransferProgress {
+    label: String,
+    interactive: bool,
+    last_drawn: Option<u64>,
+}
+
+impl TransferProgress {
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            interactive: std::io::stderr().is_terminal(),
+            last_drawn: None,
+        }
+    }
+
+    /// Record that `done` bytes of `total` (if known) have been transferred
+    pub fn update(&mut self, done: u64, total: Option<u64>) {
+        if !self.interactive {
+            return;
+        }
+        let finished = total.is_some_and(|t| done >= t);
+        if let Some(last) = self.last_drawn {
+            if done < last + REDRAW_BYTES && !finished {
+                return;
+            }
+        }
+        self.last_drawn = Some(done);
+        eprint!("\r{}", self.line(done, total));
+        std::io::stderr().flush().ok();
+    }
+
+    /// End the progress line
+    pub fn finish(&self) {
+        if self.interactive && self.last_drawn.is_some() {
+            eprintln!();
+        }
+    }
+
+    fn line(&self, done: u64, total: Option<u64>) -> String {
+        match total {
+            Some(total) if total > 0 => format!(
+                "  {}: {:>3}% ({} of {})",
+                self.label,
+                done * 100 / total,
+                super::table::format_bytes(done),
+                super::table::format_bytes(total)
+            ),
+            _ => format!("  {}: {}", self.label, super::table::format_bytes(done)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_line() {
+        let progress = TransferProgress {
+            label: "Downloading".to_string(),
+            interactive: false,
+            last_drawn: None,
+        };
+        assert_eq!(
+            progress.line(512 * 1024, Some(1024 * 1024)),
+            "  Downloading:  50% (512.00 KB of 1.00 MB)"
+        );
+        assert_eq!(progress.line(2048, None), "  Downloading: 2.00 KB");
+    }
+}
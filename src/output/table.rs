@@ -1,8 +1,29 @@
+use crate::api::cache::CacheReserveClear;
+use crate::api::certificates::OriginCertificate;
+use crate::api::custom_hostnames::CustomHostname;
 use crate::api::d1::{D1Database, D1QueryResult, D1RawQueryResult};
 use crate::api::dns::DnsRecord;
+use crate::api::firewall::{AccessRule, CustomList, ListItem};
+use crate::api::kv::{KvKey, KvNamespace};
+use crate::api::load_balancing::{LoadBalancer, Monitor, Pool, PoolHealth};
+use crate::api::page_rules::PageRule;
+use crate::api::queues::{PulledMessages, Queue, QueueConsumer};
 use crate::api::r2::{R2Bucket, R2CustomDomain, R2EventNotification, R2Metrics, R2MigrationJob};
+use crate::api::rulesets::{phase_alias, Rule, Ruleset};
 use crate::api::token::{PermissionGroup, Token};
+use crate::api::tunnel::{IngressRule, Tunnel, TunnelConnector};
+use crate::api::workers::{
+    CronTrigger, WorkerBinding, WorkerDomain, WorkerMetadata, WorkerRoute, WorkerScript,
+    WorkerSecret, WorkerSettings,
+};
 use crate::api::zone::Zone;
+use crate::ops::audit::{CheckStatus, ZoneAudit};
+use crate::ops::cache::CacheSettingState;
+use crate::ops::d1_migrations::MigrationStatus;
+use crate::ops::page_rules::PageRuleMigration;
+use crate::ops::ssl::SslStatus;
+use crate::ops::workers::ModuleFile;
+use crate::ops::zone::ZoneOutcome;
 use comfy_table::{presets::UTF8_FULL, Attribute, Cell, Color, ContentArrangement, Table};
 
 pub fn print_dns_records(records: &[DnsRecord]) {
@@ -112,6 +133,45 @@ pub fn print_zones(zones: &[Zone]) {
     println!("\nTotal: {} zones", zones.len());
 }
 
+pub fn print_zone_outcomes(outcomes: &[ZoneOutcome<String>]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Zone")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Result")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Details")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for outcome in outcomes {
+        let (status, details) = match &outcome.result {
+            Ok(details) => (Cell::new("✓ ok").fg(Color::Green), details.clone()),
+            Err(e) => (Cell::new("✗ failed").fg(Color::Red), e.to_string()),
+        };
+        table.add_row(vec![
+            Cell::new(&outcome.zone.name),
+            status,
+            Cell::new(details),
+        ]);
+    }
+
+    let failed = outcomes.iter().filter(|o| o.result.is_err()).count();
+    println!("{}", table);
+    println!(
+        "\nTotal: {} zones ({} succeeded, {} failed)",
+        outcomes.len(),
+        outcomes.len() - failed,
+        failed
+    );
+}
+
 pub fn print_d1_databases(databases: &[D1Database]) {
     let mut table = Table::new();
     table
@@ -212,6 +272,45 @@ pub fn print_d1_query_results(results: &[D1QueryResult]) {
     }
 }
 
+/// Print D1 migrations with their applied time
+pub fn print_d1_migrations(migrations: &[MigrationStatus]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Migration")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Status")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Applied At")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for migration in migrations {
+        let (status, applied_at) = match &migration.applied_at {
+            Some(at) => (Cell::new("applied").fg(Color::Green), at.as_str()),
+            None => (Cell::new("pending").fg(Color::Yellow), "-"),
+        };
+        table.add_row(vec![
+            Cell::new(&migration.name),
+            status,
+            Cell::new(applied_at),
+        ]);
+    }
+
+    println!("{}", table);
+    let pending = migrations.iter().filter(|m| m.applied_at.is_none()).count();
+    println!(
+        "\nTotal: {} migrations ({} pending)",
+        migrations.len(),
+        pending
+    );
+}
+
 /// Print D1 raw query results (array format) as a table
 pub fn print_d1_raw_query_results(results: &[D1RawQueryResult]) {
     for (i, result) in results.iter().enumerate() {
@@ -477,7 +576,7 @@ pub fn print_r2_migration_jobs(jobs: &[R2MigrationJob]) {
     println!("\nTotal: {} jobs", jobs.len());
 }
 
-fn format_bytes(bytes: u64) -> String {
+pub fn format_bytes(bytes: u64) -> String {
     const KB: u64 = 1024;
     const MB: u64 = KB * 1024;
     const GB: u64 = MB * 1024;
@@ -923,6 +1022,1768 @@ pub fn print_pages_domain(domain: &PagesDomain) {
     }
 }
 
+pub fn print_rulesets(rulesets: &[Ruleset]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Phase")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Kind")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Name")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Last Updated")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for ruleset in rulesets {
+        table.add_row(vec![
+            Cell::new(&ruleset.phase),
+            Cell::new(&ruleset.kind),
+            Cell::new(ruleset.name.as_deref().unwrap_or("-")),
+            Cell::new(ruleset.last_updated.as_deref().unwrap_or("-")),
+            Cell::new(&ruleset.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} rulesets", rulesets.len());
+}
+
+pub fn print_ruleset_rules(rules: &[Rule]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("#")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Action")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Description")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Expression")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Enabled")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for (i, rule) in rules.iter().enumerate() {
+        table.add_row(vec![
+            Cell::new(i + 1),
+            Cell::new(&rule.action),
+            Cell::new(rule.description.as_deref().unwrap_or("")),
+            Cell::new(&rule.expression),
+            Cell::new(if rule.enabled { "✓" } else { "✗" }),
+            Cell::new(rule.id.as_deref().unwrap_or("-")),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} rules", rules.len());
+}
+
+pub fn print_access_rules(rules: &[AccessRule]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Mode")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Target")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Value")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Scope")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Notes")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for rule in rules {
+        let scope = rule
+            .scope
+            .as_ref()
+            .map(|s| s.scope_type.as_str())
+            .unwrap_or("-");
+        table.add_row(vec![
+            Cell::new(&rule.mode),
+            Cell::new(&rule.configuration.target),
+            Cell::new(&rule.configuration.value),
+            Cell::new(scope),
+            Cell::new(&rule.notes),
+            Cell::new(&rule.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} access rules", rules.len());
+}
+
+pub fn print_custom_lists(lists: &[CustomList]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Name")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Kind")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Items")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Used By")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Description")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for list in lists {
+        table.add_row(vec![
+            Cell::new(&list.name),
+            Cell::new(&list.kind),
+            Cell::new(list.num_items),
+            Cell::new(list.num_referencing_filters),
+            Cell::new(list.description.as_deref().unwrap_or("")),
+            Cell::new(&list.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} lists", lists.len());
+}
+
+pub fn print_list_items(items: &[ListItem]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Value")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Comment")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Created")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for item in items {
+        table.add_row(vec![
+            Cell::new(item.value()),
+            Cell::new(item.comment.as_deref().unwrap_or("")),
+            Cell::new(item.created_on.as_deref().unwrap_or("-")),
+            Cell::new(item.id.as_deref().unwrap_or("-")),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} items", items.len());
+}
+
+pub fn print_page_rules(rules: &[PageRule]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Priority")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("URL")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Actions")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Status")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for rule in rules {
+        let actions: Vec<String> = rule.actions.iter().map(|a| a.summary()).collect();
+        let status_color = if rule.is_active() {
+            Color::Green
+        } else {
+            Color::Yellow
+        };
+        table.add_row(vec![
+            Cell::new(rule.priority),
+            Cell::new(rule.url_pattern().unwrap_or("-")),
+            Cell::new(actions.join("\n")),
+            Cell::new(&rule.status).fg(status_color),
+            Cell::new(&rule.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} page rules", rules.len());
+}
+
+/// Side-by-side view of each page rule and the rules that replace it
+pub fn print_page_rule_migration(plan: &[PageRuleMigration]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Page Rule")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Replacement")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Not Translated")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for migration in plan {
+        let page_rule = &migration.page_rule;
+        let mut source = vec![format!(
+            "#{} {}",
+            page_rule.priority,
+            page_rule.url_pattern().unwrap_or("-")
+        )];
+        source.extend(
+            page_rule
+                .actions
+                .iter()
+                .map(|a| format!("  {}", a.summary())),
+        );
+        if !page_rule.is_active() {
+            source.push(format!("  ({})", page_rule.status));
+        }
+
+        let replacement: Vec<String> = migration
+            .rules
+            .iter()
+            .map(|migrated| {
+                let params = migrated
+                    .rule
+                    .action_parameters
+                    .as_ref()
+                    .map(|p| p.to_string())
+                    .unwrap_or_default();
+                format!(
+                    "{}: {} {}\n  {}",
+                    phase_alias(migrated.phase),
+                    migrated.rule.action,
+                    params,
+                    migrated.rule.expression
+                )
+            })
+            .collect();
+
+        let mut flagged = migration.untranslated.clone();
+        flagged.extend(migration.notes.iter().map(|n| format!("(default) {}", n)));
+
+        table.add_row(vec![
+            Cell::new(source.join("\n")),
+            Cell::new(if replacement.is_empty() {
+                "-".to_string()
+            } else {
+                replacement.join("\n")
+            }),
+            Cell::new(flagged.join("\n")).fg(if migration.untranslated.is_empty() {
+                Color::Reset
+            } else {
+                Color::Yellow
+            }),
+        ]);
+    }
+
+    println!("{}", table);
+    let total: usize = plan.iter().map(|m| m.rules.len()).sum();
+    println!("\nTotal: {} page rules → {} rules", plan.len(), total);
+}
+
+pub fn print_custom_hostnames(hostnames: &[CustomHostname]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Hostname")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Status")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("SSL")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Method")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Origin")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for hostname in hostnames {
+        let status = hostname.status.as_deref().unwrap_or("-");
+        let ssl_color = if hostname.is_ssl_active() {
+            Color::Green
+        } else {
+            Color::Yellow
+        };
+        let method = hostname
+            .ssl
+            .as_ref()
+            .and_then(|ssl| ssl.method.as_deref())
+            .unwrap_or("-");
+        table.add_row(vec![
+            Cell::new(&hostname.hostname),
+            Cell::new(status),
+            Cell::new(hostname.ssl_status()).fg(ssl_color),
+            Cell::new(method),
+            Cell::new(
+                hostname
+                    .custom_origin_server
+                    .as_deref()
+                    .unwrap_or("(fallback)"),
+            ),
+            Cell::new(&hostname.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} custom hostnames", hostnames.len());
+}
+
+pub fn print_custom_hostname(hostname: &CustomHostname) {
+    println!("\nCustom Hostname Details:\n");
+    println!("  ID: {}", hostname.id);
+    println!("  Hostname: {}", hostname.hostname);
+    println!("  Status: {}", hostname.status.as_deref().unwrap_or("-"));
+    println!("  SSL: {}", hostname.ssl_status());
+    if let Some(ssl) = &hostname.ssl {
+        println!(
+            "  SSL Method: {} ({})",
+            ssl.method.as_deref().unwrap_or("-"),
+            ssl.r#type.as_deref().unwrap_or("-")
+        );
+    }
+    println!(
+        "  Origin: {}",
+        hostname
+            .custom_origin_server
+            .as_deref()
+            .unwrap_or("(fallback origin)")
+    );
+    if let Some(metadata) = &hostname.custom_metadata {
+        println!("  Metadata:");
+        for (key, value) in metadata {
+            println!("    {}: {}", key, value);
+        }
+    }
+    if let Some(created) = &hostname.created_at {
+        println!("  Created: {}", created);
+    }
+}
+
+/// Print the ownership and certificate validation records a customer must publish
+pub fn print_custom_hostname_verification(hostname: &CustomHostname) {
+    println!("\nVerification for {}:\n", hostname.hostname);
+    println!(
+        "  Hostname status: {}",
+        hostname.status.as_deref().unwrap_or("-")
+    );
+    println!("  SSL status: {}", hostname.ssl_status());
+
+    let pending_ownership = hostname.status.as_deref() != Some("active");
+    if pending_ownership {
+        if let Some(txt) = &hostname.ownership_verification {
+            println!("\n  Ownership (TXT record):");
+            println!("    Name:  {}", txt.name);
+            println!("    Value: {}", txt.value);
+        }
+        if let Some(http) = &hostname.ownership_verification_http {
+            println!("\n  Ownership (HTTP file):");
+            println!("    URL:  {}", http.http_url);
+            println!("    Body: {}", http.http_body);
+        }
+    }
+
+    if let Some(ssl) = hostname.ssl.as_ref().filter(|_| !hostname.is_ssl_active()) {
+        for record in &ssl.validation_records {
+            if let (Some(name), Some(value)) = (&record.txt_name, &record.txt_value) {
+                println!("\n  Certificate validation (TXT record):");
+                println!("    Name:  {}", name);
+                println!("    Value: {}", value);
+            }
+            if let (Some(url), Some(body)) = (&record.http_url, &record.http_body) {
+                println!("\n  Certificate validation (HTTP file):");
+                println!("    URL:  {}", url);
+                println!("    Body: {}", body);
+            }
+            if !record.emails.is_empty() {
+                println!("\n  Certificate validation (approval email sent to):");
+                for email in &record.emails {
+                    println!("    {}", email);
+                }
+            }
+        }
+        for error in &ssl.validation_errors {
+            println!("\n  ✗ {}", error.message);
+        }
+    }
+
+    for error in &hostname.verification_errors {
+        println!("\n  ✗ {}", error);
+    }
+
+    if !pending_ownership && hostname.is_ssl_active() {
+        println!("\n✓ Hostname and certificate are active; no records needed");
+    }
+}
+
+pub fn print_origin_certificates(certificates: &[OriginCertificate]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Hostnames")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Type")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Validity")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Expires")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for certificate in certificates {
+        let validity = certificate
+            .requested_validity
+            .map(|days| format!("{} days", days))
+            .unwrap_or_else(|| "-".to_string());
+        let expires = match &certificate.revoked_at {
+            Some(revoked_at) => Cell::new(format!("revoked {}", revoked_at)).fg(Color::Red),
+            None => Cell::new(certificate.expires_on.as_deref().unwrap_or("-")),
+        };
+        table.add_row(vec![
+            Cell::new(certificate.hostnames.join(", ")),
+            Cell::new(certificate.request_type.as_deref().unwrap_or("-")),
+            Cell::new(validity),
+            expires,
+            Cell::new(&certificate.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} certificates", certificates.len());
+}
+
+pub fn print_ssl_status(status: &SslStatus) {
+    let on_off = |enabled: bool| if enabled { "on" } else { "off" };
+    println!();
+    println!("  SSL mode: {}", status.ssl_mode.as_deref().unwrap_or("-"));
+    println!(
+        "  Minimum TLS version: {}",
+        status.min_tls_version.as_deref().unwrap_or("-")
+    );
+    println!("  TLS 1.3: {}", status.tls_1_3.as_deref().unwrap_or("-"));
+    println!(
+        "  Always Use HTTPS: {}",
+        status.always_use_https.as_deref().unwrap_or("-")
+    );
+    match &status.hsts {
+        Some(hsts) if hsts.enabled => println!(
+            "  HSTS: on (max-age={}{}{})",
+            hsts.max_age,
+            if hsts.include_subdomains {
+                ", includeSubDomains"
+            } else {
+                ""
+            },
+            if hsts.preload { ", preload" } else { "" }
+        ),
+        _ => println!("  HSTS: off"),
+    }
+    println!("  Universal SSL: {}", on_off(status.universal_ssl));
+    println!(
+        "  Authenticated Origin Pulls: {}",
+        on_off(status.authenticated_origin_pulls)
+    );
+    println!();
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Type")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Hosts")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Status")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("CA")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Expires")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for pack in &status.certificate_packs {
+        let pack_status = pack.status.as_deref().unwrap_or("-");
+        let status_color = if pack_status == "active" {
+            Color::Green
+        } else {
+            Color::Yellow
+        };
+        let expires = match pack.expires_on() {
+            Some(expires_on) => match crate::ops::ssl::days_until(expires_on, now) {
+                Some(days) => {
+                    let color = if days < 0 {
+                        Color::Red
+                    } else if days < 30 {
+                        Color::Yellow
+                    } else {
+                        Color::Reset
+                    };
+                    Cell::new(format!("{} ({} days)", &expires_on[..10], days)).fg(color)
+                }
+                None => Cell::new(expires_on),
+            },
+            None => Cell::new("-"),
+        };
+        table.add_row(vec![
+            Cell::new(pack.r#type.as_deref().unwrap_or("-")),
+            Cell::new(pack.hosts.join(", ")),
+            Cell::new(pack_status).fg(status_color),
+            Cell::new(pack.certificate_authority.as_deref().unwrap_or("-")),
+            expires,
+            Cell::new(&pack.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!(
+        "\nTotal: {} certificate packs",
+        status.certificate_packs.len()
+    );
+}
+
+fn check_status_cell(status: CheckStatus) -> Cell {
+    match status {
+        CheckStatus::Pass => Cell::new("✓ pass").fg(Color::Green),
+        CheckStatus::Warn => Cell::new("⚠ warn").fg(Color::Yellow),
+        CheckStatus::Fail => Cell::new("✗ fail").fg(Color::Red),
+    }
+}
+
+pub fn print_zone_audit(audit: &ZoneAudit) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Result")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Check")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Details")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for check in &audit.checks {
+        table.add_row(vec![
+            check_status_cell(check.status),
+            Cell::new(check.title),
+            Cell::new(&check.detail),
+        ]);
+    }
+
+    println!("{}", table);
+    println!(
+        "\nScore: {}/100 ({} passed, {} warnings, {} failed)",
+        audit.score,
+        audit.count(CheckStatus::Pass),
+        audit.count(CheckStatus::Warn),
+        audit.count(CheckStatus::Fail)
+    );
+}
+
+pub fn print_zone_audit_summary(audits: &[ZoneAudit]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Zone")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Score")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Result")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Warnings")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Failures")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    let mut sorted: Vec<&ZoneAudit> = audits.iter().collect();
+    sorted.sort_by_key(|audit| audit.score);
+    for audit in sorted {
+        table.add_row(vec![
+            Cell::new(&audit.zone),
+            Cell::new(audit.score),
+            check_status_cell(audit.worst()),
+            Cell::new(audit.count(CheckStatus::Warn)),
+            Cell::new(audit.count(CheckStatus::Fail)),
+        ]);
+    }
+
+    println!("{}", table);
+    let average = audits.iter().map(|a| a.score).sum::<u32>() / audits.len().max(1) as u32;
+    println!(
+        "\nTotal: {} zones (average score {})",
+        audits.len(),
+        average
+    );
+}
+
+pub fn print_lb_monitors(monitors: &[Monitor]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Type")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Target")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Expected")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Interval")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Description")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for monitor in monitors {
+        let target = match (&monitor.method, &monitor.path) {
+            (Some(method), Some(path)) => format!("{} {}", method, path),
+            (None, Some(path)) => path.clone(),
+            _ => monitor
+                .port
+                .map(|port| format!("port {}", port))
+                .unwrap_or_else(|| "-".to_string()),
+        };
+        table.add_row(vec![
+            Cell::new(monitor.r#type.as_deref().unwrap_or("-")),
+            Cell::new(target),
+            Cell::new(monitor.expected_codes.as_deref().unwrap_or("-")),
+            Cell::new(
+                monitor
+                    .interval
+                    .map(|secs| format!("{}s", secs))
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            Cell::new(monitor.description.as_deref().unwrap_or("")),
+            Cell::new(&monitor.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} monitors", monitors.len());
+}
+
+pub fn print_lb_monitor(monitor: &Monitor) {
+    println!("\nMonitor Details:\n");
+    println!("  ID: {}", monitor.id);
+    println!("  Type: {}", monitor.r#type.as_deref().unwrap_or("-"));
+    if let Some(description) = &monitor.description {
+        println!("  Description: {}", description);
+    }
+    if let Some(method) = &monitor.method {
+        println!("  Method: {}", method);
+    }
+    if let Some(path) = &monitor.path {
+        println!("  Path: {}", path);
+    }
+    if let Some(port) = monitor.port {
+        println!("  Port: {}", port);
+    }
+    if let Some(codes) = &monitor.expected_codes {
+        println!("  Expected Codes: {}", codes);
+    }
+    if let Some(body) = &monitor.expected_body {
+        println!("  Expected Body: {}", body);
+    }
+    println!(
+        "  Interval / Timeout / Retries: {}s / {}s / {}",
+        monitor.interval.unwrap_or(60),
+        monitor.timeout.unwrap_or(5),
+        monitor.retries.unwrap_or(2)
+    );
+    if let Some(headers) = &monitor.header {
+        println!("  Headers:");
+        for (name, values) in headers {
+            println!("    {}: {}", name, values.join(", "));
+        }
+    }
+    if let Some(modified) = &monitor.modified_on {
+        println!("  Modified: {}", modified);
+    }
+}
+
+fn pool_health_cell(healthy: Option<bool>) -> Cell {
+    match healthy {
+        Some(true) => Cell::new("healthy").fg(Color::Green),
+        Some(false) => Cell::new("unhealthy").fg(Color::Red),
+        None => Cell::new("unknown").fg(Color::Yellow),
+    }
+}
+
+pub fn print_lb_pools(pools: &[Pool]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Name")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Enabled")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Health")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Origins")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Monitor")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for pool in pools {
+        let active = pool.origins.iter().filter(|o| o.enabled).count();
+        table.add_row(vec![
+            Cell::new(&pool.name),
+            Cell::new(if pool.enabled { "yes" } else { "no" }),
+            pool_health_cell(pool.healthy),
+            Cell::new(format!("{}/{} enabled", active, pool.origins.len())),
+            Cell::new(pool.monitor.as_deref().unwrap_or("-")),
+            Cell::new(&pool.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} pools", pools.len());
+}
+
+pub fn print_lb_pool(pool: &Pool) {
+    println!("\nPool Details:\n");
+    println!("  ID: {}", pool.id);
+    println!("  Name: {}", pool.name);
+    if let Some(description) = &pool.description {
+        println!("  Description: {}", description);
+    }
+    println!("  Enabled: {}", if pool.enabled { "yes" } else { "no" });
+    println!("  Monitor: {}", pool.monitor.as_deref().unwrap_or("-"));
+    println!("  Minimum Origins: {}", pool.minimum_origins.unwrap_or(1));
+    if let Some(regions) = &pool.check_regions {
+        println!("  Check Regions: {}", regions.join(", "));
+    }
+    if let Some(email) = &pool.notification_email {
+        println!("  Notification Email: {}", email);
+    }
+    println!();
+
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Origin")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Address")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Enabled")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Weight")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for origin in &pool.origins {
+        let enabled = if origin.enabled {
+            Cell::new("yes").fg(Color::Green)
+        } else {
+            Cell::new("no").fg(Color::Red)
+        };
+        table.add_row(vec![
+            Cell::new(&origin.name),
+            Cell::new(&origin.address),
+            enabled,
+            Cell::new(origin.weight),
+        ]);
+    }
+
+    println!("{}", table);
+}
+
+/// Print origin health per check region, naming origins from the pool where possible
+pub fn print_lb_pool_health(pool: &Pool, health: &PoolHealth) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Region")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Origin")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Health")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("RTT")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Details")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for (region, region_health) in &health.pop_health {
+        for origins in &region_health.origins {
+            for (address, origin) in origins {
+                let name = pool
+                    .origins
+                    .iter()
+                    .find(|o| &o.address == address)
+                    .map(|o| format!("{} ({})", o.name, address))
+                    .unwrap_or_else(|| address.clone());
+                let details = match (&origin.failure_reason, origin.response_code) {
+                    (Some(reason), _) if !reason.is_empty() && reason != "No failures" => {
+                        reason.clone()
+                    }
+                    (_, Some(code)) => format!("HTTP {}", code),
+                    _ => String::new(),
+                };
+                table.add_row(vec![
+                    Cell::new(region),
+                    Cell::new(name),
+                    pool_health_cell(Some(origin.healthy)),
+                    Cell::new(origin.rtt.as_deref().unwrap_or("-")),
+                    Cell::new(details),
+                ]);
+            }
+        }
+    }
+
+    println!("{}", table);
+    let unhealthy = health
+        .pop_health
+        .values()
+        .filter(|region| !region.healthy)
+        .count();
+    println!(
+        "\nRegions: {} ({} unhealthy)",
+        health.pop_health.len(),
+        unhealthy
+    );
+}
+
+pub fn print_load_balancers(balancers: &[LoadBalancer]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Hostname")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Enabled")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Proxied")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Steering")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Pools")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for balancer in balancers {
+        table.add_row(vec![
+            Cell::new(&balancer.name),
+            Cell::new(if balancer.enabled { "yes" } else { "no" }),
+            Cell::new(if balancer.proxied { "yes" } else { "no" }),
+            Cell::new(balancer.steering_policy.as_deref().unwrap_or("off")),
+            Cell::new(balancer.default_pools.len()),
+            Cell::new(&balancer.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} load balancers", balancers.len());
+}
+
+pub fn print_load_balancer(balancer: &LoadBalancer) {
+    println!("\nLoad Balancer Details:\n");
+    println!("  ID: {}", balancer.id);
+    println!("  Hostname: {}", balancer.name);
+    if let Some(description) = &balancer.description {
+        println!("  Description: {}", description);
+    }
+    println!("  Enabled: {}", if balancer.enabled { "yes" } else { "no" });
+    println!("  Proxied: {}", if balancer.proxied { "yes" } else { "no" });
+    if let Some(ttl) = balancer.ttl {
+        println!("  TTL: {}", ttl);
+    }
+    println!(
+        "  Steering: {}",
+        balancer.steering_policy.as_deref().unwrap_or("off")
+    );
+    println!(
+        "  Session Affinity: {}",
+        balancer.session_affinity.as_deref().unwrap_or("none")
+    );
+    println!("  Default Pools:");
+    for (i, pool) in balancer.default_pools.iter().enumerate() {
+        println!("    {}. {}", i + 1, pool);
+    }
+    println!(
+        "  Fallback Pool: {}",
+        balancer.fallback_pool.as_deref().unwrap_or("-")
+    );
+    if let Some(modified) = &balancer.modified_on {
+        println!("  Modified: {}", modified);
+    }
+}
+
+fn tunnel_status_cell(status: Option<&str>) -> Cell {
+    match status {
+        Some("healthy") => Cell::new("healthy").fg(Color::Green),
+        Some("degraded") => Cell::new("degraded").fg(Color::Yellow),
+        Some("down") => Cell::new("down").fg(Color::Red),
+        Some(other) => Cell::new(other),
+        None => Cell::new("-"),
+    }
+}
+
+pub fn print_tunnels(tunnels: &[Tunnel]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Name")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Status")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Config")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Connections")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Created")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for tunnel in tunnels {
+        table.add_row(vec![
+            Cell::new(&tunnel.name),
+            tunnel_status_cell(tunnel.status.as_deref()),
+            Cell::new(if tunnel.is_remotely_managed() {
+                "remote"
+            } else {
+                "local"
+            }),
+            Cell::new(tunnel.connections.len()),
+            Cell::new(tunnel.created_at.as_deref().unwrap_or("-")),
+            Cell::new(&tunnel.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} tunnels", tunnels.len());
+}
+
+pub fn print_tunnel(tunnel: &Tunnel) {
+    println!("\nTunnel Details:\n");
+    println!("  ID: {}", tunnel.id);
+    println!("  Name: {}", tunnel.name);
+    println!("  Status: {}", tunnel.status.as_deref().unwrap_or("-"));
+    println!(
+        "  Configuration: {}",
+        if tunnel.is_remotely_managed() {
+            "remotely managed"
+        } else {
+            "locally managed (config.yml)"
+        }
+    );
+    println!("  CNAME Target: {}", tunnel.cname_target());
+    if let Some(created) = &tunnel.created_at {
+        println!("  Created: {}", created);
+    }
+    if let Some(active) = &tunnel.conns_active_at {
+        println!("  Connected Since: {}", active);
+    }
+}
+
+/// Print one row per connection, grouped by the connector that opened it
+pub fn print_tunnel_connections(connectors: &[TunnelConnector]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Connector")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Version")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Arch")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Colo")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Origin IP")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Opened")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("State")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    let mut connections = 0;
+    for connector in connectors {
+        for conn in &connector.conns {
+            connections += 1;
+            let state = if conn.is_pending_reconnect {
+                Cell::new("reconnecting").fg(Color::Yellow)
+            } else {
+                Cell::new("connected").fg(Color::Green)
+            };
+            table.add_row(vec![
+                Cell::new(&connector.id),
+                Cell::new(connector.version.as_deref().unwrap_or("-")),
+                Cell::new(connector.arch.as_deref().unwrap_or("-")),
+                Cell::new(conn.colo_name.as_deref().unwrap_or("-")),
+                Cell::new(conn.origin_ip.as_deref().unwrap_or("-")),
+                Cell::new(conn.opened_at.as_deref().unwrap_or("-")),
+                state,
+            ]);
+        }
+    }
+
+    println!("{}", table);
+    println!(
+        "\nTotal: {} connections from {} connectors",
+        connections,
+        connectors.len()
+    );
+}
+
+pub fn print_tunnel_ingress(rules: &[IngressRule]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("#")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Hostname")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Path")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Service")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for (i, rule) in rules.iter().enumerate() {
+        table.add_row(vec![
+            Cell::new(i + 1),
+            Cell::new(rule.hostname.as_deref().unwrap_or("*")),
+            Cell::new(rule.path.as_deref().unwrap_or("")),
+            Cell::new(&rule.service),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} rules", rules.len());
+}
+
+pub fn print_worker_scripts(scripts: &[WorkerScript]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Name")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Handlers")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Compat Date")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Modified")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for script in scripts {
+        table.add_row(vec![
+            Cell::new(&script.id),
+            Cell::new(script.handlers.join(", ")),
+            Cell::new(script.compatibility_date.as_deref().unwrap_or("-")),
+            Cell::new(script.modified_on.as_deref().unwrap_or("-")),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} workers", scripts.len());
+}
+
+fn print_worker_bindings(bindings: &[WorkerBinding]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Binding")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Type")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Target")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for binding in bindings {
+        // Secret values are never returned, only their names
+        let target = if binding.r#type.starts_with("secret") {
+            "(hidden)".to_string()
+        } else {
+            binding.target()
+        };
+        table.add_row(vec![
+            Cell::new(&binding.name),
+            Cell::new(&binding.r#type),
+            Cell::new(target),
+        ]);
+    }
+
+    println!("{}", table);
+}
+
+pub fn print_worker_script(
+    script: &WorkerScript,
+    settings: &WorkerSettings,
+    schedules: &[CronTrigger],
+    domains: &[WorkerDomain],
+) {
+    println!("\nWorker Details:\n");
+    println!("  Name: {}", script.id);
+    if !script.handlers.is_empty() {
+        println!("  Handlers: {}", script.handlers.join(", "));
+    }
+    println!(
+        "  Compatibility Date: {}",
+        settings
+            .compatibility_date
+            .as_deref()
+            .or(script.compatibility_date.as_deref())
+            .unwrap_or("-")
+    );
+    if !settings.compatibility_flags.is_empty() {
+        println!(
+            "  Compatibility Flags: {}",
+            settings.compatibility_flags.join(", ")
+        );
+    }
+    if let Some(modified) = &script.modified_on {
+        println!("  Modified: {}", modified);
+    }
+    if !schedules.is_empty() {
+        println!("  Cron Triggers:");
+        for schedule in schedules {
+            println!("    {}", schedule.cron);
+        }
+    }
+    if !domains.is_empty() {
+        println!("  Custom Domains:");
+        for domain in domains {
+            println!("    {}", domain.hostname);
+        }
+    }
+
+    println!("\nBindings:\n");
+    print_worker_bindings(&settings.bindings);
+}
+
+/// Summary of a deploy for --dry-run
+pub fn print_worker_upload(metadata: &WorkerMetadata, modules: &[ModuleFile]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Module")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Type")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Size")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for module in modules {
+        let name = if module.name == metadata.main_module {
+            format!("{} (main)", module.name)
+        } else {
+            module.name.clone()
+        };
+        table.add_row(vec![
+            Cell::new(name),
+            Cell::new(&module.content_type),
+            Cell::new(format!("{} bytes", module.data.len())),
+        ]);
+    }
+
+    println!("{}", table);
+    println!(
+        "\nCompatibility Date: {}",
+        metadata.compatibility_date.as_deref().unwrap_or("-")
+    );
+    if !metadata.compatibility_flags.is_empty() {
+        println!(
+            "Compatibility Flags: {}",
+            metadata.compatibility_flags.join(", ")
+        );
+    }
+    if !metadata.bindings.is_empty() {
+        println!();
+        print_worker_bindings(&metadata.bindings);
+    }
+}
+
+pub fn print_worker_routes(routes: &[WorkerRoute]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Pattern")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Script")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for route in routes {
+        let script = match &route.script {
+            Some(script) => Cell::new(script),
+            None => Cell::new("(disabled)").fg(Color::Yellow),
+        };
+        table.add_row(vec![
+            Cell::new(&route.pattern),
+            script,
+            Cell::new(&route.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} routes", routes.len());
+}
+
+pub fn print_worker_domains(domains: &[WorkerDomain]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Hostname")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Worker")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Zone")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for domain in domains {
+        table.add_row(vec![
+            Cell::new(&domain.hostname),
+            Cell::new(&domain.service),
+            Cell::new(domain.zone_name.as_deref().unwrap_or("-")),
+            Cell::new(&domain.id),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} domains", domains.len());
+}
+
+pub fn print_worker_crons(schedules: &[CronTrigger]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Cron")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Created")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Modified")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for schedule in schedules {
+        table.add_row(vec![
+            Cell::new(&schedule.cron),
+            Cell::new(schedule.created_on.as_deref().unwrap_or("-")),
+            Cell::new(schedule.modified_on.as_deref().unwrap_or("-")),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} cron triggers", schedules.len());
+}
+
+pub fn print_worker_secrets(secrets: &[WorkerSecret]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Name")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Type")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for secret in secrets {
+        table.add_row(vec![
+            Cell::new(&secret.name),
+            Cell::new(secret.r#type.as_deref().unwrap_or("-")),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} secrets", secrets.len());
+}
+
+pub fn print_cache_settings(states: &[CacheSettingState]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Setting")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Value")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Editable")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Modified")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for state in states {
+        let row = match &state.setting {
+            Ok(setting) => {
+                let value = setting.value.as_str().unwrap_or("-");
+                let color = if value == "on" {
+                    Color::Green
+                } else {
+                    Color::Reset
+                };
+                vec![
+                    Cell::new(state.name),
+                    Cell::new(value).fg(color),
+                    Cell::new(if setting.editable { "yes" } else { "no" }),
+                    Cell::new(setting.modified_on.as_deref().unwrap_or("-")),
+                ]
+            }
+            Err(_) => vec![
+                Cell::new(state.name),
+                Cell::new("unavailable").fg(Color::Yellow),
+                Cell::new("-"),
+                Cell::new("-"),
+            ],
+        };
+        table.add_row(row);
+    }
+
+    println!("{}", table);
+}
+
+pub fn print_cache_reserve_clear(operation: &CacheReserveClear) {
+    println!("\nCache Reserve Clear:\n");
+    println!("  State: {}", operation.state);
+    if let Some(started) = &operation.start_ts {
+        println!("  Started: {}", started);
+    }
+    if let Some(ended) = &operation.end_ts {
+        println!("  Finished: {}", ended);
+    }
+}
+
+pub fn print_queues(queues: &[Queue]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Name")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Producers")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Consumers")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Created")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for queue in queues {
+        table.add_row(vec![
+            Cell::new(&queue.queue_name),
+            Cell::new(&queue.queue_id),
+            Cell::new(
+                queue
+                    .producers_total_count
+                    .unwrap_or(queue.producers.len() as u32),
+            ),
+            Cell::new(
+                queue
+                    .consumers_total_count
+                    .unwrap_or(queue.consumers.len() as u32),
+            ),
+            Cell::new(queue.created_on.as_deref().unwrap_or("-")),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} queues", queues.len());
+}
+
+pub fn print_queue(queue: &Queue) {
+    println!("\nQueue Details:\n");
+    println!("  Name: {}", queue.queue_name);
+    println!("  ID: {}", queue.queue_id);
+    if let Some(created) = &queue.created_on {
+        println!("  Created: {}", created);
+    }
+    if let Some(settings) = &queue.settings {
+        if let Some(retention) = settings.message_retention_period {
+            println!("  Message Retention: {}s", retention);
+        }
+        if let Some(delay) = settings.delivery_delay {
+            println!("  Delivery Delay: {}s", delay);
+        }
+    }
+    if !queue.producers.is_empty() {
+        println!("  Producers:");
+        for producer in &queue.producers {
+            let name = producer
+                .script
+                .as_deref()
+                .or(producer.bucket_name.as_deref())
+                .unwrap_or("-");
+            println!(
+                "    {} ({})",
+                name,
+                producer.r#type.as_deref().unwrap_or("worker")
+            );
+        }
+    }
+
+    println!("\nConsumers:\n");
+    print_queue_consumers(&queue.consumers);
+}
+
+pub fn print_queue_consumers(consumers: &[QueueConsumer]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Type")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Consumer")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Batch")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Retries")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Dead Letter Queue")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    let or_dash = |value: Option<u32>| value.map(|v| v.to_string()).unwrap_or("-".to_string());
+    for consumer in consumers {
+        table.add_row(vec![
+            Cell::new(consumer.consumer_id.as_deref().unwrap_or("-")),
+            Cell::new(&consumer.r#type),
+            Cell::new(consumer.target()),
+            Cell::new(or_dash(consumer.settings.batch_size)),
+            Cell::new(or_dash(consumer.settings.max_retries)),
+            Cell::new(consumer.dead_letter_queue.as_deref().unwrap_or("-")),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} consumers", consumers.len());
+}
+
+/// Messages leased by `queues pull`, with the lease IDs needed to ack them
+pub fn print_queue_messages(pulled: &PulledMessages) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Lease ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Attempts")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Body")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for message in &pulled.messages {
+        let body = match &message.body {
+            serde_json::Value::String(text) => text.clone(),
+            other => other.to_string(),
+        };
+        table.add_row(vec![
+            Cell::new(&message.lease_id),
+            Cell::new(message.attempts),
+            Cell::new(body),
+        ]);
+    }
+
+    println!("{}", table);
+    print!("\nLeased: {} messages", pulled.messages.len());
+    match pulled.message_backlog_count {
+        Some(backlog) => println!(" ({} in backlog)", backlog),
+        None => println!(),
+    }
+}
+
+pub fn print_kv_namespaces(namespaces: &[KvNamespace]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Title")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("ID")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for namespace in namespaces {
+        table.add_row(vec![Cell::new(&namespace.title), Cell::new(&namespace.id)]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} namespaces", namespaces.len());
+}
+
+pub fn print_kv_keys(keys: &[KvKey]) {
+    let mut table = Table::new();
+    table
+        .load_preset(UTF8_FULL)
+        .set_content_arrangement(ContentArrangement::Dynamic)
+        .set_header(vec![
+            Cell::new("Key")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Expiration")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+            Cell::new("Metadata")
+                .add_attribute(Attribute::Bold)
+                .fg(Color::Cyan),
+        ]);
+
+    for key in keys {
+        table.add_row(vec![
+            Cell::new(&key.name),
+            Cell::new(
+                key.expiration
+                    .map(|e| e.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+            Cell::new(
+                key.metadata
+                    .as_ref()
+                    .map(|m| m.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+            ),
+        ]);
+    }
+
+    println!("{}", table);
+    println!("\nTotal: {} keys", keys.len());
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,6 +43,14 @@ where
         return handle_config_command(cmd).await;
     }
 
+    // Expression validation and testing run entirely offline
+    if let Commands::Rules(
+        cmd @ (cli::rules::RulesCommand::Validate { .. } | cli::rules::RulesCommand::Test { .. }),
+    ) = command
+    {
+        return handle_rules_offline_command(cmd);
+    }
+
     // Load configuration and apply CLI overrides
     let mut profile = Config::load(cli.profile.as_deref())?;
     if let Some(api_token) = &cli.api_token {
@@ -68,9 +76,18 @@ where
         Commands::Zone(cmd) => handle_zone_command(&client, cmd).await?,
         Commands::Cache(cmd) => handle_cache_command(&client, cmd).await?,
         Commands::D1(cmd) => handle_d1_command(&client, cmd).await?,
+        Commands::Firewall(cmd) => handle_firewall_command(&client, cmd).await?,
+        Commands::Kv(cmd) => handle_kv_command(&client, cmd).await?,
+        Commands::Lb(cmd) => handle_lb_command(&client, cmd).await?,
+        Commands::Lists(cmd) => handle_lists_command(&client, cmd).await?,
         Commands::Pages(cmd) => handle_pages_command(&client, cmd).await?,
+        Commands::Queues(cmd) => handle_queues_command(&client, cmd).await?,
         Commands::R2(cmd) => handle_r2_command(&client, cmd).await?,
+        Commands::Rules(cmd) => handle_rules_command(&client, cmd).await?,
+        Commands::Ssl(cmd) => handle_ssl_command(&client, cmd).await?,
         Commands::Token(cmd) => handle_token_command(&client, cmd).await?,
+        Commands::Tunnel(cmd) => handle_tunnel_command(&client, cmd).await?,
+        Commands::Workers(cmd) => handle_workers_command(&client, cmd).await?,
     }
 
     Ok(())
@@ -144,7 +161,16 @@ pub async fn handle_dns_command(
     use cli::dns::DnsCommand;
 
     match cmd {
-        DnsCommand::List { zone, r#type, name } => {
+        DnsCommand::List {
+            zone,
+            selector,
+            r#type,
+            name,
+        } => {
+            if selector.is_active() {
+                return handle_dns_list_zones(client, &selector, r#type, name).await;
+            }
+            let zone = require_zone(zone)?;
             return handle_dns_list(client, &zone, r#type, name).await;
         }
         DnsCommand::Show { zone, record_id } => {
@@ -208,6 +234,32 @@ pub async fn handle_dns_list(
     Ok(())
 }
 
+pub async fn handle_dns_list_zones(
+    client: &client::CloudflareClient,
+    selector: &cli::zone::ZoneSelector,
+    record_type: Option<String>,
+    name: Option<String>,
+) -> Result<()> {
+    let zones = resolve_selected_zones(client, selector).await?;
+    let outcomes = ops::zone::for_each_zone(zones, selector.concurrency, |zone| {
+        let record_type = record_type.clone();
+        let name = name.clone();
+        async move {
+            ops::dns::list_records(client, &zone.id, record_type.as_deref(), name.as_deref()).await
+        }
+    })
+    .await;
+
+    for outcome in &outcomes {
+        if let Ok(records) = &outcome.result {
+            println!("\nDNS Records for {}:\n", outcome.zone.name);
+            output::table::print_dns_records(records);
+        }
+    }
+
+    finish_zone_fan_out(outcomes, |records| format!("{} records", records.len()))
+}
+
 pub async fn handle_dns_show(
     client: &client::CloudflareClient,
     zone: &str,
@@ -314,6 +366,7 @@ pub async fn handle_zone_command(
         ZoneCommand::Settings { zone } => return handle_zone_settings(client, &zone).await,
         ZoneCommand::Update {
             zone,
+            selector,
             security_level,
             cache_level,
             dev_mode,
@@ -321,6 +374,18 @@ pub async fn handle_zone_command(
             ssl,
             always_https,
         } => {
+            if selector.is_active() {
+                let settings = build_zone_settings(
+                    security_level,
+                    cache_level,
+                    dev_mode,
+                    ipv6,
+                    ssl,
+                    always_https,
+                );
+                return handle_zone_update_zones(client, &selector, settings).await;
+            }
+            let zone = require_zone(zone)?;
             return handle_zone_update(
                 client,
                 &zone,
@@ -333,6 +398,262 @@ pub async fn handle_zone_command(
             )
             .await;
         }
+        ZoneCommand::Audit {
+            zone,
+            selector,
+            report,
+        } => return handle_zone_audit(client, zone, &selector, report).await,
+        ZoneCommand::PageRules(cmd) => return handle_page_rule_command(client, cmd).await,
+        ZoneCommand::CustomHostnames(cmd) => {
+            return handle_custom_hostname_command(client, cmd).await
+        }
+    }
+}
+
+pub async fn handle_page_rule_command(
+    client: &client::CloudflareClient,
+    cmd: cli::zone::PageRuleCommand,
+) -> Result<()> {
+    use cli::zone::PageRuleCommand;
+
+    match cmd {
+        PageRuleCommand::List { zone } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let rules = ops::page_rules::list_page_rules(client, &zone_obj.id).await?;
+            println!("\nPage rules for {}:\n", zone_obj.name);
+            output::table::print_page_rules(&rules);
+            Ok(())
+        }
+        PageRuleCommand::Show { zone, rule_id } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let rule = ops::page_rules::get_page_rule(client, &zone_obj.id, &rule_id).await?;
+            println!("Page rule: {}", rule.id);
+            println!("  URL: {}", rule.url_pattern().unwrap_or("-"));
+            println!("  Priority: {}", rule.priority);
+            println!("  Status: {}", rule.status);
+            println!("  Actions:");
+            for action in &rule.actions {
+                println!("    {}", action.summary());
+            }
+            if let Some(modified) = &rule.modified_on {
+                println!("  Modified: {}", modified);
+            }
+            Ok(())
+        }
+        PageRuleCommand::Delete {
+            zone,
+            rule_id,
+            confirm,
+        } => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            ops::page_rules::delete_page_rule(client, &zone_obj.id, &rule_id).await
+        }
+        PageRuleCommand::Migrate { zone, apply } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let rules = ops::page_rules::list_page_rules(client, &zone_obj.id).await?;
+            if rules.is_empty() {
+                println!("No page rules found for {}", zone_obj.name);
+                return Ok(());
+            }
+
+            let plan = ops::page_rules::plan_migration(&rules);
+            println!("\nMigration plan for {}:\n", zone_obj.name);
+            output::table::print_page_rule_migration(&plan);
+
+            let flagged = plan.iter().filter(|m| !m.untranslated.is_empty()).count();
+            if flagged > 0 {
+                println!(
+                    "\n⚠ {} page rule(s) have settings that cannot be translated; review them manually",
+                    flagged
+                );
+            }
+
+            if !apply {
+                println!("\nRun again with --apply to create these rules");
+                return Ok(());
+            }
+            let added = ops::page_rules::apply_migration(client, &zone_obj.id, &plan).await?;
+            if added == 0 {
+                println!("\n✓ All page rules were already migrated");
+                return Ok(());
+            }
+            println!(
+                "\n✓ Created {} rules. Delete the page rules once the new rules are verified:",
+                added
+            );
+            println!(
+                "  cfad zone page-rules delete {} <RULE_ID> --confirm",
+                zone_obj.name
+            );
+            Ok(())
+        }
+    }
+}
+
+/// How often `custom-hostnames --wait` checks the certificate status
+const CUSTOM_HOSTNAME_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+pub async fn handle_custom_hostname_command(
+    client: &client::CloudflareClient,
+    cmd: cli::zone::CustomHostnameCommand,
+) -> Result<()> {
+    use crate::api::custom_hostnames::{
+        CreateCustomHostname, CustomHostnameSsl, UpdateCustomHostname,
+    };
+    use cli::zone::CustomHostnameCommand;
+
+    match cmd {
+        CustomHostnameCommand::List { zone, hostname } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let hostnames = ops::custom_hostnames::list_custom_hostnames(
+                client,
+                &zone_obj.id,
+                hostname.as_deref(),
+            )
+            .await?;
+            println!("\nCustom hostnames for {}:\n", zone_obj.name);
+            output::table::print_custom_hostnames(&hostnames);
+            Ok(())
+        }
+        CustomHostnameCommand::Show { zone, hostname } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let hostname =
+                ops::custom_hostnames::get_custom_hostname(client, &zone_obj.id, &hostname).await?;
+            output::table::print_custom_hostname(&hostname);
+            Ok(())
+        }
+        CustomHostnameCommand::Create {
+            zone,
+            hostname,
+            ssl_method,
+            ssl_type,
+            options,
+            wait,
+        } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let metadata = ops::custom_hostnames::parse_metadata(&options.metadata)?;
+            let create = CreateCustomHostname {
+                hostname,
+                ssl: CustomHostnameSsl {
+                    method: Some(ssl_method),
+                    r#type: Some(ssl_type),
+                    ..Default::default()
+                },
+                custom_origin_server: options.origin,
+                custom_metadata: (!metadata.is_empty()).then_some(metadata),
+            };
+            let created =
+                ops::custom_hostnames::create_custom_hostname(client, &zone_obj.id, create).await?;
+            output::table::print_custom_hostname_verification(&created);
+            if wait.wait {
+                ops::custom_hostnames::wait_for_ssl_active(
+                    client,
+                    &zone_obj.id,
+                    &created.id,
+                    CUSTOM_HOSTNAME_POLL_INTERVAL,
+                    std::time::Duration::from_secs(wait.timeout),
+                )
+                .await?;
+            }
+            Ok(())
+        }
+        CustomHostnameCommand::Update {
+            zone,
+            hostname,
+            ssl_method,
+            ssl_type,
+            options,
+        } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let existing =
+                ops::custom_hostnames::get_custom_hostname(client, &zone_obj.id, &hostname).await?;
+            let metadata = ops::custom_hostnames::parse_metadata(&options.metadata)?;
+            let ssl = (ssl_method.is_some() || ssl_type.is_some()).then(|| {
+                let current = existing.ssl.clone().unwrap_or_default();
+                CustomHostnameSsl {
+                    method: ssl_method.or(current.method),
+                    r#type: ssl_type.or(current.r#type),
+                    ..Default::default()
+                }
+            });
+            let update = UpdateCustomHostname {
+                ssl,
+                custom_origin_server: options.origin,
+                custom_metadata: (!metadata.is_empty()).then_some(metadata),
+            };
+            ops::custom_hostnames::update_custom_hostname(
+                client,
+                &zone_obj.id,
+                &existing.id,
+                update,
+            )
+            .await?;
+            Ok(())
+        }
+        CustomHostnameCommand::Delete {
+            zone,
+            hostname,
+            confirm,
+        } => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let existing =
+                ops::custom_hostnames::get_custom_hostname(client, &zone_obj.id, &hostname).await?;
+            ops::custom_hostnames::delete_custom_hostname(client, &zone_obj.id, &existing.id).await
+        }
+        CustomHostnameCommand::Verify {
+            zone,
+            hostname,
+            wait,
+        } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let hostname =
+                ops::custom_hostnames::get_custom_hostname(client, &zone_obj.id, &hostname).await?;
+            output::table::print_custom_hostname_verification(&hostname);
+            if wait.wait && !hostname.is_ssl_active() {
+                ops::custom_hostnames::wait_for_ssl_active(
+                    client,
+                    &zone_obj.id,
+                    &hostname.id,
+                    CUSTOM_HOSTNAME_POLL_INTERVAL,
+                    std::time::Duration::from_secs(wait.timeout),
+                )
+                .await?;
+            }
+            Ok(())
+        }
+        CustomHostnameCommand::Import {
+            zone,
+            file,
+            ssl_method,
+            ssl_type,
+        } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let contents = std::fs::read_to_string(&file)?;
+            let hostnames =
+                ops::custom_hostnames::parse_hostnames_csv(&contents, &ssl_method, &ssl_type)?;
+            let stats =
+                ops::custom_hostnames::import_custom_hostnames(client, &zone_obj.id, hostnames)
+                    .await;
+            if stats.failed > 0 {
+                return Err(crate::error::CfadError::api(format!(
+                    "{} of {} custom hostnames failed",
+                    stats.failed, stats.total
+                )));
+            }
+            println!(
+                "\nRun 'cfad zone custom-hostnames verify {} <HOSTNAME>' for the records each customer must add",
+                zone_obj.name
+            );
+            Ok(())
+        }
     }
 }
 
@@ -434,11 +755,118 @@ pub async fn handle_zone_update(
     ssl: Option<String>,
     always_https: Option<String>,
 ) -> Result<()> {
-    use crate::api::zone::ZoneSettings;
-
     let zone_obj = ops::zone::get_zone(client, zone).await?;
 
-    let settings = ZoneSettings {
+    let settings = build_zone_settings(
+        security_level,
+        cache_level,
+        dev_mode,
+        ipv6,
+        ssl,
+        always_https,
+    );
+
+    ops::zone::update_zone_settings(client, &zone_obj.id, settings).await
+}
+
+pub async fn handle_zone_update_zones(
+    client: &client::CloudflareClient,
+    selector: &cli::zone::ZoneSelector,
+    settings: api::zone::ZoneSettings,
+) -> Result<()> {
+    let zones = resolve_selected_zones(client, selector).await?;
+    let outcomes = ops::zone::for_each_zone(zones, selector.concurrency, |zone| {
+        let settings = settings.clone();
+        async move { ops::zone::update_zone_settings(client, &zone.id, settings).await }
+    })
+    .await;
+
+    finish_zone_fan_out(outcomes, |_| "updated".to_string())
+}
+
+pub async fn handle_zone_audit(
+    client: &client::CloudflareClient,
+    zone: Option<String>,
+    selector: &cli::zone::ZoneSelector,
+    report: cli::zone::AuditReportArgs,
+) -> Result<()> {
+    use ops::audit::CheckStatus;
+
+    let outcomes = if selector.is_active() {
+        let zones = resolve_selected_zones(client, selector).await?;
+        ops::zone::for_each_zone(zones, selector.concurrency, |zone| async move {
+            ops::audit::audit_zone(client, &zone).await
+        })
+        .await
+    } else {
+        let zone = ops::zone::get_zone(client, &require_zone(zone)?).await?;
+        let result = ops::audit::audit_zone(client, &zone).await;
+        vec![ops::zone::ZoneOutcome { zone, result }]
+    };
+
+    let total = outcomes.len();
+    let mut audits = Vec::new();
+    for outcome in outcomes {
+        match outcome.result {
+            Ok(audit) => {
+                println!("\nSecurity audit for {}:\n", audit.zone);
+                output::table::print_zone_audit(&audit);
+                audits.push(audit);
+            }
+            Err(e) => println!("\n✗ {}: audit failed: {}", outcome.zone.name, e),
+        }
+    }
+    if audits.len() > 1 {
+        println!("\nSummary:\n");
+        output::table::print_zone_audit_summary(&audits);
+    }
+
+    if let Some(path) = &report.json {
+        let json = serde_json::to_string_pretty(&audits)?;
+        std::fs::write(path, json + "\n")?;
+        println!("✓ Wrote JSON report to {}", path);
+    }
+    if let Some(path) = &report.junit {
+        std::fs::write(path, ops::audit::to_junit(&audits))?;
+        println!("✓ Wrote JUnit report to {}", path);
+    }
+
+    if audits.len() < total {
+        return Err(crate::error::CfadError::Other(format!(
+            "{} of {} zones could not be audited",
+            total - audits.len(),
+            total
+        )));
+    }
+
+    let fail_on = match report.fail_on.as_str() {
+        "warn" => Some(CheckStatus::Warn),
+        "fail" => Some(CheckStatus::Fail),
+        _ => None,
+    };
+    let failing: Vec<&str> = audits
+        .iter()
+        .filter(|audit| !audit.meets(fail_on, report.min_score))
+        .map(|audit| audit.zone.as_str())
+        .collect();
+    if !failing.is_empty() {
+        return Err(crate::error::CfadError::Other(format!(
+            "Audit did not pass for: {}",
+            failing.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+fn build_zone_settings(
+    security_level: Option<String>,
+    cache_level: Option<String>,
+    dev_mode: Option<String>,
+    ipv6: Option<String>,
+    ssl: Option<String>,
+    always_https: Option<String>,
+) -> api::zone::ZoneSettings {
+    api::zone::ZoneSettings {
         security_level,
         cache_level,
         development_mode: dev_mode.map(|v| v == "on"),
@@ -446,9 +874,96 @@ pub async fn handle_zone_update(
         ssl,
         always_use_https: always_https.map(|v| v == "on"),
         minify: None,
-    };
+    }
+}
 
-    ops::zone::update_zone_settings(client, &zone_obj.id, settings).await
+/// Positional zone for commands that also accept a zone selector
+fn require_zone(zone: Option<String>) -> Result<String> {
+    zone.ok_or_else(|| {
+        crate::error::CfadError::validation(
+            "Zone is required unless --zones, --zones-file, --all-zones or --zone-status is given",
+        )
+    })
+}
+
+/// Resolve the zones a multi-zone command should run against.
+pub async fn resolve_selected_zones(
+    client: &client::CloudflareClient,
+    selector: &cli::zone::ZoneSelector,
+) -> Result<Vec<api::zone::Zone>> {
+    let mut patterns = selector.zones.clone().unwrap_or_default();
+    if let Some(file) = &selector.zones_file {
+        patterns.extend(read_list_file(file)?);
+    }
+    patterns.retain(|p| !p.trim().is_empty());
+
+    // An explicit list that turns out empty must not widen to "every zone"
+    let explicit = selector.zones.is_some() || selector.zones_file.is_some();
+    if explicit && patterns.is_empty() {
+        return Err(crate::error::CfadError::validation(
+            "Zone selector is empty: no zone names or patterns given",
+        ));
+    }
+
+    let zones = ops::zone::select_zones(client, &patterns, selector.zone_status.as_deref()).await?;
+    if zones.is_empty() {
+        return Err(crate::error::CfadError::validation(
+            "No zones matched the selector",
+        ));
+    }
+
+    println!("Selected {} zones", zones.len());
+    Ok(zones)
+}
+
+/// Read a file, or stdin when the path is `-`
+fn read_input(path: &str) -> Result<String> {
+    if path == "-" {
+        let mut contents = String::new();
+        std::io::Read::read_to_string(&mut std::io::stdin(), &mut contents)?;
+        Ok(contents)
+    } else {
+        Ok(std::fs::read_to_string(path)?)
+    }
+}
+
+/// Read a list with one entry per line, skipping blank lines and `#` comments.
+/// A path of `-` reads stdin.
+fn read_list_file(path: &str) -> Result<Vec<String>> {
+    let contents = read_input(path)?;
+    Ok(contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(String::from)
+        .collect())
+}
+
+/// Print the per-zone summary of a fan-out and fail if any zone failed.
+pub fn finish_zone_fan_out<T>(
+    outcomes: Vec<ops::zone::ZoneOutcome<T>>,
+    describe: impl Fn(&T) -> String,
+) -> Result<()> {
+    let outcomes: Vec<ops::zone::ZoneOutcome<String>> = outcomes
+        .into_iter()
+        .map(|o| ops::zone::ZoneOutcome {
+            zone: o.zone,
+            result: o.result.map(|v| describe(&v)),
+        })
+        .collect();
+
+    println!("\nResults:\n");
+    output::table::print_zone_outcomes(&outcomes);
+
+    let failed = outcomes.iter().filter(|o| o.result.is_err()).count();
+    if failed > 0 {
+        return Err(crate::error::CfadError::Other(format!(
+            "{} of {} zones failed",
+            failed,
+            outcomes.len()
+        )));
+    }
+    Ok(())
 }
 
 pub async fn handle_cache_command(
@@ -460,59 +975,248 @@ pub async fn handle_cache_command(
     match cmd {
         CacheCommand::Purge {
             zone,
+            selector,
             all,
             files,
+            from_file,
+            sitemap,
+            changed_files,
+            base_url,
+            headers,
             tags,
             hosts,
             prefixes,
         } => {
-            let zone_obj = ops::zone::get_zone(client, &zone).await?;
-            execute_cache_purge(client, &zone_obj.id, all, files, tags, hosts, prefixes).await?;
+            // Build the list once, so stdin also works when fanning out
+            let files: Option<Vec<api::cache::PurgeFile>> = if let Some(path) = from_file {
+                Some(ops::cache::parse_purge_list(&read_input(&path)?)?)
+            } else if let Some(source) = sitemap {
+                let urls = ops::cache::load_sitemap(&source).await?;
+                println!("Found {} URLs in sitemap {}", urls.len(), source);
+                Some(urls.into_iter().map(Into::into).collect())
+            } else if let Some(path) = changed_files {
+                let base_url = base_url.unwrap_or_default();
+                let paths = read_list_file(&path)?;
+                let urls = ops::cache::changed_file_urls(&paths, &base_url)?;
+                println!(
+                    "Mapped {} changed files to {} URLs",
+                    paths.len(),
+                    urls.len()
+                );
+                Some(urls.into_iter().map(Into::into).collect())
+            } else {
+                files.map(|urls| urls.into_iter().map(Into::into).collect())
+            };
+            let files = match (files, headers.is_empty()) {
+                (files, true) => files,
+                (Some(files), false) => {
+                    let headers = headers
+                        .iter()
+                        .map(|h| ops::cache::parse_purge_header(h))
+                        .collect::<Result<Vec<_>>>()?;
+                    Some(ops::cache::apply_purge_headers(files, &headers))
+                }
+                (None, false) => {
+                    return Err(crate::error::CfadError::validation(
+                        "--header only applies to URL purges (--files, --from-file, --sitemap, --changed-files)",
+                    ))
+                }
+            };
+            if selector.is_active() {
+                let zones = resolve_selected_zones(client, &selector).await?;
+                let outcomes = ops::zone::for_each_zone(zones, selector.concurrency, |zone| {
+                    let (files, tags, hosts, prefixes) =
+                        (files.clone(), tags.clone(), hosts.clone(), prefixes.clone());
+                    async move {
+                        execute_cache_purge(client, &zone.id, all, files, tags, hosts, prefixes)
+                            .await
+                    }
+                })
+                .await;
+                return finish_zone_fan_out(outcomes, |_| "purged".to_string());
+            }
+            match (zone, files) {
+                (None, Some(urls)) => purge_files_by_zone(client, urls).await?,
+                (zone, files) => {
+                    let zone = require_zone(zone)?;
+                    let zone_obj = ops::zone::get_zone(client, &zone).await?;
+                    execute_cache_purge(client, &zone_obj.id, all, files, tags, hosts, prefixes)
+                        .await?;
+                }
+            }
         }
+        CacheCommand::Settings(cmd) => handle_cache_settings_command(client, cmd).await?,
     }
 
     Ok(())
 }
 
-pub async fn execute_cache_purge(
+const CACHE_RESERVE_CLEAR_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(10);
+
+pub async fn handle_cache_settings_command(
     client: &client::CloudflareClient,
-    zone_id: &str,
-    all: bool,
-    files: Option<Vec<String>>,
-    tags: Option<Vec<String>>,
-    hosts: Option<Vec<String>>,
-    prefixes: Option<Vec<String>>,
+    cmd: cli::cache::CacheSettingsCommand,
 ) -> Result<()> {
-    if all {
-        return ops::cache::purge_all(client, zone_id).await;
-    }
-
-    if let Some(files) = files {
-        return ops::cache::purge_files(client, zone_id, files).await;
-    }
-
-    if let Some(tags) = tags {
-        return ops::cache::purge_tags(client, zone_id, tags).await;
-    }
-
-    if let Some(hosts) = hosts {
-        return ops::cache::purge_hosts(client, zone_id, hosts).await;
-    }
+    use cli::cache::CacheSettingsCommand;
 
-    if let Some(prefixes) = prefixes {
-        return ops::cache::purge_prefixes(client, zone_id, prefixes).await;
+    match cmd {
+        CacheSettingsCommand::Show { zone } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let states = ops::cache::get_cache_settings(client, &zone_obj.id).await;
+            println!("\nCache settings for {}:\n", zone_obj.name);
+            output::table::print_cache_settings(&states);
+            for state in &states {
+                if let Err(e) = &state.setting {
+                    println!("⚠ {}: {}", state.name, e);
+                }
+            }
+        }
+        CacheSettingsCommand::Set {
+            zone,
+            tiered_caching,
+            smart_topology,
+            cache_reserve,
+            argo,
+        } => {
+            let changes: Vec<(&str, String)> = [
+                ("tiered-caching", tiered_caching),
+                ("smart-topology", smart_topology),
+                ("cache-reserve", cache_reserve),
+                ("argo", argo),
+            ]
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|v| (name, v)))
+            .collect();
+            if changes.is_empty() {
+                return Err(crate::error::CfadError::validation(
+                    "No settings given: use --tiered-caching, --smart-topology, --cache-reserve or --argo",
+                ));
+            }
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            for (name, value) in changes {
+                ops::cache::set_cache_setting(client, &zone_obj.id, name, value == "on").await?;
+            }
+        }
+        CacheSettingsCommand::ClearReserve {
+            zone,
+            status,
+            confirm,
+            no_wait,
+            timeout,
+        } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            if status {
+                let operation = ops::cache::get_cache_reserve_clear(client, &zone_obj.id).await?;
+                output::table::print_cache_reserve_clear(&operation);
+                return Ok(());
+            }
+            if !confirm {
+                println!("⚠ Clearing Cache Reserve requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            ops::cache::start_cache_reserve_clear(client, &zone_obj.id).await?;
+            if no_wait {
+                println!(
+                    "Check status with: cfad cache settings clear-reserve {} --status",
+                    zone_obj.name
+                );
+                return Ok(());
+            }
+            let operation = ops::cache::wait_for_cache_reserve_clear(
+                client,
+                &zone_obj.id,
+                CACHE_RESERVE_CLEAR_POLL_INTERVAL,
+                std::time::Duration::from_secs(timeout),
+            )
+            .await?;
+            output::table::print_cache_reserve_clear(&operation);
+        }
     }
 
-    Err(crate::error::CfadError::validation(
-        "Must specify purge type: --all, --files, --tags, --hosts, or --prefixes",
-    ))
+    Ok(())
 }
 
-pub async fn handle_d1_command(
+/// Purge URLs without a zone argument: route each URL to the zone owning its
+/// host and purge zone by zone. URLs outside the account's zones fail the
+/// command once the others are purged.
+async fn purge_files_by_zone(
     client: &client::CloudflareClient,
-    cmd: cli::d1::D1Command,
+    files: Vec<api::cache::PurgeFile>,
 ) -> Result<()> {
-    use cli::d1::D1Command;
+    let zones = ops::zone::list_zones(client, None).await?;
+    let routing = ops::cache::route_urls_to_zones(files, &zones);
+
+    for (zone, files) in &routing.zones {
+        for file in files {
+            println!("  {} → {}", file, zone.name);
+        }
+    }
+    for file in &routing.unrouted {
+        println!("  ⚠ {} → no zone in this account", file);
+    }
+
+    let mut outcomes = Vec::new();
+    for (zone, files) in routing.zones {
+        println!("\nPurging {} URLs in {}", files.len(), zone.name);
+        let result = ops::cache::purge_file_entries(client, &zone.id, files).await;
+        outcomes.push(ops::zone::ZoneOutcome { zone, result });
+    }
+    let fan_out = if outcomes.is_empty() {
+        Ok(())
+    } else {
+        finish_zone_fan_out(outcomes, |summary| {
+            format!("purged {} URLs", summary.purged)
+        })
+    };
+
+    if !routing.unrouted.is_empty() {
+        fan_out?;
+        return Err(crate::error::CfadError::validation(format!(
+            "{} URLs belong to no zone in this account",
+            routing.unrouted.len()
+        )));
+    }
+    fan_out
+}
+
+pub async fn execute_cache_purge(
+    client: &client::CloudflareClient,
+    zone_id: &str,
+    all: bool,
+    files: Option<Vec<api::cache::PurgeFile>>,
+    tags: Option<Vec<String>>,
+    hosts: Option<Vec<String>>,
+    prefixes: Option<Vec<String>>,
+) -> Result<()> {
+    if all {
+        return ops::cache::purge_all(client, zone_id).await;
+    }
+
+    let summary = if let Some(files) = files {
+        ops::cache::purge_file_entries(client, zone_id, files).await?
+    } else if let Some(tags) = tags {
+        ops::cache::purge_tags(client, zone_id, tags).await?
+    } else if let Some(hosts) = hosts {
+        ops::cache::purge_hosts(client, zone_id, hosts).await?
+    } else if let Some(prefixes) = prefixes {
+        ops::cache::purge_prefixes(client, zone_id, prefixes).await?
+    } else {
+        return Err(crate::error::CfadError::validation(
+            "Must specify purge type: --all, --files, --from-file, --sitemap, --changed-files, --tags, --hosts, or --prefixes",
+        ));
+    };
+
+    if summary.requests == 0 {
+        println!("⚠ Nothing to purge: the list is empty");
+    }
+    Ok(())
+}
+
+pub async fn handle_d1_command(
+    client: &client::CloudflareClient,
+    cmd: cli::d1::D1Command,
+) -> Result<()> {
+    use cli::d1::{D1Command, D1MigrationsCommand};
 
     match cmd {
         D1Command::List { account_id } => {
@@ -573,14 +1277,17 @@ pub async fn handle_d1_command(
             account_id,
             database_id,
             sql,
+            params,
             raw,
             format,
         } => {
             let account_id = resolve_account_id(account_id, None)?;
+            let params = ops::d1::query_params(&params.params, params.params_json.as_deref())?;
+            ops::d1::validate_query_params(&sql, params.as_deref())?;
             let db_id = resolve_d1_database_id(client, &account_id, &database_id).await?;
             if raw {
                 let results =
-                    ops::d1::query_database_raw(client, &account_id, &db_id, &sql, None).await?;
+                    ops::d1::query_database_raw(client, &account_id, &db_id, &sql, params).await?;
                 if format == "table" {
                     output::table::print_d1_raw_query_results(&results);
                 } else {
@@ -588,7 +1295,7 @@ pub async fn handle_d1_command(
                 }
             } else {
                 let results =
-                    ops::d1::query_database(client, &account_id, &db_id, &sql, None).await?;
+                    ops::d1::query_database(client, &account_id, &db_id, &sql, params).await?;
                 if format == "table" {
                     output::table::print_d1_query_results(&results);
                 } else {
@@ -601,15 +1308,18 @@ pub async fn handle_d1_command(
             account_id,
             database_id,
             file,
+            params,
             raw,
             format,
         } => {
             let account_id = resolve_account_id(account_id, None)?;
-            let db_id = resolve_d1_database_id(client, &account_id, &database_id).await?;
             let sql = std::fs::read_to_string(&file)?;
+            let params = ops::d1::query_params(&params.params, params.params_json.as_deref())?;
+            ops::d1::validate_query_params(&sql, params.as_deref())?;
+            let db_id = resolve_d1_database_id(client, &account_id, &database_id).await?;
             if raw {
                 let results =
-                    ops::d1::query_database_raw(client, &account_id, &db_id, &sql, None).await?;
+                    ops::d1::query_database_raw(client, &account_id, &db_id, &sql, params).await?;
                 if format == "table" {
                     output::table::print_d1_raw_query_results(&results);
                 } else {
@@ -617,7 +1327,7 @@ pub async fn handle_d1_command(
                 }
             } else {
                 let results =
-                    ops::d1::query_database(client, &account_id, &db_id, &sql, None).await?;
+                    ops::d1::query_database(client, &account_id, &db_id, &sql, params).await?;
                 if format == "table" {
                     output::table::print_d1_query_results(&results);
                 } else {
@@ -626,6 +1336,62 @@ pub async fn handle_d1_command(
             }
             Ok(())
         }
+        D1Command::Migrations(D1MigrationsCommand::Create {
+            database_id,
+            name,
+            dir,
+        }) => {
+            ops::d1_migrations::create_migration(std::path::Path::new(&dir), &name)?;
+            println!(
+                "  Apply it with: cfad d1 migrations apply {} --dir {}",
+                database_id, dir
+            );
+            Ok(())
+        }
+        D1Command::Migrations(D1MigrationsCommand::List {
+            account_id,
+            database_id,
+            dir,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let db_id = resolve_d1_database_id(client, &account_id, &database_id).await?;
+            let files = ops::d1_migrations::migration_files(std::path::Path::new(&dir))?;
+            let applied =
+                ops::d1_migrations::applied_migrations(client, &account_id, &db_id).await?;
+            let statuses = ops::d1_migrations::migration_statuses(&files, &applied);
+            output::table::print_d1_migrations(&statuses);
+            Ok(())
+        }
+        D1Command::Migrations(D1MigrationsCommand::Apply {
+            account_id,
+            database_id,
+            dir,
+            bookmark,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let db_id = resolve_d1_database_id(client, &account_id, &database_id).await?;
+            let applied = ops::d1_migrations::apply_migrations(
+                client,
+                &account_id,
+                &db_id,
+                std::path::Path::new(&dir),
+                bookmark,
+            )
+            .await?;
+            if applied > 0 {
+                println!("\n✓ Applied {} migration(s) to {}", applied, database_id);
+            }
+            Ok(())
+        }
+        D1Command::Shell {
+            account_id,
+            database_id,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let db_id = resolve_d1_database_id(client, &account_id, &database_id).await?;
+            let history_path = Config::config_path()?.with_file_name("d1_history");
+            ops::d1_shell::run_shell(client, &account_id, &db_id, &database_id, &history_path).await
+        }
         D1Command::Schema {
             account_id,
             database_id,
@@ -637,27 +1403,74 @@ pub async fn handle_d1_command(
         D1Command::Export {
             account_id,
             database_id,
+            output: output_path,
+            no_data,
+            no_schema,
+            tables,
+            timeout,
         } => {
             let account_id = resolve_account_id(account_id, None)?;
             let db_id = resolve_d1_database_id(client, &account_id, &database_id).await?;
-            let result = ops::d1::export_database(client, &account_id, &db_id).await?;
-            println!("\nExport initiated:");
-            println!("  Task ID: {}", result.task_id);
-            println!("  Status: {}", result.status);
-            if let Some(url) = result.signed_url {
-                println!("  Download URL: {}", url);
-            }
+            let options = api::d1::D1DumpOptions {
+                no_data,
+                no_schema,
+                tables,
+            };
+            println!("Exporting {}...", database_id);
+            let export = ops::d1::wait_for_export(
+                client,
+                &account_id,
+                &db_id,
+                &options,
+                D1_EXPORT_POLL_INTERVAL,
+                std::time::Duration::from_secs(timeout),
+            )
+            .await?;
+            let url = export.download_url().ok_or_else(|| {
+                crate::error::CfadError::api("Export completed without a download URL")
+            })?;
+
+            let mut progress = output::progress::TransferProgress::new("Downloading");
+            let bytes =
+                ops::d1::download_export(url, std::path::Path::new(&output_path), |done, total| {
+                    progress.update(done, total)
+                })
+                .await;
+            progress.finish();
+            println!(
+                "✓ Exported {} to {} ({})",
+                database_id,
+                output_path,
+                output::table::format_bytes(bytes?)
+            );
             Ok(())
         }
         D1Command::Import {
             account_id,
             database_id,
             file,
+            timeout,
         } => {
             let account_id = resolve_account_id(account_id, None)?;
             let db_id = resolve_d1_database_id(client, &account_id, &database_id).await?;
-            let sql = std::fs::read_to_string(&file)?;
-            ops::d1::import_database(client, &account_id, &db_id, &sql).await?;
+            let sql = std::fs::read(&file)?;
+            if sql.len() <= ops::d1::D1_INLINE_IMPORT_LIMIT {
+                let sql = String::from_utf8(sql).map_err(|_| {
+                    crate::error::CfadError::validation(format!("{} is not valid UTF-8", file))
+                })?;
+                ops::d1::import_database(client, &account_id, &db_id, &sql).await?;
+            } else {
+                println!("Importing {}...", file);
+                ops::d1::import_database_via_upload(
+                    client,
+                    &account_id,
+                    &db_id,
+                    sql,
+                    D1_IMPORT_POLL_INTERVAL,
+                    std::time::Duration::from_secs(timeout),
+                )
+                .await?;
+            }
             Ok(())
         }
         D1Command::Bookmark {
@@ -793,6 +1606,9 @@ pub async fn handle_d1_schema(
     Ok(())
 }
 
+const D1_EXPORT_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+const D1_IMPORT_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+
 /// Resolve a database identifier (name or ID) to a database ID
 pub async fn resolve_d1_database_id(
     client: &client::CloudflareClient,
@@ -1446,6 +2262,7 @@ pub async fn handle_r2_notification_command(
             queue_id,
         } => {
             let account_id = resolve_account_id(account_id, None)?;
+            let queue_id = resolve_queue_id(client, &account_id, &queue_id).await?;
             let notification =
                 ops::r2::get_notification(client, &account_id, &bucket, &queue_id).await?;
             println!("\nEvent Notification:");
@@ -1468,6 +2285,7 @@ pub async fn handle_r2_notification_command(
             suffix,
         } => {
             let account_id = resolve_account_id(account_id, None)?;
+            let queue_id = resolve_queue_id(client, &account_id, &queue_id).await?;
             let notification = api::r2::CreateR2EventNotification {
                 events,
                 prefix,
@@ -1488,6 +2306,7 @@ pub async fn handle_r2_notification_command(
                 println!("⚠ Deletion requires --confirm flag");
                 return Err(crate::error::CfadError::validation("Confirmation required"));
             }
+            let queue_id = resolve_queue_id(client, &account_id, &queue_id).await?;
             ops::r2::delete_notification(client, &account_id, &bucket, &queue_id).await
         }
     }
@@ -1624,121 +2443,2007 @@ pub async fn handle_r2_temp_creds_command(
     }
 }
 
-pub async fn handle_token_command(
+pub async fn handle_rules_command(
     client: &client::CloudflareClient,
-    cmd: cli::token::TokenCommand,
+    cmd: cli::rules::RulesCommand,
 ) -> Result<()> {
-    use cli::token::TokenCommand;
+    use crate::api::rulesets::{
+        PHASE_CACHE_SETTINGS, PHASE_CONFIG_SETTINGS, PHASE_DYNAMIC_REDIRECT, PHASE_FIREWALL_CUSTOM,
+        PHASE_LATE_TRANSFORM, PHASE_ORIGIN, PHASE_RATELIMIT,
+    };
+    use cli::rules::RulesCommand;
 
     match cmd {
-        TokenCommand::List => {
-            let tokens = ops::token::list_tokens(client).await?;
-            println!("\nAPI Tokens:\n");
-            output::table::print_tokens(&tokens);
+        RulesCommand::Phases { target } => {
+            let scope = resolve_ruleset_scope(client, &target).await?;
+            let rulesets = ops::rulesets::list_rulesets(client, &scope).await?;
+            println!("\nRulesets:\n");
+            output::table::print_rulesets(&rulesets);
             Ok(())
         }
-        TokenCommand::Show { token_id } => {
-            let token = ops::token::get_token(client, &token_id).await?;
-            output::table::print_token(&token);
-            Ok(())
+        RulesCommand::Waf(cmd) => {
+            handle_phase_rule_command(client, PHASE_FIREWALL_CUSTOM, cmd).await
         }
-        TokenCommand::Create {
-            name,
-            permissions,
-            resources,
-            expires,
-            not_before,
-        } => {
-            use crate::api::token::{CreatePermissionGroupRef, CreateToken, CreateTokenPolicy};
-
-            let policy = CreateTokenPolicy {
-                effect: "allow".to_string(),
-                resources: serde_json::json!({ &resources: "*" }),
-                permission_groups: permissions
-                    .into_iter()
-                    .map(|id| CreatePermissionGroupRef { id })
-                    .collect(),
-            };
+        RulesCommand::Redirect(cmd) => {
+            handle_phase_rule_command(client, PHASE_DYNAMIC_REDIRECT, cmd).await
+        }
+        RulesCommand::Transform(cmd) => {
+            handle_phase_rule_command(client, PHASE_LATE_TRANSFORM, cmd).await
+        }
+        RulesCommand::Cache(cmd) => {
+            handle_phase_rule_command(client, PHASE_CACHE_SETTINGS, cmd).await
+        }
+        RulesCommand::Origin(cmd) => handle_phase_rule_command(client, PHASE_ORIGIN, cmd).await,
+        RulesCommand::Ratelimit(cmd) => {
+            handle_phase_rule_command(client, PHASE_RATELIMIT, cmd).await
+        }
+        RulesCommand::Config(cmd) => {
+            handle_phase_rule_command(client, PHASE_CONFIG_SETTINGS, cmd).await
+        }
+        RulesCommand::Apply {
+            file,
+            target,
+            dry_run,
+            no_validate,
+        } => handle_rules_apply(client, &file, &target, dry_run, no_validate).await,
+        cmd @ (RulesCommand::Validate { .. } | RulesCommand::Test { .. }) => {
+            handle_rules_offline_command(cmd)
+        }
+    }
+}
 
-            let create = CreateToken {
-                name,
-                policies: vec![policy],
-                not_before,
-                expires_on: expires,
-                condition: None,
-            };
+/// `rules validate` and `rules test`, which never call the API
+pub fn handle_rules_offline_command(cmd: cli::rules::RulesCommand) -> Result<()> {
+    use cli::rules::RulesCommand;
 
-            let result = ops::token::create_token(client, create).await?;
-            println!("\n✓ Token created successfully!\n");
-            println!("  ID: {}", result.id);
-            println!("  Name: {}", result.name);
-            println!("  Status: {}", result.status);
-            println!("\n  TOKEN VALUE (save this - it won't be shown again):");
-            println!("  {}", result.value);
+    match cmd {
+        RulesCommand::Validate { expression } => {
+            crate::expression::check_expression(&expression)?;
+            println!("✓ Expression is valid");
             Ok(())
         }
-        TokenCommand::Update {
-            token_id,
-            name,
-            status,
-            expires,
+        RulesCommand::Test {
+            expression,
+            request,
         } => {
-            use crate::api::token::UpdateToken;
-
-            let update = UpdateToken {
-                name,
-                status,
-                policies: None,
-                not_before: None,
-                expires_on: expires,
-                condition: None,
-            };
+            let contents = std::fs::read_to_string(&request)?;
+            let json: serde_json::Value = serde_json::from_str(&contents)?;
+            let context = crate::expression::RequestContext::from_json(json)
+                .map_err(crate::error::CfadError::validation)?;
+            let evaluation = crate::expression::evaluate(&expression, &context)?;
 
-            let token = ops::token::update_token(client, &token_id, update).await?;
-            println!("✓ Token updated: {}", token.name);
+            if evaluation.matched {
+                println!("✓ Expression matches the request");
+            } else {
+                println!("✗ Expression does not match the request");
+            }
+            if !evaluation.defaulted_fields.is_empty() {
+                println!("\nFields not set in {} (evaluated as empty/zero):", request);
+                for field in &evaluation.defaulted_fields {
+                    println!("  {}", field);
+                }
+            }
             Ok(())
         }
-        TokenCommand::Delete { token_id, confirm } => {
-            if !confirm {
-                println!("⚠ Deletion requires --confirm flag");
-                return Err(crate::error::CfadError::validation("Confirmation required"));
+        _ => Err(crate::error::CfadError::validation(
+            "Command requires API access",
+        )),
+    }
+}
+
+pub async fn handle_phase_rule_command(
+    client: &client::CloudflareClient,
+    phase: &str,
+    cmd: cli::rules::PhaseRuleCommand,
+) -> Result<()> {
+    use crate::api::rulesets::RuleInput;
+    use cli::rules::PhaseRuleCommand;
+
+    match cmd {
+        PhaseRuleCommand::List { target } => {
+            let scope = resolve_ruleset_scope(client, &target).await?;
+            match ops::rulesets::get_entrypoint(client, &scope, phase).await {
+                Ok(ruleset) => {
+                    println!("\nRules in {}:\n", phase);
+                    output::table::print_ruleset_rules(&ruleset.rules);
+                }
+                Err(crate::error::CfadError::NotFound { .. }) => {
+                    println!("No rules configured for {}", phase);
+                }
+                Err(e) => return Err(e),
             }
-            ops::token::delete_token(client, &token_id).await
+            Ok(())
         }
-        TokenCommand::Verify => {
-            let verification = ops::token::verify_token(client).await?;
-            println!("\nToken Verification:\n");
-            println!("  ID: {}", verification.id);
-            println!("  Status: {}", verification.status);
-            if let Some(not_before) = &verification.not_before {
-                println!("  Not Before: {}", not_before);
-            }
-            if let Some(expires) = &verification.expires_on {
-                println!("  Expires: {}", expires);
+        PhaseRuleCommand::Add {
+            target,
+            expression,
+            action,
+            action_parameters,
+            ratelimit,
+            description,
+            disabled,
+            no_validate,
+            position,
+        } => {
+            if !no_validate {
+                crate::expression::check_expression(&expression)?;
             }
+            let scope = resolve_ruleset_scope(client, &target).await?;
+            let rule = RuleInput {
+                action,
+                expression,
+                description,
+                enabled: Some(!disabled),
+                action_parameters: parse_json_arg(
+                    action_parameters.as_deref(),
+                    "--action-parameters",
+                )?,
+                ratelimit: parse_json_arg(ratelimit.as_deref(), "--ratelimit")?,
+                rule_ref: None,
+                position: rule_position(position),
+            };
+            let ruleset = ops::rulesets::add_rule(client, &scope, phase, rule).await?;
+            output::table::print_ruleset_rules(&ruleset.rules);
             Ok(())
         }
-        TokenCommand::Permissions { scope } => {
-            let groups = ops::token::list_permission_groups(client).await?;
-            println!("\nAvailable Permission Groups:\n");
-            output::table::print_permission_groups(&groups, scope.as_deref());
+        PhaseRuleCommand::Update {
+            target,
+            rule_id,
+            expression,
+            action,
+            action_parameters,
+            ratelimit,
+            description,
+            enabled,
+            no_validate,
+        } => {
+            if let Some(expression) = expression.as_deref().filter(|_| !no_validate) {
+                crate::expression::check_expression(expression)?;
+            }
+            let scope = resolve_ruleset_scope(client, &target).await?;
+            let action_parameters =
+                parse_json_arg(action_parameters.as_deref(), "--action-parameters")?;
+            let ratelimit = parse_json_arg(ratelimit.as_deref(), "--ratelimit")?;
+            ops::rulesets::update_rule(client, &scope, phase, &rule_id, |rule| {
+                if let Some(expression) = expression {
+                    rule.expression = expression;
+                }
+                if let Some(action) = action {
+                    rule.action = action;
+                }
+                if action_parameters.is_some() {
+                    rule.action_parameters = action_parameters;
+                }
+                if ratelimit.is_some() {
+                    rule.ratelimit = ratelimit;
+                }
+                if description.is_some() {
+                    rule.description = description;
+                }
+                if enabled.is_some() {
+                    rule.enabled = enabled;
+                }
+            })
+            .await?;
             Ok(())
         }
-        TokenCommand::Roll { token_id, confirm } => {
+        PhaseRuleCommand::Delete {
+            target,
+            rule_id,
+            confirm,
+        } => {
             if !confirm {
-                println!("⚠ Rolling a token requires --confirm flag");
+                println!("⚠ Deletion requires --confirm flag");
                 return Err(crate::error::CfadError::validation("Confirmation required"));
             }
-            let result = ops::token::roll_token(client, &token_id).await?;
-            println!("\n✓ Token rolled successfully!\n");
-            println!("  ID: {}", result.id);
-            println!("\n  NEW TOKEN VALUE (save this - it won't be shown again):");
-            println!("  {}", result.value);
+            let scope = resolve_ruleset_scope(client, &target).await?;
+            ops::rulesets::delete_rule(client, &scope, phase, &rule_id).await
+        }
+        PhaseRuleCommand::Reorder {
+            target,
+            rule_id,
+            position,
+        } => {
+            let position = rule_position(position).ok_or_else(|| {
+                crate::error::CfadError::validation("Specify one of --before, --after or --index")
+            })?;
+            let scope = resolve_ruleset_scope(client, &target).await?;
+            let ruleset =
+                ops::rulesets::reorder_rule(client, &scope, phase, &rule_id, position).await?;
+            output::table::print_ruleset_rules(&ruleset.rules);
             Ok(())
         }
     }
 }
 
+pub async fn handle_rules_apply(
+    client: &client::CloudflareClient,
+    file: &str,
+    target: &cli::rules::RulesetTarget,
+    dry_run: bool,
+    no_validate: bool,
+) -> Result<()> {
+    use crate::api::rulesets::{resolve_phase, RulesDocument, UpdateEntrypoint};
+
+    let contents = std::fs::read_to_string(file)?;
+    let document: RulesDocument = if file.ends_with(".json") {
+        serde_json::from_str(&contents)?
+    } else {
+        serde_yaml::from_str(&contents)?
+    };
+    let phases = document.into_phases();
+
+    if !no_validate {
+        for phase_rules in &phases {
+            for (i, rule) in phase_rules.rules.iter().enumerate() {
+                crate::expression::validate(&rule.expression).map_err(|e| {
+                    crate::error::CfadError::validation(format!(
+                        "Invalid expression in {} rule {}: {}",
+                        phase_rules.phase,
+                        i + 1,
+                        e.render(&rule.expression)
+                    ))
+                })?;
+            }
+        }
+    }
+
+    if dry_run {
+        for phase_rules in &phases {
+            let phase = resolve_phase(&phase_rules.phase);
+            println!(
+                "\n{} ({} rules, replaces existing rules):",
+                phase,
+                phase_rules.rules.len()
+            );
+            println!("{}", serde_json::to_string_pretty(&phase_rules.rules)?);
+        }
+        return Ok(());
+    }
+
+    let scope = resolve_ruleset_scope(client, target).await?;
+    for phase_rules in phases {
+        let phase = resolve_phase(&phase_rules.phase).to_string();
+        let update = UpdateEntrypoint {
+            description: phase_rules.description,
+            rules: phase_rules.rules,
+        };
+        ops::rulesets::put_entrypoint(client, &scope, &phase, update).await?;
+    }
+    Ok(())
+}
+
+/// Resolve `--zone`/`--account` into the scope rulesets are read from.
+pub async fn resolve_ruleset_scope(
+    client: &client::CloudflareClient,
+    target: &cli::rules::RulesetTarget,
+) -> Result<api::rulesets::RulesetScope> {
+    use crate::api::rulesets::RulesetScope;
+
+    if target.account {
+        let account_id = resolve_account_id(target.account_id.clone(), None)?;
+        return Ok(RulesetScope::Account(account_id));
+    }
+    let zone = target
+        .zone
+        .as_deref()
+        .ok_or_else(|| crate::error::CfadError::validation("Specify --zone or --account"))?;
+    let zone_obj = ops::zone::get_zone(client, zone).await?;
+    Ok(RulesetScope::Zone(zone_obj.id))
+}
+
+fn rule_position(args: cli::rules::RulePositionArgs) -> Option<api::rulesets::RulePosition> {
+    if !args.is_set() {
+        return None;
+    }
+    Some(api::rulesets::RulePosition {
+        before: args.before,
+        after: args.after,
+        index: args.index,
+    })
+}
+
+/// Parse an optional JSON-valued CLI argument
+fn parse_json_arg(value: Option<&str>, flag: &str) -> Result<Option<serde_json::Value>> {
+    value
+        .map(|v| {
+            serde_json::from_str(v).map_err(|e| {
+                crate::error::CfadError::validation(format!("Invalid JSON for {}: {}", flag, e))
+            })
+        })
+        .transpose()
+}
+
+pub async fn handle_firewall_command(
+    client: &client::CloudflareClient,
+    cmd: cli::firewall::FirewallCommand,
+) -> Result<()> {
+    use cli::firewall::FirewallCommand;
+
+    match cmd {
+        FirewallCommand::AccessRules(cmd) => handle_access_rule_command(client, cmd).await,
+    }
+}
+
+pub async fn handle_access_rule_command(
+    client: &client::CloudflareClient,
+    cmd: cli::firewall::AccessRuleCommand,
+) -> Result<()> {
+    use crate::api::firewall::{AccessRuleConfiguration, AccessRuleFilter, CreateAccessRule};
+    use cli::firewall::AccessRuleCommand;
+
+    match cmd {
+        AccessRuleCommand::List {
+            target,
+            mode,
+            target_type,
+            value,
+            notes,
+        } => {
+            let scope = resolve_ruleset_scope(client, &target).await?;
+            let filter = AccessRuleFilter {
+                mode,
+                target: target_type,
+                value,
+                notes,
+            };
+            let rules = ops::firewall::list_access_rules(client, &scope, &filter).await?;
+            output::table::print_access_rules(&rules);
+            Ok(())
+        }
+        AccessRuleCommand::Add {
+            target,
+            mode,
+            matcher,
+            notes,
+        } => {
+            let (target_type, value) = matcher.target_and_value().ok_or_else(|| {
+                crate::error::CfadError::validation(
+                    "Specify one of --ip, --ip-range, --asn or --country",
+                )
+            })?;
+            let scope = resolve_ruleset_scope(client, &target).await?;
+            let rule = CreateAccessRule {
+                mode,
+                configuration: AccessRuleConfiguration {
+                    target: target_type.to_string(),
+                    value,
+                },
+                notes,
+            };
+            let rule = ops::firewall::create_access_rule(client, &scope, rule).await?;
+            output::table::print_access_rules(&[rule]);
+            Ok(())
+        }
+        AccessRuleCommand::Delete {
+            target,
+            rule_id,
+            confirm,
+        } => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let scope = resolve_ruleset_scope(client, &target).await?;
+            ops::firewall::delete_access_rule(client, &scope, &rule_id).await
+        }
+    }
+}
+
+/// How often `lists add/remove` polls a bulk operation
+const LIST_OPERATION_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
+/// How long `lists add/remove` waits for a bulk operation before giving up
+const LIST_OPERATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+pub async fn handle_lb_command(
+    client: &client::CloudflareClient,
+    cmd: cli::lb::LbCommand,
+) -> Result<()> {
+    use cli::lb::LbCommand;
+
+    match cmd {
+        LbCommand::Monitors(cmd) => handle_lb_monitor_command(client, cmd).await,
+        LbCommand::Pools(cmd) => handle_lb_pool_command(client, cmd).await,
+        LbCommand::Balancers(cmd) => handle_lb_balancer_command(client, cmd).await,
+    }
+}
+
+fn build_monitor_payload(
+    monitor_type: Option<String>,
+    options: cli::lb::MonitorArgs,
+) -> Result<api::load_balancing::MonitorPayload> {
+    Ok(api::load_balancing::MonitorPayload {
+        r#type: monitor_type,
+        description: options.description,
+        method: options.method,
+        path: options.path,
+        expected_codes: options.expected_codes,
+        expected_body: options.expected_body,
+        interval: options.interval,
+        timeout: options.timeout,
+        retries: options.retries,
+        port: options.port,
+        header: ops::load_balancing::parse_headers(&options.headers)?,
+        follow_redirects: options.follow_redirects.map(|v| v == "on"),
+        allow_insecure: options.allow_insecure.map(|v| v == "on"),
+    })
+}
+
+pub async fn handle_lb_monitor_command(
+    client: &client::CloudflareClient,
+    cmd: cli::lb::MonitorCommand,
+) -> Result<()> {
+    use cli::lb::MonitorCommand;
+
+    match cmd {
+        MonitorCommand::List { account_id } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let monitors = ops::load_balancing::list_monitors(client, &account_id).await?;
+            output::table::print_lb_monitors(&monitors);
+        }
+        MonitorCommand::Show {
+            account_id,
+            monitor_id,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let monitor =
+                ops::load_balancing::get_monitor(client, &account_id, &monitor_id).await?;
+            output::table::print_lb_monitor(&monitor);
+        }
+        MonitorCommand::Create {
+            account_id,
+            monitor_type,
+            options,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let monitor = build_monitor_payload(Some(monitor_type), options)?;
+            ops::load_balancing::create_monitor(client, &account_id, monitor).await?;
+        }
+        MonitorCommand::Update {
+            account_id,
+            monitor_id,
+            options,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let monitor = build_monitor_payload(None, options)?;
+            ops::load_balancing::update_monitor(client, &account_id, &monitor_id, monitor).await?;
+        }
+        MonitorCommand::Delete {
+            account_id,
+            monitor_id,
+            confirm,
+        } => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let account_id = resolve_account_id(account_id, None)?;
+            ops::load_balancing::delete_monitor(client, &account_id, &monitor_id).await?;
+        }
+    }
+    Ok(())
+}
+
+fn build_pool_payload(options: cli::lb::PoolArgs) -> api::load_balancing::PoolPayload {
+    api::load_balancing::PoolPayload {
+        description: options.description,
+        monitor: options.monitor,
+        minimum_origins: options.minimum_origins,
+        notification_email: options.notification_email,
+        check_regions: options.check_regions,
+        ..Default::default()
+    }
+}
+
+pub async fn handle_lb_pool_command(
+    client: &client::CloudflareClient,
+    cmd: cli::lb::PoolCommand,
+) -> Result<()> {
+    use cli::lb::PoolCommand;
+
+    match cmd {
+        PoolCommand::List { account_id } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let pools = ops::load_balancing::list_pools(client, &account_id).await?;
+            output::table::print_lb_pools(&pools);
+        }
+        PoolCommand::Show { account_id, pool } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let pool = ops::load_balancing::get_pool(client, &account_id, &pool).await?;
+            output::table::print_lb_pool(&pool);
+        }
+        PoolCommand::Create {
+            account_id,
+            name,
+            origins,
+            options,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let origins = origins
+                .iter()
+                .map(|spec| ops::load_balancing::parse_origin(spec))
+                .collect::<Result<Vec<_>>>()?;
+            let pool = api::load_balancing::PoolPayload {
+                name: Some(name),
+                origins: Some(origins),
+                ..build_pool_payload(options)
+            };
+            ops::load_balancing::create_pool(client, &account_id, pool).await?;
+        }
+        PoolCommand::Update {
+            account_id,
+            pool,
+            name,
+            add_origins,
+            remove_origins,
+            enabled,
+            options,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let existing = ops::load_balancing::get_pool(client, &account_id, &pool).await?;
+
+            let origins = if add_origins.is_empty() && remove_origins.is_empty() {
+                None
+            } else {
+                let mut origins = existing.origins.clone();
+                for removed in &remove_origins {
+                    let before = origins.len();
+                    origins.retain(|o| &o.name != removed && &o.address != removed);
+                    if origins.len() == before {
+                        return Err(crate::error::CfadError::not_found("Origin", removed));
+                    }
+                }
+                for spec in &add_origins {
+                    origins.push(ops::load_balancing::parse_origin(spec)?);
+                }
+                Some(origins)
+            };
+
+            let update = api::load_balancing::PoolPayload {
+                name,
+                enabled: enabled.map(|v| v == "on"),
+                origins,
+                ..build_pool_payload(options)
+            };
+            ops::load_balancing::update_pool(client, &account_id, &existing.id, update).await?;
+        }
+        PoolCommand::Delete {
+            account_id,
+            pool,
+            confirm,
+        } => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let account_id = resolve_account_id(account_id, None)?;
+            let pool = ops::load_balancing::get_pool(client, &account_id, &pool).await?;
+            ops::load_balancing::delete_pool(client, &account_id, &pool.id).await?;
+        }
+        PoolCommand::Health { account_id, pool } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let pool = ops::load_balancing::get_pool(client, &account_id, &pool).await?;
+            let health =
+                ops::load_balancing::get_pool_health(client, &account_id, &pool.id).await?;
+            println!("\nHealth of pool {}:\n", pool.name);
+            output::table::print_lb_pool_health(&pool, &health);
+        }
+        PoolCommand::EnableOrigin {
+            account_id,
+            pool,
+            origin,
+            weight,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let weight = weight
+                .as_deref()
+                .map(ops::load_balancing::parse_weight)
+                .transpose()?;
+            let previous =
+                ops::load_balancing::modify_origin(client, &account_id, &pool, &origin, |o| {
+                    o.enabled = true;
+                    if let Some(weight) = weight {
+                        o.weight = weight;
+                    }
+                })
+                .await?;
+            println!("✓ Enabled origin: {}", previous.name);
+        }
+        PoolCommand::DisableOrigin {
+            account_id,
+            pool,
+            origin,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let previous =
+                ops::load_balancing::modify_origin(client, &account_id, &pool, &origin, |o| {
+                    o.enabled = false
+                })
+                .await?;
+            println!("✓ Disabled origin: {}", previous.name);
+        }
+        PoolCommand::Drain {
+            account_id,
+            pool,
+            origin,
+            wait,
+            disable,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let previous = ops::load_balancing::drain_origin(
+                client,
+                &account_id,
+                &pool,
+                &origin,
+                std::time::Duration::from_secs(wait),
+                disable,
+            )
+            .await?;
+            println!(
+                "Restore with: cfad lb pools enable-origin {} {} --weight {}",
+                pool, previous.name, previous.weight
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Pool names or IDs to pool IDs; only looks pools up when a name is given
+async fn resolve_lb_pool_ids(
+    client: &client::CloudflareClient,
+    account_id: Option<String>,
+    identifiers: &[String],
+) -> Result<Vec<String>> {
+    let is_id = |p: &String| p.len() == 32 && p.chars().all(|c| c.is_ascii_hexdigit());
+    if identifiers.iter().all(is_id) {
+        return Ok(identifiers.to_vec());
+    }
+    let account_id = resolve_account_id(account_id, None)?;
+    let pools = ops::load_balancing::list_pools(client, &account_id).await?;
+    ops::load_balancing::resolve_pool_ids(&pools, identifiers)
+}
+
+pub async fn handle_lb_balancer_command(
+    client: &client::CloudflareClient,
+    cmd: cli::lb::BalancerCommand,
+) -> Result<()> {
+    use crate::api::load_balancing::LoadBalancerPayload;
+    use cli::lb::BalancerCommand;
+
+    match cmd {
+        BalancerCommand::List { zone } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let balancers = ops::load_balancing::list_load_balancers(client, &zone_obj.id).await?;
+            println!("\nLoad balancers for {}:\n", zone_obj.name);
+            output::table::print_load_balancers(&balancers);
+        }
+        BalancerCommand::Show {
+            zone,
+            load_balancer,
+        } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let balancer =
+                ops::load_balancing::get_load_balancer(client, &zone_obj.id, &load_balancer)
+                    .await?;
+            output::table::print_load_balancer(&balancer);
+        }
+        BalancerCommand::Create {
+            zone,
+            hostname,
+            default_pools,
+            options,
+        } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            // The fallback pool defaults to the last pool in the failover order
+            let mut identifiers = default_pools.clone();
+            identifiers.push(
+                options
+                    .fallback_pool
+                    .clone()
+                    .unwrap_or_else(|| default_pools[default_pools.len() - 1].clone()),
+            );
+            let mut pool_ids =
+                resolve_lb_pool_ids(client, options.account_id.clone(), &identifiers).await?;
+            let fallback_pool = pool_ids.pop();
+            let balancer = LoadBalancerPayload {
+                name: Some(hostname),
+                description: options.description,
+                proxied: Some(options.proxied.as_deref() != Some("off")),
+                ttl: options.ttl,
+                default_pools: Some(pool_ids),
+                fallback_pool,
+                steering_policy: options.steering_policy,
+                session_affinity: options.session_affinity,
+                ..Default::default()
+            };
+            ops::load_balancing::create_load_balancer(client, &zone_obj.id, balancer).await?;
+        }
+        BalancerCommand::Update {
+            zone,
+            load_balancer,
+            default_pools,
+            enabled,
+            options,
+        } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let existing =
+                ops::load_balancing::get_load_balancer(client, &zone_obj.id, &load_balancer)
+                    .await?;
+            let default_pools = match default_pools {
+                Some(pools) => {
+                    Some(resolve_lb_pool_ids(client, options.account_id.clone(), &pools).await?)
+                }
+                None => None,
+            };
+            let fallback_pool = match &options.fallback_pool {
+                Some(pool) => resolve_lb_pool_ids(
+                    client,
+                    options.account_id.clone(),
+                    std::slice::from_ref(pool),
+                )
+                .await?
+                .pop(),
+                None => None,
+            };
+            let update = LoadBalancerPayload {
+                description: options.description,
+                enabled: enabled.map(|v| v == "on"),
+                proxied: options.proxied.map(|v| v == "on"),
+                ttl: options.ttl,
+                default_pools,
+                fallback_pool,
+                steering_policy: options.steering_policy,
+                session_affinity: options.session_affinity,
+                ..Default::default()
+            };
+            ops::load_balancing::update_load_balancer(client, &zone_obj.id, &existing.id, update)
+                .await?;
+        }
+        BalancerCommand::Delete {
+            zone,
+            load_balancer,
+            confirm,
+        } => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let existing =
+                ops::load_balancing::get_load_balancer(client, &zone_obj.id, &load_balancer)
+                    .await?;
+            ops::load_balancing::delete_load_balancer(client, &zone_obj.id, &existing.id).await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn handle_lists_command(
+    client: &client::CloudflareClient,
+    cmd: cli::lists::ListsCommand,
+) -> Result<()> {
+    use crate::api::firewall::{CreateCustomList, CreateListItem};
+    use cli::lists::ListsCommand;
+
+    match cmd {
+        ListsCommand::List { account_id } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let lists = ops::firewall::list_lists(client, &account_id).await?;
+            output::table::print_custom_lists(&lists);
+        }
+        ListsCommand::Create {
+            account_id,
+            name,
+            kind,
+            description,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let list = CreateCustomList {
+                name,
+                kind,
+                description,
+            };
+            ops::firewall::create_list(client, &account_id, list).await?;
+        }
+        ListsCommand::Delete {
+            account_id,
+            list,
+            confirm,
+        } => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let account_id = resolve_account_id(account_id, None)?;
+            let list = ops::firewall::get_list(client, &account_id, &list).await?;
+            ops::firewall::delete_list(client, &account_id, &list.id).await?;
+        }
+        ListsCommand::Items { account_id, list } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let list = ops::firewall::get_list(client, &account_id, &list).await?;
+            let items = ops::firewall::list_items(client, &account_id, &list.id).await?;
+            println!("\nItems in {} ({}):\n", list.name, list.kind);
+            output::table::print_list_items(&items);
+        }
+        ListsCommand::Add {
+            account_id,
+            list,
+            values,
+            file,
+            comment,
+            replace,
+            no_wait,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let list = ops::firewall::get_list(client, &account_id, &list).await?;
+            let items: Vec<CreateListItem> = match file {
+                Some(path) => {
+                    let contents = std::fs::read_to_string(&path)?;
+                    let csv = std::path::Path::new(&path)
+                        .extension()
+                        .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+                    ops::firewall::parse_list_items(&contents, &list.kind, csv)?
+                }
+                None => values
+                    .iter()
+                    .map(|value| ops::firewall::list_item(&list.kind, value, comment.clone()))
+                    .collect::<Result<_>>()?,
+            };
+            if items.is_empty() {
+                return Err(crate::error::CfadError::validation("No items to add"));
+            }
+
+            println!(
+                "{} {} items {} list {}...",
+                if replace { "Replacing with" } else { "Adding" },
+                items.len(),
+                if replace { "in" } else { "to" },
+                list.name
+            );
+            let operation_id = if replace {
+                ops::firewall::replace_items(client, &account_id, &list.id, items).await?
+            } else {
+                ops::firewall::add_items(client, &account_id, &list.id, items).await?
+            };
+            finish_list_operation(client, &account_id, &operation_id, no_wait).await?;
+        }
+        ListsCommand::Remove {
+            account_id,
+            list,
+            items,
+            no_wait,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let list = ops::firewall::get_list(client, &account_id, &list).await?;
+            let existing = ops::firewall::list_items(client, &account_id, &list.id).await?;
+
+            // Accept item IDs or item values (e.g. "192.0.2.1")
+            let mut item_ids = Vec::new();
+            for wanted in &items {
+                let item = existing
+                    .iter()
+                    .find(|i| i.id.as_deref() == Some(wanted.as_str()) || i.value() == *wanted)
+                    .ok_or_else(|| crate::error::CfadError::not_found("List item", wanted))?;
+                if let Some(id) = &item.id {
+                    item_ids.push(id.clone());
+                }
+            }
+
+            let operation_id =
+                ops::firewall::delete_items(client, &account_id, &list.id, item_ids).await?;
+            finish_list_operation(client, &account_id, &operation_id, no_wait).await?;
+        }
+        ListsCommand::Operation {
+            account_id,
+            operation_id,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let operation =
+                ops::firewall::get_operation(client, &account_id, &operation_id).await?;
+            println!("Operation: {}", operation.id);
+            println!("Status:    {}", operation.status);
+            if let Some(completed) = &operation.completed {
+                println!("Completed: {}", completed);
+            }
+            if let Some(error) = &operation.error {
+                println!("Error:     {}", error);
+            }
+        }
+    }
+    Ok(())
+}
+
+async fn finish_list_operation(
+    client: &client::CloudflareClient,
+    account_id: &str,
+    operation_id: &str,
+    no_wait: bool,
+) -> Result<()> {
+    if no_wait {
+        println!("Submitted operation {}", operation_id);
+        println!("Check status with: cfad lists operation {}", operation_id);
+        return Ok(());
+    }
+    ops::firewall::wait_for_operation(
+        client,
+        account_id,
+        operation_id,
+        LIST_OPERATION_POLL_INTERVAL,
+        LIST_OPERATION_TIMEOUT,
+    )
+    .await?;
+    println!("✓ List operation {} completed", operation_id);
+    Ok(())
+}
+
+pub async fn handle_ssl_command(
+    client: &client::CloudflareClient,
+    cmd: cli::ssl::SslCommand,
+) -> Result<()> {
+    use cli::ssl::SslCommand;
+
+    match cmd {
+        SslCommand::Status { zone } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let status = ops::ssl::get_ssl_status(client, &zone_obj.id).await?;
+            println!("\nSSL/TLS status for {}:", zone_obj.name);
+            output::table::print_ssl_status(&status);
+            Ok(())
+        }
+        SslCommand::OrderPack {
+            zone,
+            hosts,
+            certificate_authority,
+            validity,
+            validation_method,
+            cloudflare_branding,
+        } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let order = api::certificates::OrderCertificatePack {
+                r#type: "advanced".to_string(),
+                hosts,
+                certificate_authority,
+                validation_method,
+                validity_days: validity,
+                cloudflare_branding,
+            };
+            let pack =
+                ops::certificates::order_certificate_pack(client, &zone_obj.id, order).await?;
+            println!(
+                "  Status: {}",
+                pack.status.as_deref().unwrap_or("initializing")
+            );
+            println!(
+                "Run 'cfad ssl status {}' to follow validation and issuance",
+                zone_obj.name
+            );
+            Ok(())
+        }
+        SslCommand::Universal { zone, state } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            ops::certificates::set_universal_ssl(client, &zone_obj.id, state == "on").await
+        }
+        SslCommand::OriginCert(cmd) => handle_origin_cert_command(client, cmd).await,
+    }
+}
+
+pub async fn handle_origin_cert_command(
+    client: &client::CloudflareClient,
+    cmd: cli::ssl::OriginCertCommand,
+) -> Result<()> {
+    use crate::api::certificates::{CreateOriginCertificate, OriginKeyType};
+    use cli::ssl::OriginCertCommand;
+
+    match cmd {
+        OriginCertCommand::Create {
+            hostnames,
+            validity,
+            key_type,
+            out_dir,
+            key_file,
+            cert_file,
+            force,
+        } => {
+            ops::certificates::validate_origin_cert_request(&hostnames, validity)?;
+            let key_type = OriginKeyType::parse(&key_type).ok_or_else(|| {
+                crate::error::CfadError::validation(format!("Invalid key type '{}'", key_type))
+            })?;
+            let (default_key, default_cert) =
+                ops::certificates::default_output_paths(&out_dir, &hostnames);
+            let key_path = key_file.unwrap_or(default_key);
+            let cert_path = cert_file.unwrap_or(default_cert);
+            ops::certificates::ensure_writable(&[&key_path, &cert_path], force)?;
+
+            let generated = ops::certificates::generate_key_and_csr(&hostnames, key_type)?;
+            let create = CreateOriginCertificate {
+                csr: generated.csr_pem,
+                hostnames,
+                request_type: key_type.request_type().to_string(),
+                requested_validity: validity,
+            };
+            let certificate = ops::certificates::create_origin_certificate(client, create).await?;
+            let pem = certificate.certificate.as_deref().ok_or_else(|| {
+                crate::error::CfadError::api("No certificate returned from create certificate")
+            })?;
+
+            ops::certificates::write_private_file(&key_path, &generated.private_key_pem)?;
+            ops::certificates::write_private_file(&cert_path, pem)?;
+            println!("  Private key: {}", key_path.display());
+            println!("  Certificate: {}", cert_path.display());
+            if let Some(expires_on) = &certificate.expires_on {
+                println!("  Expires: {}", expires_on);
+            }
+            Ok(())
+        }
+        OriginCertCommand::List { zone } => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let certificates =
+                ops::certificates::list_origin_certificates(client, &zone_obj.id).await?;
+            println!("\nOrigin CA certificates for {}:\n", zone_obj.name);
+            output::table::print_origin_certificates(&certificates);
+            Ok(())
+        }
+        OriginCertCommand::Revoke { cert_id, confirm } => {
+            if !confirm {
+                println!("⚠ Revocation requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            ops::certificates::revoke_origin_certificate(client, &cert_id).await
+        }
+    }
+}
+
+pub async fn handle_token_command(
+    client: &client::CloudflareClient,
+    cmd: cli::token::TokenCommand,
+) -> Result<()> {
+    use cli::token::TokenCommand;
+
+    match cmd {
+        TokenCommand::List => {
+            let tokens = ops::token::list_tokens(client).await?;
+            println!("\nAPI Tokens:\n");
+            output::table::print_tokens(&tokens);
+            Ok(())
+        }
+        TokenCommand::Show { token_id } => {
+            let token = ops::token::get_token(client, &token_id).await?;
+            output::table::print_token(&token);
+            Ok(())
+        }
+        TokenCommand::Create {
+            name,
+            permissions,
+            resources,
+            expires,
+            not_before,
+        } => {
+            use crate::api::token::{CreatePermissionGroupRef, CreateToken, CreateTokenPolicy};
+
+            let policy = CreateTokenPolicy {
+                effect: "allow".to_string(),
+                resources: serde_json::json!({ &resources: "*" }),
+                permission_groups: permissions
+                    .into_iter()
+                    .map(|id| CreatePermissionGroupRef { id })
+                    .collect(),
+            };
+
+            let create = CreateToken {
+                name,
+                policies: vec![policy],
+                not_before,
+                expires_on: expires,
+                condition: None,
+            };
+
+            let result = ops::token::create_token(client, create).await?;
+            println!("\n✓ Token created successfully!\n");
+            println!("  ID: {}", result.id);
+            println!("  Name: {}", result.name);
+            println!("  Status: {}", result.status);
+            println!("\n  TOKEN VALUE (save this - it won't be shown again):");
+            println!("  {}", result.value);
+            Ok(())
+        }
+        TokenCommand::Update {
+            token_id,
+            name,
+            status,
+            expires,
+        } => {
+            use crate::api::token::UpdateToken;
+
+            let update = UpdateToken {
+                name,
+                status,
+                policies: None,
+                not_before: None,
+                expires_on: expires,
+                condition: None,
+            };
+
+            let token = ops::token::update_token(client, &token_id, update).await?;
+            println!("✓ Token updated: {}", token.name);
+            Ok(())
+        }
+        TokenCommand::Delete { token_id, confirm } => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            ops::token::delete_token(client, &token_id).await
+        }
+        TokenCommand::Verify => {
+            let verification = ops::token::verify_token(client).await?;
+            println!("\nToken Verification:\n");
+            println!("  ID: {}", verification.id);
+            println!("  Status: {}", verification.status);
+            if let Some(not_before) = &verification.not_before {
+                println!("  Not Before: {}", not_before);
+            }
+            if let Some(expires) = &verification.expires_on {
+                println!("  Expires: {}", expires);
+            }
+            Ok(())
+        }
+        TokenCommand::Permissions { scope } => {
+            let groups = ops::token::list_permission_groups(client).await?;
+            println!("\nAvailable Permission Groups:\n");
+            output::table::print_permission_groups(&groups, scope.as_deref());
+            Ok(())
+        }
+        TokenCommand::Roll { token_id, confirm } => {
+            if !confirm {
+                println!("⚠ Rolling a token requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let result = ops::token::roll_token(client, &token_id).await?;
+            println!("\n✓ Token rolled successfully!\n");
+            println!("  ID: {}", result.id);
+            println!("\n  NEW TOKEN VALUE (save this - it won't be shown again):");
+            println!("  {}", result.value);
+            Ok(())
+        }
+    }
+}
+
+pub async fn handle_tunnel_command(
+    client: &client::CloudflareClient,
+    cmd: cli::tunnel::TunnelCommand,
+) -> Result<()> {
+    use cli::tunnel::{TunnelCommand, TunnelConfigCommand, TunnelRouteCommand};
+
+    match cmd {
+        TunnelCommand::List { account_id } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let tunnels = ops::tunnel::list_tunnels(client, &account_id).await?;
+            output::table::print_tunnels(&tunnels);
+        }
+        TunnelCommand::Show { account_id, tunnel } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let tunnel = ops::tunnel::get_tunnel(client, &account_id, &tunnel).await?;
+            let connectors = ops::tunnel::list_connections(client, &account_id, &tunnel.id).await?;
+            output::table::print_tunnel(&tunnel);
+            println!("\nConnections:\n");
+            output::table::print_tunnel_connections(&connectors);
+        }
+        TunnelCommand::Create {
+            account_id,
+            name,
+            locally_managed,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            ops::tunnel::create_tunnel(client, &account_id, &name, !locally_managed).await?;
+            println!(
+                "  Run it with: cloudflared tunnel run --token \"$(cfad tunnel token {})\"",
+                name
+            );
+        }
+        TunnelCommand::Delete {
+            account_id,
+            tunnel,
+            cleanup_connections,
+            confirm,
+        } => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let account_id = resolve_account_id(account_id, None)?;
+            let tunnel = ops::tunnel::get_tunnel(client, &account_id, &tunnel).await?;
+            ops::tunnel::delete_tunnel(client, &account_id, &tunnel.id, cleanup_connections)
+                .await?;
+        }
+        TunnelCommand::Token { account_id, tunnel } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let tunnel = ops::tunnel::get_tunnel(client, &account_id, &tunnel).await?;
+            // Printed bare so it can be captured by `$(...)`
+            let token = ops::tunnel::get_tunnel_token(client, &account_id, &tunnel.id).await?;
+            println!("{}", token);
+        }
+        TunnelCommand::Config(TunnelConfigCommand::Get {
+            account_id,
+            tunnel,
+            output,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let tunnel = ops::tunnel::get_tunnel(client, &account_id, &tunnel).await?;
+            let config = ops::tunnel::get_tunnel_config(client, &account_id, &tunnel.id)
+                .await?
+                .config
+                .ok_or_else(|| {
+                    crate::error::CfadError::not_found("Tunnel configuration", &tunnel.name)
+                })?;
+            let yaml = serde_yaml::to_string(&config)?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, yaml)?;
+                    println!("✓ Wrote configuration of {} to {}", tunnel.name, path);
+                }
+                None => print!("{}", yaml),
+            }
+        }
+        TunnelCommand::Config(TunnelConfigCommand::Put {
+            account_id,
+            tunnel,
+            file,
+            dry_run,
+        }) => {
+            let config = ops::tunnel::parse_tunnel_config(&std::fs::read_to_string(&file)?)?;
+            let account_id = resolve_account_id(account_id, None)?;
+            let tunnel = ops::tunnel::get_tunnel(client, &account_id, &tunnel).await?;
+            if dry_run {
+                println!("\nIngress rules for {} (dry run):\n", tunnel.name);
+                output::table::print_tunnel_ingress(&config.ingress);
+                return Ok(());
+            }
+            if !tunnel.is_remotely_managed() {
+                println!(
+                    "⚠ Tunnel {} is locally managed; cloudflared keeps using its local config.yml",
+                    tunnel.name
+                );
+            }
+            ops::tunnel::put_tunnel_config(client, &account_id, &tunnel.id, config).await?;
+        }
+        TunnelCommand::Route(TunnelRouteCommand::Dns {
+            account_id,
+            tunnel,
+            hostname,
+            zone,
+            overwrite,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let tunnel = ops::tunnel::get_tunnel(client, &account_id, &tunnel).await?;
+            let zone_obj = match zone {
+                Some(zone) => ops::zone::get_zone(client, &zone).await?,
+                None => {
+                    let zones = ops::zone::list_zones(client, None).await?;
+                    ops::zone::zone_for_hostname(&zones, &hostname)
+                        .cloned()
+                        .ok_or_else(|| crate::error::CfadError::not_found("Zone", &hostname))?
+                }
+            };
+            ops::tunnel::route_dns(client, &zone_obj.id, &tunnel, &hostname, overwrite).await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn handle_workers_command(
+    client: &client::CloudflareClient,
+    cmd: cli::workers::WorkersCommand,
+) -> Result<()> {
+    use cli::workers::{
+        WorkerCronCommand, WorkerDomainCommand, WorkerRouteCommand, WorkerSecretCommand,
+        WorkersCommand,
+    };
+
+    match cmd {
+        WorkersCommand::List { account_id } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let scripts = ops::workers::list_scripts(client, &account_id).await?;
+            output::table::print_worker_scripts(&scripts);
+        }
+        WorkersCommand::Show { account_id, script } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let script = ops::workers::get_script(client, &account_id, &script).await?;
+            let settings =
+                ops::workers::get_script_settings(client, &account_id, &script.id).await?;
+            let schedules = ops::workers::get_schedules(client, &account_id, &script.id).await?;
+            let domains = ops::workers::list_domains(client, &account_id, Some(&script.id)).await?;
+            output::table::print_worker_script(&script, &settings, &schedules, &domains);
+        }
+        WorkersCommand::Deploy {
+            account_id,
+            name,
+            module,
+            additional_modules,
+            compat_date,
+            compat_flags,
+            bindings,
+            dry_run,
+        } => {
+            use crate::api::workers::{WorkerMetadata, KEEP_BINDINGS};
+
+            if let Some(date) = &compat_date {
+                ops::workers::validate_compat_date(date)?;
+            }
+            let bindings = bindings
+                .iter()
+                .map(|spec| ops::workers::parse_binding(spec))
+                .collect::<Result<Vec<_>>>()?;
+            let additional: Vec<std::path::PathBuf> = additional_modules
+                .iter()
+                .map(std::path::PathBuf::from)
+                .collect();
+            let modules = ops::workers::load_modules(std::path::Path::new(&module), &additional)?;
+
+            let metadata = WorkerMetadata {
+                main_module: modules[0].name.clone(),
+                compatibility_date: compat_date,
+                compatibility_flags: compat_flags,
+                bindings,
+                keep_bindings: KEEP_BINDINGS.iter().map(|b| b.to_string()).collect(),
+            };
+
+            if dry_run {
+                println!("\nWorker {} (dry run):\n", name);
+                output::table::print_worker_upload(&metadata, &modules);
+                return Ok(());
+            }
+            if metadata.compatibility_date.is_none() {
+                println!(
+                    "⚠ No --compat-date given; the runtime uses its oldest compatibility date"
+                );
+            }
+            let account_id = resolve_account_id(account_id, None)?;
+            ops::workers::deploy_script(client, &account_id, &name, metadata, modules).await?;
+        }
+        WorkersCommand::Delete {
+            account_id,
+            script,
+            force,
+            confirm,
+        } => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let account_id = resolve_account_id(account_id, None)?;
+            ops::workers::delete_script(client, &account_id, &script, force).await?;
+        }
+        WorkersCommand::Routes(WorkerRouteCommand::List { zone }) => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let routes = ops::workers::list_routes(client, &zone_obj.id).await?;
+            println!("\nWorker routes for {}:\n", zone_obj.name);
+            output::table::print_worker_routes(&routes);
+        }
+        WorkersCommand::Routes(WorkerRouteCommand::Add {
+            zone,
+            pattern,
+            script,
+        }) => {
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            ops::workers::create_route(client, &zone_obj.id, &pattern, &script).await?;
+        }
+        WorkersCommand::Routes(WorkerRouteCommand::Delete {
+            zone,
+            route,
+            confirm,
+        }) => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let zone_obj = ops::zone::get_zone(client, &zone).await?;
+            let existing = ops::workers::list_routes(client, &zone_obj.id)
+                .await?
+                .into_iter()
+                .find(|r| r.id == route || r.pattern == route)
+                .ok_or_else(|| crate::error::CfadError::not_found("Worker route", &route))?;
+            ops::workers::delete_route(client, &zone_obj.id, &existing.id).await?;
+        }
+        WorkersCommand::Domains(WorkerDomainCommand::List { account_id, script }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let domains =
+                ops::workers::list_domains(client, &account_id, script.as_deref()).await?;
+            output::table::print_worker_domains(&domains);
+        }
+        WorkersCommand::Domains(WorkerDomainCommand::Add {
+            account_id,
+            script,
+            hostname,
+            zone,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let zone_obj = match zone {
+                Some(zone) => ops::zone::get_zone(client, &zone).await?,
+                None => {
+                    let zones = ops::zone::list_zones(client, None).await?;
+                    ops::zone::zone_for_hostname(&zones, &hostname)
+                        .cloned()
+                        .ok_or_else(|| crate::error::CfadError::not_found("Zone", &hostname))?
+                }
+            };
+            ops::workers::attach_domain(client, &account_id, &script, &hostname, &zone_obj.id)
+                .await?;
+        }
+        WorkersCommand::Domains(WorkerDomainCommand::Delete {
+            account_id,
+            domain,
+            confirm,
+        }) => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let account_id = resolve_account_id(account_id, None)?;
+            let existing = ops::workers::list_domains(client, &account_id, None)
+                .await?
+                .into_iter()
+                .find(|d| d.id == domain || d.hostname == domain)
+                .ok_or_else(|| crate::error::CfadError::not_found("Worker domain", &domain))?;
+            ops::workers::detach_domain(client, &account_id, &existing.id).await?;
+        }
+        WorkersCommand::Cron(WorkerCronCommand::List { account_id, script }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let schedules = ops::workers::get_schedules(client, &account_id, &script).await?;
+            output::table::print_worker_crons(&schedules);
+        }
+        WorkersCommand::Cron(WorkerCronCommand::Set {
+            account_id,
+            script,
+            crons,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            ops::workers::set_schedules(client, &account_id, &script, &crons).await?;
+        }
+        WorkersCommand::Cron(WorkerCronCommand::Add {
+            account_id,
+            script,
+            cron,
+        }) => {
+            ops::workers::validate_cron(&cron)?;
+            let account_id = resolve_account_id(account_id, None)?;
+            let mut crons: Vec<String> = ops::workers::get_schedules(client, &account_id, &script)
+                .await?
+                .into_iter()
+                .map(|s| s.cron)
+                .collect();
+            if crons.contains(&cron) {
+                println!("✓ {} already has cron trigger \"{}\"", script, cron);
+                return Ok(());
+            }
+            crons.push(cron);
+            ops::workers::set_schedules(client, &account_id, &script, &crons).await?;
+        }
+        WorkersCommand::Cron(WorkerCronCommand::Remove {
+            account_id,
+            script,
+            cron,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let mut crons: Vec<String> = ops::workers::get_schedules(client, &account_id, &script)
+                .await?
+                .into_iter()
+                .map(|s| s.cron)
+                .collect();
+            let before = crons.len();
+            crons.retain(|c| c != &cron);
+            if crons.len() == before {
+                return Err(crate::error::CfadError::not_found("Cron trigger", &cron));
+            }
+            ops::workers::set_schedules(client, &account_id, &script, &crons).await?;
+        }
+        WorkersCommand::Secret(WorkerSecretCommand::List { account_id, script }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let secrets = ops::workers::list_secrets(client, &account_id, &script).await?;
+            output::table::print_worker_secrets(&secrets);
+        }
+        WorkersCommand::Secret(WorkerSecretCommand::Put {
+            account_id,
+            script,
+            name,
+            from_file,
+            from_env,
+        }) => {
+            ops::workers::validate_secret_name(&name)?;
+            let value = read_secret_value(&name, from_file.as_deref(), from_env.as_deref())?;
+            let account_id = resolve_account_id(account_id, None)?;
+            ops::workers::put_secret(client, &account_id, &script, &name, &value).await?;
+        }
+        WorkersCommand::Secret(WorkerSecretCommand::Delete {
+            account_id,
+            script,
+            name,
+            confirm,
+        }) => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let account_id = resolve_account_id(account_id, None)?;
+            ops::workers::delete_secret(client, &account_id, &script, &name).await?;
+        }
+        WorkersCommand::Secret(WorkerSecretCommand::Bulk {
+            account_id,
+            script,
+            file,
+            dry_run,
+            confirm,
+        }) => {
+            let desired = ops::workers::parse_dotenv(&std::fs::read_to_string(&file)?)?;
+            let account_id = resolve_account_id(account_id, None)?;
+            let existing = ops::workers::list_secrets(client, &account_id, &script).await?;
+            let plan = ops::workers::plan_secret_sync(&existing, &desired);
+
+            println!("\nSecrets of {} from {}:\n", script, file);
+            for name in &plan.add {
+                println!("  + {}", name);
+            }
+            for name in &plan.change {
+                println!("  ~ {}", name);
+            }
+            for name in &plan.remove {
+                println!("  - {}", name);
+            }
+            println!(
+                "\n{} to add, {} to change, {} to remove",
+                plan.add.len(),
+                plan.change.len(),
+                plan.remove.len()
+            );
+            if dry_run || plan.is_empty() {
+                return Ok(());
+            }
+            if !plan.remove.is_empty() && !confirm {
+                println!("⚠ Removing secrets requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            ops::workers::sync_secrets(client, &account_id, &script, &desired, &plan).await?;
+        }
+    }
+    Ok(())
+}
+
+pub async fn handle_queues_command(
+    client: &client::CloudflareClient,
+    cmd: cli::queues::QueuesCommand,
+) -> Result<()> {
+    use cli::queues::{QueueConsumerCommand, QueuesCommand};
+
+    match cmd {
+        QueuesCommand::List { account_id } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let queues = ops::queues::list_queues(client, &account_id).await?;
+            output::table::print_queues(&queues);
+        }
+        QueuesCommand::Show { account_id, queue } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let queue_id = resolve_queue_id(client, &account_id, &queue).await?;
+            let queue = ops::queues::get_queue(client, &account_id, &queue_id).await?;
+            output::table::print_queue(&queue);
+        }
+        QueuesCommand::Create { account_id, name } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            ops::queues::create_queue(client, &account_id, &name).await?;
+        }
+        QueuesCommand::Delete {
+            account_id,
+            queue,
+            confirm,
+        } => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let account_id = resolve_account_id(account_id, None)?;
+            let queue_id = resolve_queue_id(client, &account_id, &queue).await?;
+            ops::queues::delete_queue(client, &account_id, &queue_id).await?;
+        }
+        QueuesCommand::Consumer(QueueConsumerCommand::List { account_id, queue }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let queue_id = resolve_queue_id(client, &account_id, &queue).await?;
+            let consumers = ops::queues::list_consumers(client, &account_id, &queue_id).await?;
+            output::table::print_queue_consumers(&consumers);
+        }
+        QueuesCommand::Consumer(QueueConsumerCommand::Add {
+            account_id,
+            queue,
+            script,
+            http_pull: _,
+            settings,
+        }) => {
+            let consumer = ops::queues::consumer_payload(
+                script,
+                settings.dead_letter_queue.clone(),
+                consumer_settings(&settings),
+            )?;
+            let account_id = resolve_account_id(account_id, None)?;
+            let queue_id = resolve_queue_id(client, &account_id, &queue).await?;
+            ops::queues::create_consumer(client, &account_id, &queue_id, consumer).await?;
+        }
+        QueuesCommand::Consumer(QueueConsumerCommand::Update {
+            account_id,
+            queue,
+            consumer,
+            settings,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let queue_id = resolve_queue_id(client, &account_id, &queue).await?;
+            let consumers = ops::queues::list_consumers(client, &account_id, &queue_id).await?;
+            let existing = ops::queues::find_consumer(&consumers, &consumer)
+                .ok_or_else(|| crate::error::CfadError::not_found("Queue consumer", &consumer))?;
+            let consumer_id = existing.consumer_id.clone().ok_or_else(|| {
+                crate::error::CfadError::api(format!("Queue consumer '{}' has no ID", consumer))
+            })?;
+            let payload = ops::queues::consumer_payload(
+                existing.script_name.clone(),
+                settings
+                    .dead_letter_queue
+                    .clone()
+                    .or_else(|| existing.dead_letter_queue.clone()),
+                updated_consumer_settings(existing, &settings),
+            )?;
+            ops::queues::update_consumer(client, &account_id, &queue_id, &consumer_id, payload)
+                .await?;
+        }
+        QueuesCommand::Consumer(QueueConsumerCommand::Delete {
+            account_id,
+            queue,
+            consumer,
+            confirm,
+        }) => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let account_id = resolve_account_id(account_id, None)?;
+            let queue_id = resolve_queue_id(client, &account_id, &queue).await?;
+            let consumers = ops::queues::list_consumers(client, &account_id, &queue_id).await?;
+            let consumer_id = ops::queues::find_consumer(&consumers, &consumer)
+                .and_then(|c| c.consumer_id.clone())
+                .ok_or_else(|| crate::error::CfadError::not_found("Queue consumer", &consumer))?;
+            ops::queues::delete_consumer(client, &account_id, &queue_id, &consumer_id).await?;
+        }
+        QueuesCommand::Send {
+            account_id,
+            queue,
+            body,
+            json,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let queue_id = resolve_queue_id(client, &account_id, &queue).await?;
+            ops::queues::send_message(client, &account_id, &queue_id, &body, json).await?;
+        }
+        QueuesCommand::Pull {
+            account_id,
+            queue,
+            batch_size,
+            visibility_timeout_ms,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let queue_id = resolve_queue_id(client, &account_id, &queue).await?;
+            let pulled = ops::queues::pull_messages(
+                client,
+                &account_id,
+                &queue_id,
+                batch_size,
+                visibility_timeout_ms,
+            )
+            .await?;
+            output::table::print_queue_messages(&pulled);
+        }
+        QueuesCommand::Ack {
+            account_id,
+            queue,
+            lease_ids,
+            retries,
+            retry_delay,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let queue_id = resolve_queue_id(client, &account_id, &queue).await?;
+            ops::queues::ack_messages(
+                client,
+                &account_id,
+                &queue_id,
+                &lease_ids,
+                &retries,
+                retry_delay,
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+fn consumer_settings(args: &cli::queues::ConsumerSettingsArgs) -> api::queues::ConsumerSettings {
+    api::queues::ConsumerSettings {
+        batch_size: args.batch_size,
+        max_retries: args.max_retries,
+        max_wait_time_ms: args.max_wait_ms,
+        max_concurrency: args.max_concurrency,
+        retry_delay: args.retry_delay,
+        visibility_timeout_ms: args.visibility_timeout_ms,
+    }
+}
+
+/// Settings of an existing consumer with only the given flags changed.
+/// Settings that don't apply to the consumer's type are left to the flags,
+/// so `consumer_payload` still rejects them when they're passed explicitly.
+fn updated_consumer_settings(
+    existing: &api::queues::QueueConsumer,
+    args: &cli::queues::ConsumerSettingsArgs,
+) -> api::queues::ConsumerSettings {
+    let base = &existing.settings;
+    let is_worker = existing.script_name.is_some();
+    api::queues::ConsumerSettings {
+        batch_size: args.batch_size.or(base.batch_size),
+        max_retries: args.max_retries.or(base.max_retries),
+        max_wait_time_ms: args
+            .max_wait_ms
+            .or(base.max_wait_time_ms.filter(|_| is_worker)),
+        max_concurrency: args
+            .max_concurrency
+            .or(base.max_concurrency.filter(|_| is_worker)),
+        retry_delay: args.retry_delay.or(base.retry_delay),
+        visibility_timeout_ms: args
+            .visibility_timeout_ms
+            .or(base.visibility_timeout_ms.filter(|_| !is_worker)),
+    }
+}
+
+/// Resolve a queue name to its ID (IDs are passed through)
+pub async fn resolve_queue_id(
+    client: &client::CloudflareClient,
+    account_id: &str,
+    identifier: &str,
+) -> Result<String> {
+    // Queue IDs are 32 hex characters
+    if identifier.len() == 32 && identifier.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Ok(identifier.to_string());
+    }
+
+    let queues = ops::queues::list_queues(client, account_id).await?;
+    for queue in queues {
+        if queue.queue_name == identifier || queue.queue_id == identifier {
+            return Ok(queue.queue_id);
+        }
+    }
+
+    Err(crate::error::CfadError::not_found("Queue", identifier))
+}
+
+/// Read a secret value from a file, an environment variable or stdin, so it
+/// never appears on the command line
+fn read_secret_value(
+    name: &str,
+    from_file: Option<&str>,
+    from_env: Option<&str>,
+) -> Result<String> {
+    use std::io::{IsTerminal, Read};
+
+    let value = match (from_file, from_env) {
+        (Some(path), _) => std::fs::read_to_string(path)?,
+        (None, Some(var)) => std::env::var(var).map_err(|_| {
+            crate::error::CfadError::validation(format!("Environment variable {} is not set", var))
+        })?,
+        (None, None) => {
+            if std::io::stdin().is_terminal() {
+                eprintln!("Enter the value of {} and press Ctrl-D:", name);
+            }
+            let mut value = String::new();
+            std::io::stdin().read_to_string(&mut value)?;
+            value
+        }
+    };
+    ops::workers::trim_secret_value(value)
+}
+
+pub async fn handle_kv_command(
+    client: &client::CloudflareClient,
+    cmd: cli::kv::KvCommand,
+) -> Result<()> {
+    use cli::kv::{KvBulkCommand, KvCommand, KvKeyCommand, KvNamespaceCommand};
+
+    match cmd {
+        KvCommand::Namespace(KvNamespaceCommand::List { account_id }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let namespaces = ops::kv::list_namespaces(client, &account_id).await?;
+            output::table::print_kv_namespaces(&namespaces);
+        }
+        KvCommand::Namespace(KvNamespaceCommand::Create { account_id, title }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            ops::kv::create_namespace(client, &account_id, &title).await?;
+        }
+        KvCommand::Namespace(KvNamespaceCommand::Rename {
+            account_id,
+            namespace,
+            title,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let ns_id = resolve_kv_namespace_id(client, &account_id, &namespace).await?;
+            ops::kv::rename_namespace(client, &account_id, &ns_id, &title).await?;
+        }
+        KvCommand::Namespace(KvNamespaceCommand::Delete {
+            account_id,
+            namespace,
+            confirm,
+        }) => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let account_id = resolve_account_id(account_id, None)?;
+            let ns_id = resolve_kv_namespace_id(client, &account_id, &namespace).await?;
+            ops::kv::delete_namespace(client, &account_id, &ns_id).await?;
+        }
+        KvCommand::Key(KvKeyCommand::List {
+            account_id,
+            namespace,
+            prefix,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let ns_id = resolve_kv_namespace_id(client, &account_id, &namespace).await?;
+            let keys = ops::kv::list_keys(client, &account_id, &ns_id, prefix.as_deref()).await?;
+            output::table::print_kv_keys(&keys);
+        }
+        KvCommand::Key(KvKeyCommand::Get {
+            account_id,
+            namespace,
+            key,
+            output,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let ns_id = resolve_kv_namespace_id(client, &account_id, &namespace).await?;
+            let value = ops::kv::get_value(client, &account_id, &ns_id, &key).await?;
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, &value)?;
+                    println!("✓ Wrote {} bytes to {}", value.len(), path);
+                }
+                None => {
+                    use std::io::Write;
+                    std::io::stdout().write_all(&value)?;
+                }
+            }
+        }
+        KvCommand::Key(KvKeyCommand::Put {
+            account_id,
+            namespace,
+            key,
+            value,
+            path,
+            expiration_ttl,
+            metadata,
+        }) => {
+            let metadata = metadata
+                .as_deref()
+                .map(ops::kv::parse_metadata)
+                .transpose()?;
+            let value = match (value, path) {
+                (_, Some(path)) => std::fs::read(&path)?,
+                (Some(value), None) => value.into_bytes(),
+                (None, None) => {
+                    return Err(crate::error::CfadError::validation(
+                        "Provide a value or --path",
+                    ))
+                }
+            };
+            let account_id = resolve_account_id(account_id, None)?;
+            let ns_id = resolve_kv_namespace_id(client, &account_id, &namespace).await?;
+            ops::kv::put_value(
+                client,
+                &account_id,
+                &ns_id,
+                &key,
+                value,
+                expiration_ttl,
+                metadata.as_ref(),
+            )
+            .await?;
+        }
+        KvCommand::Key(KvKeyCommand::Delete {
+            account_id,
+            namespace,
+            key,
+        }) => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let ns_id = resolve_kv_namespace_id(client, &account_id, &namespace).await?;
+            ops::kv::delete_value(client, &account_id, &ns_id, &key).await?;
+        }
+        KvCommand::Bulk(KvBulkCommand::Put {
+            account_id,
+            namespace,
+            file,
+        }) => {
+            let entries = ops::kv::parse_bulk_entries(&std::fs::read_to_string(&file)?)?;
+            let account_id = resolve_account_id(account_id, None)?;
+            let ns_id = resolve_kv_namespace_id(client, &account_id, &namespace).await?;
+            let result = ops::kv::bulk_put(client, &account_id, &ns_id, &entries).await?;
+            report_kv_bulk_result("written", &result)?;
+        }
+        KvCommand::Bulk(KvBulkCommand::Delete {
+            account_id,
+            namespace,
+            file,
+            confirm,
+        }) => {
+            if !confirm {
+                println!("⚠ Deletion requires --confirm flag");
+                return Err(crate::error::CfadError::validation("Confirmation required"));
+            }
+            let keys = ops::kv::parse_bulk_delete_keys(&std::fs::read_to_string(&file)?)?;
+            let account_id = resolve_account_id(account_id, None)?;
+            let ns_id = resolve_kv_namespace_id(client, &account_id, &namespace).await?;
+            let result = ops::kv::bulk_delete(client, &account_id, &ns_id, &keys).await?;
+            report_kv_bulk_result("deleted", &result)?;
+        }
+        KvCommand::Export {
+            account_id,
+            namespace,
+            dir,
+            prefix,
+        } => {
+            let account_id = resolve_account_id(account_id, None)?;
+            let ns_id = resolve_kv_namespace_id(client, &account_id, &namespace).await?;
+            ops::kv::export_namespace(
+                client,
+                &account_id,
+                &ns_id,
+                prefix.as_deref(),
+                std::path::Path::new(&dir),
+            )
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+fn report_kv_bulk_result(verb: &str, result: &crate::api::kv::KvBulkResult) -> Result<()> {
+    println!(
+        "
+{} keys {}",
+        result.successful_key_count, verb
+    );
+    if result.unsuccessful_keys.is_empty() {
+        return Ok(());
+    }
+    for key in &result.unsuccessful_keys {
+        println!("  ✗ {}", key);
+    }
+    Err(crate::error::CfadError::api(format!(
+        "{} keys could not be {}",
+        result.unsuccessful_keys.len(),
+        verb
+    )))
+}
+
+/// Resolve a KV namespace title to its ID (IDs are passed through)
+pub async fn resolve_kv_namespace_id(
+    client: &client::CloudflareClient,
+    account_id: &str,
+    identifier: &str,
+) -> Result<String> {
+    // Namespace IDs are 32 hex characters
+    if identifier.len() == 32 && identifier.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Ok(identifier.to_string());
+    }
+
+    let namespaces = ops::kv::list_namespaces(client, account_id).await?;
+    for ns in namespaces {
+        if ns.title == identifier || ns.id == identifier {
+            return Ok(ns.id);
+        }
+    }
+
+    Err(crate::error::CfadError::not_found(
+        "KV namespace",
+        identifier,
+    ))
+}
+
 pub async fn handle_config_command(cmd: cli::config::ConfigCommand) -> Result<()> {
     use cli::config::{ConfigCommand, ProfileCommand};
 
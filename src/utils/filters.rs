@@ -1,2 +1,62 @@
-// Placeholder for filter utilities
-// To be implemented with filtering logic for DNS records and zones
+use regex::Regex;
+
+/// Returns true when the pattern contains shell-style glob metacharacters.
+pub fn is_glob(pattern: &str) -> bool {
+    pattern.contains('*') || pattern.contains('?')
+}
+
+/// Match a name against a shell-style glob (`*` and `?`), case-insensitively.
+///
+/// Zone and record names are DNS names, so comparison ignores case.
+pub fn glob_match(pattern: &str, name: &str) -> bool {
+    let mut regex = String::from("(?i)^");
+    for c in pattern.chars() {
+        match c {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            _ => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+
+    Regex::new(&regex)
+        .map(|re| re.is_match(name))
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_glob() {
+        assert!(is_glob("*.example.com"));
+        assert!(is_glob("shop-?.com"));
+        assert!(!is_glob("example.com"));
+    }
+
+    #[test]
+    fn test_glob_match_star() {
+        assert!(glob_match("*.com", "example.com"));
+        assert!(glob_match("example*", "example.org"));
+        assert!(glob_match("*", "anything.net"));
+        assert!(!glob_match("*.com", "example.org"));
+    }
+
+    #[test]
+    fn test_glob_match_question_mark() {
+        assert!(glob_match("shop-?.com", "shop-1.com"));
+        assert!(!glob_match("shop-?.com", "shop-12.com"));
+    }
+
+    #[test]
+    fn test_glob_match_escapes_dots() {
+        assert!(!glob_match("example.com", "exampleXcom"));
+        assert!(glob_match("example.com", "example.com"));
+    }
+
+    #[test]
+    fn test_glob_match_case_insensitive() {
+        assert!(glob_match("*.EXAMPLE.com", "www.example.COM"));
+    }
+}
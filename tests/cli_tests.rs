@@ -245,7 +245,55 @@ fn test_d1_export_help() {
         .args(["d1", "export", "--help"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("DATABASE_ID"));
+        .stdout(predicate::str::contains("DATABASE_ID"))
+        .stdout(predicate::str::contains("--no-schema"));
+}
+
+#[test]
+fn test_d1_shell_help() {
+    cfad()
+        .args(["d1", "shell", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("DATABASE_ID"))
+        .stdout(predicate::str::contains(".schema [TABLE]"));
+}
+
+#[test]
+fn test_d1_migrations_help() {
+    cfad()
+        .args(["d1", "migrations", "apply", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--dir"))
+        .stdout(predicate::str::contains("--bookmark"));
+}
+
+#[test]
+fn test_d1_query_param_conflicts_with_params_json() {
+    cfad()
+        .args([
+            "d1",
+            "query",
+            "my-db",
+            "SELECT ?",
+            "--param",
+            "int:1",
+            "--params-json",
+            "[1]",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_d1_export_requires_output() {
+    cfad()
+        .args(["d1", "export", "my-db"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--output"));
 }
 
 #[test]
@@ -322,7 +370,538 @@ fn test_cache_purge_help() {
         .success()
         .stdout(predicate::str::contains("--all"))
         .stdout(predicate::str::contains("--files"))
-        .stdout(predicate::str::contains("--tags"));
+        .stdout(predicate::str::contains("--tags"))
+        .stdout(predicate::str::contains("--all-zones"))
+        .stdout(predicate::str::contains("--zones-file"))
+        .stdout(predicate::str::contains("--header"));
+}
+
+#[test]
+fn test_cache_purge_from_file_conflicts_with_files() {
+    cfad()
+        .args([
+            "cache",
+            "purge",
+            "example.com",
+            "--files",
+            "https://example.com/a",
+            "--from-file",
+            "urls.txt",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_cache_purge_changed_files_requires_base_url() {
+    cfad()
+        .args([
+            "cache",
+            "purge",
+            "example.com",
+            "--changed-files",
+            "changed.txt",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--base-url"));
+}
+
+#[test]
+fn test_cache_purge_tags_require_zone() {
+    cfad()
+        .args(["cache", "purge", "--tags", "product-1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("required"));
+}
+
+#[test]
+fn test_cache_settings_set_rejects_invalid_value() {
+    cfad()
+        .args(["cache", "settings", "set", "example.com", "--argo", "maybe"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+#[test]
+fn test_cache_settings_help_lists_settings() {
+    cfad()
+        .args(["cache", "settings", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("clear-reserve"))
+        .stdout(predicate::str::contains("smart-topology"));
+}
+
+#[test]
+fn test_cache_purge_zone_conflicts_with_selector() {
+    cfad()
+        .args(["cache", "purge", "example.com", "--all-zones", "--all"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_zone_status_rejects_unknown_status() {
+    cfad()
+        .args(["dns", "list", "--zone-status", "active&per_page=1"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+// =============================================================================
+// Rules Subcommand Tests
+// =============================================================================
+
+#[test]
+fn test_rules_help_lists_phases() {
+    cfad()
+        .args(["rules", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("waf"))
+        .stdout(predicate::str::contains("redirect"))
+        .stdout(predicate::str::contains("ratelimit"))
+        .stdout(predicate::str::contains("apply"));
+}
+
+#[test]
+fn test_rules_waf_list_requires_scope() {
+    cfad()
+        .args(["rules", "waf", "list"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--zone"));
+}
+
+#[test]
+fn test_rules_validate_works_offline() {
+    cfad()
+        .env_remove("CLOUDFLARE_API_TOKEN")
+        .env_remove("CLOUDFLARE_API_KEY")
+        .args(["rules", "validate", r#"http.request.uri.path eq "/admin""#])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Expression is valid"));
+}
+
+#[test]
+fn test_rules_validate_reports_unknown_field() {
+    cfad()
+        .args(["rules", "validate", r#"http.request.uri.pth eq "/admin""#])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Unknown field"))
+        .stderr(predicate::str::contains("http.request.uri.path"));
+}
+
+#[test]
+fn test_rules_test_against_request_file() {
+    let dir = std::env::temp_dir().join(format!("cfad-rules-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let request = dir.join("request.json");
+    std::fs::write(
+        &request,
+        r#"{"http.request.uri.path": "/admin/login", "ip.src": "192.0.2.10"}"#,
+    )
+    .unwrap();
+
+    let assert = cfad()
+        .args([
+            "rules",
+            "test",
+            r#"starts_with(http.request.uri.path, "/admin") and ip.src in {192.0.2.0/24}"#,
+            "--request",
+            request.to_str().unwrap(),
+        ])
+        .assert();
+    std::fs::remove_dir_all(&dir).ok();
+    assert
+        .success()
+        .stdout(predicate::str::contains("Expression matches the request"));
+}
+
+// =============================================================================
+// Firewall / Lists Subcommand Tests
+// =============================================================================
+
+#[test]
+fn test_firewall_access_rules_add_requires_single_target() {
+    cfad()
+        .args([
+            "firewall",
+            "access-rules",
+            "add",
+            "--zone",
+            "example.com",
+            "--mode",
+            "block",
+            "--ip",
+            "192.0.2.1",
+            "--asn",
+            "AS64496",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_lists_add_help_shows_file_and_replace() {
+    cfad()
+        .args(["lists", "add", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--file"))
+        .stdout(predicate::str::contains("--replace"))
+        .stdout(predicate::str::contains("--no-wait"));
+}
+
+// =============================================================================
+// Page Rules Subcommand Tests
+// =============================================================================
+
+#[test]
+fn test_zone_page_rules_help() {
+    cfad()
+        .args(["zone", "page-rules", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("list"))
+        .stdout(predicate::str::contains("migrate"));
+}
+
+#[test]
+fn test_zone_page_rules_migrate_help_shows_apply() {
+    cfad()
+        .args(["zone", "page-rules", "migrate", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--apply"));
+}
+
+// =============================================================================
+// Custom Hostnames Subcommand Tests
+// =============================================================================
+
+#[test]
+fn test_zone_custom_hostnames_help() {
+    cfad()
+        .args(["zone", "custom-hostnames", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("verify"))
+        .stdout(predicate::str::contains("import"));
+}
+
+#[test]
+fn test_zone_custom_hostnames_create_rejects_ssl_method() {
+    cfad()
+        .args([
+            "zone",
+            "custom-hostnames",
+            "create",
+            "example.com",
+            "app.customer.com",
+            "--ssl-method",
+            "dns",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+#[test]
+fn test_zone_custom_hostnames_timeout_requires_wait() {
+    cfad()
+        .args([
+            "zone",
+            "custom-hostnames",
+            "verify",
+            "example.com",
+            "app.customer.com",
+            "--timeout",
+            "60",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--wait"));
+}
+
+#[test]
+fn test_zone_audit_requires_zone_or_selector() {
+    cfad()
+        .args(["zone", "audit"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("required"));
+}
+
+#[test]
+fn test_zone_audit_rejects_score_above_100() {
+    cfad()
+        .args(["zone", "audit", "example.com", "--min-score", "101"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+#[test]
+fn test_zone_audit_rejects_invalid_fail_on() {
+    cfad()
+        .args(["zone", "audit", "example.com", "--fail-on", "error"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+// =============================================================================
+// SSL Subcommand Tests
+// =============================================================================
+
+#[test]
+fn test_ssl_universal_rejects_invalid_state() {
+    cfad()
+        .args(["ssl", "universal", "example.com", "maybe"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+#[test]
+fn test_ssl_order_pack_requires_hosts() {
+    cfad()
+        .args(["ssl", "order-pack", "example.com"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--hosts"));
+}
+
+#[test]
+fn test_ssl_origin_cert_help() {
+    cfad()
+        .args(["ssl", "origin-cert", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("create"))
+        .stdout(predicate::str::contains("revoke"));
+}
+
+#[test]
+fn test_ssl_origin_cert_create_rejects_invalid_type() {
+    cfad()
+        .args([
+            "ssl",
+            "origin-cert",
+            "create",
+            "--hostnames",
+            "example.com",
+            "--type",
+            "dsa",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+#[test]
+fn test_ssl_origin_cert_create_requires_hostnames() {
+    cfad()
+        .args(["ssl", "origin-cert", "create"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--hostnames"));
+}
+
+// =============================================================================
+// Load Balancing Subcommand Tests
+// =============================================================================
+
+#[test]
+fn test_lb_help() {
+    cfad()
+        .args(["lb", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("monitors"))
+        .stdout(predicate::str::contains("pools"))
+        .stdout(predicate::str::contains("balancers"));
+}
+
+#[test]
+fn test_lb_pool_create_requires_origin() {
+    cfad()
+        .args(["lb", "pools", "create", "primary"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--origin"));
+}
+
+#[test]
+fn test_lb_balancer_rejects_invalid_steering() {
+    cfad()
+        .args([
+            "lb",
+            "balancers",
+            "create",
+            "example.com",
+            "app.example.com",
+            "--default-pools",
+            "primary",
+            "--steering",
+            "fastest",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("invalid value"));
+}
+
+// =============================================================================
+// Tunnel Subcommand Tests
+// =============================================================================
+
+#[test]
+fn test_tunnel_help() {
+    cfad()
+        .args(["tunnel", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("token"))
+        .stdout(predicate::str::contains("route"));
+}
+
+#[test]
+fn test_tunnel_route_dns_requires_hostname() {
+    cfad()
+        .args(["tunnel", "route", "dns", "prod-web"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("<HOSTNAME>"));
+}
+
+#[test]
+fn test_tunnel_config_put_requires_file() {
+    cfad()
+        .args(["tunnel", "config", "put", "prod-web"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("<FILE>"));
+}
+
+// =============================================================================
+// Workers Subcommand Tests
+// =============================================================================
+
+#[test]
+fn test_workers_deploy_requires_module() {
+    cfad()
+        .args(["workers", "deploy", "api"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--module"));
+}
+
+#[test]
+fn test_workers_cron_set_requires_expression() {
+    cfad()
+        .args(["workers", "cron", "set", "api"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("<CRONS>"));
+}
+
+#[test]
+fn test_workers_secret_put_rejects_both_sources() {
+    cfad()
+        .args([
+            "workers",
+            "secret",
+            "put",
+            "api",
+            "API_TOKEN",
+            "--from-file",
+            "token.txt",
+            "--from-env",
+            "TOKEN",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_workers_help_lists_binding_types() {
+    cfad()
+        .args(["workers", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("kv:CACHE=<namespace_id>"));
+}
+
+// =============================================================================
+// KV Subcommand Tests
+// =============================================================================
+
+#[test]
+fn test_kv_key_put_requires_value_or_path() {
+    cfad()
+        .args(["kv", "key", "put", "SESSIONS", "user:42"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("<VALUE>"));
+}
+
+#[test]
+fn test_kv_key_put_value_conflicts_with_path() {
+    cfad()
+        .args(["kv", "key", "put", "SESSIONS", "k", "v", "--path", "v.txt"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn test_kv_help_documents_bulk_format() {
+    cfad()
+        .args(["kv", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("10,000 keys"));
+}
+
+// =============================================================================
+// Queues Subcommand Tests
+// =============================================================================
+
+#[test]
+fn test_queues_consumer_add_requires_kind() {
+    cfad()
+        .args(["queues", "consumer", "add", "uploads"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--script"));
+}
+
+#[test]
+fn test_queues_consumer_add_script_conflicts_with_http_pull() {
+    cfad()
+        .args([
+            "queues",
+            "consumer",
+            "add",
+            "uploads",
+            "--script",
+            "w",
+            "--http-pull",
+        ])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
 }
 
 // =============================================================================
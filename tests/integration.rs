@@ -2,15 +2,26 @@
 // These tests use wiremock to mock the Cloudflare API
 
 mod integration {
+    mod audit_tests;
     mod cache_tests;
+    mod certificates_tests;
     mod client_tests;
+    mod custom_hostnames_tests;
     mod d1_tests;
     mod dns_import_tests;
     mod dns_tests;
     mod error_tests;
+    mod firewall_tests;
+    mod kv_tests;
+    mod load_balancing_tests;
+    mod page_rules_tests;
     mod pages_tests;
+    mod queues_tests;
     mod r2_tests;
+    mod rulesets_tests;
     mod runner_tests;
     mod token_tests;
+    mod tunnel_tests;
+    mod workers_tests;
     mod zone_tests;
 }
@@ -0,0 +1,176 @@
+use cfad::api::zone::Zone;
+use cfad::client::CloudflareClient;
+use cfad::config::AuthMethod;
+use cfad::ops::audit::{self, CheckStatus};
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
+    let auth = AuthMethod::ApiToken("test_token".to_string());
+    CloudflareClient::new_with_base_url(auth, mock_server.uri()).unwrap()
+}
+
+fn envelope(result: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "success": true,
+        "errors": [],
+        "messages": [],
+        "result": result
+    })
+}
+
+async fn mount(mock_server: &MockServer, endpoint: &str, result: serde_json::Value) {
+    Mock::given(method("GET"))
+        .and(path(endpoint))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(result)))
+        .mount(mock_server)
+        .await;
+}
+
+fn zone() -> Zone {
+    serde_json::from_value(serde_json::json!({
+        "id": "zone123",
+        "name": "example.com",
+        "status": "active",
+        "paused": false,
+        "development_mode": 0,
+        "owner": {"id": "o1", "type": "user", "email": "u@example.com"},
+        "account": {"id": "a1", "name": "Test"},
+        "created_on": "2026-01-01T00:00:00Z",
+        "modified_on": "2026-01-01T00:00:00Z"
+    }))
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_audit_zone_evaluates_fetched_data() {
+    let mock_server = MockServer::start().await;
+
+    mount(
+        &mock_server,
+        "/zones/zone123/settings",
+        serde_json::json!([
+            {"id": "ssl", "value": "strict"},
+            {"id": "always_use_https", "value": "on"},
+            {"id": "min_tls_version", "value": "1.0"},
+            {"id": "development_mode", "value": "off"}
+        ]),
+    )
+    .await;
+    mount(
+        &mock_server,
+        "/zones/zone123/dns_records",
+        serde_json::json!([
+            {"id": "r1", "name": "example.com", "type": "A", "content": "192.0.2.1",
+             "ttl": 1, "proxiable": true, "proxied": false},
+            {"id": "r2", "name": "example.com", "type": "TXT", "content": "v=spf1 -all",
+             "ttl": 1, "proxiable": false, "proxied": false}
+        ]),
+    )
+    .await;
+    mount(
+        &mock_server,
+        "/zones/zone123/dnssec",
+        serde_json::json!({"status": "disabled"}),
+    )
+    .await;
+    mount(
+        &mock_server,
+        "/zones/zone123/ssl/certificate_packs",
+        serde_json::json!([{
+            "id": "pack1",
+            "status": "active",
+            "certificates": [{"id": "c1", "expires_on": "2099-01-01T00:00:00Z"}]
+        }]),
+    )
+    .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = audit::audit_zone(&client, &zone()).await.unwrap();
+
+    let status = |id: &str| result.checks.iter().find(|c| c.id == id).unwrap().status;
+    assert_eq!(status("ssl_mode"), CheckStatus::Pass);
+    assert_eq!(status("min_tls_version"), CheckStatus::Fail);
+    assert_eq!(status("dnssec"), CheckStatus::Warn);
+    assert_eq!(status("spf_record"), CheckStatus::Pass);
+    assert_eq!(status("dmarc_record"), CheckStatus::Fail);
+    assert_eq!(status("proxied_records"), CheckStatus::Warn);
+    assert_eq!(status("edge_certificate"), CheckStatus::Pass);
+    assert!(result.score < 100);
+}
+
+#[tokio::test]
+async fn test_audit_zone_reads_every_page_of_dns_records() {
+    let mock_server = MockServer::start().await;
+
+    mount(
+        &mock_server,
+        "/zones/zone123/settings",
+        serde_json::json!([]),
+    )
+    .await;
+    for (page, records) in [
+        (
+            "1",
+            serde_json::json!([{"id": "r1", "name": "example.com", "type": "A",
+                "content": "192.0.2.1", "ttl": 1, "proxiable": true, "proxied": true}]),
+        ),
+        (
+            "2",
+            serde_json::json!([
+                {"id": "r2", "name": "example.com", "type": "TXT", "content": "v=spf1 -all",
+                 "ttl": 1, "proxiable": false, "proxied": false},
+                {"id": "r3", "name": "_dmarc.example.com", "type": "TXT",
+                 "content": "v=DMARC1; p=reject", "ttl": 1, "proxiable": false, "proxied": false}
+            ]),
+        ),
+    ] {
+        let mut body = envelope(records);
+        body["result_info"] = serde_json::json!({"page": 1, "per_page": 100, "count": 1, "total_count": 3, "total_pages": 2});
+        Mock::given(method("GET"))
+            .and(path("/zones/zone123/dns_records"))
+            .and(query_param("page", page))
+            .respond_with(ResponseTemplate::new(200).set_body_json(body))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    mount(
+        &mock_server,
+        "/zones/zone123/dnssec",
+        serde_json::json!({"status": "active"}),
+    )
+    .await;
+    mount(
+        &mock_server,
+        "/zones/zone123/ssl/certificate_packs",
+        serde_json::json!([]),
+    )
+    .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = audit::audit_zone(&client, &zone()).await.unwrap();
+
+    let status = |id: &str| result.checks.iter().find(|c| c.id == id).unwrap().status;
+    assert_eq!(status("spf_record"), CheckStatus::Pass);
+    assert_eq!(status("dmarc_record"), CheckStatus::Pass);
+}
+
+#[tokio::test]
+async fn test_audit_zone_propagates_api_errors() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/settings"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "success": false,
+            "errors": [{"code": 9109, "message": "Unauthorized to access requested resource"}],
+            "messages": [],
+            "result": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    assert!(audit::audit_zone(&client, &zone()).await.is_err());
+}
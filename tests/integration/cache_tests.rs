@@ -1,7 +1,8 @@
+use cfad::api::cache::PurgeFile;
 use cfad::client::CloudflareClient;
 use cfad::config::AuthMethod;
 use cfad::ops::cache;
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{body_json, body_partial_json, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
@@ -185,3 +186,356 @@ async fn test_purge_files_api_error() {
 
     assert!(result.is_err());
 }
+
+fn urls(count: usize) -> Vec<String> {
+    (0..count)
+        .map(|i| format!("https://example.com/page-{}.html", i))
+        .collect()
+}
+
+fn purge_ok() -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "success": true, "errors": [], "messages": [], "result": {"id": "purge"}
+    }))
+}
+
+#[tokio::test]
+async fn test_purge_files_chunks_at_api_limit() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/purge_cache"))
+        .respond_with(purge_ok())
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let summary = cache::purge_files(&client, "zone123", urls(70))
+        .await
+        .unwrap();
+    assert_eq!(
+        summary,
+        cache::PurgeSummary {
+            requests: 3,
+            purged: 70,
+            failed: 0
+        }
+    );
+
+    let sizes: Vec<usize> = mock_server
+        .received_requests()
+        .await
+        .unwrap()
+        .iter()
+        .map(|r| {
+            let body: serde_json::Value = serde_json::from_slice(&r.body).unwrap();
+            body["files"].as_array().unwrap().len()
+        })
+        .collect();
+    assert_eq!(sizes, vec![30, 30, 10]);
+}
+
+#[tokio::test]
+async fn test_purge_files_skips_duplicates_and_blank_lines() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/purge_cache"))
+        .and(body_partial_json(serde_json::json!({
+            "files": ["https://example.com/a", "https://example.com/b"]
+        })))
+        .respond_with(purge_ok())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let input = [
+        "https://example.com/a",
+        "",
+        "https://example.com/b",
+        "https://example.com/a",
+    ]
+    .iter()
+    .map(|s| s.to_string());
+    let summary = cache::purge_files(&client, "zone123", input).await.unwrap();
+    assert_eq!(summary.purged, 2);
+}
+
+#[tokio::test]
+async fn test_purge_tags_failed_chunk_continues_and_errors() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/purge_cache"))
+        .respond_with(purge_ok())
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/purge_cache"))
+        .respond_with(ResponseTemplate::new(429).set_body_json(serde_json::json!({
+            "success": false, "errors": [{"code": 971, "message": "rate limited"}], "messages": []
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/purge_cache"))
+        .respond_with(purge_ok())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let tags = (0..65).map(|i| format!("tag-{}", i));
+    let err = cache::purge_tags(&client, "zone123", tags)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Purged 35 tags, 30 failed"));
+}
+
+#[tokio::test]
+async fn test_load_sitemap_follows_index() {
+    let mock_server = MockServer::start().await;
+    let base = mock_server.uri();
+
+    Mock::given(method("GET"))
+        .and(path("/sitemap.xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(format!(
+            "<sitemapindex><sitemap><loc>{0}/pages.xml</loc></sitemap>\
+             <sitemap><loc>{0}/posts.xml</loc></sitemap></sitemapindex>",
+            base
+        )))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/pages.xml"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string("<urlset><url><loc>https://example.com/</loc></url></urlset>"),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/posts.xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            "<urlset><url><loc>https://example.com/posts/1</loc></url>\
+             <url><loc>https://example.com/posts/2</loc></url></urlset>",
+        ))
+        .mount(&mock_server)
+        .await;
+
+    let urls = cache::load_sitemap(&format!("{}/sitemap.xml", base))
+        .await
+        .unwrap();
+    assert_eq!(
+        urls,
+        vec![
+            "https://example.com/",
+            "https://example.com/posts/1",
+            "https://example.com/posts/2"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_load_sitemap_rejects_non_sitemap() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/sitemap.xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html>Not found</html>"))
+        .mount(&mock_server)
+        .await;
+
+    let result = cache::load_sitemap(&format!("{}/sitemap.xml", mock_server.uri())).await;
+    assert!(result.unwrap_err().to_string().contains("is not a sitemap"));
+}
+
+#[tokio::test]
+async fn test_purge_file_entries_sends_header_objects() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/purge_cache"))
+        .and(body_partial_json(serde_json::json!({
+            "files": [
+                "https://example.com/a",
+                {"url": "https://example.com/a", "headers": {"CF-Device-Type": "mobile"}}
+            ]
+        })))
+        .respond_with(purge_ok())
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let mobile = PurgeFile::WithHeaders {
+        url: "https://example.com/a".to_string(),
+        headers: [("CF-Device-Type".to_string(), "mobile".to_string())].into(),
+    };
+    let files = vec![
+        PurgeFile::Url("https://example.com/a".to_string()),
+        mobile.clone(),
+        mobile,
+    ];
+    let summary = cache::purge_file_entries(&client, "zone123", files)
+        .await
+        .unwrap();
+    assert_eq!(summary.purged, 2);
+}
+
+fn setting(id: &str, value: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "success": true, "errors": [], "messages": [],
+        "result": {"id": id, "value": value, "editable": true, "modified_on": "2026-03-01T00:00:00Z"}
+    }))
+}
+
+#[tokio::test]
+async fn test_get_cache_settings_reports_unavailable_setting() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/argo/tiered_caching"))
+        .respond_with(setting("tiered_caching", "on"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/zones/zone123/cache/tiered_cache_smart_topology_enable",
+        ))
+        .respond_with(setting("tiered_cache_smart_topology_enable", "off"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/cache/cache_reserve"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "success": false, "errors": [{"code": 1142, "message": "not entitled"}], "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/argo/smart_routing"))
+        .respond_with(setting("smart_routing", "on"))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let states = cache::get_cache_settings(&client, "zone123").await;
+    let values: Vec<(&str, Option<String>)> = states
+        .iter()
+        .map(|s| {
+            let value = s
+                .setting
+                .as_ref()
+                .ok()
+                .map(|v| v.value.as_str().unwrap().to_string());
+            (s.name, value)
+        })
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            ("tiered-caching", Some("on".to_string())),
+            ("smart-topology", Some("off".to_string())),
+            ("cache-reserve", None),
+            ("argo", Some("on".to_string())),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_set_cache_setting_patches_value() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/zones/zone123/argo/smart_routing"))
+        .and(body_json(serde_json::json!({"value": "off"})))
+        .respond_with(setting("smart_routing", "off"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let updated = cache::set_cache_setting(&client, "zone123", "argo", false)
+        .await
+        .unwrap();
+    assert_eq!(updated.value, "off");
+}
+
+#[tokio::test]
+async fn test_cache_reserve_clear_requires_reserve_off() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/cache/cache_reserve"))
+        .respond_with(setting("cache_reserve", "on"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/cache/cache_reserve_clear"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let err = cache::start_cache_reserve_clear(&client, "zone123")
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("must be off"));
+}
+
+#[tokio::test]
+async fn test_cache_reserve_clear_starts_and_polls_until_completed() {
+    let mock_server = MockServer::start().await;
+    let operation = |state: &str| {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {"id": "cache_reserve_clear", "state": state, "start_ts": "2026-03-01T00:00:00Z"}
+        }))
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/cache/cache_reserve"))
+        .respond_with(setting("cache_reserve", "off"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/cache/cache_reserve_clear"))
+        .respond_with(operation("In-progress"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/cache/cache_reserve_clear"))
+        .respond_with(operation("In-progress"))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/cache/cache_reserve_clear"))
+        .respond_with(operation("Completed"))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let started = cache::start_cache_reserve_clear(&client, "zone123")
+        .await
+        .unwrap();
+    assert!(!started.is_completed());
+    let finished = cache::wait_for_cache_reserve_clear(
+        &client,
+        "zone123",
+        std::time::Duration::from_millis(10),
+        std::time::Duration::from_secs(5),
+    )
+    .await
+    .unwrap();
+    assert!(finished.is_completed());
+}
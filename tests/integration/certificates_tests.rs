@@ -0,0 +1,244 @@
+use cfad::api::certificates::{CreateOriginCertificate, OrderCertificatePack, OriginKeyType};
+use cfad::client::CloudflareClient;
+use cfad::config::AuthMethod;
+use cfad::ops::{certificates, ssl};
+use wiremock::matchers::{body_json, body_partial_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const CERT_ID: &str = "328578533902268680212849205732770752308931942346";
+
+async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
+    let auth = AuthMethod::ApiToken("test_token".to_string());
+    CloudflareClient::new_with_base_url(auth, mock_server.uri()).unwrap()
+}
+
+fn certificate_json() -> serde_json::Value {
+    serde_json::json!({
+        "id": CERT_ID,
+        "hostnames": ["example.com", "*.example.com"],
+        "request_type": "origin-ecc",
+        "requested_validity": 5475,
+        "expires_on": "2040-01-01 00:00:00 +0000 UTC",
+        "certificate": "-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----\n"
+    })
+}
+
+#[tokio::test]
+async fn test_create_origin_certificate_sends_csr() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/certificates"))
+        .and(body_partial_json(serde_json::json!({
+            "hostnames": ["example.com", "*.example.com"],
+            "request_type": "origin-ecc",
+            "requested_validity": 5475
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": certificate_json()
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let hostnames = vec!["example.com".to_string(), "*.example.com".to_string()];
+    let generated = certificates::generate_key_and_csr(&hostnames, OriginKeyType::Ecdsa).unwrap();
+    let create = CreateOriginCertificate {
+        csr: generated.csr_pem,
+        hostnames,
+        request_type: OriginKeyType::Ecdsa.request_type().to_string(),
+        requested_validity: 5475,
+    };
+
+    let certificate = certificates::create_origin_certificate(&client, create)
+        .await
+        .unwrap();
+    assert_eq!(certificate.id, CERT_ID);
+    assert!(certificate
+        .certificate
+        .unwrap()
+        .contains("BEGIN CERTIFICATE"));
+}
+
+#[tokio::test]
+async fn test_list_origin_certificates_by_zone() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/certificates"))
+        .and(query_param("zone_id", "zone123"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": [certificate_json()]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let certs = certificates::list_origin_certificates(&client, "zone123")
+        .await
+        .unwrap();
+    assert_eq!(certs.len(), 1);
+    assert_eq!(certs[0].hostnames[1], "*.example.com");
+}
+
+#[tokio::test]
+async fn test_revoke_origin_certificate() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(format!("/certificates/{}", CERT_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": {"id": CERT_ID, "revoked_at": "2025-01-01T00:00:00Z"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    assert!(certificates::revoke_origin_certificate(&client, CERT_ID)
+        .await
+        .is_ok());
+}
+
+fn envelope(result: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "success": true,
+        "errors": [],
+        "messages": [],
+        "result": result
+    })
+}
+
+#[tokio::test]
+async fn test_order_certificate_pack() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/ssl/certificate_packs/order"))
+        .and(body_json(serde_json::json!({
+            "type": "advanced",
+            "hosts": ["example.com", "*.example.com"],
+            "certificate_authority": "google",
+            "validation_method": "txt",
+            "validity_days": 90
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "id": "pack1",
+                "type": "advanced",
+                "hosts": ["example.com", "*.example.com"],
+                "status": "initializing"
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let order = OrderCertificatePack {
+        r#type: "advanced".to_string(),
+        hosts: vec!["example.com".to_string(), "*.example.com".to_string()],
+        certificate_authority: "google".to_string(),
+        validation_method: "txt".to_string(),
+        validity_days: 90,
+        cloudflare_branding: false,
+    };
+    let pack = certificates::order_certificate_pack(&client, "zone123", order)
+        .await
+        .unwrap();
+    assert_eq!(pack.status.as_deref(), Some("initializing"));
+}
+
+#[tokio::test]
+async fn test_set_universal_ssl() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/zones/zone123/ssl/universal/settings"))
+        .and(body_json(serde_json::json!({"enabled": false})))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(envelope(serde_json::json!({"enabled": false}))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    assert!(certificates::set_universal_ssl(&client, "zone123", false)
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_get_ssl_status_combines_sources() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/settings"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([
+            {"id": "ssl", "value": "full"},
+            {"id": "min_tls_version", "value": "1.0"},
+            {"id": "tls_1_3", "value": "zrt"},
+            {"id": "security_header", "value": {"strict_transport_security": {"enabled": false}}}
+        ]))))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/ssl/universal/settings"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(envelope(serde_json::json!({"enabled": true}))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/origin_tls_client_auth/settings"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(envelope(serde_json::json!({"enabled": true}))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/ssl/certificate_packs"))
+        .and(query_param("status", "all"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([{
+                "id": "pack1",
+                "type": "universal",
+                "hosts": ["example.com", "*.example.com"],
+                "status": "active",
+                "certificate_authority": "google",
+                "certificates": [{"id": "c1", "expires_on": "2030-01-01T00:00:00Z"}]
+            }]))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let status = ssl::get_ssl_status(&client, "zone123").await.unwrap();
+    assert_eq!(status.ssl_mode.as_deref(), Some("full"));
+    assert_eq!(status.tls_1_3.as_deref(), Some("zrt"));
+    assert!(status.universal_ssl);
+    assert!(status.authenticated_origin_pulls);
+    assert!(!status.hsts.unwrap().enabled);
+    assert_eq!(status.certificate_packs.len(), 1);
+    assert_eq!(
+        status.certificate_packs[0].expires_on(),
+        Some("2030-01-01T00:00:00Z")
+    );
+}
@@ -0,0 +1,233 @@
+use cfad::api::custom_hostnames::{CreateCustomHostname, CustomHostnameSsl};
+use cfad::client::CloudflareClient;
+use cfad::config::AuthMethod;
+use cfad::error::CfadError;
+use cfad::ops::custom_hostnames;
+use std::time::Duration;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const HOSTNAME_ID: &str = "0d89c70d-ad9f-4843-b99f-6cc0252067e9";
+
+async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
+    let auth = AuthMethod::ApiToken("test_token".to_string());
+    CloudflareClient::new_with_base_url(auth, mock_server.uri()).unwrap()
+}
+
+fn create_hostname_json(hostname: &str, ssl_status: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": HOSTNAME_ID,
+        "hostname": hostname,
+        "status": "pending",
+        "ssl": {
+            "id": "ssl1",
+            "status": ssl_status,
+            "method": "txt",
+            "type": "dv",
+            "validation_records": [{
+                "txt_name": format!("_acme-challenge.{}", hostname),
+                "txt_value": "token"
+            }]
+        },
+        "ownership_verification": {
+            "type": "txt",
+            "name": format!("_cf-custom-hostname.{}", hostname),
+            "value": "uuid"
+        }
+    })
+}
+
+fn envelope(result: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "success": true,
+        "errors": [],
+        "messages": [],
+        "result": result
+    })
+}
+
+#[tokio::test]
+async fn test_list_custom_hostnames_follows_pages() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/custom_hostnames"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": [create_hostname_json("a.customer.com", "active")],
+            "result_info": {"page": 1, "per_page": 1, "count": 1, "total_count": 2, "total_pages": 2}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/custom_hostnames"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": [create_hostname_json("b.customer.com", "active")],
+            "result_info": {"page": 2, "per_page": 1, "count": 1, "total_count": 2, "total_pages": 2}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let hostnames = custom_hostnames::list_custom_hostnames(&client, "zone123", None)
+        .await
+        .unwrap();
+    assert_eq!(hostnames.len(), 2);
+    assert_eq!(hostnames[1].hostname, "b.customer.com");
+}
+
+#[tokio::test]
+async fn test_get_custom_hostname_by_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/custom_hostnames"))
+        .and(query_param("hostname", "app.customer.com"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([
+                create_hostname_json("app.customer.com", "pending_validation")
+            ]))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/custom_hostnames"))
+        .and(query_param("hostname", "other.customer.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([]))))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let hostname = custom_hostnames::get_custom_hostname(&client, "zone123", "app.customer.com")
+        .await
+        .unwrap();
+    assert_eq!(hostname.id, HOSTNAME_ID);
+
+    let missing =
+        custom_hostnames::get_custom_hostname(&client, "zone123", "other.customer.com").await;
+    assert!(matches!(missing, Err(CfadError::NotFound { .. })));
+}
+
+#[tokio::test]
+async fn test_create_custom_hostname_payload() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/custom_hostnames"))
+        .and(body_json(serde_json::json!({
+            "hostname": "app.customer.com",
+            "ssl": {"method": "txt", "type": "dv"},
+            "custom_origin_server": "origin.example.com",
+            "custom_metadata": {"customer": "acme"}
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(create_hostname_json(
+                "app.customer.com",
+                "initializing",
+            ))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let mut metadata = serde_json::Map::new();
+    metadata.insert("customer".to_string(), serde_json::json!("acme"));
+    let create = CreateCustomHostname {
+        hostname: "app.customer.com".to_string(),
+        ssl: CustomHostnameSsl {
+            method: Some("txt".to_string()),
+            r#type: Some("dv".to_string()),
+            ..Default::default()
+        },
+        custom_origin_server: Some("origin.example.com".to_string()),
+        custom_metadata: Some(metadata),
+    };
+    let created = custom_hostnames::create_custom_hostname(&client, "zone123", create)
+        .await
+        .unwrap();
+    assert_eq!(created.ssl_status(), "initializing");
+}
+
+#[tokio::test]
+async fn test_wait_for_ssl_active() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/zones/zone123/custom_hostnames/{}",
+            HOSTNAME_ID
+        )))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(create_hostname_json(
+                "app.customer.com",
+                "pending_validation",
+            ))),
+        )
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/zones/zone123/custom_hostnames/{}",
+            HOSTNAME_ID
+        )))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(envelope(create_hostname_json("app.customer.com", "active"))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let hostname = custom_hostnames::wait_for_ssl_active(
+        &client,
+        "zone123",
+        HOSTNAME_ID,
+        Duration::from_millis(10),
+        Duration::from_secs(5),
+    )
+    .await
+    .unwrap();
+    assert!(hostname.is_ssl_active());
+}
+
+#[tokio::test]
+async fn test_wait_for_ssl_fails_on_timed_out_validation() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/zones/zone123/custom_hostnames/{}",
+            HOSTNAME_ID
+        )))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(create_hostname_json(
+                "app.customer.com",
+                "validation_timed_out",
+            ))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = custom_hostnames::wait_for_ssl_active(
+        &client,
+        "zone123",
+        HOSTNAME_ID,
+        Duration::from_millis(10),
+        Duration::from_secs(5),
+    )
+    .await;
+    assert!(result.is_err());
+}
@@ -4,8 +4,8 @@ use cfad::api::d1::{
 };
 use cfad::client::{CfResponse, CloudflareClient, ResultInfo};
 use cfad::config::AuthMethod;
-use cfad::ops::d1;
-use wiremock::matchers::{method, path};
+use cfad::ops::{d1, d1_migrations, d1_shell};
+use wiremock::matchers::{body_partial_json, body_string_contains, header, method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
@@ -716,3 +716,397 @@ fn test_d1_restore_response_deserialization() {
     assert_eq!(result.bookmark, "bk_after_restore");
     assert_eq!(result.num_queries_replayed, 100);
 }
+
+#[tokio::test]
+async fn test_download_export_failure_keeps_existing_file() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/dumps/expired.sql"))
+        .respond_with(ResponseTemplate::new(403))
+        .mount(&mock_server)
+        .await;
+
+    let output = std::env::temp_dir().join(format!("cfad-d1-keep-{}.sql", std::process::id()));
+    std::fs::write(&output, "previous backup").unwrap();
+
+    let url = format!("{}/dumps/expired.sql", mock_server.uri());
+    let result = d1::download_export(&url, &output, |_, _| {}).await;
+    let kept = std::fs::read_to_string(&output).unwrap();
+    std::fs::remove_file(&output).ok();
+
+    assert!(result.is_err());
+    assert_eq!(kept, "previous backup");
+}
+
+fn import_envelope(result: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({"success": true, "errors": [], "messages": [], "result": result})
+}
+
+#[tokio::test]
+async fn test_import_via_upload_uploads_ingests_and_polls() {
+    let mock_server = MockServer::start().await;
+    let sql = b"INSERT INTO t VALUES (1);".to_vec();
+    let etag = d1::import_etag(&sql);
+    let import_path = "/accounts/acc1/d1/database/db1/import";
+
+    Mock::given(method("POST"))
+        .and(path(import_path))
+        .and(body_partial_json(
+            serde_json::json!({"action": "init", "etag": etag}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(import_envelope(
+            serde_json::json!({
+                "success": true,
+                "filename": "upload-1.sql",
+                "upload_url": format!("{}/uploads/upload-1.sql", mock_server.uri())
+            }),
+        )))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/uploads/upload-1.sql"))
+        .respond_with(ResponseTemplate::new(200).insert_header("ETag", format!("\"{}\"", etag)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(import_path))
+        .and(body_partial_json(
+            serde_json::json!({"action": "ingest", "filename": "upload-1.sql", "etag": etag}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(import_envelope(
+            serde_json::json!({
+                "success": true,
+                "status": "active",
+                "at_bookmark": "bk-1",
+                "messages": ["Ingesting upload-1.sql"]
+            }),
+        )))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(import_path))
+        .and(body_partial_json(
+            serde_json::json!({"action": "poll", "current_bookmark": "bk-1"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(import_envelope(
+            serde_json::json!({
+                "success": true,
+                "status": "complete",
+                "at_bookmark": "bk-2",
+                "result": {
+                    "num_queries": 1,
+                    "final_bookmark": "bk-2",
+                    "meta": {"rows_read": 0, "rows_written": 1}
+                }
+            }),
+        )))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = d1::import_database_via_upload(
+        &client,
+        "acc1",
+        "db1",
+        sql,
+        std::time::Duration::from_millis(1),
+        std::time::Duration::from_secs(10),
+    )
+    .await
+    .unwrap();
+
+    let totals = result.result.unwrap();
+    assert_eq!(totals.num_queries, 1);
+    assert_eq!(totals.meta.rows_written, 1);
+}
+
+#[tokio::test]
+async fn test_import_via_upload_resumes_when_etag_already_uploaded() {
+    let mock_server = MockServer::start().await;
+    let import_path = "/accounts/acc1/d1/database/db1/import";
+
+    Mock::given(method("POST"))
+        .and(path(import_path))
+        .and(body_partial_json(serde_json::json!({"action": "init"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(import_envelope(
+            serde_json::json!({
+                "success": true,
+                "filename": "upload-1.sql"
+            }),
+        )))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(import_path))
+        .and(body_partial_json(serde_json::json!({"action": "ingest"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(import_envelope(
+            serde_json::json!({
+                "success": true,
+                "status": "complete",
+                "result": {"num_queries": 3}
+            }),
+        )))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = d1::import_database_via_upload(
+        &client,
+        "acc1",
+        "db1",
+        b"SELECT 1;".to_vec(),
+        std::time::Duration::from_millis(1),
+        std::time::Duration::from_secs(10),
+    )
+    .await
+    .unwrap();
+    assert_eq!(result.result.unwrap().num_queries, 3);
+}
+
+#[tokio::test]
+async fn test_import_via_upload_reports_ingest_error() {
+    let mock_server = MockServer::start().await;
+    let import_path = "/accounts/acc1/d1/database/db1/import";
+
+    Mock::given(method("POST"))
+        .and(path(import_path))
+        .and(body_partial_json(serde_json::json!({"action": "init"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(import_envelope(
+            serde_json::json!({
+                "success": true,
+                "filename": "upload-1.sql",
+                "upload_url": format!("{}/uploads/upload-1.sql", mock_server.uri())
+            }),
+        )))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/uploads/upload-1.sql"))
+        .and(header("content-length", "14"))
+        .respond_with(ResponseTemplate::new(200))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(import_path))
+        .and(body_partial_json(serde_json::json!({"action": "ingest"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(import_envelope(
+            serde_json::json!({
+                "success": false,
+                "status": "error",
+                "error": "near \"INSRT\": syntax error at offset 0"
+            }),
+        )))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let err = d1::import_database_via_upload(
+        &client,
+        "acc1",
+        "db1",
+        b"INSRT INTO t;\n".to_vec(),
+        std::time::Duration::from_millis(1),
+        std::time::Duration::from_secs(10),
+    )
+    .await
+    .unwrap_err();
+    assert!(err.to_string().contains("syntax error"));
+}
+
+#[tokio::test]
+async fn test_shell_runs_statement_once_terminated() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/d1/database/db1/query"))
+        .and(body_partial_json(
+            serde_json::json!({"sql": "SELECT *\nFROM users;"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(import_envelope(
+            serde_json::json!([{
+                "results": [{"id": 1}],
+                "success": true,
+                "meta": {"duration": 0.4, "rows_read": 1, "rows_written": 0}
+            }]),
+        )))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let mut shell = d1_shell::D1Shell::new(&client, "acc1", "db1");
+    shell.feed_line(".timer on").await.unwrap();
+    shell.feed_line("SELECT *").await.unwrap();
+    assert!(shell.is_pending());
+    shell.feed_line("FROM users;").await.unwrap();
+    assert!(!shell.is_pending());
+    assert!(shell.timer);
+}
+
+#[tokio::test]
+async fn test_shell_meta_commands_query_sqlite_master() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/d1/database/db1/query"))
+        .and(body_partial_json(serde_json::json!({"params": ["users"]})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(import_envelope(
+            serde_json::json!([{
+                "results": [{"sql": "CREATE TABLE users (id INTEGER)"}],
+                "success": true
+            }]),
+        )))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/d1/database/db1/raw"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(import_envelope(serde_json::json!([{
+                "columns": ["name", "table", "sql"],
+                "rows": [["idx_users_email", "users", "CREATE INDEX idx_users_email ON users(email)"]],
+                "success": true
+            }]))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let mut shell = d1_shell::D1Shell::new(&client, "acc1", "db1");
+    shell.feed_line(".schema users").await.unwrap();
+    shell.feed_line(".mode csv").await.unwrap();
+    assert_eq!(shell.mode, d1_shell::OutputMode::Csv);
+    shell.feed_line(".indexes").await.unwrap();
+    assert!(shell.feed_line(".mode xml").await.is_err());
+    assert!(matches!(
+        shell.feed_line(".quit").await.unwrap(),
+        d1_shell::ShellFlow::Quit
+    ));
+}
+
+fn migrations_dir(name: &str, files: &[(&str, &str)]) -> std::path::PathBuf {
+    let dir = std::env::temp_dir().join(format!("cfad-{}-{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for (file, sql) in files {
+        std::fs::write(dir.join(file), sql).unwrap();
+    }
+    dir
+}
+
+#[tokio::test]
+async fn test_apply_migrations_skips_applied_and_stops_on_failure() {
+    let mock_server = MockServer::start().await;
+    let query_path = "/accounts/acc1/d1/database/db1/query";
+
+    Mock::given(method("POST"))
+        .and(path(query_path))
+        .and(body_string_contains("CREATE TABLE IF NOT EXISTS d1_migrations"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(import_envelope(serde_json::json!([
+                {"results": [], "success": true},
+                {"results": [{"name": "0001_init.sql", "applied_at": "2026-01-01 10:00:00"}], "success": true}
+            ]))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/d1/database/db1/time_travel/bookmark"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(import_envelope(
+            serde_json::json!({
+                "bookmark": "bk-before",
+                "timestamp": "2026-01-02T00:00:00Z"
+            }),
+        )))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(query_path))
+        .and(body_string_contains(
+            "INSERT INTO d1_migrations (name) VALUES ('0002_users.sql');",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(import_envelope(
+            serde_json::json!([{"results": [], "success": true}]),
+        )))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(query_path))
+        .and(body_string_contains("0003_posts.sql"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+            "success": false,
+            "errors": [{"code": 7500, "message": "no such table: authors"}],
+            "messages": [],
+            "result": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(query_path))
+        .and(body_string_contains("0004_tags.sql"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let dir = migrations_dir(
+        "apply",
+        &[
+            ("0001_init.sql", "CREATE TABLE a (id INTEGER);"),
+            ("0002_users.sql", "CREATE TABLE users (id INTEGER);"),
+            (
+                "0003_posts.sql",
+                "CREATE TABLE posts (author INTEGER REFERENCES authors);",
+            ),
+            ("0004_tags.sql", "CREATE TABLE tags (id INTEGER);"),
+        ],
+    );
+    let client = create_test_client(&mock_server).await;
+    let result = d1_migrations::apply_migrations(&client, "acc1", "db1", &dir, true).await;
+    std::fs::remove_dir_all(&dir).ok();
+
+    let message = result.unwrap_err().to_string();
+    assert!(message.contains("Migration 0003_posts.sql failed"));
+    assert!(message.contains("1 applied, 1 not run"));
+    assert!(message.contains("--bookmark bk-before"));
+}
+
+#[tokio::test]
+async fn test_apply_migrations_nothing_pending() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/d1/database/db1/query"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(import_envelope(serde_json::json!([
+                {"results": [], "success": true},
+                {"results": [{"name": "0001_init.sql", "applied_at": "2026-01-01 10:00:00"}], "success": true}
+            ]))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let dir = migrations_dir("noop", &[("0001_init.sql", "SELECT 1;")]);
+    let client = create_test_client(&mock_server).await;
+    let applied = d1_migrations::apply_migrations(&client, "acc1", "db1", &dir, true)
+        .await
+        .unwrap();
+    std::fs::remove_dir_all(&dir).ok();
+    assert_eq!(applied, 0);
+}
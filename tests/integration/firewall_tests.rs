@@ -0,0 +1,391 @@
+use cfad::api::firewall::{
+    AccessRuleConfiguration, AccessRuleFilter, CreateAccessRule, CreateCustomList, CreateListItem,
+};
+use cfad::api::rulesets::RulesetScope;
+use cfad::client::CloudflareClient;
+use cfad::config::AuthMethod;
+use cfad::ops::firewall;
+use std::time::Duration;
+use wiremock::matchers::{body_json, body_partial_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
+    let auth = AuthMethod::ApiToken("test_token".to_string());
+    CloudflareClient::new_with_base_url(auth, mock_server.uri()).unwrap()
+}
+
+fn create_access_rule_json(id: &str, mode: &str, target: &str, value: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "mode": mode,
+        "configuration": {"target": target, "value": value},
+        "notes": "",
+        "scope": {"id": "zone123", "type": "zone"}
+    })
+}
+
+fn create_list_json(id: &str, name: &str, kind: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "name": name,
+        "kind": kind,
+        "num_items": 0,
+        "num_referencing_filters": 0
+    })
+}
+
+#[tokio::test]
+async fn test_list_access_rules_follows_pages() {
+    let mock_server = MockServer::start().await;
+
+    for page in 1..=2 {
+        Mock::given(method("GET"))
+            .and(path("/zones/zone123/firewall/access_rules/rules"))
+            .and(query_param("page", page.to_string()))
+            .and(query_param("mode", "block"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "result": [create_access_rule_json(
+                    &format!("r{}", page),
+                    "block",
+                    "ip",
+                    "192.0.2.1"
+                )],
+                "result_info": {"page": page, "per_page": 1, "count": 1, "total_count": 2, "total_pages": 2},
+                "errors": [],
+                "messages": []
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = create_test_client(&mock_server).await;
+    let scope = RulesetScope::Zone("zone123".to_string());
+    let filter = AccessRuleFilter {
+        mode: Some("block".to_string()),
+        ..Default::default()
+    };
+    let rules = firewall::list_access_rules(&client, &scope, &filter)
+        .await
+        .unwrap();
+
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[1].id, "r2");
+}
+
+#[tokio::test]
+async fn test_create_access_rule_account_scope() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc123/firewall/access_rules/rules"))
+        .and(body_json(serde_json::json!({
+            "mode": "challenge",
+            "configuration": {"target": "asn", "value": "AS64496"},
+            "notes": "scanner"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": create_access_rule_json("r1", "challenge", "asn", "AS64496"),
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let scope = RulesetScope::Account("acc123".to_string());
+    let rule = CreateAccessRule {
+        mode: "challenge".to_string(),
+        configuration: AccessRuleConfiguration {
+            target: "asn".to_string(),
+            value: "AS64496".to_string(),
+        },
+        notes: Some("scanner".to_string()),
+    };
+    let result = firewall::create_access_rule(&client, &scope, rule).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_create_access_rule_invalid_mode_skips_api() {
+    let mock_server = MockServer::start().await;
+
+    let client = create_test_client(&mock_server).await;
+    let scope = RulesetScope::Zone("zone123".to_string());
+    let rule = CreateAccessRule {
+        mode: "allow".to_string(),
+        configuration: AccessRuleConfiguration {
+            target: "ip".to_string(),
+            value: "192.0.2.1".to_string(),
+        },
+        notes: None,
+    };
+    let result = firewall::create_access_rule(&client, &scope, rule).await;
+
+    assert!(result.is_err());
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_access_rule_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/zones/zone123/firewall/access_rules/rules/r1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": {"id": "r1"},
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let scope = RulesetScope::Zone("zone123".to_string());
+    let result = firewall::delete_access_rule(&client, &scope, "r1").await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_list_by_name() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc123/rules/lists"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": [
+                create_list_json("l1", "allowlist", "ip"),
+                create_list_json("l2", "blocklist", "ip")
+            ],
+            "errors": [],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let list = firewall::get_list(&client, "acc123", "blocklist")
+        .await
+        .unwrap();
+    assert_eq!(list.id, "l2");
+
+    let missing = firewall::get_list(&client, "acc123", "nope").await;
+    assert!(matches!(
+        missing,
+        Err(cfad::error::CfadError::NotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_create_list_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc123/rules/lists"))
+        .and(body_json(
+            serde_json::json!({"name": "asns", "kind": "asn"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": create_list_json("l1", "asns", "asn"),
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let list = CreateCustomList {
+        name: "asns".to_string(),
+        kind: "asn".to_string(),
+        description: None,
+    };
+    assert!(firewall::create_list(&client, "acc123", list).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_list_items_follows_cursor() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc123/rules/lists/l1/items"))
+        .and(query_param("cursor", "next"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": [{"id": "i2", "ip": "198.51.100.0/24"}],
+            "result_info": {"cursors": {"before": "prev"}},
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc123/rules/lists/l1/items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": [{"id": "i1", "ip": "192.0.2.1"}],
+            "result_info": {"cursors": {"after": "next"}},
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let items = firewall::list_items(&client, "acc123", "l1").await.unwrap();
+
+    assert_eq!(items.len(), 2);
+    assert_eq!(items[1].value(), "198.51.100.0/24");
+}
+
+#[tokio::test]
+async fn test_add_items_returns_operation_and_polls() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc123/rules/lists/l1/items"))
+        .and(body_json(serde_json::json!([
+            {"ip": "192.0.2.1", "comment": "scanner"}
+        ])))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": {"operation_id": "op1"},
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc123/rules/lists/bulk_operations/op1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": {"id": "op1", "status": "completed", "completed": "2026-01-01T00:00:00Z"},
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let items = vec![CreateListItem {
+        ip: Some("192.0.2.1".to_string()),
+        comment: Some("scanner".to_string()),
+        ..Default::default()
+    }];
+    let operation_id = firewall::add_items(&client, "acc123", "l1", items)
+        .await
+        .unwrap();
+    assert_eq!(operation_id, "op1");
+
+    let operation = firewall::wait_for_operation(
+        &client,
+        "acc123",
+        &operation_id,
+        Duration::from_millis(10),
+        Duration::from_secs(5),
+    )
+    .await
+    .unwrap();
+    assert_eq!(operation.status, "completed");
+}
+
+#[tokio::test]
+async fn test_wait_for_operation_failed() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc123/rules/lists/bulk_operations/op1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": {"id": "op1", "status": "failed", "error": "invalid ip"},
+            "errors": [],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = firewall::wait_for_operation(
+        &client,
+        "acc123",
+        "op1",
+        Duration::from_millis(10),
+        Duration::from_secs(5),
+    )
+    .await;
+
+    assert!(result.unwrap_err().to_string().contains("invalid ip"));
+}
+
+#[tokio::test]
+async fn test_wait_for_operation_times_out() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc123/rules/lists/bulk_operations/op1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": {"id": "op1", "status": "pending"},
+            "errors": [],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = firewall::wait_for_operation(
+        &client,
+        "acc123",
+        "op1",
+        Duration::from_millis(10),
+        Duration::from_millis(50),
+    )
+    .await;
+
+    assert!(matches!(result, Err(cfad::error::CfadError::Timeout(_))));
+}
+
+#[tokio::test]
+async fn test_delete_items_sends_ids_in_body() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/accounts/acc123/rules/lists/l1/items"))
+        .and(body_partial_json(serde_json::json!({
+            "items": [{"id": "i1"}, {"id": "i2"}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": {"operation_id": "op2"},
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let operation_id = firewall::delete_items(
+        &client,
+        "acc123",
+        "l1",
+        vec!["i1".to_string(), "i2".to_string()],
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(operation_id, "op2");
+}
@@ -0,0 +1,281 @@
+use cfad::api::kv::KvBulkEntry;
+use cfad::client::CloudflareClient;
+use cfad::config::AuthMethod;
+use cfad::ops::kv;
+use wiremock::matchers::{
+    body_json, body_string_contains, header_regex, method, path, path_regex, query_param,
+    query_param_is_missing,
+};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
+    let auth = AuthMethod::ApiToken("test_token".to_string());
+    CloudflareClient::new_with_base_url(auth, mock_server.uri()).unwrap()
+}
+
+fn envelope(result: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "success": true,
+        "errors": [],
+        "messages": [],
+        "result": result
+    })
+}
+
+fn entries(count: usize) -> Vec<KvBulkEntry> {
+    (0..count)
+        .map(|i| KvBulkEntry {
+            key: format!("key-{}", i),
+            value: i.to_string(),
+            expiration: None,
+            expiration_ttl: None,
+            metadata: None,
+            base64: false,
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_list_keys_follows_cursor() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/storage/kv/namespaces/ns1/keys"))
+        .and(query_param("prefix", "user:"))
+        .and(query_param_is_missing("cursor"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"name": "user:1"}],
+            "result_info": {"count": 1, "cursor": "page2"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/storage/kv/namespaces/ns1/keys"))
+        .and(query_param("cursor", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"name": "user:2", "expiration": 1900000000, "metadata": {"a": 1}}],
+            "result_info": {"count": 1, "cursor": ""}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let keys = kv::list_keys(&client, "acc1", "ns1", Some("user:"))
+        .await
+        .unwrap();
+    let names: Vec<&str> = keys.iter().map(|k| k.name.as_str()).collect();
+    assert_eq!(names, vec!["user:1", "user:2"]);
+    assert_eq!(keys[1].expiration, Some(1900000000));
+}
+
+#[tokio::test]
+async fn test_put_value_with_ttl_and_metadata() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/accounts/acc1/storage/kv/namespaces/ns1/values/user%3A42",
+        ))
+        .and(query_param("expiration_ttl", "3600"))
+        .and(header_regex(
+            "content-type",
+            "^multipart/form-data; boundary=",
+        ))
+        .and(body_string_contains(
+            "name=\"value\"\r\nContent-Type: application/octet-stream\r\n\r\ndark",
+        ))
+        .and(body_string_contains(
+            "name=\"metadata\"\r\nContent-Type: application/json\r\n\r\n{\"v\":2}",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!(null))))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let metadata = serde_json::json!({"v": 2});
+    kv::put_value(
+        &client,
+        "acc1",
+        "ns1",
+        "user:42",
+        b"dark".to_vec(),
+        Some(3600),
+        Some(&metadata),
+    )
+    .await
+    .unwrap();
+
+    assert!(
+        kv::put_value(&client, "acc1", "ns1", "k", Vec::new(), Some(5), None)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_get_value_returns_raw_bytes() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/storage/kv/namespaces/ns1/values/logo"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(vec![0x89, b'P', b'N', b'G']))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let value = kv::get_value(&client, "acc1", "ns1", "logo").await.unwrap();
+    assert_eq!(value, vec![0x89, b'P', b'N', b'G']);
+}
+
+#[tokio::test]
+async fn test_bulk_put_chunks_at_api_limit() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/storage/kv/namespaces/ns1/bulk"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "successful_key_count": 10000,
+                "unsuccessful_keys": []
+            }))),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/storage/kv/namespaces/ns1/bulk"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "successful_key_count": 4,
+                "unsuccessful_keys": ["key-10004"]
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = kv::bulk_put(&client, "acc1", "ns1", &entries(10_005))
+        .await
+        .unwrap();
+    assert_eq!(result.successful_key_count, 10_004);
+    assert_eq!(result.unsuccessful_keys, vec!["key-10004"]);
+}
+
+#[tokio::test]
+async fn test_bulk_delete_posts_key_names() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/storage/kv/namespaces/ns1/bulk/delete"))
+        .and(body_json(serde_json::json!(["a", "b"])))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!(null))))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let keys = vec!["a".to_string(), "b".to_string()];
+    let result = kv::bulk_delete(&client, "acc1", "ns1", &keys)
+        .await
+        .unwrap();
+    assert_eq!(result.successful_key_count, 2);
+}
+
+#[tokio::test]
+async fn test_export_namespace_writes_values_and_manifest() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/storage/kv/namespaces/ns1/keys"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"name": "a/b", "metadata": {"t": "x"}}, {"name": "c"}],
+            "result_info": {"count": 2, "cursor": ""}
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/accounts/acc1/storage/kv/namespaces/ns1/values/a%2Fb",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("first"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/storage/kv/namespaces/ns1/values/c"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("second"))
+        .mount(&mock_server)
+        .await;
+
+    let dir = std::env::temp_dir().join(format!("cfad-kv-export-{}", std::process::id()));
+    let client = create_test_client(&mock_server).await;
+    let count = kv::export_namespace(&client, "acc1", "ns1", None, &dir)
+        .await
+        .unwrap();
+    let value = std::fs::read_to_string(dir.join("values").join("a%2Fb")).unwrap();
+    let manifest: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(dir.join("manifest.json")).unwrap()).unwrap();
+    std::fs::remove_dir_all(&dir).ok();
+
+    assert_eq!(count, 2);
+    assert_eq!(value, "first");
+    assert_eq!(
+        manifest[0],
+        serde_json::json!({"key": "a/b", "file": "values/a%2Fb", "metadata": {"t": "x"}})
+    );
+}
+
+#[tokio::test]
+async fn test_export_namespace_manifest_and_long_keys() {
+    let mock_server = MockServer::start().await;
+    let long_key = "k".repeat(256) + &":".repeat(256);
+
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/storage/kv/namespaces/ns1/keys"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"name": "manifest.json"}, {"name": long_key}],
+            "result_info": {"count": 2, "cursor": ""}
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/accounts/acc1/storage/kv/namespaces/ns1/values/manifest.json",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("stored manifest"))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path_regex(
+            "^/accounts/acc1/storage/kv/namespaces/ns1/values/k{256}",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("long value"))
+        .mount(&mock_server)
+        .await;
+
+    let dir = std::env::temp_dir().join(format!("cfad-kv-export-long-{}", std::process::id()));
+    let client = create_test_client(&mock_server).await;
+    let count = kv::export_namespace(&client, "acc1", "ns1", None, &dir)
+        .await
+        .unwrap();
+    let manifest: Vec<serde_json::Value> =
+        serde_json::from_str(&std::fs::read_to_string(dir.join("manifest.json")).unwrap()).unwrap();
+    let values: Vec<String> = manifest
+        .iter()
+        .map(|entry| std::fs::read_to_string(dir.join(entry["file"].as_str().unwrap())).unwrap())
+        .collect();
+    std::fs::remove_dir_all(&dir).ok();
+
+    assert_eq!(count, 2);
+    assert_eq!(manifest[0]["key"], "manifest.json");
+    assert_eq!(manifest[1]["key"], long_key.as_str());
+    assert_eq!(values, vec!["stored manifest", "long value"]);
+}
@@ -0,0 +1,342 @@
+use std::time::Duration;
+
+use cfad::api::load_balancing::{LoadBalancerPayload, MonitorPayload, PoolPayload};
+use cfad::client::CloudflareClient;
+use cfad::config::AuthMethod;
+use cfad::ops::load_balancing;
+use wiremock::matchers::{body_json, body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const POOL_ID: &str = "17b5962d775c646f3f9725cbc7a53df4";
+
+async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
+    let auth = AuthMethod::ApiToken("test_token".to_string());
+    CloudflareClient::new_with_base_url(auth, mock_server.uri()).unwrap()
+}
+
+fn envelope(result: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "success": true,
+        "errors": [],
+        "messages": [],
+        "result": result
+    })
+}
+
+fn pool_json() -> serde_json::Value {
+    serde_json::json!({
+        "id": POOL_ID,
+        "name": "primary",
+        "enabled": true,
+        "monitor": "mon1",
+        "origins": [
+            {"name": "web1", "address": "192.0.2.1", "enabled": true, "weight": 1},
+            {"name": "web2", "address": "192.0.2.2", "enabled": true, "weight": 0.5}
+        ]
+    })
+}
+
+async fn mount_pools(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/load_balancers/pools"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([pool_json()]))),
+        )
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_create_monitor() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/load_balancers/monitors"))
+        .and(body_json(serde_json::json!({
+            "type": "https",
+            "path": "/healthz",
+            "expected_codes": "200",
+            "header": {"Host": ["app.example.com"]}
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "id": "mon1",
+                "type": "https",
+                "path": "/healthz",
+                "expected_codes": "200"
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let monitor = MonitorPayload {
+        r#type: Some("https".to_string()),
+        path: Some("/healthz".to_string()),
+        expected_codes: Some("200".to_string()),
+        header: load_balancing::parse_headers(&["Host: app.example.com".to_string()]).unwrap(),
+        ..Default::default()
+    };
+    let created = load_balancing::create_monitor(&client, "acc1", monitor)
+        .await
+        .unwrap();
+    assert_eq!(created.id, "mon1");
+}
+
+#[tokio::test]
+async fn test_get_pool_by_name() {
+    let mock_server = MockServer::start().await;
+    mount_pools(&mock_server).await;
+
+    let client = create_test_client(&mock_server).await;
+    let pool = load_balancing::get_pool(&client, "acc1", "primary")
+        .await
+        .unwrap();
+    assert_eq!(pool.id, POOL_ID);
+    assert_eq!(pool.origins.len(), 2);
+
+    assert!(load_balancing::get_pool(&client, "acc1", "missing")
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_create_pool_requires_origins() {
+    let mock_server = MockServer::start().await;
+    let client = create_test_client(&mock_server).await;
+    let pool = PoolPayload {
+        name: Some("empty".to_string()),
+        ..Default::default()
+    };
+    assert!(load_balancing::create_pool(&client, "acc1", pool)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_disable_origin_patches_origin_list() {
+    let mock_server = MockServer::start().await;
+    mount_pools(&mock_server).await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/accounts/acc1/load_balancers/pools/{}",
+            POOL_ID
+        )))
+        .and(body_json(serde_json::json!({
+            "origins": [
+                {"name": "web1", "address": "192.0.2.1", "enabled": true, "weight": 1.0},
+                {"name": "web2", "address": "192.0.2.2", "enabled": false, "weight": 0.5}
+            ]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(pool_json())))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let previous = load_balancing::modify_origin(&client, "acc1", "primary", "192.0.2.2", |o| {
+        o.enabled = false
+    })
+    .await
+    .unwrap();
+    assert_eq!(previous.name, "web2");
+    assert!(previous.enabled);
+}
+
+#[tokio::test]
+async fn test_modify_origin_keeps_unmodelled_fields() {
+    let mock_server = MockServer::start().await;
+
+    let pool = serde_json::json!({
+        "id": POOL_ID,
+        "name": "private",
+        "enabled": true,
+        "origins": [
+            {
+                "name": "tunnel-origin",
+                "address": "10.0.0.5",
+                "enabled": true,
+                "weight": 1,
+                "port": 8443,
+                "virtual_network_id": "a5624d4e-044a-4ff0-b3e1-e2465353d4b4"
+            }
+        ]
+    });
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/load_balancers/pools"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([pool.clone()]))),
+        )
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/accounts/acc1/load_balancers/pools/{}",
+            POOL_ID
+        )))
+        .and(body_json(serde_json::json!({
+            "origins": [{
+                "name": "tunnel-origin",
+                "address": "10.0.0.5",
+                "enabled": false,
+                "weight": 1.0,
+                "port": 8443,
+                "virtual_network_id": "a5624d4e-044a-4ff0-b3e1-e2465353d4b4"
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(pool)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let previous =
+        load_balancing::modify_origin(&client, "acc1", "private", "tunnel-origin", |o| {
+            o.enabled = false
+        })
+        .await
+        .unwrap();
+    assert_eq!(previous.extra["port"], serde_json::json!(8443));
+}
+
+#[tokio::test]
+async fn test_drain_origin_sets_weight_and_disables() {
+    let mock_server = MockServer::start().await;
+    mount_pools(&mock_server).await;
+
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/accounts/acc1/load_balancers/pools/{}",
+            POOL_ID
+        )))
+        .and(body_partial_json(serde_json::json!({
+            "origins": [{"name": "web1", "address": "192.0.2.1", "enabled": true, "weight": 0.0}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(pool_json())))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // The mocked pool list is static, so the disable step starts from weight 1 again
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/accounts/acc1/load_balancers/pools/{}",
+            POOL_ID
+        )))
+        .and(body_partial_json(serde_json::json!({
+            "origins": [{"name": "web1", "address": "192.0.2.1", "enabled": false, "weight": 1.0}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(pool_json())))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let previous =
+        load_balancing::drain_origin(&client, "acc1", "primary", "web1", Duration::ZERO, true)
+            .await
+            .unwrap();
+    assert_eq!(previous.weight, 1.0);
+}
+
+#[tokio::test]
+async fn test_get_pool_health() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/accounts/acc1/load_balancers/pools/{}/health",
+            POOL_ID
+        )))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "pool_id": POOL_ID,
+                "pop_health": {
+                    "WNAM": {
+                        "healthy": false,
+                        "origins": [
+                            {"192.0.2.1": {"healthy": true, "rtt": "12ms", "response_code": 200}},
+                            {"192.0.2.2": {"healthy": false, "failure_reason": "TCP connection failed"}}
+                        ]
+                    }
+                }
+            }))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let health = load_balancing::get_pool_health(&client, "acc1", POOL_ID)
+        .await
+        .unwrap();
+    let region = &health.pop_health["WNAM"];
+    assert!(!region.healthy);
+    assert_eq!(
+        region.origins[1]["192.0.2.2"].failure_reason.as_deref(),
+        Some("TCP connection failed")
+    );
+}
+
+#[tokio::test]
+async fn test_create_load_balancer() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/load_balancers"))
+        .and(body_json(serde_json::json!({
+            "name": "app.example.com",
+            "proxied": true,
+            "default_pools": [POOL_ID],
+            "fallback_pool": POOL_ID,
+            "steering_policy": "dynamic_latency"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "id": "lb1",
+                "name": "app.example.com",
+                "proxied": true,
+                "default_pools": [POOL_ID],
+                "fallback_pool": POOL_ID
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let balancer = LoadBalancerPayload {
+        name: Some("app.example.com".to_string()),
+        proxied: Some(true),
+        default_pools: Some(vec![POOL_ID.to_string()]),
+        fallback_pool: Some(POOL_ID.to_string()),
+        steering_policy: Some("dynamic_latency".to_string()),
+        ..Default::default()
+    };
+    let created = load_balancing::create_load_balancer(&client, "zone123", balancer)
+        .await
+        .unwrap();
+    assert_eq!(created.id, "lb1");
+}
+
+#[tokio::test]
+async fn test_delete_load_balancer() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/zones/zone123/load_balancers/lb1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({"id": "lb1"}))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    assert!(
+        load_balancing::delete_load_balancer(&client, "zone123", "lb1")
+            .await
+            .is_ok()
+    );
+}
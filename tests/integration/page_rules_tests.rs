@@ -0,0 +1,265 @@
+use cfad::client::CloudflareClient;
+use cfad::config::AuthMethod;
+use cfad::ops::page_rules;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
+    let auth = AuthMethod::ApiToken("test_token".to_string());
+    CloudflareClient::new_with_base_url(auth, mock_server.uri()).unwrap()
+}
+
+fn create_page_rule_json(
+    id: &str,
+    priority: i64,
+    pattern: &str,
+    actions: serde_json::Value,
+) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "targets": [{"target": "url", "constraint": {"operator": "matches", "value": pattern}}],
+        "actions": actions,
+        "priority": priority,
+        "status": "active",
+        "created_on": "2024-01-01T00:00:00Z",
+        "modified_on": "2024-01-01T00:00:00Z"
+    })
+}
+
+#[tokio::test]
+async fn test_list_page_rules_sorted_by_priority() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/pagerules"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": [
+                create_page_rule_json("low", 1, "example.com/*", serde_json::json!([])),
+                create_page_rule_json("high", 5, "example.com/admin/*", serde_json::json!([]))
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let rules = page_rules::list_page_rules(&client, "zone123")
+        .await
+        .unwrap();
+    assert_eq!(rules.len(), 2);
+    assert_eq!(rules[0].id, "high");
+    assert_eq!(rules[1].id, "low");
+}
+
+#[tokio::test]
+async fn test_delete_page_rule() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/zones/zone123/pagerules/pr1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": {"id": "pr1"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    assert!(page_rules::delete_page_rule(&client, "zone123", "pr1")
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_apply_migration_appends_without_replacing_existing_rules() {
+    let mock_server = MockServer::start().await;
+
+    // Redirect phase already has a rule that must be kept
+    Mock::given(method("GET"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_dynamic_redirect/entrypoint",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": {
+                "id": "rs1",
+                "kind": "zone",
+                "phase": "http_request_dynamic_redirect",
+                "rules": [{
+                    "id": "existing",
+                    "action": "redirect",
+                    "expression": "http.request.uri.path eq \"/x\"",
+                    "enabled": true
+                }]
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    // Existing rules are never sent back, so they keep their IDs
+    Mock::given(method("PUT"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_dynamic_redirect/entrypoint",
+        ))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/rulesets/rs1/rules"))
+        .and(body_partial_json(serde_json::json!({
+            "action": "redirect",
+            "expression": "http.request.full_uri wildcard \"http*://example.com/old\""
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": {
+                "id": "rs1",
+                "kind": "zone",
+                "phase": "http_request_dynamic_redirect",
+                "rules": [
+                    {"id": "existing", "action": "redirect", "expression": "http.request.uri.path eq \"/x\""},
+                    {"id": "new", "action": "redirect", "expression": "http.request.full_uri wildcard \"http*://example.com/old\""}
+                ]
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    // Cache phase has no entrypoint yet
+    Mock::given(method("GET"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_cache_settings/entrypoint",
+        ))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "success": false,
+            "errors": [{"code": 10003, "message": "not found"}],
+            "messages": [],
+            "result": null
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_cache_settings/entrypoint",
+        ))
+        .and(body_partial_json(serde_json::json!({
+            "rules": [{"action": "set_cache_settings", "action_parameters": {"cache": false}}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": {"id": "rs2", "kind": "zone", "phase": "http_request_cache_settings", "rules": []}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let rules: Vec<cfad::api::page_rules::PageRule> = serde_json::from_value(serde_json::json!([
+        create_page_rule_json(
+            "pr1",
+            2,
+            "example.com/old",
+            serde_json::json!([{"id": "forwarding_url", "value": {"url": "https://example.com/new", "status_code": 301}}])
+        ),
+        create_page_rule_json(
+            "pr2",
+            1,
+            "example.com/api/*",
+            serde_json::json!([{"id": "cache_level", "value": "bypass"}])
+        )
+    ]))
+    .unwrap();
+    let plan = page_rules::plan_migration(&rules);
+
+    let client = create_test_client(&mock_server).await;
+    let added = page_rules::apply_migration(&client, "zone123", &plan)
+        .await
+        .unwrap();
+    assert_eq!(added, 2);
+}
+
+#[tokio::test]
+async fn test_apply_migration_twice_skips_migrated_rules() {
+    let mock_server = MockServer::start().await;
+
+    // Both phases already hold the rules from an earlier --apply
+    for (phase, action, page_rule) in [
+        (
+            "http_request_dynamic_redirect",
+            "redirect",
+            "pr1: example.com/old",
+        ),
+        (
+            "http_request_cache_settings",
+            "set_cache_settings",
+            "pr2: example.com/api/*",
+        ),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/zones/zone123/rulesets/phases/{}/entrypoint",
+                phase
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "errors": [],
+                "messages": [],
+                "result": {
+                    "id": "rs1",
+                    "kind": "zone",
+                    "phase": phase,
+                    "rules": [{
+                        "id": "migrated",
+                        "action": action,
+                        "expression": "true",
+                        "description": format!("Migrated from page rule {}", page_rule),
+                        "enabled": true
+                    }]
+                }
+            })))
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let rules: Vec<cfad::api::page_rules::PageRule> = serde_json::from_value(serde_json::json!([
+        create_page_rule_json(
+            "pr1",
+            2,
+            "example.com/old",
+            serde_json::json!([{"id": "forwarding_url", "value": {"url": "https://example.com/new", "status_code": 301}}])
+        ),
+        create_page_rule_json(
+            "pr2",
+            1,
+            "example.com/api/*",
+            serde_json::json!([{"id": "cache_level", "value": "bypass"}])
+        )
+    ]))
+    .unwrap();
+    let plan = page_rules::plan_migration(&rules);
+
+    let client = create_test_client(&mock_server).await;
+    let added = page_rules::apply_migration(&client, "zone123", &plan)
+        .await
+        .unwrap();
+    assert_eq!(added, 0);
+}
@@ -0,0 +1,177 @@
+use cfad::api::queues::ConsumerSettings;
+use cfad::client::CloudflareClient;
+use cfad::config::AuthMethod;
+use cfad::ops::queues;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
+    let auth = AuthMethod::ApiToken("test_token".to_string());
+    CloudflareClient::new_with_base_url(auth, mock_server.uri()).unwrap()
+}
+
+fn envelope(result: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "success": true,
+        "errors": [],
+        "messages": [],
+        "result": result
+    })
+}
+
+#[tokio::test]
+async fn test_create_queue() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/queues"))
+        .and(body_json(
+            serde_json::json!({"queue_name": "image-uploads"}),
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "queue_id": "4e1ca4a8d3c94b2f9a8b1f6f2c0e7d11",
+                "queue_name": "image-uploads"
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let queue = queues::create_queue(&client, "acc1", "image-uploads")
+        .await
+        .unwrap();
+    assert_eq!(queue.queue_id, "4e1ca4a8d3c94b2f9a8b1f6f2c0e7d11");
+
+    assert!(queues::create_queue(&client, "acc1", "Image_Uploads")
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_create_worker_consumer_with_batch_settings() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/queues/q1/consumers"))
+        .and(body_json(serde_json::json!({
+            "type": "worker",
+            "script_name": "thumbnailer",
+            "dead_letter_queue": "image-uploads-dlq",
+            "settings": {"batch_size": 25, "max_wait_time_ms": 5000}
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "consumer_id": "c1",
+                "type": "worker",
+                "script": "thumbnailer",
+                "settings": {"batch_size": 25, "max_wait_time_ms": 5000}
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let payload = queues::consumer_payload(
+        Some("thumbnailer".to_string()),
+        Some("image-uploads-dlq".to_string()),
+        ConsumerSettings {
+            batch_size: Some(25),
+            max_wait_time_ms: Some(5000),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let consumer = queues::create_consumer(&client, "acc1", "q1", payload)
+        .await
+        .unwrap();
+    assert_eq!(consumer.target(), "thumbnailer");
+}
+
+#[tokio::test]
+async fn test_send_json_message() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/queues/q1/messages"))
+        .and(body_json(serde_json::json!({
+            "body": {"key": "cat.png"},
+            "content_type": "json"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!(null))))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    queues::send_message(&client, "acc1", "q1", r#"{"key":"cat.png"}"#, true)
+        .await
+        .unwrap();
+    assert!(
+        queues::send_message(&client, "acc1", "q1", "not json", true)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_pull_and_ack_messages() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/queues/q1/messages/pull"))
+        .and(body_json(serde_json::json!({"batch_size": 2})))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "message_backlog_count": 7,
+                "messages": [
+                    {"id": "m1", "lease_id": "lease1", "body": "hello", "attempts": 1},
+                    {"id": "m2", "lease_id": "lease2", "body": "world", "attempts": 2}
+                ]
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/queues/q1/messages/ack"))
+        .and(body_json(serde_json::json!({
+            "acks": [{"lease_id": "lease1"}],
+            "retries": [{"lease_id": "lease2", "delay_seconds": 30}]
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "ackCount": 1,
+                "retryCount": 1,
+                "warnings": []
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let pulled = queues::pull_messages(&client, "acc1", "q1", 2, None)
+        .await
+        .unwrap();
+    assert_eq!(pulled.messages.len(), 2);
+    assert_eq!(pulled.message_backlog_count, Some(7));
+
+    let result = queues::ack_messages(
+        &client,
+        "acc1",
+        "q1",
+        &["lease1".to_string()],
+        &["lease2".to_string()],
+        Some(30),
+    )
+    .await
+    .unwrap();
+    assert_eq!((result.ack_count, result.retry_count), (1, 1));
+
+    assert!(queues::pull_messages(&client, "acc1", "q1", 0, None)
+        .await
+        .is_err());
+}
@@ -0,0 +1,374 @@
+use cfad::api::rulesets::{RuleInput, RulePosition, RulesetScope, UpdateEntrypoint};
+use cfad::client::CloudflareClient;
+use cfad::config::AuthMethod;
+use cfad::ops::rulesets;
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
+    let auth = AuthMethod::ApiToken("test_token".to_string());
+    CloudflareClient::new_with_base_url(auth, mock_server.uri()).unwrap()
+}
+
+fn create_ruleset_json(rules: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "id": "rs123",
+        "name": "default",
+        "kind": "zone",
+        "phase": "http_request_firewall_custom",
+        "version": "3",
+        "last_updated": "2026-01-01T00:00:00Z",
+        "rules": rules
+    })
+}
+
+fn create_rule_json(id: &str, expression: &str) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "version": "1",
+        "action": "block",
+        "expression": expression,
+        "description": "Block it",
+        "enabled": true
+    })
+}
+
+fn zone_scope() -> RulesetScope {
+    RulesetScope::Zone("zone123".to_string())
+}
+
+#[tokio::test]
+async fn test_list_rulesets_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/rulesets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": [{
+                "id": "rs123",
+                "name": "default",
+                "kind": "zone",
+                "phase": "http_request_firewall_custom"
+            }],
+            "errors": [],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = rulesets::list_rulesets(&client, &zone_scope())
+        .await
+        .unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].phase, "http_request_firewall_custom");
+    assert!(result[0].rules.is_empty());
+}
+
+#[tokio::test]
+async fn test_list_rulesets_account_scope() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc123/rulesets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": [],
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let scope = RulesetScope::Account("acc123".to_string());
+    let result = rulesets::list_rulesets(&client, &scope).await.unwrap();
+
+    assert!(result.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_entrypoint_missing_is_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_firewall_custom/entrypoint",
+        ))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "success": false,
+            "errors": [{"code": 10003, "message": "could not find entrypoint ruleset"}],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result =
+        rulesets::get_entrypoint(&client, &zone_scope(), "http_request_firewall_custom").await;
+
+    assert!(matches!(
+        result,
+        Err(cfad::error::CfadError::NotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_add_rule_posts_to_existing_entrypoint() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_firewall_custom/entrypoint",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": create_ruleset_json(serde_json::json!([create_rule_json("r1", "true")])),
+            "errors": [],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/rulesets/rs123/rules"))
+        .and(body_partial_json(serde_json::json!({
+            "action": "block",
+            "expression": "(ip.src eq 192.0.2.1)",
+            "position": {"before": "r1"}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": create_ruleset_json(serde_json::json!([
+                create_rule_json("r2", "(ip.src eq 192.0.2.1)"),
+                create_rule_json("r1", "true")
+            ])),
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let rule = RuleInput {
+        action: "block".to_string(),
+        expression: "(ip.src eq 192.0.2.1)".to_string(),
+        position: Some(RulePosition {
+            before: Some("r1".to_string()),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let ruleset = rulesets::add_rule(&client, &zone_scope(), "http_request_firewall_custom", rule)
+        .await
+        .unwrap();
+
+    assert_eq!(ruleset.rules.len(), 2);
+    assert_eq!(ruleset.rules[0].id.as_deref(), Some("r2"));
+}
+
+#[tokio::test]
+async fn test_add_rule_creates_entrypoint_when_missing() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_origin/entrypoint",
+        ))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "success": false,
+            "errors": [{"code": 10003, "message": "not found"}],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_origin/entrypoint",
+        ))
+        .and(body_partial_json(serde_json::json!({
+            "rules": [{"action": "route", "expression": "true"}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": create_ruleset_json(serde_json::json!([create_rule_json("r1", "true")])),
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let rule = RuleInput {
+        action: "route".to_string(),
+        expression: "true".to_string(),
+        ..Default::default()
+    };
+    let result = rulesets::add_rule(&client, &zone_scope(), "http_request_origin", rule).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_update_rule_merges_existing_definition() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_firewall_custom/entrypoint",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": create_ruleset_json(serde_json::json!([create_rule_json("r1", "true")])),
+            "errors": [],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path("/zones/zone123/rulesets/rs123/rules/r1"))
+        .and(body_partial_json(serde_json::json!({
+            "action": "block",
+            "expression": "true",
+            "description": "Block it",
+            "enabled": false
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": create_ruleset_json(serde_json::json!([create_rule_json("r1", "true")])),
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = rulesets::update_rule(
+        &client,
+        &zone_scope(),
+        "http_request_firewall_custom",
+        "r1",
+        |rule| rule.enabled = Some(false),
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_update_rule_unknown_id_is_not_found() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_firewall_custom/entrypoint",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": create_ruleset_json(serde_json::json!([create_rule_json("r1", "true")])),
+            "errors": [],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = rulesets::reorder_rule(
+        &client,
+        &zone_scope(),
+        "http_request_firewall_custom",
+        "missing",
+        RulePosition {
+            index: Some(1),
+            ..Default::default()
+        },
+    )
+    .await;
+
+    assert!(matches!(
+        result,
+        Err(cfad::error::CfadError::NotFound { .. })
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_rule_success() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_firewall_custom/entrypoint",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": create_ruleset_json(serde_json::json!([create_rule_json("r1", "true")])),
+            "errors": [],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/zones/zone123/rulesets/rs123/rules/r1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": create_ruleset_json(serde_json::json!([])),
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result =
+        rulesets::delete_rule(&client, &zone_scope(), "http_request_firewall_custom", "r1").await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_put_entrypoint_replaces_rules() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path(
+            "/zones/zone123/rulesets/phases/http_request_cache_settings/entrypoint",
+        ))
+        .and(body_partial_json(serde_json::json!({
+            "description": "cache rules",
+            "rules": [{"action": "set_cache_settings", "expression": "true"}]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": create_ruleset_json(serde_json::json!([create_rule_json("r1", "true")])),
+            "errors": [],
+            "messages": []
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let update = UpdateEntrypoint {
+        description: Some("cache rules".to_string()),
+        rules: vec![RuleInput {
+            action: "set_cache_settings".to_string(),
+            expression: "true".to_string(),
+            ..Default::default()
+        }],
+    };
+    let result = rulesets::put_entrypoint(
+        &client,
+        &zone_scope(),
+        "http_request_cache_settings",
+        update,
+    )
+    .await;
+
+    assert!(result.is_ok());
+}
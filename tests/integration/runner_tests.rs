@@ -9,7 +9,7 @@ use cfad::cli;
 use cfad::client::CloudflareClient;
 use cfad::config::AuthMethod;
 use cfad::runner;
-use wiremock::matchers::{method, path};
+use wiremock::matchers::{body_partial_json, method, path, path_regex};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
 async fn mock_client(mock_server: &MockServer) -> CloudflareClient {
@@ -20,6 +20,18 @@ async fn mock_client(mock_server: &MockServer) -> CloudflareClient {
     .unwrap()
 }
 
+/// Mount the queue listing used to resolve R2 notification queue names
+async fn mount_queue_list(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/queues"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"queue_id": "q1", "queue_name": "uploads"}]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
 fn zone_body() -> serde_json::Value {
     serde_json::json!({
         "id": "zone123abc",
@@ -77,7 +89,8 @@ async fn test_handle_dns_list_dispatches() {
 
     let client = mock_client(&mock_server).await;
     let cmd = cli::dns::DnsCommand::List {
-        zone: "example.com".to_string(),
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
         r#type: None,
         name: None,
     };
@@ -385,7 +398,8 @@ async fn test_handle_zone_update_dispatches_all_settings() {
 
     let client = mock_client(&mock_server).await;
     let cmd = cli::zone::ZoneCommand::Update {
-        zone: "example.com".to_string(),
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
         security_level: Some("high".to_string()),
         cache_level: Some("aggressive".to_string()),
         dev_mode: Some("on".to_string()),
@@ -420,9 +434,15 @@ async fn test_handle_cache_purge_all_dispatches() {
 
     let client = mock_client(&mock_server).await;
     let cmd = cli::cache::CacheCommand::Purge {
-        zone: "example.com".to_string(),
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
         all: true,
         files: None,
+        from_file: None,
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec![],
         tags: None,
         hosts: None,
         prefixes: None,
@@ -445,9 +465,15 @@ async fn test_handle_cache_purge_no_options_errors() {
 
     let client = mock_client(&mock_server).await;
     let cmd = cli::cache::CacheCommand::Purge {
-        zone: "example.com".to_string(),
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
         all: false,
         files: None,
+        from_file: None,
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec![],
         tags: None,
         hosts: None,
         prefixes: None,
@@ -455,6 +481,350 @@ async fn test_handle_cache_purge_no_options_errors() {
     assert!(runner::handle_cache_command(&client, cmd).await.is_err());
 }
 
+#[tokio::test]
+async fn test_handle_cache_settings_set_dispatches() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+    for (endpoint, id) in [
+        ("cache/cache_reserve", "cache_reserve"),
+        ("argo/tiered_caching", "tiered_caching"),
+    ] {
+        Mock::given(method("PATCH"))
+            .and(path(format!("/zones/zone123abc/{}", endpoint)))
+            .and(body_partial_json(serde_json::json!({"value": "off"})))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true, "errors": [], "messages": [],
+                "result": {"id": id, "value": "off", "editable": true}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::cache::CacheCommand::Settings(cli::cache::CacheSettingsCommand::Set {
+        zone: "example.com".to_string(),
+        tiered_caching: Some("off".to_string()),
+        smart_topology: None,
+        cache_reserve: Some("off".to_string()),
+        argo: None,
+    });
+    assert!(runner::handle_cache_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_cache_settings_clear_reserve_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123abc/cache/cache_reserve_clear"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::cache::CacheCommand::Settings(cli::cache::CacheSettingsCommand::ClearReserve {
+        zone: "example.com".to_string(),
+        status: false,
+        confirm: false,
+        no_wait: true,
+        timeout: 60,
+    });
+    assert!(runner::handle_cache_command(&client, cmd).await.is_err());
+}
+
+fn fleet_zone_body(id: &str, name: &str) -> serde_json::Value {
+    let mut zone = zone_body();
+    zone["id"] = serde_json::json!(id);
+    zone["name"] = serde_json::json!(name);
+    zone
+}
+
+async fn mount_fleet_zones(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [
+                fleet_zone_body("zoneA", "shop-a.com"),
+                fleet_zone_body("zoneB", "shop-b.com"),
+                fleet_zone_body("zoneC", "blog.com"),
+            ]
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_handle_cache_purge_zone_selector_fans_out() {
+    let mock_server = MockServer::start().await;
+    mount_fleet_zones(&mock_server).await;
+    for zone_id in ["zoneA", "zoneB"] {
+        Mock::given(method("POST"))
+            .and(path(format!("/zones/{}/purge_cache", zone_id)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true, "errors": [], "messages": [],
+                "result": {"id": zone_id}
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::cache::CacheCommand::Purge {
+        zone: None,
+        selector: cli::zone::ZoneSelector {
+            zones: Some(vec!["shop-*".to_string()]),
+            concurrency: 2,
+            ..Default::default()
+        },
+        all: true,
+        files: None,
+        from_file: None,
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec![],
+        tags: None,
+        hosts: None,
+        prefixes: None,
+    };
+    assert!(runner::handle_cache_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_cache_purge_zone_selector_reports_failed_zone() {
+    let mock_server = MockServer::start().await;
+    mount_fleet_zones(&mock_server).await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zoneA/purge_cache"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {"id": "zoneA"}
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zoneB/purge_cache"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(serde_json::json!({
+            "success": false, "errors": [{"code": 10000, "message": "forbidden"}], "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zoneC/purge_cache"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {"id": "zoneC"}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::cache::CacheCommand::Purge {
+        zone: None,
+        selector: cli::zone::ZoneSelector {
+            all_zones: true,
+            concurrency: 4,
+            ..Default::default()
+        },
+        all: true,
+        files: None,
+        from_file: None,
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec![],
+        tags: None,
+        hosts: None,
+        prefixes: None,
+    };
+    let err = runner::handle_cache_command(&client, cmd)
+        .await
+        .unwrap_err();
+    assert_eq!(err.to_string(), "1 of 3 zones failed");
+}
+
+fn url_purge_cmd(urls: &[&str]) -> cli::cache::CacheCommand {
+    cli::cache::CacheCommand::Purge {
+        zone: None,
+        selector: Default::default(),
+        all: false,
+        files: Some(urls.iter().map(|u| u.to_string()).collect()),
+        from_file: None,
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec![],
+        tags: None,
+        hosts: None,
+        prefixes: None,
+    }
+}
+
+#[tokio::test]
+async fn test_handle_cache_purge_files_routes_urls_to_zones() {
+    let mock_server = MockServer::start().await;
+    mount_fleet_zones(&mock_server).await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zoneA/purge_cache"))
+        .and(body_partial_json(serde_json::json!({
+            "files": ["https://www.shop-a.com/x", "https://shop-a.com/y"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": {"id": "zoneA"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zoneC/purge_cache"))
+        .and(body_partial_json(serde_json::json!({
+            "files": ["https://blog.com/post"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": {"id": "zoneC"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = url_purge_cmd(&[
+        "https://www.shop-a.com/x",
+        "https://blog.com/post",
+        "https://shop-a.com/y",
+    ]);
+    assert!(runner::handle_cache_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_cache_purge_files_flags_unrouted_urls() {
+    let mock_server = MockServer::start().await;
+    mount_fleet_zones(&mock_server).await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zoneB/purge_cache"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": {"id": "zoneB"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = url_purge_cmd(&["https://shop-b.com/", "https://elsewhere.net/a"]);
+    let err = runner::handle_cache_command(&client, cmd)
+        .await
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("1 URLs belong to no zone in this account"));
+}
+
+#[tokio::test]
+async fn test_handle_dns_list_zones_file_dispatches() {
+    let mock_server = MockServer::start().await;
+    mount_fleet_zones(&mock_server).await;
+    Mock::given(method("GET"))
+        .and(path("/zones/zoneC/dns_records"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [dns_record_body()]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let dir = std::env::temp_dir().join(format!("cfad-zones-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("zones.txt");
+    std::fs::write(&file, "# fleet\nblog.com\n\n").unwrap();
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::dns::DnsCommand::List {
+        zone: None,
+        selector: cli::zone::ZoneSelector {
+            zones_file: Some(file.to_string_lossy().to_string()),
+            concurrency: 4,
+            ..Default::default()
+        },
+        r#type: None,
+        name: None,
+    };
+    let result = runner::handle_dns_command(&client, cmd).await;
+    std::fs::remove_dir_all(&dir).ok();
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_zone_update_selector_empty_list_errors() {
+    let mock_server = MockServer::start().await;
+    mount_fleet_zones(&mock_server).await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::zone::ZoneCommand::Update {
+        zone: None,
+        selector: cli::zone::ZoneSelector {
+            zones: Some(vec![]),
+            concurrency: 4,
+            ..Default::default()
+        },
+        security_level: Some("high".to_string()),
+        cache_level: None,
+        dev_mode: None,
+        ipv6: None,
+        ssl: None,
+        always_https: None,
+    };
+    assert!(runner::handle_zone_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_zone_update_selector_by_status_dispatches() {
+    let mock_server = MockServer::start().await;
+    mount_fleet_zones(&mock_server).await;
+    Mock::given(method("PATCH"))
+        .and(path_regex(r"^/zones/zone[ABC]/settings/ssl$"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {"id": "ssl", "value": "strict"}
+        })))
+        .expect(3)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::zone::ZoneCommand::Update {
+        zone: None,
+        selector: cli::zone::ZoneSelector {
+            zone_status: Some("active".to_string()),
+            concurrency: 4,
+            ..Default::default()
+        },
+        security_level: None,
+        cache_level: None,
+        dev_mode: None,
+        ipv6: None,
+        ssl: Some("strict".to_string()),
+        always_https: None,
+    };
+    assert!(runner::handle_zone_command(&client, cmd).await.is_ok());
+}
+
 // ------------------ D1 handler coverage ------------------
 
 fn d1_db_body(uuid: &str, name: &str) -> serde_json::Value {
@@ -584,6 +954,7 @@ async fn test_handle_d1_query_dispatches() {
         account_id: Some("acc1".to_string()),
         database_id: "db-1".to_string(),
         sql: "SELECT 1".to_string(),
+        params: Default::default(),
         raw: false,
         format: "table".to_string(),
     };
@@ -692,9 +1063,15 @@ async fn test_handle_cache_purge_files_dispatches() {
 
     let client = mock_client(&mock_server).await;
     let cmd = cli::cache::CacheCommand::Purge {
-        zone: "example.com".to_string(),
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
         all: false,
         files: Some(vec!["https://example.com/a.js".to_string()]),
+        from_file: None,
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec![],
         tags: None,
         hosts: None,
         prefixes: None,
@@ -703,7 +1080,7 @@ async fn test_handle_cache_purge_files_dispatches() {
 }
 
 #[tokio::test]
-async fn test_handle_cache_purge_tags_dispatches() {
+async fn test_handle_cache_purge_from_file_dispatches() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
         .and(path("/zones"))
@@ -714,25 +1091,42 @@ async fn test_handle_cache_purge_tags_dispatches() {
         .await;
     Mock::given(method("POST"))
         .and(path("/zones/zone123abc/purge_cache"))
+        .and(body_partial_json(serde_json::json!({
+            "files": ["https://example.com/a.js", "https://example.com/b.css"]
+        })))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
             "success": true, "errors": [], "messages": [], "result": {"id": "zone123abc"}
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
+
+    let urls = write_temp_file(
+        "# changed in deploy\nhttps://example.com/a.js\n\nhttps://example.com/b.css\n",
+        "txt",
+    );
     let client = mock_client(&mock_server).await;
     let cmd = cli::cache::CacheCommand::Purge {
-        zone: "example.com".to_string(),
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
         all: false,
         files: None,
-        tags: Some(vec!["tag1".to_string()]),
+        from_file: Some(urls.to_string_lossy().to_string()),
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec![],
+        tags: None,
         hosts: None,
         prefixes: None,
     };
-    assert!(runner::handle_cache_command(&client, cmd).await.is_ok());
+    let result = runner::handle_cache_command(&client, cmd).await;
+    std::fs::remove_file(&urls).ok();
+    assert!(result.is_ok());
 }
 
 #[tokio::test]
-async fn test_handle_cache_purge_hosts_dispatches() {
+async fn test_handle_cache_purge_changed_files_dispatches() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
         .and(path("/zones"))
@@ -743,25 +1137,44 @@ async fn test_handle_cache_purge_hosts_dispatches() {
         .await;
     Mock::given(method("POST"))
         .and(path("/zones/zone123abc/purge_cache"))
+        .and(body_partial_json(serde_json::json!({
+            "files": [
+                "https://example.com/blog/index.html",
+                "https://example.com/blog/",
+                "https://example.com/blog",
+                "https://example.com/css/site.css"
+            ]
+        })))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
             "success": true, "errors": [], "messages": [], "result": {"id": "zone123abc"}
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
+
+    let manifest = write_temp_file("blog/index.html\ncss/site.css\n", "txt");
     let client = mock_client(&mock_server).await;
     let cmd = cli::cache::CacheCommand::Purge {
-        zone: "example.com".to_string(),
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
         all: false,
         files: None,
+        from_file: None,
+        sitemap: None,
+        changed_files: Some(manifest.to_string_lossy().to_string()),
+        base_url: Some("https://example.com/".to_string()),
+        headers: vec![],
         tags: None,
-        hosts: Some(vec!["example.com".to_string()]),
+        hosts: None,
         prefixes: None,
     };
-    assert!(runner::handle_cache_command(&client, cmd).await.is_ok());
+    let result = runner::handle_cache_command(&client, cmd).await;
+    std::fs::remove_file(&manifest).ok();
+    assert!(result.is_ok());
 }
 
 #[tokio::test]
-async fn test_handle_cache_purge_prefixes_dispatches() {
+async fn test_handle_cache_purge_files_with_headers_dispatches() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
         .and(path("/zones"))
@@ -772,50 +1185,206 @@ async fn test_handle_cache_purge_prefixes_dispatches() {
         .await;
     Mock::given(method("POST"))
         .and(path("/zones/zone123abc/purge_cache"))
+        .and(body_partial_json(serde_json::json!({
+            "files": [
+                {"url": "https://example.com/", "headers": {"CF-Device-Type": "mobile"}},
+                {"url": "https://example.com/", "headers": {"CF-Device-Type": "tablet"}},
+                {"url": "https://example.com/api", "headers": {"Origin": "https://app.example.com"}}
+            ]
+        })))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
             "success": true, "errors": [], "messages": [], "result": {"id": "zone123abc"}
         })))
+        .expect(1)
         .mount(&mock_server)
         .await;
+
+    let list = write_temp_file(
+        r#"["https://example.com/",
+            {"url": "https://example.com/api", "headers": {"Origin": "https://app.example.com"}}]"#,
+        "json",
+    );
     let client = mock_client(&mock_server).await;
     let cmd = cli::cache::CacheCommand::Purge {
-        zone: "example.com".to_string(),
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
         all: false,
         files: None,
+        from_file: Some(list.to_string_lossy().to_string()),
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec![
+            "CF-Device-Type: mobile".to_string(),
+            "CF-Device-Type: tablet".to_string(),
+        ],
         tags: None,
         hosts: None,
-        prefixes: Some(vec!["/api/".to_string()]),
+        prefixes: None,
     };
-    assert!(runner::handle_cache_command(&client, cmd).await.is_ok());
+    let result = runner::handle_cache_command(&client, cmd).await;
+    std::fs::remove_file(&list).ok();
+    assert!(result.is_ok());
 }
 
-// ------------------ D1 extended handler coverage ------------------
+#[tokio::test]
+async fn test_handle_cache_purge_header_requires_url_purge() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::cache::CacheCommand::Purge {
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
+        all: false,
+        files: None,
+        from_file: None,
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec!["Origin: https://app.example.com".to_string()],
+        tags: Some(vec!["tag1".to_string()]),
+        hosts: None,
+        prefixes: None,
+    };
+    let err = runner::handle_cache_command(&client, cmd)
+        .await
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("--header only applies to URL purges"));
+}
 
 #[tokio::test]
-async fn test_handle_d1_update_dispatches() {
+async fn test_handle_cache_purge_tags_dispatches() {
     let mock_server = MockServer::start().await;
     Mock::given(method("GET"))
-        .and(path("/accounts/acc1/d1/database"))
+        .and(path("/zones"))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "success": true, "errors": [], "messages": [], "result": [d1_db_body("db-1", "my-db")]
+            "success": true, "errors": [], "messages": [], "result": [zone_body()]
         })))
         .mount(&mock_server)
         .await;
-    Mock::given(method("PUT"))
-        .and(path("/accounts/acc1/d1/database/db-1"))
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123abc/purge_cache"))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
-            "success": true, "errors": [], "messages": [], "result": d1_db_body("db-1", "renamed")
+            "success": true, "errors": [], "messages": [], "result": {"id": "zone123abc"}
         })))
         .mount(&mock_server)
         .await;
     let client = mock_client(&mock_server).await;
-    let cmd = cli::d1::D1Command::Update {
-        account_id: Some("acc1".to_string()),
-        database_id: "db-1".to_string(),
-        name: Some("renamed".to_string()),
-    };
-    assert!(runner::handle_d1_command(&client, cmd).await.is_ok());
-}
+    let cmd = cli::cache::CacheCommand::Purge {
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
+        all: false,
+        files: None,
+        from_file: None,
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec![],
+        tags: Some(vec!["tag1".to_string()]),
+        hosts: None,
+        prefixes: None,
+    };
+    assert!(runner::handle_cache_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_cache_purge_hosts_dispatches() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123abc/purge_cache"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": {"id": "zone123abc"}
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::cache::CacheCommand::Purge {
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
+        all: false,
+        files: None,
+        from_file: None,
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec![],
+        tags: None,
+        hosts: Some(vec!["example.com".to_string()]),
+        prefixes: None,
+    };
+    assert!(runner::handle_cache_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_cache_purge_prefixes_dispatches() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123abc/purge_cache"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": {"id": "zone123abc"}
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::cache::CacheCommand::Purge {
+        zone: Some("example.com".to_string()),
+        selector: Default::default(),
+        all: false,
+        files: None,
+        from_file: None,
+        sitemap: None,
+        changed_files: None,
+        base_url: None,
+        headers: vec![],
+        tags: None,
+        hosts: None,
+        prefixes: Some(vec!["/api/".to_string()]),
+    };
+    assert!(runner::handle_cache_command(&client, cmd).await.is_ok());
+}
+
+// ------------------ D1 extended handler coverage ------------------
+
+#[tokio::test]
+async fn test_handle_d1_update_dispatches() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/d1/database"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": [d1_db_body("db-1", "my-db")]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/d1/database/db-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": d1_db_body("db-1", "renamed")
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::d1::D1Command::Update {
+        account_id: Some("acc1".to_string()),
+        database_id: "db-1".to_string(),
+        name: Some("renamed".to_string()),
+    };
+    assert!(runner::handle_d1_command(&client, cmd).await.is_ok());
+}
 
 #[tokio::test]
 async fn test_handle_d1_delete_confirmed_dispatches() {
@@ -866,6 +1435,7 @@ async fn test_handle_d1_query_raw_dispatches() {
         account_id: Some("acc1".to_string()),
         database_id: "db-1".to_string(),
         sql: "SELECT 1".to_string(),
+        params: Default::default(),
         raw: true,
         format: "table".to_string(),
     };
@@ -895,12 +1465,76 @@ async fn test_handle_d1_query_json_format_dispatches() {
         account_id: Some("acc1".to_string()),
         database_id: "db-1".to_string(),
         sql: "SELECT 1".to_string(),
+        params: Default::default(),
+        raw: false,
+        format: "json".to_string(),
+    };
+    assert!(runner::handle_d1_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_d1_query_binds_params() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/d1/database"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": [d1_db_body("db-1", "my-db")]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/d1/database/db-1/query"))
+        .and(body_partial_json(serde_json::json!({
+            "sql": "SELECT * FROM users WHERE id = ? AND email = ? AND deleted_at IS ?",
+            "params": [42, "ada@example.com", null]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{ "success": true, "results": [], "meta": {} }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::d1::D1Command::Query {
+        account_id: Some("acc1".to_string()),
+        database_id: "db-1".to_string(),
+        sql: "SELECT * FROM users WHERE id = ? AND email = ? AND deleted_at IS ?".to_string(),
+        params: cli::d1::D1QueryParamsArgs {
+            params: vec![
+                "int:42".to_string(),
+                "ada@example.com".to_string(),
+                "null".to_string(),
+            ],
+            params_json: None,
+        },
         raw: false,
         format: "json".to_string(),
     };
     assert!(runner::handle_d1_command(&client, cmd).await.is_ok());
 }
 
+#[tokio::test]
+async fn test_handle_d1_query_rejects_param_count_mismatch() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::d1::D1Command::Query {
+        account_id: Some("acc1".to_string()),
+        database_id: "db-1".to_string(),
+        sql: "SELECT * FROM users WHERE id = ? AND email = ?".to_string(),
+        params: cli::d1::D1QueryParamsArgs {
+            params: Vec::new(),
+            params_json: Some("[42]".to_string()),
+        },
+        raw: false,
+        format: "json".to_string(),
+    };
+    let err = runner::handle_d1_command(&client, cmd).await.unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("2 placeholder(s) but 1 parameter(s)"));
+}
+
 #[tokio::test]
 async fn test_handle_d1_bookmark_dispatches() {
     let mock_server = MockServer::start().await;
@@ -961,18 +1595,92 @@ async fn test_handle_d1_export_dispatches() {
         .await;
     Mock::given(method("POST"))
         .and(path("/accounts/acc1/d1/database/db-1/export"))
+        .and(body_partial_json(
+            serde_json::json!({"output_format": "polling"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": { "status": "active", "at_bookmark": "bm-1", "messages": ["Generating dump"] }
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/d1/database/db-1/export"))
+        .and(body_partial_json(serde_json::json!({
+            "current_bookmark": "bm-1",
+            "dump_options": {"no_data": true, "tables": ["users"]}
+        })))
         .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
             "success": true, "errors": [], "messages": [],
-            "result": { "task_id": "t1", "status": "processing", "signed_url": null }
+            "result": {
+                "status": "complete", "at_bookmark": "bm-1",
+                "result": {"filename": "db-1.sql", "signed_url": format!("{}/dumps/db-1.sql", mock_server.uri())}
+            }
         })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/dumps/db-1.sql"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string("CREATE TABLE users (id INTEGER);\n"),
+        )
         .mount(&mock_server)
         .await;
+
+    let output = write_temp_file("stale", "sql");
     let client = mock_client(&mock_server).await;
     let cmd = cli::d1::D1Command::Export {
         account_id: Some("acc1".to_string()),
         database_id: "db-1".to_string(),
+        output: output.to_string_lossy().into_owned(),
+        no_data: true,
+        no_schema: false,
+        tables: vec!["users".to_string()],
+        timeout: 30,
     };
-    assert!(runner::handle_d1_command(&client, cmd).await.is_ok());
+    let result = runner::handle_d1_command(&client, cmd).await;
+    let dump = std::fs::read_to_string(&output).unwrap();
+    std::fs::remove_file(&output).ok();
+    assert!(result.is_ok());
+    assert_eq!(dump, "CREATE TABLE users (id INTEGER);\n");
+}
+
+#[tokio::test]
+async fn test_handle_d1_export_reports_failed_export() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/d1/database"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": [d1_db_body("db-1", "my-db")]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/d1/database/db-1/export"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": { "status": "error", "error": "database is too large" }
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let output = std::env::temp_dir().join(format!("cfad-d1-failed-{}.sql", std::process::id()));
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::d1::D1Command::Export {
+        account_id: Some("acc1".to_string()),
+        database_id: "db-1".to_string(),
+        output: output.to_string_lossy().into_owned(),
+        no_data: false,
+        no_schema: false,
+        tables: vec![],
+        timeout: 30,
+    };
+    let err = runner::handle_d1_command(&client, cmd).await.unwrap_err();
+    assert!(err.to_string().contains("database is too large"));
+    assert!(!output.exists());
 }
 
 // ------------------ R2 sub-command handler coverage ------------------
@@ -1561,6 +2269,7 @@ async fn test_handle_r2_sippy_disable_dispatches() {
 #[tokio::test]
 async fn test_handle_r2_notifications_show_dispatches() {
     let mock_server = MockServer::start().await;
+    mount_queue_list(&mock_server).await;
     Mock::given(method("GET"))
         .and(path(
             "/accounts/acc1/event_notifications/r2/b1/configuration/queues/q1",
@@ -1583,6 +2292,7 @@ async fn test_handle_r2_notifications_show_dispatches() {
 #[tokio::test]
 async fn test_handle_r2_notifications_delete_confirmed_dispatches() {
     let mock_server = MockServer::start().await;
+    mount_queue_list(&mock_server).await;
     Mock::given(method("DELETE"))
         .and(path(
             "/accounts/acc1/event_notifications/r2/b1/configuration/queues/q1",
@@ -2010,6 +2720,7 @@ async fn test_handle_r2_sippy_enable_dispatches() {
 #[tokio::test]
 async fn test_handle_r2_notifications_create_dispatches() {
     let mock_server = MockServer::start().await;
+    mount_queue_list(&mock_server).await;
     Mock::given(method("PUT"))
         .and(path(
             "/accounts/acc1/event_notifications/r2/b1/configuration/queues/q1",
@@ -2198,6 +2909,7 @@ async fn test_handle_d1_query_file_dispatches() {
         account_id: Some("acc1".to_string()),
         database_id: "db-1".to_string(),
         file: sql_file.to_string_lossy().into_owned(),
+        params: Default::default(),
         raw: false,
         format: "json".to_string(),
     };
@@ -2229,6 +2941,7 @@ async fn test_handle_d1_import_dispatches() {
         account_id: Some("acc1".to_string()),
         database_id: "db-1".to_string(),
         file: sql_file.to_string_lossy().into_owned(),
+        timeout: 1800,
     };
     assert!(runner::handle_d1_command(&client, cmd).await.is_ok());
     let _ = std::fs::remove_file(sql_file);
@@ -2655,3 +3368,1353 @@ async fn test_r2_create_temp_credentials_missing_result_errors() {
     let res = cfad::ops::r2::create_temp_credentials(&client, "acc1", req).await;
     assert!(res.is_err());
 }
+
+// ------------------ Rules handler coverage ------------------
+
+fn entrypoint_body() -> serde_json::Value {
+    serde_json::json!({
+        "id": "rs123",
+        "kind": "zone",
+        "phase": "http_request_firewall_custom",
+        "rules": [{"id": "r1", "action": "block", "expression": "true", "enabled": true}]
+    })
+}
+
+#[tokio::test]
+async fn test_handle_rules_waf_list_dispatches() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/zones/zone123abc/rulesets/phases/http_request_firewall_custom/entrypoint",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": entrypoint_body()
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::rules::RulesCommand::Waf(cli::rules::PhaseRuleCommand::List {
+        target: cli::rules::RulesetTarget {
+            zone: Some("example.com".to_string()),
+            ..Default::default()
+        },
+    });
+    assert!(runner::handle_rules_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_rules_add_invalid_action_parameters_errors() {
+    let mock_server = MockServer::start().await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::rules::RulesCommand::Redirect(cli::rules::PhaseRuleCommand::Add {
+        target: cli::rules::RulesetTarget {
+            zone: Some("example.com".to_string()),
+            ..Default::default()
+        },
+        expression: "true".to_string(),
+        action: "redirect".to_string(),
+        action_parameters: Some("{not json".to_string()),
+        ratelimit: None,
+        description: None,
+        disabled: false,
+        no_validate: false,
+        position: Default::default(),
+    });
+    assert!(runner::handle_rules_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_rules_add_invalid_expression_skips_api() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(zone_body()))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::rules::RulesCommand::Waf(cli::rules::PhaseRuleCommand::Add {
+        target: cli::rules::RulesetTarget {
+            zone: Some("example.com".to_string()),
+            ..Default::default()
+        },
+        expression: r#"http.request.uri.pth eq "/admin""#.to_string(),
+        action: "block".to_string(),
+        action_parameters: None,
+        ratelimit: None,
+        description: None,
+        disabled: false,
+        no_validate: false,
+        position: Default::default(),
+    });
+    let err = runner::handle_rules_command(&client, cmd)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("Unknown field"));
+}
+
+#[tokio::test]
+async fn test_handle_rules_delete_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::rules::RulesCommand::Waf(cli::rules::PhaseRuleCommand::Delete {
+        target: cli::rules::RulesetTarget {
+            zone: Some("example.com".to_string()),
+            ..Default::default()
+        },
+        rule_id: "r1".to_string(),
+        confirm: false,
+    });
+    assert!(runner::handle_rules_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_rules_reorder_requires_position() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::rules::RulesCommand::Cache(cli::rules::PhaseRuleCommand::Reorder {
+        target: cli::rules::RulesetTarget {
+            zone: Some("example.com".to_string()),
+            ..Default::default()
+        },
+        rule_id: "r1".to_string(),
+        position: Default::default(),
+    });
+    assert!(runner::handle_rules_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_rules_apply_yaml_puts_each_phase() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+    for phase in [
+        "http_request_firewall_custom",
+        "http_request_cache_settings",
+    ] {
+        Mock::given(method("PUT"))
+            .and(path(format!(
+                "/zones/zone123abc/rulesets/phases/{}/entrypoint",
+                phase
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true, "errors": [], "messages": [],
+                "result": entrypoint_body()
+            })))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+
+    let dir = std::env::temp_dir().join(format!("cfad-rules-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("rules.yaml");
+    std::fs::write(
+        &file,
+        r#"rulesets:
+  - phase: waf
+    rules:
+      - description: Block bad bots
+        expression: (cf.client.bot)
+        action: block
+  - phase: http_request_cache_settings
+    rules:
+      - expression: (http.request.uri.path contains "/static/")
+        action: set_cache_settings
+        action_parameters:
+          cache: true
+"#,
+    )
+    .unwrap();
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::rules::RulesCommand::Apply {
+        file: file.to_string_lossy().to_string(),
+        target: cli::rules::RulesetTarget {
+            zone: Some("example.com".to_string()),
+            ..Default::default()
+        },
+        dry_run: false,
+        no_validate: false,
+    };
+    let result = runner::handle_rules_command(&client, cmd).await;
+    std::fs::remove_dir_all(&dir).ok();
+    assert!(result.is_ok());
+}
+
+// ------------------ Firewall / lists handler coverage ------------------
+
+fn lists_body() -> serde_json::Value {
+    serde_json::json!({
+        "success": true, "errors": [], "messages": [],
+        "result": [{"id": "l1", "name": "blocklist", "kind": "ip", "num_items": 1}]
+    })
+}
+
+fn completed_operation_body() -> serde_json::Value {
+    serde_json::json!({
+        "success": true, "errors": [], "messages": [],
+        "result": {"id": "op1", "status": "completed"}
+    })
+}
+
+#[tokio::test]
+async fn test_handle_access_rule_add_zone() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123abc/firewall/access_rules/rules"))
+        .and(body_partial_json(serde_json::json!({
+            "mode": "block",
+            "configuration": {"target": "ip6", "value": "2001:db8::1"}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {
+                "id": "r1",
+                "mode": "block",
+                "configuration": {"target": "ip6", "value": "2001:db8::1"}
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::firewall::FirewallCommand::AccessRules(cli::firewall::AccessRuleCommand::Add {
+        target: cli::rules::RulesetTarget {
+            zone: Some("example.com".to_string()),
+            ..Default::default()
+        },
+        mode: "block".to_string(),
+        matcher: cli::firewall::AccessRuleTargetArgs {
+            ip: Some("2001:db8::1".to_string()),
+            ..Default::default()
+        },
+        notes: None,
+    });
+    assert!(runner::handle_firewall_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_access_rule_delete_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd =
+        cli::firewall::FirewallCommand::AccessRules(cli::firewall::AccessRuleCommand::Delete {
+            target: cli::rules::RulesetTarget {
+                zone: Some("example.com".to_string()),
+                ..Default::default()
+            },
+            rule_id: "r1".to_string(),
+            confirm: false,
+        });
+    assert!(runner::handle_firewall_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_lists_add_from_file_replaces_items() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/rules/lists"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(lists_body()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/rules/lists/l1/items"))
+        .and(body_partial_json(serde_json::json!([
+            {"ip": "192.0.2.1"},
+            {"ip": "198.51.100.0/24", "comment": "scanner"}
+        ])))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {"operation_id": "op1"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/rules/lists/bulk_operations/op1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completed_operation_body()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let dir = std::env::temp_dir().join(format!("cfad-lists-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("blocklist.csv");
+    std::fs::write(&file, "ip,comment\n192.0.2.1,\n198.51.100.0/24,scanner\n").unwrap();
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::lists::ListsCommand::Add {
+        account_id: Some("acc1".to_string()),
+        list: "blocklist".to_string(),
+        values: vec![],
+        file: Some(file.to_string_lossy().to_string()),
+        comment: None,
+        replace: true,
+        no_wait: false,
+    };
+    let result = runner::handle_lists_command(&client, cmd).await;
+    std::fs::remove_dir_all(&dir).ok();
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_lists_add_values_with_comma_in_comment() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/rules/lists"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(lists_body()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/rules/lists/l1/items"))
+        .and(body_partial_json(serde_json::json!([
+            {"ip": "192.0.2.1", "comment": "scanner, tor"}
+        ])))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {"operation_id": "op1"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/rules/lists/bulk_operations/op1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(completed_operation_body()))
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::lists::ListsCommand::Add {
+        account_id: Some("acc1".to_string()),
+        list: "blocklist".to_string(),
+        values: vec!["192.0.2.1".to_string()],
+        file: None,
+        comment: Some("scanner, tor".to_string()),
+        replace: false,
+        no_wait: false,
+    };
+    assert!(runner::handle_lists_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_lists_remove_by_value() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/rules/lists"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(lists_body()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/rules/lists/l1/items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"id": "i1", "ip": "192.0.2.1"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/accounts/acc1/rules/lists/l1/items"))
+        .and(body_partial_json(
+            serde_json::json!({"items": [{"id": "i1"}]}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {"operation_id": "op1"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::lists::ListsCommand::Remove {
+        account_id: Some("acc1".to_string()),
+        list: "l1".to_string(),
+        items: vec!["192.0.2.1".to_string()],
+        no_wait: true,
+    };
+    assert!(runner::handle_lists_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_lists_remove_unknown_item_errors() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/rules/lists"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(lists_body()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/rules/lists/l1/items"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::lists::ListsCommand::Remove {
+        account_id: Some("acc1".to_string()),
+        list: "blocklist".to_string(),
+        items: vec!["203.0.113.9".to_string()],
+        no_wait: true,
+    };
+    assert!(runner::handle_lists_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_page_rules_delete_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::zone::ZoneCommand::PageRules(cli::zone::PageRuleCommand::Delete {
+        zone: "example.com".to_string(),
+        rule_id: "pr1".to_string(),
+        confirm: false,
+    });
+    assert!(runner::handle_zone_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_page_rules_migrate_plan_only() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123abc/pagerules"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": [{
+                "id": "pr1",
+                "targets": [{"target": "url", "constraint": {"operator": "matches", "value": "example.com/*"}}],
+                "actions": [{"id": "ssl", "value": "full"}, {"id": "disable_security"}],
+                "priority": 1,
+                "status": "active"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::zone::ZoneCommand::PageRules(cli::zone::PageRuleCommand::Migrate {
+        zone: "example.com".to_string(),
+        apply: false,
+    });
+    assert!(runner::handle_zone_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_custom_hostnames_delete_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::zone::ZoneCommand::CustomHostnames(cli::zone::CustomHostnameCommand::Delete {
+        zone: "example.com".to_string(),
+        hostname: "app.customer.com".to_string(),
+        confirm: false,
+    });
+    assert!(runner::handle_zone_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_custom_hostnames_import_csv() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123abc/custom_hostnames"))
+        .and(body_partial_json(
+            serde_json::json!({"ssl": {"method": "txt"}}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": {"id": "ch1", "hostname": "app.acme.com"}
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+
+    let dir = std::env::temp_dir().join(format!("cfad-ch-import-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let file = dir.join("customers.csv");
+    std::fs::write(
+        &file,
+        "hostname,custom_origin_server,customer\napp.acme.com,origin.example.com,acme\nshop.globex.com,,globex\n",
+    )
+    .unwrap();
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::zone::ZoneCommand::CustomHostnames(cli::zone::CustomHostnameCommand::Import {
+        zone: "example.com".to_string(),
+        file: file.to_string_lossy().to_string(),
+        ssl_method: "txt".to_string(),
+        ssl_type: "dv".to_string(),
+    });
+    let result = runner::handle_zone_command(&client, cmd).await;
+    std::fs::remove_dir_all(&dir).ok();
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_origin_cert_revoke_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::ssl::SslCommand::OriginCert(cli::ssl::OriginCertCommand::Revoke {
+        cert_id: "cert1".to_string(),
+        confirm: false,
+    });
+    assert!(runner::handle_ssl_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_origin_cert_create_writes_private_files() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/certificates"))
+        .and(body_partial_json(serde_json::json!({
+            "hostnames": ["example.com", "*.example.com"],
+            "request_type": "origin-rsa",
+            "requested_validity": 365
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": {
+                "id": "cert1",
+                "hostnames": ["example.com", "*.example.com"],
+                "certificate": "-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----\n",
+                "expires_on": "2026-01-01 00:00:00 +0000 UTC"
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let dir = std::env::temp_dir().join(format!("cfad-origin-cert-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::ssl::SslCommand::OriginCert(cli::ssl::OriginCertCommand::Create {
+        hostnames: vec!["example.com".to_string(), "*.example.com".to_string()],
+        validity: 365,
+        key_type: "rsa".to_string(),
+        out_dir: dir.clone(),
+        key_file: None,
+        cert_file: None,
+        force: false,
+    });
+    let result = runner::handle_ssl_command(&client, cmd).await;
+
+    let key_path = dir.join("example.com.key");
+    let cert_path = dir.join("example.com.pem");
+    let key_mode = std::fs::metadata(&key_path).map(|m| m.permissions().mode());
+    let cert = std::fs::read_to_string(&cert_path).unwrap_or_default();
+    let key = std::fs::read_to_string(&key_path).unwrap_or_default();
+    std::fs::remove_dir_all(&dir).ok();
+
+    assert!(result.is_ok());
+    assert_eq!(key_mode.unwrap() & 0o777, 0o600);
+    assert!(key.contains("PRIVATE KEY"));
+    assert!(cert.contains("BEGIN CERTIFICATE"));
+}
+
+#[tokio::test]
+async fn test_handle_origin_cert_create_refuses_existing_files() {
+    let mock_server = MockServer::start().await;
+    let dir = std::env::temp_dir().join(format!("cfad-origin-cert-exists-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(dir.join("example.com.key"), "existing").unwrap();
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::ssl::SslCommand::OriginCert(cli::ssl::OriginCertCommand::Create {
+        hostnames: vec!["example.com".to_string()],
+        validity: 5475,
+        key_type: "ecdsa".to_string(),
+        out_dir: dir.clone(),
+        key_file: None,
+        cert_file: None,
+        force: false,
+    });
+    let result = runner::handle_ssl_command(&client, cmd).await;
+    let key = std::fs::read_to_string(dir.join("example.com.key")).unwrap();
+    std::fs::remove_dir_all(&dir).ok();
+
+    assert!(result.is_err());
+    assert_eq!(key, "existing");
+}
+
+#[tokio::test]
+async fn test_handle_ssl_status_prints_overview() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123abc/settings"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": [{"id": "ssl", "value": "strict"}]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path_regex(
+            r"^/zones/zone123abc/(ssl/universal|origin_tls_client_auth)/settings$",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": {"enabled": true}
+        })))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123abc/ssl/certificate_packs"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": [{
+                "id": "pack1",
+                "type": "universal",
+                "hosts": ["example.com"],
+                "status": "active",
+                "certificates": [{"id": "c1", "expires_on": "2020-01-01T00:00:00Z"}]
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::ssl::SslCommand::Status {
+        zone: "example.com".to_string(),
+    };
+    assert!(runner::handle_ssl_command(&client, cmd).await.is_ok());
+}
+
+async fn mount_audit_mocks(mock_server: &MockServer, min_tls: &str) {
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "errors": [],
+            "messages": [],
+            "result": [zone_body()]
+        })))
+        .mount(mock_server)
+        .await;
+
+    let responses = [
+        (
+            "/zones/zone123abc/settings",
+            serde_json::json!([
+                {"id": "ssl", "value": "strict"},
+                {"id": "always_use_https", "value": "on"},
+                {"id": "min_tls_version", "value": min_tls},
+                {"id": "development_mode", "value": "off"}
+            ]),
+        ),
+        (
+            "/zones/zone123abc/dns_records",
+            serde_json::json!([
+                {"id": "r1", "name": "example.com", "type": "CAA", "content": "0 issue \"pki.goog\"",
+                 "ttl": 1, "proxied": false},
+                {"id": "r2", "name": "example.com", "type": "TXT", "content": "v=spf1 -all",
+                 "ttl": 1, "proxied": false},
+                {"id": "r3", "name": "_dmarc.example.com", "type": "TXT", "content": "v=DMARC1; p=reject",
+                 "ttl": 1, "proxied": false}
+            ]),
+        ),
+        (
+            "/zones/zone123abc/dnssec",
+            serde_json::json!({"status": "disabled"}),
+        ),
+        (
+            "/zones/zone123abc/ssl/certificate_packs",
+            serde_json::json!([{
+                "id": "pack1",
+                "status": "active",
+                "certificates": [{"id": "c1", "expires_on": "2099-01-01T00:00:00Z"}]
+            }]),
+        ),
+    ];
+    for (endpoint, result) in responses {
+        Mock::given(method("GET"))
+            .and(path(endpoint))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "success": true,
+                "errors": [],
+                "messages": [],
+                "result": result
+            })))
+            .mount(mock_server)
+            .await;
+    }
+}
+
+fn audit_command(report: cli::zone::AuditReportArgs) -> cli::zone::ZoneCommand {
+    cli::zone::ZoneCommand::Audit {
+        zone: Some("example.com".to_string()),
+        selector: cli::zone::ZoneSelector::default(),
+        report,
+    }
+}
+
+#[tokio::test]
+async fn test_handle_zone_audit_writes_reports() {
+    let mock_server = MockServer::start().await;
+    mount_audit_mocks(&mock_server, "1.2").await;
+
+    let json_path = write_temp_file("", "json");
+    let junit_path = write_temp_file("", "xml");
+    let client = mock_client(&mock_server).await;
+    let cmd = audit_command(cli::zone::AuditReportArgs {
+        json: Some(json_path.to_string_lossy().to_string()),
+        junit: Some(junit_path.to_string_lossy().to_string()),
+        fail_on: "fail".to_string(),
+        min_score: 0,
+    });
+    let result = runner::handle_zone_command(&client, cmd).await;
+
+    let json = std::fs::read_to_string(&json_path).unwrap();
+    let junit = std::fs::read_to_string(&junit_path).unwrap();
+    std::fs::remove_file(&json_path).ok();
+    std::fs::remove_file(&junit_path).ok();
+
+    // Only DNSSEC warns, which does not reach the default --fail-on level
+    assert!(result.is_ok());
+    let audits: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(audits[0]["zone"], "example.com");
+    assert_eq!(audits[0]["score"], 95);
+    assert!(junit.contains("<testsuite name=\"example.com\" tests=\"10\" failures=\"0\">"));
+}
+
+#[tokio::test]
+async fn test_handle_zone_audit_gates_on_failures() {
+    let mock_server = MockServer::start().await;
+    mount_audit_mocks(&mock_server, "1.0").await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = audit_command(cli::zone::AuditReportArgs {
+        json: None,
+        junit: None,
+        fail_on: "fail".to_string(),
+        min_score: 0,
+    });
+    assert!(runner::handle_zone_command(&client, cmd).await.is_err());
+
+    let cmd = audit_command(cli::zone::AuditReportArgs {
+        json: None,
+        junit: None,
+        fail_on: "never".to_string(),
+        min_score: 0,
+    });
+    assert!(runner::handle_zone_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_lb_pool_delete_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::lb::LbCommand::Pools(cli::lb::PoolCommand::Delete {
+        account_id: Some("acc1".to_string()),
+        pool: "primary".to_string(),
+        confirm: false,
+    });
+    assert!(runner::handle_lb_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_lb_balancer_create_resolves_pool_names() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/load_balancers/pools"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [
+                {"id": "pool1", "name": "primary", "origins": []},
+                {"id": "pool2", "name": "secondary", "origins": []}
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123abc/load_balancers"))
+        .and(body_partial_json(serde_json::json!({
+            "name": "app.example.com",
+            "default_pools": ["pool1", "pool2"],
+            "fallback_pool": "pool2",
+            "proxied": true
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {"id": "lb1", "name": "app.example.com"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::lb::LbCommand::Balancers(cli::lb::BalancerCommand::Create {
+        zone: "example.com".to_string(),
+        hostname: "app.example.com".to_string(),
+        default_pools: vec!["primary".to_string(), "secondary".to_string()],
+        options: cli::lb::BalancerArgs {
+            account_id: Some("acc1".to_string()),
+            ..Default::default()
+        },
+    });
+    runner::handle_lb_command(&client, cmd).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_handle_tunnel_delete_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::tunnel::TunnelCommand::Delete {
+        account_id: Some("acc1".to_string()),
+        tunnel: "prod-web".to_string(),
+        cleanup_connections: false,
+        confirm: false,
+    };
+    assert!(runner::handle_tunnel_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_tunnel_route_dns_infers_zone() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/cfd_tunnel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"id": "6ff42ae2-765d-4adf-8112-31c55c1551ef", "name": "prod-web"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [zone_body()]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123abc/dns_records"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": []
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123abc/dns_records"))
+        .and(body_partial_json(serde_json::json!({
+            "type": "CNAME",
+            "content": "6ff42ae2-765d-4adf-8112-31c55c1551ef.cfargotunnel.com",
+            "proxied": true
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": dns_record_body()
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::tunnel::TunnelCommand::Route(cli::tunnel::TunnelRouteCommand::Dns {
+        account_id: Some("acc1".to_string()),
+        tunnel: "prod-web".to_string(),
+        hostname: "app.example.com".to_string(),
+        zone: None,
+        overwrite: false,
+    });
+    runner::handle_tunnel_command(&client, cmd).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_handle_tunnel_config_put_dry_run_validates_only() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/cfd_tunnel"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"id": "t1", "name": "prod-web"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let file = write_temp_file(
+        "ingress:\n  - hostname: app.example.com\n    service: http://localhost:8080\n  - service: http_status:404\n",
+        "yml",
+    );
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::tunnel::TunnelCommand::Config(cli::tunnel::TunnelConfigCommand::Put {
+        account_id: Some("acc1".to_string()),
+        tunnel: "prod-web".to_string(),
+        file: file.to_string_lossy().to_string(),
+        dry_run: true,
+    });
+    let result = runner::handle_tunnel_command(&client, cmd).await;
+    std::fs::remove_file(&file).ok();
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_workers_delete_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::workers::WorkersCommand::Delete {
+        account_id: Some("acc1".to_string()),
+        script: "api".to_string(),
+        force: false,
+        confirm: false,
+    };
+    assert!(runner::handle_workers_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_workers_deploy_dry_run_skips_upload() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let module = write_temp_file("export default { fetch() {} }", "js");
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::workers::WorkersCommand::Deploy {
+        account_id: Some("acc1".to_string()),
+        name: "api".to_string(),
+        module: module.to_string_lossy().to_string(),
+        additional_modules: vec![],
+        compat_date: Some("2026-03-01".to_string()),
+        compat_flags: vec![],
+        bindings: vec!["var:ENV=production".to_string()],
+        dry_run: true,
+    };
+    let result = runner::handle_workers_command(&client, cmd).await;
+    std::fs::remove_file(&module).ok();
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_workers_cron_add_appends_to_existing() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/workers/scripts/api/schedules"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {"schedules": [{"cron": "0 3 * * *"}]}
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/workers/scripts/api/schedules"))
+        .and(body_partial_json(serde_json::json!([
+            {"cron": "0 3 * * *"},
+            {"cron": "*/5 * * * *"}
+        ])))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {"schedules": []}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::workers::WorkersCommand::Cron(cli::workers::WorkerCronCommand::Add {
+        account_id: Some("acc1".to_string()),
+        script: "api".to_string(),
+        cron: "*/5 * * * *".to_string(),
+    });
+    runner::handle_workers_command(&client, cmd).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_handle_kv_namespace_delete_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::kv::KvCommand::Namespace(cli::kv::KvNamespaceCommand::Delete {
+        account_id: Some("acc1".to_string()),
+        namespace: "SESSIONS".to_string(),
+        confirm: false,
+    });
+    assert!(runner::handle_kv_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_kv_key_delete_resolves_namespace_title() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/storage/kv/namespaces"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"id": "0f2ac74b498b48028cb68387c421e279", "title": "SESSIONS"}],
+            "result_info": {"page": 1, "per_page": 100, "count": 1, "total_count": 1, "total_pages": 1}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(
+            "/accounts/acc1/storage/kv/namespaces/0f2ac74b498b48028cb68387c421e279/values/user%3A42",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::kv::KvCommand::Key(cli::kv::KvKeyCommand::Delete {
+        account_id: Some("acc1".to_string()),
+        namespace: "SESSIONS".to_string(),
+        key: "user:42".to_string(),
+    });
+    assert!(runner::handle_kv_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_kv_bulk_put_rejects_invalid_file() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let file = write_temp_file(r#"{"key": "a", "value": "b"}"#, "json");
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::kv::KvCommand::Bulk(cli::kv::KvBulkCommand::Put {
+        account_id: Some("acc1".to_string()),
+        namespace: "0f2ac74b498b48028cb68387c421e279".to_string(),
+        file: file.to_string_lossy().to_string(),
+    });
+    let result = runner::handle_kv_command(&client, cmd).await;
+    std::fs::remove_file(&file).ok();
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_workers_secret_put_from_env() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/workers/scripts/api/secrets"))
+        .and(body_partial_json(serde_json::json!({
+            "name": "API_TOKEN",
+            "text": "from-env-value"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": {}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    std::env::set_var("CFAD_TEST_SECRET_PUT", "from-env-value");
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::workers::WorkersCommand::Secret(cli::workers::WorkerSecretCommand::Put {
+        account_id: Some("acc1".to_string()),
+        script: "api".to_string(),
+        name: "API_TOKEN".to_string(),
+        from_file: None,
+        from_env: Some("CFAD_TEST_SECRET_PUT".to_string()),
+    });
+    let result = runner::handle_workers_command(&client, cmd).await;
+    std::env::remove_var("CFAD_TEST_SECRET_PUT");
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_workers_secret_bulk_removal_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/workers/scripts/api/secrets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"name": "OLD", "type": "secret_text"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let file = write_temp_file("NEW=value\n", "env");
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::workers::WorkersCommand::Secret(cli::workers::WorkerSecretCommand::Bulk {
+        account_id: Some("acc1".to_string()),
+        script: "api".to_string(),
+        file: file.to_string_lossy().to_string(),
+        dry_run: false,
+        confirm: false,
+    });
+    let result = runner::handle_workers_command(&client, cmd).await;
+    std::fs::remove_file(&file).ok();
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_queues_delete_requires_confirm() {
+    let mock_server = MockServer::start().await;
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::queues::QueuesCommand::Delete {
+        account_id: Some("acc1".to_string()),
+        queue: "uploads".to_string(),
+        confirm: false,
+    };
+    assert!(runner::handle_queues_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_queues_send_resolves_queue_name() {
+    let mock_server = MockServer::start().await;
+    mount_queue_list(&mock_server).await;
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/queues/q1/messages"))
+        .and(body_partial_json(
+            serde_json::json!({"content_type": "text"}),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::queues::QueuesCommand::Send {
+        account_id: Some("acc1".to_string()),
+        queue: "uploads".to_string(),
+        body: "hello".to_string(),
+        json: false,
+    };
+    assert!(runner::handle_queues_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_queues_consumer_delete_by_script_name() {
+    let mock_server = MockServer::start().await;
+    mount_queue_list(&mock_server).await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/queues/q1/consumers"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"consumer_id": "c1", "type": "worker", "script": "thumbnailer"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/accounts/acc1/queues/q1/consumers/c1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [], "result": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::queues::QueuesCommand::Consumer(cli::queues::QueueConsumerCommand::Delete {
+        account_id: Some("acc1".to_string()),
+        queue: "uploads".to_string(),
+        consumer: "thumbnailer".to_string(),
+        confirm: true,
+    });
+    assert!(runner::handle_queues_command(&client, cmd).await.is_ok());
+}
+
+#[tokio::test]
+async fn test_handle_queues_consumer_update_keeps_other_settings() {
+    let mock_server = MockServer::start().await;
+    mount_queue_list(&mock_server).await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/queues/q1/consumers"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{
+                "consumer_id": "c1", "type": "worker", "script": "thumbnailer",
+                "settings": {"batch_size": 50, "max_wait_time_ms": 5000}
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/queues/q1/consumers/c1"))
+        .and(body_partial_json(serde_json::json!({
+            "settings": {"batch_size": 50, "max_wait_time_ms": 5000, "max_retries": 5}
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": {"consumer_id": "c1", "type": "worker", "script": "thumbnailer"}
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::queues::QueuesCommand::Consumer(cli::queues::QueueConsumerCommand::Update {
+        account_id: Some("acc1".to_string()),
+        queue: "uploads".to_string(),
+        consumer: "thumbnailer".to_string(),
+        settings: cli::queues::ConsumerSettingsArgs {
+            batch_size: None,
+            max_retries: Some(5),
+            max_wait_ms: None,
+            max_concurrency: None,
+            retry_delay: None,
+            visibility_timeout_ms: None,
+            dead_letter_queue: None,
+        },
+    });
+    runner::handle_queues_command(&client, cmd).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_handle_queues_consumer_update_without_id_fails() {
+    let mock_server = MockServer::start().await;
+    mount_queue_list(&mock_server).await;
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/queues/q1/consumers"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true, "errors": [], "messages": [],
+            "result": [{"type": "worker", "script": "thumbnailer"}]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::queues::QueuesCommand::Consumer(cli::queues::QueueConsumerCommand::Update {
+        account_id: Some("acc1".to_string()),
+        queue: "uploads".to_string(),
+        consumer: "thumbnailer".to_string(),
+        settings: cli::queues::ConsumerSettingsArgs {
+            batch_size: Some(10),
+            max_retries: None,
+            max_wait_ms: None,
+            max_concurrency: None,
+            retry_delay: None,
+            visibility_timeout_ms: None,
+            dead_letter_queue: None,
+        },
+    });
+    assert!(runner::handle_queues_command(&client, cmd).await.is_err());
+}
+
+#[tokio::test]
+async fn test_handle_r2_notifications_create_unknown_queue_fails() {
+    let mock_server = MockServer::start().await;
+    mount_queue_list(&mock_server).await;
+    Mock::given(method("PUT"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = mock_client(&mock_server).await;
+    let cmd = cli::r2::R2Command::Notifications(cli::r2::R2NotificationCommand::Create {
+        account_id: Some("acc1".to_string()),
+        bucket: "b1".to_string(),
+        queue_id: "missing-queue".to_string(),
+        events: vec!["object:create".to_string()],
+        prefix: None,
+        suffix: None,
+    });
+    assert!(runner::handle_r2_command(&client, cmd).await.is_err());
+}
@@ -0,0 +1,320 @@
+use cfad::client::CloudflareClient;
+use cfad::config::AuthMethod;
+use cfad::ops::tunnel;
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TUNNEL_ID: &str = "6ff42ae2-765d-4adf-8112-31c55c1551ef";
+
+async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
+    let auth = AuthMethod::ApiToken("test_token".to_string());
+    CloudflareClient::new_with_base_url(auth, mock_server.uri()).unwrap()
+}
+
+fn envelope(result: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "success": true,
+        "errors": [],
+        "messages": [],
+        "result": result
+    })
+}
+
+fn tunnel_json() -> serde_json::Value {
+    serde_json::json!({
+        "id": TUNNEL_ID,
+        "name": "prod-web",
+        "status": "healthy",
+        "config_src": "cloudflare",
+        "created_at": "2026-01-01T00:00:00Z",
+        "connections": [{"colo_name": "DFW", "id": "conn1"}]
+    })
+}
+
+async fn mount_tunnels(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/cfd_tunnel"))
+        .and(query_param("is_deleted", "false"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([tunnel_json()]))),
+        )
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_get_tunnel_by_name() {
+    let mock_server = MockServer::start().await;
+    mount_tunnels(&mock_server).await;
+
+    let client = create_test_client(&mock_server).await;
+    let found = tunnel::get_tunnel(&client, "acc1", "prod-web")
+        .await
+        .unwrap();
+    assert_eq!(found.id, TUNNEL_ID);
+    assert_eq!(found.connections[0].colo_name.as_deref(), Some("DFW"));
+
+    assert!(tunnel::get_tunnel(&client, "acc1", "staging")
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_create_remotely_managed_tunnel() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/accounts/acc1/cfd_tunnel"))
+        .and(body_json(serde_json::json!({
+            "name": "prod-web",
+            "config_src": "cloudflare"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(tunnel_json())))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let created = tunnel::create_tunnel(&client, "acc1", "prod-web", true)
+        .await
+        .unwrap();
+    assert!(created.is_remotely_managed());
+}
+
+#[tokio::test]
+async fn test_delete_tunnel_cleans_up_connections() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/accounts/acc1/cfd_tunnel/{}/connections",
+            TUNNEL_ID
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!(null))))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path(format!("/accounts/acc1/cfd_tunnel/{}", TUNNEL_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(tunnel_json())))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    assert!(tunnel::delete_tunnel(&client, "acc1", TUNNEL_ID, true)
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_get_tunnel_token() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/accounts/acc1/cfd_tunnel/{}/token",
+            TUNNEL_ID
+        )))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!("eyJhIjoi"))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let token = tunnel::get_tunnel_token(&client, "acc1", TUNNEL_ID)
+        .await
+        .unwrap();
+    assert_eq!(token, "eyJhIjoi");
+}
+
+#[tokio::test]
+async fn test_list_connections() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/accounts/acc1/cfd_tunnel/{}/connections", TUNNEL_ID)))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([{
+                "id": "connector1",
+                "version": "2026.1.0",
+                "arch": "linux_amd64",
+                "conns": [
+                    {"colo_name": "DFW", "origin_ip": "198.51.100.7", "is_pending_reconnect": false},
+                    {"colo_name": "IAD", "origin_ip": "198.51.100.7", "is_pending_reconnect": true}
+                ]
+            }]))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let connectors = tunnel::list_connections(&client, "acc1", TUNNEL_ID)
+        .await
+        .unwrap();
+    assert_eq!(connectors[0].conns.len(), 2);
+    assert!(connectors[0].conns[1].is_pending_reconnect);
+}
+
+#[tokio::test]
+async fn test_put_tunnel_config_from_yaml() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/accounts/acc1/cfd_tunnel/{}/configurations",
+            TUNNEL_ID
+        )))
+        .and(body_json(serde_json::json!({
+            "config": {
+                "ingress": [
+                    {"hostname": "app.example.com", "service": "http://localhost:8080",
+                     "originRequest": {"noTLSVerify": true}},
+                    {"service": "http_status:404"}
+                ]
+            }
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "tunnel_id": TUNNEL_ID,
+                "version": 3
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let config = tunnel::parse_tunnel_config(
+        r#"
+tunnel: prod-web
+credentials-file: /etc/cloudflared/creds.json
+ingress:
+  - hostname: app.example.com
+    service: http://localhost:8080
+    originRequest:
+      noTLSVerify: true
+  - service: http_status:404
+"#,
+    )
+    .unwrap();
+    let updated = tunnel::put_tunnel_config(&client, "acc1", TUNNEL_ID, config)
+        .await
+        .unwrap();
+    assert_eq!(updated.version, Some(3));
+}
+
+#[tokio::test]
+async fn test_get_tunnel_config() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/accounts/acc1/cfd_tunnel/{}/configurations",
+            TUNNEL_ID
+        )))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "tunnel_id": TUNNEL_ID,
+                "version": 2,
+                "source": "cloudflare",
+                "config": {
+                    "ingress": [
+                        {"hostname": "app.example.com", "service": "http://localhost:8080"},
+                        {"service": "http_status:404"}
+                    ],
+                    "warp-routing": {"enabled": false}
+                }
+            }))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let config = tunnel::get_tunnel_config(&client, "acc1", TUNNEL_ID)
+        .await
+        .unwrap()
+        .config
+        .unwrap();
+    assert_eq!(config.ingress.len(), 2);
+    let yaml = serde_yaml::to_string(&config).unwrap();
+    assert!(yaml.contains("warp-routing"));
+    assert_eq!(tunnel::parse_tunnel_config(&yaml).unwrap(), config);
+}
+
+#[tokio::test]
+async fn test_route_dns_creates_proxied_cname() {
+    let mock_server = MockServer::start().await;
+    mount_tunnels(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/dns_records"))
+        .and(query_param("name", "app.example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([]))))
+        .mount(&mock_server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/dns_records"))
+        .and(body_json(serde_json::json!({
+            "type": "CNAME",
+            "name": "app.example.com",
+            "content": format!("{}.cfargotunnel.com", TUNNEL_ID),
+            "ttl": 1,
+            "proxied": true
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "id": "rec1",
+                "type": "CNAME",
+                "name": "app.example.com",
+                "content": format!("{}.cfargotunnel.com", TUNNEL_ID),
+                "proxied": true,
+                "ttl": 1
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let found = tunnel::get_tunnel(&client, "acc1", "prod-web")
+        .await
+        .unwrap();
+    let record = tunnel::route_dns(&client, "zone123", &found, "app.example.com", false)
+        .await
+        .unwrap();
+    assert_eq!(record.id, "rec1");
+}
+
+#[tokio::test]
+async fn test_route_dns_refuses_to_replace_without_overwrite() {
+    let mock_server = MockServer::start().await;
+    mount_tunnels(&mock_server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones/zone123/dns_records"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([{
+                "id": "rec1",
+                "type": "A",
+                "name": "app.example.com",
+                "content": "192.0.2.1",
+                "proxied": true,
+                "ttl": 1
+            }]))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let found = tunnel::get_tunnel(&client, "acc1", "prod-web")
+        .await
+        .unwrap();
+    let err = tunnel::route_dns(&client, "zone123", &found, "app.example.com", false)
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("--overwrite"));
+}
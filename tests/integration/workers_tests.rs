@@ -0,0 +1,328 @@
+use cfad::api::workers::WorkerMetadata;
+use cfad::client::CloudflareClient;
+use cfad::config::AuthMethod;
+use cfad::ops::workers::{self, ModuleFile};
+use wiremock::matchers::{
+    body_json, body_string_contains, header_regex, method, path, query_param,
+};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+async fn create_test_client(mock_server: &MockServer) -> CloudflareClient {
+    let auth = AuthMethod::ApiToken("test_token".to_string());
+    CloudflareClient::new_with_base_url(auth, mock_server.uri()).unwrap()
+}
+
+fn envelope(result: serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "success": true,
+        "errors": [],
+        "messages": [],
+        "result": result
+    })
+}
+
+#[tokio::test]
+async fn test_deploy_script_uploads_multipart() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/workers/scripts/api"))
+        .and(header_regex(
+            "content-type",
+            "^multipart/form-data; boundary=",
+        ))
+        .and(body_string_contains("name=\"metadata\""))
+        .and(body_string_contains(
+            r#""main_module":"index.js","compatibility_date":"2026-03-01""#,
+        ))
+        .and(body_string_contains(
+            r#"{"type":"kv_namespace","name":"CACHE","namespace_id":"ns1"}"#,
+        ))
+        .and(body_string_contains(
+            "filename=\"index.js\"\r\nContent-Type: application/javascript+module\r\n\r\nexport default {}",
+        ))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "id": "api",
+                "handlers": ["fetch"],
+                "etag": "abc"
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let metadata = WorkerMetadata {
+        main_module: "index.js".to_string(),
+        compatibility_date: Some("2026-03-01".to_string()),
+        bindings: vec![workers::parse_binding("kv:CACHE=ns1").unwrap()],
+        ..Default::default()
+    };
+    let modules = vec![ModuleFile {
+        name: "index.js".to_string(),
+        content_type: "application/javascript+module".to_string(),
+        data: b"export default {}".to_vec(),
+    }];
+    let script = workers::deploy_script(&client, "acc1", "api", metadata, modules)
+        .await
+        .unwrap();
+    assert_eq!(script.handlers, vec!["fetch"]);
+}
+
+#[tokio::test]
+async fn test_deploy_script_requires_main_module_first() {
+    let mock_server = MockServer::start().await;
+    let client = create_test_client(&mock_server).await;
+    let metadata = WorkerMetadata {
+        main_module: "index.js".to_string(),
+        ..Default::default()
+    };
+    let modules = vec![ModuleFile {
+        name: "util.js".to_string(),
+        content_type: "application/javascript+module".to_string(),
+        data: Vec::new(),
+    }];
+    assert!(
+        workers::deploy_script(&client, "acc1", "api", metadata, modules)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_get_script_settings_bindings() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/workers/scripts/api/settings"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "compatibility_date": "2026-03-01",
+                "compatibility_flags": ["nodejs_compat"],
+                "bindings": [
+                    {"type": "r2_bucket", "name": "ASSETS", "bucket_name": "assets"},
+                    {"type": "secret_text", "name": "API_KEY"}
+                ]
+            }))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let settings = workers::get_script_settings(&client, "acc1", "api")
+        .await
+        .unwrap();
+    assert_eq!(settings.bindings.len(), 2);
+    assert_eq!(settings.bindings[0].target(), "assets");
+    assert_eq!(settings.compatibility_flags, vec!["nodejs_compat"]);
+}
+
+#[tokio::test]
+async fn test_delete_script_force() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/accounts/acc1/workers/scripts/api"))
+        .and(query_param("force", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!(null))))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    assert!(workers::delete_script(&client, "acc1", "api", true)
+        .await
+        .is_ok());
+}
+
+#[tokio::test]
+async fn test_create_route() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("POST"))
+        .and(path("/zones/zone123/workers/routes"))
+        .and(body_json(serde_json::json!({
+            "pattern": "example.com/api/*",
+            "script": "api"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "id": "route1",
+                "pattern": "example.com/api/*",
+                "script": "api"
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let route = workers::create_route(&client, "zone123", "example.com/api/*", "api")
+        .await
+        .unwrap();
+    assert_eq!(route.id, "route1");
+
+    assert!(
+        workers::create_route(&client, "zone123", "example.com", "api")
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_attach_domain() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/workers/domains"))
+        .and(body_json(serde_json::json!({
+            "hostname": "api.example.com",
+            "service": "api",
+            "environment": "production",
+            "zone_id": "zone123"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "id": "dom1",
+                "hostname": "api.example.com",
+                "service": "api",
+                "zone_id": "zone123",
+                "zone_name": "example.com"
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let domain = workers::attach_domain(&client, "acc1", "api", "api.example.com", "zone123")
+        .await
+        .unwrap();
+    assert_eq!(domain.zone_name.as_deref(), Some("example.com"));
+}
+
+#[tokio::test]
+async fn test_list_domains_by_script() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/accounts/acc1/workers/domains"))
+        .and(query_param("service", "api"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!([{
+                "id": "dom1",
+                "hostname": "api.example.com",
+                "service": "api"
+            }]))),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let domains = workers::list_domains(&client, "acc1", Some("api"))
+        .await
+        .unwrap();
+    assert_eq!(domains.len(), 1);
+}
+
+#[tokio::test]
+async fn test_set_schedules() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/workers/scripts/api/schedules"))
+        .and(body_json(serde_json::json!([
+            {"cron": "*/15 * * * *"},
+            {"cron": "0 3 * * *"}
+        ])))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "schedules": [{"cron": "*/15 * * * *"}, {"cron": "0 3 * * *"}]
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let crons = vec!["*/15 * * * *".to_string(), "0 3 * * *".to_string()];
+    let schedules = workers::set_schedules(&client, "acc1", "api", &crons)
+        .await
+        .unwrap();
+    assert_eq!(schedules.len(), 2);
+
+    let invalid = vec!["every minute".to_string()];
+    assert!(workers::set_schedules(&client, "acc1", "api", &invalid)
+        .await
+        .is_err());
+}
+
+#[tokio::test]
+async fn test_put_secret() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/workers/scripts/api/secrets"))
+        .and(body_json(serde_json::json!({
+            "name": "API_TOKEN",
+            "text": "s3cret",
+            "type": "secret_text"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({
+                "name": "API_TOKEN",
+                "type": "secret_text"
+            }))),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    assert!(
+        workers::put_secret(&client, "acc1", "api", "API_TOKEN", "s3cret")
+            .await
+            .is_ok()
+    );
+    assert!(
+        workers::put_secret(&client, "acc1", "api", "API-TOKEN", "s3cret")
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_sync_secrets_uploads_and_removes() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/accounts/acc1/workers/scripts/api/secrets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!({}))))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/accounts/acc1/workers/scripts/api/secrets/OLD"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(envelope(serde_json::json!(null))))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let existing = vec![
+        cfad::api::workers::WorkerSecret {
+            name: "KEEP".to_string(),
+            r#type: Some("secret_text".to_string()),
+        },
+        cfad::api::workers::WorkerSecret {
+            name: "OLD".to_string(),
+            r#type: Some("secret_text".to_string()),
+        },
+    ];
+    let desired = workers::parse_dotenv("KEEP=1\nNEW=2\n").unwrap();
+    let plan = workers::plan_secret_sync(&existing, &desired);
+    workers::sync_secrets(&client, "acc1", "api", &desired, &plan)
+        .await
+        .unwrap();
+}
@@ -84,6 +84,22 @@ async fn test_list_zones_with_status_filter() {
     assert_eq!(zones[0].status, "active");
 }
 
+#[tokio::test]
+async fn test_list_zones_rejects_unknown_status() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .respond_with(ResponseTemplate::new(500))
+        .expect(0)
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let result = zone::list_zones(&client, Some("active&name=x")).await;
+
+    assert!(result.is_err());
+}
+
 #[tokio::test]
 async fn test_list_zones_empty() {
     let mock_server = MockServer::start().await;
@@ -415,3 +431,135 @@ async fn test_update_zone_settings_always_https() {
     let result = zone::update_zone_settings(&client, "zone123", settings).await;
     assert!(result.is_ok());
 }
+
+#[tokio::test]
+async fn test_list_zones_follows_pagination() {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": [create_zone_json("zone1", "example.com", "active")],
+            "result_info": {"page": 1, "per_page": 1, "count": 1, "total_count": 2, "total_pages": 2},
+            "errors": [],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": [create_zone_json("zone2", "test.com", "active")],
+            "result_info": {"page": 2, "per_page": 1, "count": 1, "total_count": 2, "total_pages": 2},
+            "errors": [],
+            "messages": []
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = create_test_client(&mock_server).await;
+    let zones = zone::list_zones(&client, None).await.unwrap();
+
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[1].name, "test.com");
+}
+
+async fn mount_zone_list(mock_server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "success": true,
+            "result": [
+                create_zone_json("zone1", "shop-eu.com", "active"),
+                create_zone_json("zone2", "shop-us.com", "active"),
+                create_zone_json("zone3", "blog.org", "active"),
+            ],
+            "errors": [],
+            "messages": []
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn test_select_zones_by_glob() {
+    let mock_server = MockServer::start().await;
+    mount_zone_list(&mock_server).await;
+
+    let client = create_test_client(&mock_server).await;
+    let zones = zone::select_zones(&client, &["shop-*".to_string()], None)
+        .await
+        .unwrap();
+
+    let names: Vec<&str> = zones.iter().map(|z| z.name.as_str()).collect();
+    assert_eq!(names, vec!["shop-eu.com", "shop-us.com"]);
+}
+
+#[tokio::test]
+async fn test_select_zones_without_patterns_returns_all() {
+    let mock_server = MockServer::start().await;
+    mount_zone_list(&mock_server).await;
+
+    let client = create_test_client(&mock_server).await;
+    let zones = zone::select_zones(&client, &[], None).await.unwrap();
+
+    assert_eq!(zones.len(), 3);
+}
+
+#[tokio::test]
+async fn test_select_zones_unknown_literal_name_errors() {
+    let mock_server = MockServer::start().await;
+    mount_zone_list(&mock_server).await;
+
+    let client = create_test_client(&mock_server).await;
+    let patterns = vec!["blog.org".to_string(), "missing.com".to_string()];
+    let result = zone::select_zones(&client, &patterns, None).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn test_for_each_zone_keeps_order_and_collects_failures() {
+    let mock_server = MockServer::start().await;
+    mount_zone_list(&mock_server).await;
+
+    let client = create_test_client(&mock_server).await;
+    let zones = zone::list_zones(&client, None).await.unwrap();
+    let outcomes = zone::for_each_zone(zones, 2, |z| async move {
+        if z.name == "shop-us.com" {
+            Err(cfad::error::CfadError::api("boom"))
+        } else {
+            Ok(z.name.len())
+        }
+    })
+    .await;
+
+    assert_eq!(outcomes.len(), 3);
+    assert_eq!(outcomes[0].zone.name, "shop-eu.com");
+    assert!(outcomes[0].result.is_ok());
+    assert!(outcomes[1].result.is_err());
+    assert_eq!(*outcomes[2].result.as_ref().unwrap(), "blog.org".len());
+}
+
+#[tokio::test]
+async fn test_zone_for_hostname_matches_parent_domain() {
+    let mock_server = MockServer::start().await;
+    mount_zone_list(&mock_server).await;
+
+    let client = create_test_client(&mock_server).await;
+    let zones = zone::list_zones(&client, None).await.unwrap();
+
+    assert_eq!(
+        zone::zone_for_hostname(&zones, "app.Shop-EU.com").map(|z| z.name.as_str()),
+        Some("shop-eu.com")
+    );
+    assert_eq!(
+        zone::zone_for_hostname(&zones, "blog.org").map(|z| z.name.as_str()),
+        Some("blog.org")
+    );
+    assert!(zone::zone_for_hostname(&zones, "notblog.org").is_none());
+}